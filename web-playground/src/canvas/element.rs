@@ -5,10 +5,16 @@
 //!     animation div (CSS animation - only if animated)
 //!       inner div (snippet HTML via dangerous_inner_html)
 //!
-//! Clicking toggles between html and html_active states.
+//! Clicking toggles between html and html_active states. Interactive
+//! snippets only react on their hotspots, each by its role, and report
+//! `"{id}:{hotspot}"` as the clicked id.
 //! Each element exposes its state via data-* attributes for DOM queries.
 
 use dioxus::prelude::*;
+use dioxus::web::WebEventExt;
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::pool::interactive::{self, HotspotRole};
 use crate::transform::PlacedElement;
 
 /// Renders a PlacedElement on the canvas
@@ -16,6 +22,8 @@ use crate::transform::PlacedElement;
 pub fn CanvasElement(
     placed: PlacedElement,
     on_click: EventHandler<String>,
+    /// The scene's target: gets `class="target"` and `data-target`
+    #[props(default)] is_target: bool,
 ) -> Element {
     let wrapper_style = placed.wrapper_style();
     let anim_style = placed.animation_style();
@@ -35,8 +43,17 @@ pub fn CanvasElement(
     let animation = placed.animation.describe();
     let description = placed.describe();
     let has_animation = !placed.animation.is_none();
+    let hotspots = placed.snippet.hotspots.iter()
+        .map(|h| format!("{}:{}", h.name, h.role.describe()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let snippet = placed.snippet.clone();
 
     let mut is_active = use_signal(|| false);
+    let mut dismissed = use_signal(|| false);
+    if dismissed() {
+        return rsx! {};
+    }
 
     let current_html = if *is_active.read() {
         html_active.clone()
@@ -47,8 +64,10 @@ pub fn CanvasElement(
 
     rsx! {
         div {
+            class: if is_target { "target" } else { "" },
             style: "{wrapper_style}",
             cursor: "pointer",
+            "data-target": "{is_target}",
             "data-element-id": "{id}",
             "data-kind": "{kind}",
             "data-label": "{label}",
@@ -62,9 +81,28 @@ pub fn CanvasElement(
             "data-animation": "{animation}",
             "data-active": "{active_str}",
             "data-description": "{description}",
-            onclick: move |_| {
-                is_active.toggle();
-                on_click(id.clone());
+            "data-hotspots": "{hotspots}",
+            onclick: move |e: MouseEvent| {
+                if !snippet.is_interactive() {
+                    is_active.toggle();
+                    on_click(id.clone());
+                    return;
+                }
+                let Some(target) = e.try_as_web_event()
+                    .and_then(|ev| ev.target())
+                    .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+                else {
+                    return;
+                };
+                let Ok(Some(root)) = target.closest(&format!("[data-element-id=\"{id}\"]")) else { return };
+                let Some(spot) = interactive::hit(&snippet, &target, &root) else { return };
+                match spot.role {
+                    HotspotRole::Activate => is_active.set(true),
+                    HotspotRole::Toggle => is_active.toggle(),
+                    HotspotRole::Dismiss => dismissed.set(true),
+                    HotspotRole::Focus => {}
+                }
+                on_click(format!("{id}:{}", spot.name));
             },
             if has_animation {
                 div {
@@ -2,5 +2,7 @@
 
 pub mod element;
 pub mod playground;
+pub mod workbench;
 
 pub use playground::Playground;
+pub use workbench::PoolWorkbench;
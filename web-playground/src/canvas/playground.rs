@@ -1,4 +1,8 @@
 //! Playground - the main training canvas
+//!
+//! Every page is a `transform::Scene`: K pool snippets with random
+//! transforms, one of them the target, with the scene's UINode ground truth
+//! resolved below the canvas.
 
 use dioxus::prelude::*;
 use rand::SeedableRng;
@@ -6,39 +10,50 @@ use rand::rngs::SmallRng;
 
 use crate::pool::ElementPool;
 use crate::primitives::Animation;
-use crate::transform::Sampler;
+use crate::transform::scene::Scene;
+use crate::ui_node::Rect;
 use super::element::CanvasElement;
 
+const DEFAULT_COUNT: usize = 5;
+
 /// The dynamically-sized training playground
 #[component]
 pub fn Playground() -> Element {
-    let pool = use_hook(|| ElementPool::with_builtins());
+    let pool = use_hook(|| ElementPool::with_builtins().with_interactive());
     let pool_total = pool.total();
 
     let mut seed_counter = use_signal(|| 42u64);
-    let mut elements = use_signal(|| {
-        let pool = ElementPool::with_builtins();
+    let mut count = use_signal(|| DEFAULT_COUNT);
+    let mut scene = use_signal(|| {
+        let pool = ElementPool::with_builtins().with_interactive();
         let mut rng = SmallRng::seed_from_u64(42);
-        Sampler::random_page(&mut rng, &pool, 5)
+        Scene::generate(&mut rng, &pool, DEFAULT_COUNT).expect("pool has click targets")
     });
     let mut clicked = use_signal(|| Option::<String>::None);
     let mut bg_speed = use_signal(|| 30u32);
 
-    let regenerate = {
+    let mut regenerate = {
         let pool = pool.clone();
-        move |_| {
+        move || {
             let new_seed = *seed_counter.read() + 1;
             seed_counter.set(new_seed);
             let mut rng = SmallRng::seed_from_u64(new_seed);
-            let new_elements = Sampler::random_page(&mut rng, &pool, 5);
-            elements.set(new_elements);
+            if let Some(new_scene) = Scene::generate(&mut rng, &pool, count()) {
+                scene.set(new_scene);
+            }
             clicked.set(None);
         }
     };
 
+    let current = scene();
+    let target_id = current.target().snippet.id.clone();
+    let instruction = current.instruction();
+    let hit = clicked().map(|id| id.split(':').next() == Some(target_id.as_str()));
+
     let keyframes = Animation::keyframes_css();
 
     let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let resolved = current.tree(Rect::new(0.0, 0.0, vp_w, vp_h)).resolve();
 
     rsx! {
         // Inject keyframe definitions once
@@ -61,6 +76,8 @@ pub fn Playground() -> Element {
                         angle: parseFloat(el.dataset.angle),
                         opacity: parseFloat(el.dataset.opacity),
                         animation: el.dataset.animation || "none",
+                        target: el.dataset.target === "true",
+                        hotspots: (el.dataset.hotspots || "").split(" ").filter(Boolean),
                         active: el.dataset.active === "true",
                         description: el.dataset.description,
                         rect: el.getBoundingClientRect(),
@@ -78,17 +95,39 @@ pub fn Playground() -> Element {
                 style: "display: flex; gap: 12px; align-items: center;",
                 button {
                     style: "padding: 8px 20px; background: #3b82f6; color: white; border: none; border-radius: 6px; cursor: pointer; font-size: 14px;",
-                    onclick: regenerate,
-                    "Generate New Page"
+                    onclick: move |_| regenerate(),
+                    "Generate New Scene"
                 }
                 span {
                     style: "color: #9ca3af; font-size: 13px; font-family: monospace;",
                     "{pool_total} snippets in pool"
                 }
+                label {
+                    style: "color: #9ca3af; font-size: 13px; font-family: monospace;",
+                    "elements: {count}"
+                }
+                input {
+                    r#type: "range",
+                    min: "2",
+                    max: "12",
+                    value: "{count}",
+                    style: "width: 100px; accent-color: #3b82f6;",
+                    oninput: move |e: Event<FormData>| {
+                        if let Ok(v) = e.value().parse::<usize>() {
+                            count.set(v);
+                        }
+                    },
+                }
+            }
+
+            // Instruction and click feedback
+            div {
+                style: "display: flex; gap: 12px; align-items: center; font-size: 14px;",
+                span { style: "color: #e5e7eb;", "{instruction}" }
                 if let Some(ref id) = clicked() {
                     span {
-                        style: "color: #22c55e; font-size: 13px; font-family: monospace;",
-                        "clicked: {id}"
+                        style: if hit == Some(true) { "color: #22c55e; font-family: monospace;" } else { "color: #ef4444; font-family: monospace;" },
+                        if hit == Some(true) { "\u{2713} {id}" } else { "\u{2717} {id}" }
                     }
                 }
             }
@@ -118,10 +157,11 @@ pub fn Playground() -> Element {
             div {
                 style: "width: {vp_w}px; height: {vp_h}px; background: #1a1a2e; position: relative; border: 1px solid #2a2a4a; overflow: auto; animation: bg-shift {bg_speed}s infinite ease-in-out;",
 
-                for placed in elements() {
+                for (i, placed) in current.elements.iter().enumerate() {
                     CanvasElement {
                         key: "{placed.snippet.id}-{placed.position.x}-{placed.position.y}",
                         placed: placed.clone(),
+                        is_target: i == current.target,
                         on_click: move |id: String| {
                             clicked.set(Some(id));
                         },
@@ -136,12 +176,21 @@ pub fn Playground() -> Element {
                     style: "margin: 0 0 8px 0; color: #e5e7eb; font-size: 13px;",
                     "Ground Truth"
                 }
-                for placed in elements() {
+                for (i, placed) in current.elements.iter().enumerate() {
                     div {
-                        style: "padding: 4px 0; border-bottom: 1px solid #1f2937;",
+                        style: if i == current.target { "padding: 4px 0; border-bottom: 1px solid #1f2937; color: #22c55e;" } else { "padding: 4px 0; border-bottom: 1px solid #1f2937;" },
+                        if i == current.target { "\u{2192} " }
                         "{placed.describe()}"
                     }
                 }
+                div {
+                    style: "padding: 8px 0 0 0; color: #6b7280;",
+                    "{resolved.description}"
+                }
+                div {
+                    style: "padding: 4px 0 0 0; color: #6b7280;",
+                    "steps: {resolved.steps_json()}"
+                }
             }
         }
     }
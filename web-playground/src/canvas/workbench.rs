@@ -0,0 +1,357 @@
+//! Pool workbench - browse the snippet pool and preview new snippets
+//!
+//! `/pool` lists every snippet by kind, rendered live, with its declared
+//! `approx_width` x `approx_height` next to the size the browser actually
+//! lays it out at. Sizes off by more than `SIZE_TOLERANCE` are flagged: the
+//! declared size is what placement falls back to when it can't measure.
+//!
+//! The draft panel takes pasted snippet HTML and renders it under a row of
+//! sampled transforms (scale, angle, opacity, animation) with the layout
+//! bounds (`PlacedElement::bounds`, from the measured size) outlined, then
+//! prints the snippet as a `?pool=` JSON entry.
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+use crate::pool::{DesignSnippet, ElementKind, ElementPool};
+use crate::primitives::{Animation, Position};
+use crate::transform::{PlacedElement, Sampler};
+use crate::ui_node::escape_json;
+use super::element::CanvasElement;
+
+/// Relative size difference flagged as a bad declaration.
+const SIZE_TOLERANCE: f32 = 0.2;
+/// Transformed samples shown for the draft.
+const SAMPLES: usize = 6;
+/// Preview cell size, px.
+const CELL_W: f32 = 280.0;
+const CELL_H: f32 = 170.0;
+const MEASURE_CLASS: &str = "__pool-measure";
+
+const DRAFT_HTML: &str = r#"<button style="padding: 8px 18px; background: #0ea5e9; color: white; border: none; border-radius: 6px; font-size: 14px; font-family: system-ui, sans-serif;">Continue</button>"#;
+
+/// Rendered (width, height) of every `MEASURE_CLASS` element, by snippet id.
+fn measure_snippets() -> HashMap<String, (f32, f32)> {
+    let mut out = HashMap::new();
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else { return out };
+    let all = document.get_elements_by_class_name(MEASURE_CLASS);
+    for i in 0..all.length() {
+        let Some(el) = all.item(i) else { continue };
+        let Some(id) = el.get_attribute("data-snippet-id") else { continue };
+        let r = el.get_bounding_client_rect();
+        out.insert(id, (r.width() as f32, r.height() as f32));
+    }
+    out
+}
+
+fn size_off(declared: (f32, f32), measured: (f32, f32)) -> bool {
+    let off = |d: f32, m: f32| (d - m).abs() > d.max(1.0) * SIZE_TOLERANCE;
+    off(declared.0, measured.0) || off(declared.1, measured.1)
+}
+
+/// The snippet as an entry of a `?pool=` snippet file.
+fn snippet_json(s: &DesignSnippet) -> String {
+    format!(
+        r#"{{"id":"{}","kind":"{}","label":"{}","html":"{}","html_active":"{}","approx_width":{:.0},"approx_height":{:.0},"tags":[{}],"weight":{},"hotspots":[{}]}}"#,
+        escape_json(&s.id),
+        format!("{:?}", s.kind).to_lowercase(),
+        escape_json(&s.label),
+        escape_json(&s.html),
+        escape_json(&s.html_active),
+        s.approx_width,
+        s.approx_height,
+        s.tags.iter().map(|t| format!(r#""{}""#, escape_json(t))).collect::<Vec<_>>().join(","),
+        s.weight,
+        s.hotspots.iter()
+            .map(|h| format!(
+                r#"{{"name":"{}","selector":"{}","role":"{}"}}"#,
+                escape_json(&h.name), escape_json(&h.selector), h.role.describe(),
+            ))
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+/// Sampled transforms of `snippet`, each placed near the middle of a cell.
+fn transformed_samples(snippet: &DesignSnippet, seed: u64) -> Vec<PlacedElement> {
+    let mut rng = SmallRng::seed_from_u64(seed);
+    (0..SAMPLES)
+        .map(|_| {
+            let scale = Sampler::random_scale(&mut rng);
+            let (w, h) = snippet.size();
+            let (w, h) = (w * scale.value(), h * scale.value());
+            let pos = Position::new(((CELL_W - w) / 2.0).max(4.0), ((CELL_H - h) / 2.0).max(4.0));
+            PlacedElement::new(snippet.clone(), pos)
+                .with_scale(scale)
+                .with_angle(Sampler::random_angle(&mut rng))
+                .with_opacity(Sampler::random_opacity(&mut rng))
+                .with_animation(Sampler::random_animation(&mut rng))
+        })
+        .collect()
+}
+
+/// A snippet's live render with its declared and measured size.
+#[component]
+fn SnippetCard(snippet: DesignSnippet, measured: Option<(f32, f32)>) -> Element {
+    let declared = (snippet.approx_width, snippet.approx_height);
+    let (size_text, size_color) = match measured {
+        Some(m) => (
+            format!("declared {:.0}x{:.0} \u{00B7} measured {:.0}x{:.0}", declared.0, declared.1, m.0, m.1),
+            if size_off(declared, m) { "#f59e0b" } else { "#6b7280" },
+        ),
+        None => (format!("declared {:.0}x{:.0}", declared.0, declared.1), "#6b7280"),
+    };
+
+    rsx! {
+        div {
+            style: "width: 260px; background: #1a1a2e; border: 1px solid #2a2a4a; border-radius: 8px; padding: 10px; display: flex; flex-direction: column; gap: 8px;",
+            div {
+                style: "height: 90px; background: #f9fafb; border-radius: 6px; display: flex; align-items: center; justify-content: center; overflow: hidden;",
+                div {
+                    class: MEASURE_CLASS,
+                    "data-snippet-id": "{snippet.id}",
+                    style: "display: inline-block;",
+                    dangerous_inner_html: "{snippet.html}",
+                }
+            }
+            div {
+                style: "font-family: monospace; font-size: 11px; color: #e5e7eb;",
+                "{snippet.id}"
+            }
+            div {
+                style: "font-size: 12px; color: #9ca3af;",
+                "{snippet.label}"
+            }
+            if !snippet.tags.is_empty() || snippet.weight != 1.0 {
+                div {
+                    style: "font-family: monospace; font-size: 11px; color: #a78bfa;",
+                    "{snippet.tags.join(\", \")} \u{00B7} weight {snippet.weight}"
+                }
+            }
+            for spot in snippet.hotspots.iter() {
+                div {
+                    style: "font-family: monospace; font-size: 11px; color: #38bdf8;",
+                    "hotspot {spot.describe()} \u{2192} {spot.selector}"
+                }
+            }
+            div {
+                style: "font-family: monospace; font-size: 11px; color: {size_color};",
+                "{size_text}"
+            }
+        }
+    }
+}
+
+/// `/pool` - every pool snippet by kind, plus the draft preview.
+#[component]
+pub fn PoolWorkbench() -> Element {
+    let pool = use_hook(|| ElementPool::with_builtins().with_interactive());
+    let mut measured = use_signal(HashMap::<String, (f32, f32)>::new);
+
+    let mut draft_html = use_signal(|| DRAFT_HTML.to_string());
+    let mut draft_kind = use_signal(|| ElementKind::Button);
+    let mut draft_w = use_signal(|| 110.0f32);
+    let mut draft_h = use_signal(|| 36.0f32);
+    let mut draft_tags = use_signal(String::new);
+    let mut sample_seed = use_signal(|| 1u64);
+
+    // Re-measure as snippets (and the draft) lay out and fonts load
+    use_future(move || async move {
+        loop {
+            let sizes = measure_snippets();
+            if *measured.peek() != sizes {
+                measured.set(sizes);
+            }
+            TimeoutFuture::new(500).await;
+        }
+    });
+
+    let sizes = measured();
+    let tag_list = draft_tags();
+    let tags: Vec<&str> = tag_list.split(',').map(str::trim).filter(|t| !t.is_empty()).collect();
+    let draft = DesignSnippet::static_new("draft", draft_kind(), "draft snippet", draft_html(), draft_w(), draft_h())
+        .with_tags(&tags);
+    let draft_measured = sizes.get("draft").copied();
+    let samples = transformed_samples(&draft, sample_seed());
+    let draft_json = snippet_json(&draft);
+    let keyframes = Animation::keyframes_css();
+
+    rsx! {
+        style { "{keyframes}" }
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; padding: 32px 24px; font-family: system-ui, sans-serif; display: flex; flex-direction: column; gap: 28px;",
+            div {
+                style: "display: flex; gap: 16px; align-items: baseline;",
+                h1 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 26px; font-weight: 700;",
+                    "Snippet pool"
+                }
+                span {
+                    style: "color: #9ca3af; font-size: 13px; font-family: monospace;",
+                    "{pool.total()} snippets \u{00B7} sizes off by more than {SIZE_TOLERANCE * 100.0:.0}% in amber"
+                }
+            }
+
+            // Draft workbench
+            div {
+                style: "background: #111827; border: 1px solid #2a2a4a; border-radius: 10px; padding: 16px; display: flex; flex-direction: column; gap: 12px;",
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 16px;",
+                    "Draft snippet"
+                }
+                textarea {
+                    value: "{draft_html}",
+                    spellcheck: "false",
+                    style: "width: 100%; height: 90px; box-sizing: border-box; background: #0f0f1a; color: #e5e7eb; border: 1px solid #2a2a4a; border-radius: 6px; padding: 8px; font-family: monospace; font-size: 12px; resize: vertical;",
+                    oninput: move |e: Event<FormData>| draft_html.set(e.value()),
+                }
+                div {
+                    style: "display: flex; gap: 12px; align-items: center; color: #9ca3af; font-size: 13px; font-family: monospace; flex-wrap: wrap;",
+                    "kind"
+                    select {
+                        style: "background: #0f0f1a; color: #e5e7eb; border: 1px solid #2a2a4a; border-radius: 4px; padding: 3px 6px;",
+                        onchange: move |e: Event<FormData>| {
+                            if let Some(kind) = ElementKind::parse(&e.value()) {
+                                draft_kind.set(kind);
+                            }
+                        },
+                        for kind in ElementKind::ALL.iter() {
+                            option {
+                                value: format!("{kind:?}").to_lowercase(),
+                                selected: *kind == draft_kind(),
+                                "{kind.describe()}"
+                            }
+                        }
+                    }
+                    "declared w"
+                    input {
+                        r#type: "number",
+                        value: "{draft_w}",
+                        style: "width: 64px; background: #0f0f1a; color: #e5e7eb; border: 1px solid #2a2a4a; border-radius: 4px; padding: 3px 6px;",
+                        oninput: move |e: Event<FormData>| {
+                            if let Ok(v) = e.value().parse::<f32>() {
+                                draft_w.set(v.max(1.0));
+                            }
+                        },
+                    }
+                    "h"
+                    input {
+                        r#type: "number",
+                        value: "{draft_h}",
+                        style: "width: 64px; background: #0f0f1a; color: #e5e7eb; border: 1px solid #2a2a4a; border-radius: 4px; padding: 3px 6px;",
+                        oninput: move |e: Event<FormData>| {
+                            if let Ok(v) = e.value().parse::<f32>() {
+                                draft_h.set(v.max(1.0));
+                            }
+                        },
+                    }
+                    "tags"
+                    input {
+                        value: "{draft_tags}",
+                        placeholder: "flat, light, simple",
+                        style: "width: 160px; background: #0f0f1a; color: #e5e7eb; border: 1px solid #2a2a4a; border-radius: 4px; padding: 3px 6px;",
+                        oninput: move |e: Event<FormData>| draft_tags.set(e.value()),
+                    }
+                    if let Some((mw, mh)) = draft_measured {
+                        span {
+                            style: if size_off((draft_w(), draft_h()), (mw, mh)) { "color: #f59e0b;" } else { "color: #22c55e;" },
+                            "measured {mw:.0}x{mh:.0}"
+                        }
+                        button {
+                            style: "padding: 3px 10px; background: #1a1a2e; color: #9ca3af; border: 1px solid #2a2a4a; border-radius: 4px; font-size: 12px; cursor: pointer;",
+                            onclick: move |_| {
+                                draft_w.set(mw.round());
+                                draft_h.set(mh.round());
+                            },
+                            "Use measured"
+                        }
+                    }
+                    button {
+                        style: "padding: 3px 10px; background: #6366f1; color: white; border: none; border-radius: 4px; font-size: 12px; cursor: pointer;",
+                        onclick: move |_| *sample_seed.write() += 1,
+                        "Resample transforms"
+                    }
+                }
+
+                // Untransformed render, measured like the pool's
+                div {
+                    style: "background: #f9fafb; border-radius: 6px; padding: 16px; display: flex; justify-content: center;",
+                    div {
+                        class: MEASURE_CLASS,
+                        "data-snippet-id": "draft",
+                        style: "display: inline-block;",
+                        dangerous_inner_html: "{draft.html}",
+                    }
+                }
+
+                // Sampled transforms; dashed box = layout bounds after scaling
+                div {
+                    style: "display: flex; flex-wrap: wrap; gap: 12px;",
+                    for (i, placed) in samples.iter().enumerate() {
+                        {
+                            let (bx, by, bw, bh) = placed.bounds();
+                            let description = placed.describe();
+                            rsx! {
+                                div {
+                                    key: "{sample_seed}-{i}",
+                                    style: "display: flex; flex-direction: column; gap: 4px; width: {CELL_W}px;",
+                                    div {
+                                        style: "position: relative; width: {CELL_W}px; height: {CELL_H}px; background: #f9fafb; border-radius: 6px; overflow: hidden;",
+                                        CanvasElement {
+                                            placed: placed.clone(),
+                                            on_click: move |_| {},
+                                        }
+                                        div {
+                                            style: "position: absolute; left: {bx}px; top: {by}px; width: {bw}px; height: {bh}px; border: 1px dashed #ef4444; pointer-events: none; box-sizing: border-box;",
+                                        }
+                                    }
+                                    div {
+                                        style: "color: #9ca3af; font-size: 11px; font-family: monospace;",
+                                        "{description}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    style: "color: #6b7280; font-size: 12px;",
+                    "As a ?pool= entry:"
+                }
+                pre {
+                    style: "margin: 0; padding: 8px; background: #0f0f1a; border-radius: 6px; color: #9ca3af; font-size: 11px; white-space: pre-wrap; word-break: break-all;",
+                    "{draft_json}"
+                }
+            }
+
+            // The pool, by kind
+            for kind in ElementKind::ALL.iter().copied() {
+                if !pool.get(kind).is_empty() {
+                    div {
+                        key: "{kind:?}",
+                        style: "display: flex; flex-direction: column; gap: 10px;",
+                        h2 {
+                            style: "color: #e5e7eb; margin: 0; font-size: 16px; text-transform: capitalize;",
+                            "{kind.describe()} ({pool.get(kind).len()})"
+                        }
+                        div {
+                            style: "display: flex; flex-wrap: wrap; gap: 12px;",
+                            for snippet in pool.get(kind).iter() {
+                                SnippetCard {
+                                    key: "{snippet.id}",
+                                    snippet: snippet.clone(),
+                                    measured: sizes.get(&snippet.id).copied(),
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
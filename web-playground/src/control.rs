@@ -0,0 +1,122 @@
+//! Control — postMessage command API for embedding the playground in an iframe.
+//!
+//! A harness page posts `{ type: 'playground-control', id, command, args }`
+//! to the playground's window and gets back
+//! `{ type: 'playground-control-result', id, ok, result }` (or `ok: false`
+//! with an `error` string). Commands:
+//!
+//! - `navigate { path }` — client-side route change, e.g. `"/level5?seed=7"`
+//! - `setSeed { seed }` — reseed later rounds (`null` reverts to `?seed=`);
+//!   navigate afterwards to redraw the current round from the new seed
+//! - `step` / `solve` — drive `window.__solver` (needs the `solver` feature)
+//! - `getGroundTruth` — the current schema-v2 payload
+//! - `getState` — path, seed, seed counter and `window.__checkCompletion()`
+//! - `act { action }` — perform one action shaped like a ground truth step
+//! - `observe` — `getState` plus the ground truth, in one reply
+//! - `reset { seed? }` — optionally reseed, then remount the current route
+//!
+//! The command table is `window.__control.run(command, args)`, shared with
+//! the WebSocket bridge (`ws` feature).
+//!
+//! Only the parent frame, the opener and the page itself are listened to, so
+//! an embedded playground can't be driven by unrelated windows.
+
+use js_sys::Reflect;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+pub const CONTROL_JS: &str = r#"
+    if (!window.__control) {
+        const plain = v => v === undefined ? null : JSON.parse(JSON.stringify(v));
+        // Client-side route change the router picks up from `popstate`
+        const go = async (path, replace) => {
+            if (replace) history.replaceState({}, '', path);
+            else history.pushState({}, '', path);
+            window.dispatchEvent(new PopStateEvent('popstate', { state: {} }));
+            await new Promise(r => setTimeout(r, 50));
+        };
+        const commands = {
+            async navigate({ path }) {
+                if (typeof path !== 'string' || !path.startsWith('/')) throw new Error('path must start with "/"');
+                await go(path, false);
+                return { path: location.pathname + location.search };
+            },
+            async setSeed({ seed }) {
+                window.__setSeed(seed ?? null);
+                return { seed: window.__effectiveSeed ?? null };
+            },
+            async step() {
+                if (!window.__solver) throw new Error('solver not installed');
+                return await window.__solver.step();
+            },
+            async solve() {
+                if (!window.__solver) throw new Error('solver not installed');
+                return await window.__solver.solve();
+            },
+            async getGroundTruth() { return window.__groundTruth ?? null; },
+            async getState() {
+                return {
+                    path: location.pathname + location.search,
+                    seed: window.__effectiveSeed ?? null,
+                    seedCounter: window.__seedCounter ?? 0,
+                    check: window.__checkCompletion ? window.__checkCompletion() : null,
+                };
+            },
+            async act({ action }) {
+                if (!window.__solver) throw new Error('solver not installed');
+                if (!action || !action.action) throw new Error('act needs { action: { action, ... } }');
+                return await window.__solver.act(action);
+            },
+            async observe() {
+                return { state: await commands.getState(), groundTruth: window.__groundTruth ?? null };
+            },
+            async reset({ seed }) {
+                if (seed !== undefined) window.__setSeed(seed);
+                window.__solver && window.__solver.reset();
+                // Bounce through "/" so the level remounts and draws a new round
+                const path = location.pathname + location.search;
+                await go('/', true);
+                await go(path, true);
+                return await commands.getState();
+            },
+        };
+        window.__control = {
+            commands,
+            // Run one command; resolves to { ok, result } or { ok: false, error }
+            async run(command, args) {
+                const fn = commands[command];
+                if (!fn) return { ok: false, error: 'unknown command: ' + command };
+                try {
+                    return { ok: true, result: plain(await fn(args || {})) };
+                } catch (err) {
+                    return { ok: false, error: String(err && err.message || err) };
+                }
+            },
+        };
+        window.addEventListener('message', async (e) => {
+            const d = e.data;
+            if (!d || d.type !== 'playground-control') return;
+            if (!e.source || (e.source !== window.parent && e.source !== window.opener && e.source !== window)) return;
+            const msg = await window.__control.run(d.command, d.args);
+            e.source.postMessage(
+                { type: 'playground-control-result', id: d.id, ...msg },
+                e.origin && e.origin !== 'null' ? e.origin : '*'
+            );
+        });
+    }
+"#;
+
+/// Install `window.__setSeed` and the message listener once per page.
+pub fn install() {
+    let Some(window) = web_sys::window() else { return };
+    if !Reflect::has(&window, &"__setSeed".into()).unwrap_or(false) {
+        let set_seed = Closure::<dyn Fn(JsValue)>::new(|seed: JsValue| {
+            let seed = seed.as_f64().filter(|n| n.is_finite() && *n >= 0.0).map(|n| n as u64);
+            crate::levels::set_seed(seed);
+        });
+        let _ = Reflect::set(&window, &"__setSeed".into(), set_seed.as_ref().unchecked_ref());
+        // Lives for the whole page
+        set_seed.forget();
+    }
+    dioxus::prelude::document::eval(CONTROL_JS);
+}
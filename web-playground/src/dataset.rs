@@ -0,0 +1,184 @@
+//! Dataset — ground truths and outcomes accumulated across rounds, so data
+//! can be collected from the browser without an external driver.
+//!
+//! Each round contributes one JSONL record: the schema-v2 ground truth as it
+//! was when the round started — once its targets were measured — plus `"success"` (the level scored),
+//! `"abandoned"` (the page was left first) or `"timeout"` (its countdown ran
+//! out), the round's `"wrong_attempts"` (`{target, value, ...}` for each
+//! rejected attempt) and, for timed rounds, `"time_limit_ms"`. Exposed as
+//! `window.__dataset`: `export()` → Blob URL, `jsonl()` → string, `count()`,
+//! `clear()`. The ground-truth panel shows a "Download JSONL" button in
+//! debug mode.
+
+use std::cell::RefCell;
+
+use js_sys::{Array, Reflect};
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+use crate::recorder::set_fn;
+
+struct Dataset {
+    /// Ground truth of the round as it started.
+    round_start: Option<String>,
+    /// Whether `round_start` was measured; until then each payload replaces it.
+    start_measured: bool,
+    /// Wrong attempts logged since the last round ended.
+    wrong: Vec<String>,
+    /// Countdown of the current round, when it is timed.
+    time_limit_ms: Option<u32>,
+    records: Vec<String>,
+}
+
+impl Dataset {
+    const fn new() -> Self {
+        Dataset { round_start: None, start_measured: false, wrong: Vec::new(), time_limit_ms: None, records: Vec::new() }
+    }
+
+    /// Keep `payload` as the round's start unless a measured one is kept already.
+    fn observe(&mut self, payload: &str, measured: bool) {
+        if !self.start_measured {
+            self.round_start = Some(payload.to_string());
+            self.start_measured = measured;
+        }
+    }
+
+    fn close_round(&mut self, outcome: &str, completed_at: u64) {
+        let wrong = std::mem::take(&mut self.wrong);
+        let time_limit = self.time_limit_ms.take()
+            .map_or(String::new(), |ms| format!(r#","time_limit_ms":{ms}"#));
+        self.start_measured = false;
+        if let Some(gt) = self.round_start.take() {
+            let index = self.records.len();
+            self.records.push(format!(
+                r#"{{"round":{},"outcome":"{}","completed_at":{}{},"wrong_attempts":[{}],"ground_truth":{}}}"#,
+                index,
+                outcome,
+                completed_at,
+                time_limit,
+                wrong.join(","),
+                gt,
+            ));
+        }
+    }
+}
+
+thread_local! {
+    static DATASET: RefCell<Dataset> = const { RefCell::new(Dataset::new()) };
+}
+
+/// Note a published ground-truth payload. The round keeps the first one
+/// whose targets were `measured` in the DOM, or the latest until then.
+pub fn observe(payload: &str, measured: bool) {
+    DATASET.with(|d| d.borrow_mut().observe(payload, measured));
+}
+
+fn close_round(outcome: &str) {
+    DATASET.with(|d| d.borrow_mut().close_round(outcome, js_sys::Date::now() as u64));
+}
+
+/// The level rejected an attempt; kept for the round's record.
+pub fn round_wrong(attempt: &str) {
+    DATASET.with(|d| d.borrow_mut().wrong.push(attempt.to_string()));
+}
+
+/// A countdown started for the current round; kept for the round's record.
+pub fn set_time_limit(ms: u32) {
+    DATASET.with(|d| d.borrow_mut().time_limit_ms = Some(ms));
+}
+
+/// The round's countdown ran out — close it as a timeout.
+pub fn round_timeout() {
+    close_round("timeout");
+}
+
+/// The level scored — close the round as a success.
+pub fn round_complete() {
+    close_round("success");
+}
+
+/// The level unmounted mid-round.
+pub fn round_abandoned() {
+    close_round("abandoned");
+}
+
+pub fn jsonl() -> String {
+    DATASET.with(|d| d.borrow().records.join("\n"))
+}
+
+/// Wrap the dataset in a Blob and return an object URL for it.
+pub fn export_url() -> Option<String> {
+    let parts = Array::of1(&JsValue::from_str(&jsonl()));
+    let opts = web_sys::BlobPropertyBag::new();
+    opts.set_type("application/jsonl");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &opts).ok()?;
+    web_sys::Url::create_object_url_with_blob(&blob).ok()
+}
+
+/// Trigger a browser download of the dataset.
+pub fn download() {
+    let Some(url) = export_url() else { return };
+    dioxus::prelude::document::eval(&format!(
+        "const a = document.createElement('a'); a.href = '{url}'; a.download = 'playground-dataset.jsonl'; a.click(); setTimeout(() => URL.revokeObjectURL('{url}'), 1000);"
+    ));
+}
+
+/// Install `window.__dataset` once per page.
+pub fn install() {
+    let Some(window) = web_sys::window() else { return };
+    if Reflect::has(&window, &"__dataset".into()).unwrap_or(false) {
+        return;
+    }
+    let api = js_sys::Object::new();
+
+    let export_fn = Closure::<dyn Fn() -> JsValue>::new(|| {
+        export_url().map(JsValue::from).unwrap_or(JsValue::NULL)
+    });
+    let jsonl_fn = Closure::<dyn Fn() -> String>::new(jsonl);
+    let count_fn = Closure::<dyn Fn() -> u32>::new(|| DATASET.with(|d| d.borrow().records.len() as u32));
+    let clear_fn = Closure::<dyn Fn()>::new(|| DATASET.with(|d| d.borrow_mut().records.clear()));
+
+    set_fn(&api, "export", export_fn.as_ref().unchecked_ref());
+    set_fn(&api, "jsonl", jsonl_fn.as_ref().unchecked_ref());
+    set_fn(&api, "count", count_fn.as_ref().unchecked_ref());
+    set_fn(&api, "clear", clear_fn.as_ref().unchecked_ref());
+    // The API lives for the whole page
+    export_fn.forget();
+    jsonl_fn.forget();
+    count_fn.forget();
+    clear_fn.forget();
+
+    let _ = Reflect::set(&window, &"__dataset".into(), &api);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNMEASURED: &str = r#"{"targets":[{"bbox":[0,0,10,10]}],"epoch":0}"#;
+    const MEASURED: &str = r#"{"targets":[{"label":"Save","bbox":[40,60,80,32]}],"epoch":3}"#;
+    const AFTER_INPUT: &str = r#"{"targets":[{"label":"Save","bbox":[40,60,80,32]}],"epoch":4}"#;
+
+    #[test]
+    fn record_keeps_first_measured_payload() {
+        let mut d = Dataset::new();
+        d.observe(UNMEASURED, false);
+        d.observe(MEASURED, true);
+        d.observe(AFTER_INPUT, true);
+        d.close_round("success", 1);
+        assert_eq!(d.records.len(), 1);
+        assert!(d.records[0].ends_with(&format!(r#""ground_truth":{MEASURED}}}"#)), "{}", d.records[0]);
+    }
+
+    #[test]
+    fn next_round_starts_unmeasured() {
+        let mut d = Dataset::new();
+        d.observe(MEASURED, true);
+        d.close_round("success", 1);
+        d.observe(UNMEASURED, false);
+        d.observe(AFTER_INPUT, true);
+        d.close_round("abandoned", 2);
+        assert!(d.records[1].contains(r#""label":"Save""#), "{}", d.records[1]);
+        assert!(d.records[1].contains(r#""epoch":4"#));
+    }
+}
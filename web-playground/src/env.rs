@@ -0,0 +1,58 @@
+//! Env — Gym-style wrapper that turns any level into an RL environment.
+//!
+//! Installs `window.__env` on top of the control commands and the solver:
+//!
+//! - `reset(seed?)` → observation: reseed, remount the current level, start
+//!   a new episode
+//! - `observe()` → `{ groundTruth, state, screenshot }`: the schema-v2
+//!   payload (DOM-measured targets included), path / seed / completion, and
+//!   a screenshot reference from the solver's capture callback (or null)
+//! - `act(action)` → `{ observation, reward, done, truncated, info }`, with
+//!   `action` a ground truth step object or its JSON string
+//!
+//! Reward is 1 when the action solves the round, else the change in the
+//! tree's `check()` progress; an action naming labels that aren't on screen
+//! costs `invalidPenalty`. Episodes end when the round is solved and are
+//! truncated after `maxSteps` actions.
+
+pub const ENV_JS: &str = r#"
+    if (!window.__env && window.__control && window.__solver) {
+        const check = () => window.__checkCompletion ? window.__checkCompletion() : null;
+        window.__env = {
+            maxSteps: 50,
+            invalidPenalty: 0.1,
+            _steps: 0,
+
+            async reset(seed) {
+                this._steps = 0;
+                const r = await window.__control.run('reset', seed === undefined ? {} : { seed });
+                if (!r.ok) throw new Error(r.error);
+                return await this.observe();
+            },
+
+            async observe() {
+                const state = (await window.__control.run('getState')).result;
+                const screenshot = await window.__solver._runCapture('observe', null, this._steps);
+                return { groundTruth: window.__groundTruth ?? null, state, screenshot };
+            },
+
+            async act(action) {
+                if (typeof action === 'string') action = JSON.parse(action);
+                const before = check();
+                const acted = await window.__solver.act(action);
+                this._steps++;
+                const after = check();
+                const solved = !!(before && after && after.round > before.round);
+                let reward = solved ? 1 : ((after?.progress ?? 0) - (before?.progress ?? 0));
+                if (!acted.dispatched) reward -= this.invalidPenalty;
+                return {
+                    observation: await this.observe(),
+                    reward,
+                    done: solved,
+                    truncated: !solved && this._steps >= this.maxSteps,
+                    info: { dispatched: acted.dispatched, completion: after?.completion ?? null, step: this._steps },
+                };
+            },
+        };
+    }
+"#;
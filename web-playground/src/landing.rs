@@ -1,6 +1,20 @@
 use dioxus::prelude::*;
 use crate::Route;
 
+/// Hero buttons for the routes compiled into this build.
+fn entry_links() -> Vec<(&'static str, Route, &'static str)> {
+    Vec::from([
+        #[cfg(feature = "levels")]
+        ("Play", Route::LevelSelect {}, "linear-gradient(135deg, #22c55e, #16a34a)"),
+        #[cfg(feature = "levels")]
+        ("Campaign", Route::Campaign {}, "linear-gradient(135deg, #a855f7, #7c3aed)"),
+        #[cfg(feature = "levels")]
+        ("Endless", Route::RandomLevel { weight: String::new() }, "linear-gradient(135deg, #f59e0b, #d97706)"),
+        #[cfg(feature = "canvas-playground")]
+        ("Sandbox", Route::Playground {}, "linear-gradient(135deg, #3b82f6, #6366f1)"),
+    ])
+}
+
 #[component]
 pub fn Landing() -> Element {
     use_effect(|| {
@@ -58,15 +72,12 @@ pub fn Landing() -> Element {
                 }
                 div {
                     style: "display: flex; gap: 16px; justify-content: center;",
-                    Link {
-                        to: Route::LevelSelect {},
-                        style: "display: inline-block; padding: 14px 36px; background: linear-gradient(135deg, #22c55e, #16a34a); color: white; text-decoration: none; border-radius: 8px; font-size: 18px; font-weight: 600;",
-                        "Play \u{2192}"
-                    }
-                    Link {
-                        to: Route::Playground {},
-                        style: "display: inline-block; padding: 14px 36px; background: linear-gradient(135deg, #3b82f6, #6366f1); color: white; text-decoration: none; border-radius: 8px; font-size: 18px; font-weight: 600;",
-                        "Sandbox \u{2192}"
+                    for (label, route, gradient) in entry_links() {
+                        Link {
+                            to: route,
+                            style: "display: inline-block; padding: 14px 36px; background: {gradient}; color: white; text-decoration: none; border-radius: 8px; font-size: 18px; font-weight: 600;",
+                            "{label} \u{2192}"
+                        }
                     }
                 }
             }
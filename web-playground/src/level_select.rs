@@ -1,47 +1,6 @@
 use dioxus::prelude::*;
 use crate::Route;
-
-struct LevelInfo {
-    name: &'static str,
-    desc: &'static str,
-    route: Route,
-}
-
-const LEVELS: &[LevelInfo] = &[
-    // --- Basic single controls ---
-    LevelInfo { name: "Level 1",  desc: "Click the button",         route: Route::Level1 {} },
-    LevelInfo { name: "Level 2",  desc: "Toggle the switch",        route: Route::Level2 {} },
-    LevelInfo { name: "Level 3",  desc: "Type the word",            route: Route::Level3 {} },
-    LevelInfo { name: "Level 4",  desc: "Select the right option",  route: Route::Level4 {} },
-    LevelInfo { name: "Level 5",  desc: "Radio buttons",            route: Route::Level17 {} },
-    LevelInfo { name: "Level 6",  desc: "Slider",                   route: Route::Level16 {} },
-    LevelInfo { name: "Level 7",  desc: "Number stepper",           route: Route::Level18 {} },
-    LevelInfo { name: "Level 8",  desc: "Star rating",              route: Route::Level19 {} },
-    LevelInfo { name: "Level 9",  desc: "Tabs",                     route: Route::Level20 {} },
-    // --- Scrolling ---
-    LevelInfo { name: "Level 10", desc: "Scroll & click",           route: Route::LevelScroll {} },
-    // --- Targeted identification ---
-    LevelInfo { name: "Level 11", desc: "Find the right button",    route: Route::Level5 {} },
-    LevelInfo { name: "Level 12", desc: "Click the right toggle",   route: Route::Level6 {} },
-    LevelInfo { name: "Level 13", desc: "Type into the right input", route: Route::Level7 {} },
-    LevelInfo { name: "Level 14", desc: "Accordion",                route: Route::Level21 {} },
-    // --- Multi-element compound ---
-    LevelInfo { name: "Level 15", desc: "Multi-dropdown",           route: Route::Level8 {} },
-    LevelInfo { name: "Level 16", desc: "Mixed inputs",             route: Route::Level9 {} },
-    LevelInfo { name: "Level 17", desc: "Form submission",          route: Route::Level10 {} },
-    // --- Complex compound ---
-    LevelInfo { name: "Level 18", desc: "Carousel reading",         route: Route::Level11 {} },
-    LevelInfo { name: "Level 19", desc: "Grid form",                route: Route::Level12 {} },
-    LevelInfo { name: "Level 20", desc: "Table input",              route: Route::Level13 {} },
-    LevelInfo { name: "Level 21", desc: "License agreement",        route: Route::Level14 {} },
-    LevelInfo { name: "Level 22", desc: "Drag & drop",              route: Route::Level15 {} },
-    LevelInfo { name: "Level 23", desc: "Modal dialog",             route: Route::Level22 {} },
-    LevelInfo { name: "Level 24", desc: "Context menu",             route: Route::Level23 {} },
-    LevelInfo { name: "Level 25", desc: "Search autocomplete",      route: Route::Level24 {} },
-    LevelInfo { name: "Level 26", desc: "Sortable list",            route: Route::Level25 {} },
-    LevelInfo { name: "Level 27", desc: "Multi-select tags",        route: Route::Level26 {} },
-    LevelInfo { name: "Level 28", desc: "Toast dismiss",            route: Route::Level27 {} },
-];
+use crate::levels::LevelRegistry;
 
 const COLS: usize = 4;
 const ROWS: usize = 5;
@@ -51,7 +10,7 @@ static LEVEL_PAGE: GlobalSignal<usize> = Signal::global(|| 0);
 
 /// Total number of slots (levels + locked placeholders) to fill pages evenly
 fn total_slots() -> usize {
-    let count = LEVELS.len().max(PER_PAGE);
+    let count = LevelRegistry::all().len().max(PER_PAGE);
     // Round up to next multiple of PER_PAGE
     ((count + PER_PAGE - 1) / PER_PAGE) * PER_PAGE
 }
@@ -98,24 +57,35 @@ pub fn LevelSelect() -> Element {
                 style: "display: grid; grid-template-columns: repeat(4, 180px); gap: 16px;",
 
                 for idx in start..end {
-                    if idx < LEVELS.len() {
+                    if let Some(level) = LevelRegistry::all().get(idx) {
                         {
-                            let level = &LEVELS[idx];
+                            let category = level.category.as_str();
+                            let pips = "\u{25CF}".repeat(level.difficulty as usize);
+                            let kinds = level.interactions.iter().map(|k| k.as_str()).collect::<Vec<_>>().join(" \u{00B7} ");
                             rsx! {
                                 Link {
-                                    to: level.route.clone(),
+                                    to: Route::LevelPage { slug: level.slug.to_string() },
                                     style: "background: #1a1a2e; border: 1px solid #2a2a4a; border-radius: 10px; padding: 24px; text-decoration: none; transition: border-color 0.2s;",
                                     div {
                                         style: "color: #6366f1; font-size: 13px; font-weight: 600; margin-bottom: 8px; font-family: monospace;",
-                                        "{idx + 1}"
+                                        "{level.id}"
                                     }
                                     h3 {
                                         style: "color: #e5e7eb; font-size: 18px; margin: 0 0 8px 0;",
-                                        "{level.name}"
+                                        "Level {level.id}"
                                     }
                                     p {
                                         style: "color: #6b7280; font-size: 14px; margin: 0;",
-                                        "{level.desc}"
+                                        "{level.title}"
+                                    }
+                                    div {
+                                        style: "display: flex; justify-content: space-between; margin-top: 12px; color: #4b5563; font-size: 11px; font-family: monospace;",
+                                        span { "{category}" }
+                                        span { title: "difficulty", "{pips}" }
+                                    }
+                                    div {
+                                        style: "margin-top: 4px; color: #4b5563; font-size: 11px; font-family: monospace;",
+                                        "{kinds}"
                                     }
                                 }
                             }
@@ -0,0 +1,209 @@
+//! Accessibility tree — a simplified a11y observation of the rendered level.
+//!
+//! With `?a11y=1` GroundTruth walks `#viewport` on every DOM poll and adds
+//! the result to the payload as `"a11y"`, so dataset records carry it next
+//! to the ground truth. Each node is `{role, name, bbox, state, children}`:
+//!
+//! - `role` is the ARIA `role` attribute, else the tag's implicit role
+//!   (`button`, `textbox`, `checkbox`, `combobox`, `heading`, ...), with
+//!   `text` for plain text leaves
+//! - unnamed generic containers are flattened into their parent, like the
+//!   simplified tree browsers show in dev tools
+//! - `aria-hidden` and `hidden` subtrees are skipped
+//! - `bbox` is window space, in the payload's coordinate space
+
+use js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+
+use crate::ui_node::escape_json;
+
+/// Longest accessible name kept, in characters.
+const MAX_NAME: usize = 80;
+
+/// Whether `?a11y=1` is on.
+pub fn enabled() -> bool {
+    super::query_param("a11y").is_some_and(|s| s.trim() == "1")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct A11yNode {
+    pub role: String,
+    pub name: String,
+    /// Window-space CSS pixels: [x, y, w, h].
+    pub bbox: [f64; 4],
+    /// ("checked", "true"), ("value", "abc"), ...
+    pub state: Vec<(&'static str, String)>,
+    pub children: Vec<A11yNode>,
+}
+
+impl A11yNode {
+    /// JSON with boxes scaled by `k` (the payload's output scale).
+    pub fn to_json(&self, k: f64) -> String {
+        let state: Vec<String> = self.state.iter()
+            .map(|(key, value)| format!(r#""{}":"{}""#, key, escape_json(value)))
+            .collect();
+        let children: Vec<String> = self.children.iter().map(|c| c.to_json(k)).collect();
+        let b = self.bbox.map(|v| (v * k) as i32);
+        format!(
+            r#"{{"role":"{}","name":"{}","bbox":[{},{},{},{}],"state":{{{}}},"children":[{}]}}"#,
+            escape_json(&self.role),
+            escape_json(&self.name),
+            b[0], b[1], b[2], b[3],
+            state.join(","),
+            children.join(","),
+        )
+    }
+}
+
+/// Snapshot of `#viewport`, or `None` before it is rendered.
+pub fn snapshot() -> Option<A11yNode> {
+    let document = web_sys::window()?.document()?;
+    let viewport = document.get_element_by_id("viewport")?;
+    let focused = document.active_element();
+    let mut children = Vec::new();
+    walk_children(&viewport, focused.as_ref(), &mut children);
+    Some(A11yNode {
+        role: "document".to_string(),
+        name: String::new(),
+        bbox: bbox(&viewport),
+        state: Vec::new(),
+        children,
+    })
+}
+
+fn bbox(el: &web_sys::Element) -> [f64; 4] {
+    let r = el.get_bounding_client_rect();
+    [r.x(), r.y(), r.width(), r.height()]
+}
+
+fn walk_children(el: &web_sys::Element, focused: Option<&web_sys::Element>, out: &mut Vec<A11yNode>) {
+    let kids = el.children();
+    for i in 0..kids.length() {
+        if let Some(child) = kids.item(i) {
+            walk(&child, focused, out);
+        }
+    }
+}
+
+/// Append `el` (or, for unnamed generic containers, its children) to `out`.
+fn walk(el: &web_sys::Element, focused: Option<&web_sys::Element>, out: &mut Vec<A11yNode>) {
+    if el.get_attribute("aria-hidden").as_deref() == Some("true") || el.has_attribute("hidden") {
+        return;
+    }
+    let tag = el.tag_name().to_ascii_lowercase();
+    if matches!(tag.as_str(), "script" | "style" | "template") {
+        return;
+    }
+    let role = match el.get_attribute("role") {
+        Some(r) if r == "none" || r == "presentation" => "generic".to_string(),
+        Some(r) => r,
+        None => implicit_role(el, &tag).to_string(),
+    };
+    let leaf = el.children().length() == 0;
+    let text = collapse(&el.text_content().unwrap_or_default());
+    let role = if role == "generic" && leaf && !text.is_empty() { "text".to_string() } else { role };
+    let name = name_of(el, &role, &text);
+    let b = bbox(el);
+
+    let mut children = Vec::new();
+    if !matches!(role.as_str(), "text" | "textbox" | "combobox" | "button" | "option") {
+        walk_children(el, focused, &mut children);
+    }
+    if role == "generic" && name.is_empty() {
+        out.extend(children);
+        return;
+    }
+    // Zero-sized wrappers only matter for what they contain
+    if b[2] <= 0.0 && b[3] <= 0.0 && children.is_empty() {
+        return;
+    }
+    out.push(A11yNode { role, name, bbox: b, state: state_of(el, focused), children });
+}
+
+fn implicit_role(el: &web_sys::Element, tag: &str) -> &'static str {
+    match tag {
+        "button" => "button",
+        "a" if el.has_attribute("href") => "link",
+        "input" => match el.get_attribute("type").unwrap_or_default().to_ascii_lowercase().as_str() {
+            "checkbox" => "checkbox",
+            "radio" => "radio",
+            "range" => "slider",
+            "button" | "submit" | "reset" => "button",
+            "hidden" => "generic",
+            _ => "textbox",
+        },
+        "textarea" => "textbox",
+        "select" => "combobox",
+        "option" => "option",
+        "img" => "img",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "ul" | "ol" => "list",
+        "li" => "listitem",
+        "form" => "form",
+        "dialog" => "dialog",
+        "table" => "table",
+        "tr" => "row",
+        "td" => "cell",
+        "th" => "columnheader",
+        "nav" => "navigation",
+        "progress" => "progressbar",
+        "label" => "label",
+        _ => "generic",
+    }
+}
+
+/// Collapse whitespace runs and cap the length.
+fn collapse(text: &str) -> String {
+    let joined = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    joined.chars().take(MAX_NAME).collect()
+}
+
+fn name_of(el: &web_sys::Element, role: &str, text: &str) -> String {
+    for attr in ["aria-label", "alt", "title", "data-label", "placeholder"] {
+        if let Some(v) = el.get_attribute(attr).filter(|v| !v.trim().is_empty()) {
+            return collapse(&v);
+        }
+    }
+    match role {
+        "button" | "link" | "option" | "heading" | "text" | "label" | "listitem" | "cell" | "columnheader"
+        | "tab" | "menuitem" => text.to_string(),
+        _ => String::new(),
+    }
+}
+
+fn state_of(el: &web_sys::Element, focused: Option<&web_sys::Element>) -> Vec<(&'static str, String)> {
+    let prop = |key: &str| Reflect::get(el, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED);
+    let mut state = Vec::new();
+    let tag = el.tag_name();
+    if matches!(tag.as_str(), "INPUT" | "TEXTAREA" | "SELECT") {
+        let kind = el.get_attribute("type").unwrap_or_default();
+        if kind == "checkbox" || kind == "radio" {
+            state.push(("checked", prop("checked").as_bool().unwrap_or(false).to_string()));
+        } else if let Some(value) = prop("value").as_string() {
+            state.push(("value", value));
+        }
+        if prop("disabled").as_bool() == Some(true) {
+            state.push(("disabled", "true".to_string()));
+        }
+    }
+    if tag == "OPTION" && prop("selected").as_bool() == Some(true) {
+        state.push(("selected", "true".to_string()));
+    }
+    for (attr, key) in [
+        ("aria-checked", "checked"),
+        ("aria-selected", "selected"),
+        ("aria-expanded", "expanded"),
+        ("aria-pressed", "pressed"),
+        ("aria-disabled", "disabled"),
+        ("aria-valuenow", "value"),
+    ] {
+        let seen = state.iter().any(|(k, _)| *k == key);
+        if let (false, Some(v)) = (seen, el.get_attribute(attr)) {
+            state.push((key, v));
+        }
+    }
+    if focused.is_some_and(|f| f == el) {
+        state.push(("focused", "true".to_string()));
+    }
+    state
+}
@@ -0,0 +1,116 @@
+//! Canvas backgrounds beyond flat colours.
+//!
+//! With `?bg=varied`, `random_canvas_bg` also draws gradients, a subtle grain
+//! texture and tiled patterns — all plain CSS `background` values built from
+//! the canvas palette. The last drawn style is remembered so GroundTruth can
+//! name it in the description.
+
+use std::cell::Cell;
+
+use rand::Rng;
+
+/// Broad look of a canvas background, as named in descriptions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BgStyle {
+    Flat,
+    LinearGradient,
+    RadialGradient,
+    Noise,
+    Stripes,
+    Dots,
+    Grid,
+    Checker,
+}
+
+impl BgStyle {
+    const ALL: &[Self] = &[
+        Self::Flat,
+        Self::LinearGradient,
+        Self::RadialGradient,
+        Self::Noise,
+        Self::Stripes,
+        Self::Dots,
+        Self::Grid,
+        Self::Checker,
+    ];
+
+    /// Key suffix for the `bg.*` strings ("linear_gradient", "dots", ...).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Flat => "flat",
+            Self::LinearGradient => "linear_gradient",
+            Self::RadialGradient => "radial_gradient",
+            Self::Noise => "noise",
+            Self::Stripes => "stripes",
+            Self::Dots => "dots",
+            Self::Grid => "grid",
+            Self::Checker => "checker",
+        }
+    }
+}
+
+thread_local! {
+    static LAST: Cell<Option<BgStyle>> = const { Cell::new(None) };
+}
+
+/// Whether `?bg=varied` is on.
+pub fn varied() -> bool {
+    super::query_param("bg").is_some_and(|s| s.trim() == "varied")
+}
+
+/// Style of the most recent varied background, `None` outside that mode.
+pub fn last_style() -> Option<BgStyle> {
+    LAST.with(|l| l.get())
+}
+
+/// CSS `background` value of `style` over base colour `base`, with `other`
+/// as the second colour for two-tone styles.
+fn css(rng: &mut impl Rng, style: BgStyle, base: &str, other: &str) -> String {
+    match style {
+        BgStyle::Flat => base.to_string(),
+        BgStyle::LinearGradient => {
+            let angle = rng.random_range(0..360u32);
+            format!("linear-gradient({angle}deg, {base}, {other})")
+        }
+        BgStyle::RadialGradient => {
+            let (x, y) = (rng.random_range(10..=90u32), rng.random_range(10..=90u32));
+            format!("radial-gradient(circle at {x}% {y}%, {other}, {base} 70%)")
+        }
+        BgStyle::Noise => {
+            // Two offset grain layers at co-prime periods read as noise
+            format!(
+                "repeating-radial-gradient(circle at 17% 31%, rgba(255,255,255,0.06) 0 1px, transparent 1px 3px), \
+                 repeating-radial-gradient(circle at 73% 67%, rgba(0,0,0,0.08) 0 1px, transparent 1px 5px), {base}"
+            )
+        }
+        BgStyle::Stripes => {
+            let angle = [0u32, 45, 90, 135][rng.random_range(0..4)];
+            let w = rng.random_range(8..=24u32);
+            format!("repeating-linear-gradient({angle}deg, {base} 0 {w}px, {other} {w}px {}px)", w * 2)
+        }
+        BgStyle::Dots => {
+            let s = rng.random_range(14..=32u32);
+            format!("radial-gradient(rgba(255,255,255,0.25) 1.5px, transparent 2px) 0 0 / {s}px {s}px, {base}")
+        }
+        BgStyle::Grid => {
+            let s = rng.random_range(20..=48u32);
+            format!(
+                "linear-gradient(rgba(255,255,255,0.12) 1px, transparent 1px) 0 0 / {s}px {s}px, \
+                 linear-gradient(90deg, rgba(255,255,255,0.12) 1px, transparent 1px) 0 0 / {s}px {s}px, {base}"
+            )
+        }
+        BgStyle::Checker => {
+            let s = rng.random_range(24..=64u32);
+            format!("conic-gradient({base} 25%, {other} 0 50%, {base} 0 75%, {other} 0) 0 0 / {s}px {s}px")
+        }
+    }
+}
+
+/// Draw a varied background over `base` (already drawn from `palette`) and
+/// remember its style.
+pub fn sample(rng: &mut impl Rng, base: &str, palette: &[&str]) -> String {
+    let style = BgStyle::ALL[rng.random_range(0..BgStyle::ALL.len())];
+    let other = palette[rng.random_range(0..palette.len())];
+    LAST.with(|l| l.set(Some(style)));
+    css(rng, style, base, other)
+}
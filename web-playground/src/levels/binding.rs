@@ -0,0 +1,46 @@
+//! Live state context — interactive elements register their current value
+//! here, and `GroundTruth` binds those values into the level's tree before
+//! resolving it, so every mid-round snapshot carries fresh state.
+//!
+//! ```ignore
+//! let mut live = use_live_state();
+//! // in an element's handler
+//! live.set("Size", LiveValue::Index(oi));
+//! ```
+//!
+//! Values are scoped to the round they were registered in; a new round
+//! starts from an empty binding.
+
+use dioxus::prelude::*;
+
+use crate::ui_node::{LiveValue, StateBinding, UINode};
+
+/// Context holding the current round's `StateBinding`.
+#[derive(Clone, Copy)]
+pub struct LiveState(Signal<(u64, StateBinding)>);
+
+impl LiveState {
+    /// Register `label`'s current value for this round.
+    pub fn set(&mut self, label: impl Into<String>, value: LiveValue) {
+        let round = super::round_index();
+        let mut live = self.0.write();
+        if live.0 != round {
+            *live = (round, StateBinding::new());
+        }
+        live.1.set(label, value);
+    }
+
+    /// Bind this round's values into `tree`. Subscribes the caller.
+    pub fn apply(&self, tree: &mut UINode) -> usize {
+        let live = self.0.read();
+        if live.0 != super::round_index() {
+            return 0;
+        }
+        tree.bind(&live.1)
+    }
+}
+
+/// Provide a `LiveState` for this level and its `GroundTruth`.
+pub fn use_live_state() -> LiveState {
+    use_context_provider(|| LiveState(Signal::new((super::round_index(), StateBinding::new()))))
+}
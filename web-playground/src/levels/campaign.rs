@@ -0,0 +1,76 @@
+//! `/campaign` — every level once, easiest first. Progress lives in the
+//! `GameSession` (persisted to localStorage) and a solved round moves on to
+//! the next level.
+
+use dioxus::prelude::*;
+
+use crate::Route;
+use super::{campaign_order, GameSession, LevelHost, LevelRun};
+
+#[component]
+pub fn Campaign() -> Element {
+    let mut session = use_context::<GameSession>();
+    let order = campaign_order();
+    let total = order.len();
+
+    use_context_provider(move || LevelRun {
+        score: session.score,
+        on_complete: Callback::new(move |_| {
+            let next = *session.campaign_step.peek() + 1;
+            session.set_campaign_step(next);
+        }),
+    });
+
+    let step = (session.campaign_step)();
+    let pct = step as f32 / total.max(1) as f32 * 100.0;
+
+    let Some(level) = order.get(step).copied() else {
+        let score = session.score;
+        return rsx! {
+            div {
+                style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; justify-content: center; gap: 16px; font-family: system-ui, sans-serif;",
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 24px;",
+                    "Campaign complete"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "{total} levels \u{00B7} score: {score}"
+                }
+                div {
+                    style: "display: flex; gap: 16px; align-items: center;",
+                    button {
+                        style: "padding: 8px 16px; background: #6366f1; color: white; border: none; border-radius: 6px; font-size: 14px; cursor: pointer; font-family: system-ui, sans-serif;",
+                        onclick: move |_| {
+                            session.score.set(0);
+                            session.set_campaign_step(0);
+                        },
+                        "Restart campaign"
+                    }
+                    Link {
+                        to: Route::LevelSelect {},
+                        style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                        "\u{2190} Levels"
+                    }
+                }
+            }
+        };
+    };
+
+    let position = step + 1;
+
+    rsx! {
+        // Fixed overlays so the level's own layout (and autoFit) is untouched
+        div {
+            style: "position: fixed; left: 0; top: 0; width: 100%; height: 4px; background: #1a1a2e; z-index: 1000;",
+            div {
+                style: "width: {pct}%; height: 100%; background: #6366f1; transition: width 0.3s;",
+            }
+        }
+        div {
+            style: "position: fixed; right: 12px; top: 10px; padding: 4px 10px; background: rgba(26,26,46,0.9); border: 1px solid #2a2a4a; border-radius: 6px; color: #9ca3af; font-size: 12px; font-family: monospace; z-index: 1000;",
+            "campaign {position}/{total} \u{00B7} {level.title}"
+        }
+        LevelHost { slug: level.slug }
+    }
+}
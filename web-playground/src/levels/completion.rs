@@ -0,0 +1,96 @@
+//! Completion bus — one observable success signal for every level.
+//!
+//! `LevelScore::complete` emits a `LevelCompletion` on the `CompletionBus`
+//! context provided by the app root, and calls `window.__onLevelComplete`
+//! with the same fields when a driver has set it:
+//!
+//! ```js
+//! window.__onLevelComplete = e => console.log(e.level, e.seed, e.round, e.elapsed_ms);
+//! ```
+//!
+//! Drivers can detect success from that callback instead of watching the
+//! score text in the DOM.
+
+use std::cell::Cell;
+
+use dioxus::prelude::*;
+use js_sys::{Function, Reflect};
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+thread_local! {
+    /// `Date.now()` when the current round started.
+    static ROUND_STARTED: Cell<f64> = const { Cell::new(0.0) };
+}
+
+/// Restart the round clock (a level mounted or a round was solved).
+pub(super) fn start_round_clock() {
+    ROUND_STARTED.with(|t| t.set(js_sys::Date::now()));
+}
+
+/// A solved round.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelCompletion {
+    /// Level path, e.g. "/level5".
+    pub level: String,
+    pub seed: Option<u64>,
+    /// Index of the solved round in this page session, from 0.
+    pub round: u64,
+    /// Time from the round starting to it being solved.
+    pub elapsed_ms: f64,
+}
+
+impl LevelCompletion {
+    /// Build the event for the round being solved now.
+    pub(super) fn now(round: u64) -> Self {
+        let started = ROUND_STARTED.with(|t| t.get());
+        let now = js_sys::Date::now();
+        Self {
+            level: super::ground_truth::current_level_id(),
+            seed: super::current_seed(),
+            round,
+            elapsed_ms: if started > 0.0 { now - started } else { 0.0 },
+        }
+    }
+
+    fn to_js(&self) -> JsValue {
+        let obj = js_sys::Object::new();
+        let seed = self.seed.map_or(JsValue::NULL, |s| JsValue::from_f64(s as f64));
+        let _ = Reflect::set(&obj, &"level".into(), &self.level.as_str().into());
+        let _ = Reflect::set(&obj, &"seed".into(), &seed);
+        let _ = Reflect::set(&obj, &"round".into(), &JsValue::from_f64(self.round as f64));
+        let _ = Reflect::set(&obj, &"elapsed_ms".into(), &JsValue::from_f64(self.elapsed_ms));
+        obj.into()
+    }
+}
+
+/// Context carrying the latest completion.
+#[derive(Clone, Copy)]
+pub struct CompletionBus(Signal<Option<LevelCompletion>>);
+
+impl CompletionBus {
+    /// Provide the bus at the app root.
+    pub fn provide() -> Self {
+        use_context_provider(|| CompletionBus(Signal::new(None)))
+    }
+
+    /// The most recent completion. Subscribes the caller.
+    pub fn last(&self) -> Option<LevelCompletion> {
+        (self.0)()
+    }
+
+    /// Publish a completion to subscribers and `window.__onLevelComplete`.
+    pub fn emit(&mut self, event: LevelCompletion) {
+        notify_window(&event);
+        self.0.set(Some(event));
+    }
+}
+
+/// Call `window.__onLevelComplete(event)` if it is a function.
+pub(super) fn notify_window(event: &LevelCompletion) {
+    let Some(window) = web_sys::window() else { return };
+    let callback = Reflect::get(&window, &"__onLevelComplete".into()).unwrap_or(JsValue::UNDEFINED);
+    let Some(f) = callback.dyn_ref::<Function>() else { return };
+    if f.call1(&JsValue::NULL, &event.to_js()).is_err() {
+        web_sys::console::warn_1(&"__onLevelComplete threw".into());
+    }
+}
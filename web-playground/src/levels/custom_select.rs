@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 
+use crate::ui_node::{DropdownMode, DROPDOWN_SEARCH_LABEL};
+
 const CHEVRON_SVG: &str = "url('data:image/svg+xml;utf8,<svg xmlns=%22http://www.w3.org/2000/svg%22 width=%2212%22 height=%2212%22 viewBox=%220 0 24 24%22 fill=%22none%22 stroke=%22%236b7280%22 stroke-width=%222%22><polyline points=%226 9 12 15 18 9%22/></svg>')";
 
 const PANEL_MAX_H: f64 = 184.0;
@@ -23,6 +25,27 @@ fn get_zoom() -> f64 {
 
 static COUNTER: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
 
+/// Height of the searchable menu's filter field, border included.
+const SEARCH_H: f64 = 41.0;
+
+/// Focus element `id` once the frame that mounts it has rendered.
+fn focus_soon(id: &str) {
+    document::eval(&format!("requestAnimationFrame(() => document.getElementById('{id}')?.focus())"));
+}
+
+/// Options containing `query`, case-insensitively, with their index in `options`.
+fn filter_options(options: &[String], query: &str) -> Vec<(usize, String)> {
+    let needle = query.to_lowercase();
+    options.iter()
+        .enumerate()
+        .filter(|(_, o)| o.to_lowercase().contains(&needle))
+        .map(|(i, o)| (i, o.clone()))
+        .collect()
+}
+
+/// Styled select. Once open, arrow keys move a highlight through the
+/// options and Enter picks it; `DropdownMode::Search` adds a filter field
+/// at the top of the menu.
 #[component]
 pub fn CustomSelect(
     options: Vec<String>,
@@ -30,15 +53,27 @@ pub fn CustomSelect(
     target_option: String,
     border_color: String,
     on_select: EventHandler<String>,
+    /// Label of this select's dropdown node; the open menu reports its
+    /// options to the ground truth under it.
+    #[props(default)]
+    label: String,
+    #[props(default)]
+    mode: DropdownMode,
 ) -> Element {
     let mut is_open = use_signal(|| false);
     let mut selected_text = use_signal(|| String::new());
     let mut panel_pos = use_signal(|| (0.0f64, 0.0f64, 0.0f64));
+    let mut query = use_signal(String::new);
+    // Index into the filtered options
+    let mut highlight = use_signal(|| None::<usize>);
 
     let trigger_id = use_hook(|| {
         let n = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         format!("cs-{n}")
     });
+    let panel_id = format!("{trigger_id}-panel");
+    let search_id = format!("{trigger_id}-search");
+    let searchable = mode.searchable();
 
     let open = is_open();
     let display = if selected_text.read().is_empty() {
@@ -46,13 +81,20 @@ pub fn CustomSelect(
     } else {
         selected_text.read().clone()
     };
-    let display_color = if selected_text.read().is_empty() { "#9ca3af" } else { "#111" };
+    let display_color = if selected_text.read().is_empty() {
+        "var(--theme-muted, #9ca3af)"
+    } else {
+        "var(--theme-text, #111)"
+    };
 
     let trigger_is_target = is_target && !open;
+    let filtered = filter_options(&options, &query.read());
+    let highlighted = highlight().filter(|&h| h < filtered.len());
 
     let trigger_style = format!(
         "padding: 10px 32px 10px 14px; border: 1px solid {}; border-radius: 6px; \
-         font-size: 14px; font-family: system-ui, sans-serif; background: white; \
+         font-size: calc(14px * var(--type-scale, 1)); font-family: inherit; \
+         background-color: var(--theme-surface, white); \
          color: {}; cursor: pointer; user-select: none; width: 100%; \
          box-sizing: border-box; text-align: left; \
          background-image: {}; background-repeat: no-repeat; \
@@ -63,13 +105,17 @@ pub fn CustomSelect(
     let (panel_left, panel_top, panel_width) = *panel_pos.read();
     let panel_style = format!(
         "position: fixed; left: {panel_left}px; top: {panel_top}px; width: {panel_width}px; \
-         background: white; border: 1px solid #d1d5db; \
+         background: var(--theme-surface, white); border: 1px solid var(--theme-border, #d1d5db); \
          border-radius: 6px; \
          box-shadow: 0 4px 12px rgba(0,0,0,0.15); \
-         z-index: 1000; max-height: 180px; overflow-y: auto;"
+         z-index: 1000; outline: none; overflow: hidden;"
     );
 
     let tid = trigger_id.clone();
+    let focus_id = if searchable { search_id.clone() } else { panel_id.clone() };
+    let options_for_open = options.clone();
+    let keyed = filtered.clone();
+    let row_prefix = trigger_id.clone();
 
     rsx! {
         div {
@@ -94,7 +140,8 @@ pub fn CustomSelect(
                             let zoom = get_zoom();
                             let bottom = rect.y() + rect.height();
                             // Panel max-height in screen pixels
-                            let panel_screen_h = PANEL_MAX_H * zoom;
+                            let search_h = if searchable { SEARCH_H } else { 0.0 };
+                            let panel_screen_h = (PANEL_MAX_H + search_h) * zoom;
                             let window_h = web_sys::window()
                                 .and_then(|w| w.inner_height().ok())
                                 .and_then(|v| v.as_f64())
@@ -108,7 +155,11 @@ pub fn CustomSelect(
                             panel_pos.set((rect.x() / zoom, top_screen / zoom, rect.width() / zoom));
                         }
                     }
+                    // Start from the current selection, unfiltered
+                    query.set(String::new());
+                    highlight.set(options_for_open.iter().position(|o| *o == *selected_text.peek()));
                     is_open.set(true);
+                    focus_soon(&focus_id);
                 },
                 "{display}"
             }
@@ -126,28 +177,86 @@ pub fn CustomSelect(
 
                 // Options panel
                 div {
+                    id: "{panel_id}",
+                    tabindex: "-1",
                     style: "{panel_style}",
+                    onkeydown: move |e: KeyboardEvent| {
+                        let n = keyed.len();
+                        match e.key() {
+                            Key::ArrowDown | Key::ArrowUp if n > 0 => {
+                                e.prevent_default();
+                                let next = match (e.key(), highlighted) {
+                                    (Key::ArrowDown, None) => 0,
+                                    (Key::ArrowDown, Some(h)) => (h + 1).min(n - 1),
+                                    (_, None) => n - 1,
+                                    (_, Some(h)) => h.saturating_sub(1),
+                                };
+                                highlight.set(Some(next));
+                                let row = format!("{}-opt-{}", row_prefix, keyed[next].0);
+                                document::eval(&format!("document.getElementById('{row}')?.scrollIntoView({{ block: 'nearest' }})"));
+                            }
+                            Key::Enter => {
+                                e.prevent_default();
+                                // A filter that leaves one option picks it outright
+                                let pick = highlighted.or((n == 1).then_some(0));
+                                if let Some((_, opt)) = pick.and_then(|h| keyed.get(h)) {
+                                    selected_text.set(opt.clone());
+                                    is_open.set(false);
+                                    on_select.call(opt.clone());
+                                }
+                            }
+                            Key::Escape => is_open.set(false),
+                            _ => {}
+                        }
+                    },
+
+                    if searchable {
+                        input {
+                            id: "{search_id}",
+                            r#type: "text",
+                            tabindex: "-1",
+                            class: if is_target { "target" } else { "" },
+                            "data-label": "{DROPDOWN_SEARCH_LABEL}",
+                            placeholder: "{DROPDOWN_SEARCH_LABEL}",
+                            value: "{query}",
+                            style: "display: block; width: 100%; height: {SEARCH_H}px; box-sizing: border-box; padding: 8px 14px; border: none; \
+                                    border-bottom: 1px solid var(--theme-border, #e5e7eb); outline: none; font-size: calc(14px * var(--type-scale, 1)); \
+                                    font-family: inherit; color: var(--theme-text, #111); background: transparent;",
+                            oninput: move |e: Event<FormData>| {
+                                query.set(e.value());
+                                highlight.set(None);
+                            },
+                        }
+                    }
+
+                    div {
+                        "data-menu": "{label}",
+                        style: "max-height: 180px; overflow-y: auto;",
+
+                        for (fi, (oi, opt)) in filtered.iter().enumerate() {
+                            {
+                                let opt_val = opt.clone();
+                                let opt_display = opt.clone();
+                                let is_target_opt = is_target && *opt == target_option;
+                                let row_bg = if highlighted == Some(fi) { "var(--theme-hover, #eef2ff)" } else { "transparent" };
 
-                    for opt in options.iter() {
-                        {
-                            let opt_val = opt.clone();
-                            let opt_display = opt.clone();
-                            let is_target_opt = is_target && *opt == target_option;
-
-                            rsx! {
-                                div {
-                                    class: if is_target_opt { "target" } else { "" },
-                                    "data-label": "{opt_display}",
-                                    tabindex: "-1",
-                                    style: "padding: 8px 14px; cursor: pointer; font-size: 14px; \
-                                            color: #111; font-family: system-ui, sans-serif;",
-                                    onclick: move |e| {
-                                        e.stop_propagation();
-                                        selected_text.set(opt_val.clone());
-                                        is_open.set(false);
-                                        on_select.call(opt_val.clone());
-                                    },
-                                    "{opt_display}"
+                                rsx! {
+                                    div {
+                                        id: "{trigger_id}-opt-{oi}",
+                                        class: if is_target_opt { "target" } else { "" },
+                                        "data-label": "{opt_display}",
+                                        tabindex: "-1",
+                                        style: "padding: 8px 14px; cursor: pointer; font-size: calc(14px * var(--type-scale, 1)); \
+                                                color: var(--theme-text, #111); font-family: inherit; background: {row_bg};",
+                                        onmouseenter: move |_| highlight.set(Some(fi)),
+                                        onclick: move |e| {
+                                            e.stop_propagation();
+                                            selected_text.set(opt_val.clone());
+                                            is_open.set(false);
+                                            on_select.call(opt_val.clone());
+                                        },
+                                        "{opt_display}"
+                                    }
                                 }
                             }
                         }
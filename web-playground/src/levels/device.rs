@@ -0,0 +1,51 @@
+//! Device profiles — render levels at a phone or tablet size.
+//!
+//! `?device=iphone|pixel|tablet` pins the `#viewport` to the profile's CSS
+//! size (autoFit reads `window.__deviceProfile`), makes the solver drive it
+//! with touch input, and is recorded in the ground truth payload.
+
+use dioxus::prelude::*;
+
+/// Screen of a simulated device, in CSS pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeviceProfile {
+    pub name: &'static str,
+    pub width: u32,
+    pub height: u32,
+    /// Device pixel ratio of the real hardware. Recorded, not emulated.
+    pub dpr: f32,
+    /// Input is touch rather than mouse.
+    pub touch: bool,
+}
+
+pub const PROFILES: &[DeviceProfile] = &[
+    DeviceProfile { name: "iphone", width: 390, height: 844, dpr: 3.0, touch: true },
+    DeviceProfile { name: "pixel", width: 412, height: 915, dpr: 2.625, touch: true },
+    DeviceProfile { name: "tablet", width: 820, height: 1180, dpr: 2.0, touch: true },
+];
+
+impl DeviceProfile {
+    pub fn by_name(name: &str) -> Option<&'static DeviceProfile> {
+        PROFILES.iter().find(|p| p.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Profile chosen with `?device=`, if any.
+    pub fn current() -> Option<&'static DeviceProfile> {
+        super::query_param("device").and_then(|s| Self::by_name(&s))
+    }
+
+    pub fn to_json(self) -> String {
+        format!(
+            r#"{{"name":"{}","width":{},"height":{},"dpr":{},"touch":{}}}"#,
+            self.name, self.width, self.height, self.dpr, self.touch,
+        )
+    }
+
+    /// Publish the active profile as `window.__deviceProfile` (null when off).
+    pub fn install_js() {
+        let json = Self::current().map_or("null".to_string(), |p| p.to_json());
+        document::eval(&format!(
+            "window.__deviceProfile = {json}; window.__autoFitSchedule && window.__autoFitSchedule();",
+        ));
+    }
+}
@@ -0,0 +1,173 @@
+//! Difficulty — one set of generation knobs every level draws through.
+//!
+//! `?difficulty=easy|medium|hard` picks a preset for the session; without
+//! it levels use `MEDIUM`, which is each level's own ranges and replays
+//! seeded runs unchanged. Curricula are the same levels at rising presets:
+//! `window.__difficultyPresets` lists them and `window.__difficulty` is the
+//! active one, which the ground truth payload records by name.
+//!
+//! Each `random_levelN(d)` takes the preset and uses what applies to it:
+//!
+//! - `elements` — which part of the level's element-count range is drawn
+//! - `distractors` — clutter per round when `?distractors=` doesn't say
+//! - `font_px` — type sizes `?typography=1` may pick
+//! - `target_scale` — scale of pool snippets placed as targets
+//! - `noise_px` — random offset added to each placed card or element
+//!
+//! Knobs at their neutral value (full range, scale 1, no noise) never draw
+//! from the RNG, so only non-medium presets change a seeded round.
+
+use std::ops::RangeInclusive;
+
+use dioxus::prelude::*;
+use rand::Rng;
+
+/// Generation knobs for a round.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Difficulty {
+    pub name: &'static str,
+    /// Slice of a level's element-count range drawn from, as fractions of
+    /// it: `(0.0, 1.0)` is the whole range, `(0.5, 1.0)` its upper half.
+    pub elements: (f32, f32),
+    /// Distractors per round when `?distractors=` is absent.
+    pub distractors: usize,
+    /// Smallest and largest font size in CSS px.
+    pub font_px: (f32, f32),
+    /// Smallest and largest scale for pool-snippet targets.
+    pub target_scale: (f32, f32),
+    /// Largest offset, in CSS px, added to a placed position on each axis.
+    pub noise_px: f32,
+}
+
+pub const EASY: Difficulty = Difficulty {
+    name: "easy",
+    elements: (0.0, 0.5),
+    distractors: 0,
+    font_px: (15.0, 18.0),
+    target_scale: (1.0, 1.25),
+    noise_px: 0.0,
+};
+
+pub const MEDIUM: Difficulty = Difficulty {
+    name: "medium",
+    elements: (0.0, 1.0),
+    distractors: 0,
+    font_px: (12.0, 18.0),
+    target_scale: (1.0, 1.0),
+    noise_px: 0.0,
+};
+
+pub const HARD: Difficulty = Difficulty {
+    name: "hard",
+    elements: (0.5, 1.0),
+    distractors: 4,
+    font_px: (12.0, 14.0),
+    target_scale: (0.75, 0.9),
+    noise_px: 16.0,
+};
+
+pub const PRESETS: &[Difficulty] = &[EASY, MEDIUM, HARD];
+
+impl Difficulty {
+    pub fn by_name(name: &str) -> Option<&'static Difficulty> {
+        PRESETS.iter().find(|d| d.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Preset chosen with `?difficulty=`, else `MEDIUM`.
+    pub fn current() -> &'static Difficulty {
+        super::query_param("difficulty")
+            .and_then(|s| Self::by_name(&s))
+            .unwrap_or(&MEDIUM)
+    }
+
+    /// An element count from this preset's slice of `range`.
+    pub fn count(&self, rng: &mut impl Rng, range: RangeInclusive<usize>) -> usize {
+        let (lo, hi) = self.slice(&range);
+        rng.random_range(lo..=hi)
+    }
+
+    /// Bounds of this preset's slice of `range`, never empty.
+    pub fn slice(&self, range: &RangeInclusive<usize>) -> (usize, usize) {
+        let (start, end) = (*range.start(), *range.end());
+        let span = end.saturating_sub(start) as f32;
+        let lo = start + (span * self.elements.0).round() as usize;
+        let hi = start + (span * self.elements.1).round() as usize;
+        (lo.min(end), hi.clamp(lo.min(end), end))
+    }
+
+    /// Whether `size` is a font size this preset allows.
+    pub fn allows_font(&self, size: f32) -> bool {
+        (self.font_px.0..=self.font_px.1).contains(&size)
+    }
+
+    /// Scale for a pool-snippet target.
+    pub fn target_scale(&self, rng: &mut impl Rng) -> f32 {
+        let (lo, hi) = self.target_scale;
+        if lo == hi { lo } else { rng.random_range(lo..=hi) }
+    }
+
+    /// `pos` moved by up to `noise_px` on each axis, kept non-negative.
+    pub fn jitter(&self, rng: &mut impl Rng, pos: (f32, f32)) -> (f32, f32) {
+        if self.noise_px <= 0.0 {
+            return pos;
+        }
+        let n = self.noise_px;
+        (
+            (pos.0 + rng.random_range(-n..=n)).max(0.0),
+            (pos.1 + rng.random_range(-n..=n)).max(0.0),
+        )
+    }
+
+    pub fn to_json(self) -> String {
+        format!(
+            r#"{{"name":"{}","elements":[{},{}],"distractors":{},"font_px":[{},{}],"target_scale":[{},{}],"noise_px":{}}}"#,
+            self.name,
+            self.elements.0, self.elements.1,
+            self.distractors,
+            self.font_px.0, self.font_px.1,
+            self.target_scale.0, self.target_scale.1,
+            self.noise_px,
+        )
+    }
+
+    /// Publish the presets and the active one on `window`.
+    pub fn install_js() {
+        let presets = PRESETS.iter().map(|d| d.to_json()).collect::<Vec<_>>().join(",");
+        document::eval(&format!(
+            "window.__difficultyPresets = [{presets}]; window.__difficulty = {};",
+            Self::current().to_json(),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn medium_draws_the_whole_range() {
+        assert_eq!(MEDIUM.slice(&(3..=6)), (3, 6));
+    }
+
+    #[test]
+    fn easy_and_hard_split_the_range() {
+        assert_eq!(EASY.slice(&(4..=8)), (4, 6));
+        assert_eq!(HARD.slice(&(4..=8)), (6, 8));
+    }
+
+    #[test]
+    fn slices_are_never_empty() {
+        for d in PRESETS {
+            let (lo, hi) = d.slice(&(2..=2));
+            assert_eq!((lo, hi), (2, 2));
+            let (lo, hi) = d.slice(&(1..=2));
+            assert!(lo <= hi && (1..=2).contains(&lo) && (1..=2).contains(&hi));
+        }
+    }
+
+    #[test]
+    fn presets_by_name() {
+        assert_eq!(Difficulty::by_name(" Hard ").map(|d| d.name), Some("hard"));
+        assert!(Difficulty::by_name("nightmare").is_none());
+    }
+}
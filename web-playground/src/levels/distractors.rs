@@ -0,0 +1,128 @@
+//! Distractors — decorative clutter sprinkled over any level's canvas.
+//!
+//! `?distractors=N` adds 0–N non-interactive pool snippets (badges, avatars,
+//! fake buttons) to every round. GroundTruth drives them, so no level has to
+//! render anything: they are sampled once per round away from the round's
+//! target boxes, injected into `#viewport` as a `pointer-events: none` layer,
+//! and listed as clutter in the description.
+
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::pool::{ElementKind, ElementPool};
+use crate::transform::Sampler;
+use crate::ui_node::{tr, Lang, Rect, ViewportTransform};
+
+const LAYER_ID: &str = "__distractors";
+const KINDS: &[ElementKind] = &[ElementKind::Badge, ElementKind::Avatar, ElementKind::Button];
+/// Placement attempts per distractor before it is dropped.
+const ATTEMPTS: usize = 20;
+/// Clearance kept around target boxes.
+const GAP: f32 = 12.0;
+
+/// One decorative element placed for the current round.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distractor {
+    pub kind: ElementKind,
+    /// Visible text of the snippet ("New", "JD", "Submit").
+    pub text: String,
+    /// Viewport-local box.
+    pub rect: Rect,
+    html: String,
+}
+
+/// Upper bound on distractors per round, from `?distractors=` (0 = off),
+/// else the difficulty preset's.
+pub fn max_distractors() -> usize {
+    super::query_param("distractors")
+        .and_then(|s| s.trim().parse().ok())
+        .unwrap_or_else(|| super::Difficulty::current().distractors)
+}
+
+fn overlaps(a: &Rect, b: &Rect) -> bool {
+    a.x < b.x + b.w + GAP && b.x < a.x + a.w + GAP && a.y < b.y + b.h + GAP && b.y < a.y + a.h + GAP
+}
+
+/// Sample 0..=`max_distractors()` distractors that keep clear of `avoid`
+/// and of each other.
+pub fn sample(avoid: &[Rect]) -> Vec<Distractor> {
+    let max = max_distractors();
+    if max == 0 {
+        return Vec::new();
+    }
+    let mut rng = super::fresh_rng();
+    let pool = ElementPool::with_builtins();
+    let count = rng.random_range(0..=max);
+    let mut taken: Vec<Rect> = avoid.to_vec();
+    let mut out = Vec::new();
+    for _ in 0..count {
+        let kind = KINDS[rng.random_range(0..KINDS.len())];
+        let Some(snippet) = Sampler::pick_kind(&mut rng, &pool, kind) else { continue };
+        let (w, h) = snippet.size();
+        let spot = (0..ATTEMPTS)
+            .map(|_| {
+                let (x, y) = super::safe_position(&mut rng, w, h, 20.0);
+                Rect::new(x, y, w, h)
+            })
+            .find(|r| !taken.iter().any(|t| overlaps(r, t)));
+        let Some(rect) = spot else { continue };
+        taken.push(rect);
+        out.push(Distractor {
+            kind,
+            text: super::ground_truth::strip_tags(&snippet.html).trim().to_string(),
+            rect,
+            html: snippet.html,
+        });
+    }
+    out
+}
+
+/// Description fragments, one per distractor, in window coordinates.
+pub fn describe(list: &[Distractor], vt: &ViewportTransform, lang: Lang) -> Vec<String> {
+    list.iter()
+        .map(|d| {
+            let (x, y, w, h) = vt.apply(&d.rect);
+            let region = tr(lang, &format!("region.{}", d.rect.region()), &[]);
+            let pos = tr(lang, "pos.near", &[&region, &format!("{},{} {}x{}", x, y, w, h)]);
+            let kind = tr(lang, &format!("clutter.{}", d.kind.describe()), &[]);
+            tr(lang, "desc.clutter", &[&kind, &d.text, &pos])
+        })
+        .collect()
+}
+
+/// Replace the distractor layer inside `#viewport` with `list`.
+fn inject(list: &[Distractor]) {
+    let Some(doc) = web_sys::window().and_then(|w| w.document()) else { return };
+    if let Some(old) = doc.get_element_by_id(LAYER_ID) {
+        old.remove();
+    }
+    let Some(viewport) = doc.get_element_by_id("viewport") else { return };
+    if list.is_empty() {
+        return;
+    }
+    let items: String = list.iter()
+        .map(|d| format!(
+            r#"<div data-distractor="{}" style="position: absolute; left: {}px; top: {}px;">{}</div>"#,
+            d.kind.describe(), d.rect.x, d.rect.y, d.html,
+        ))
+        .collect();
+    let layer = format!(
+        r#"<div id="{LAYER_ID}" aria-hidden="true" style="position: absolute; left: 0; top: 0; width: 0; height: 0; pointer-events: none; z-index: 0;">{items}</div>"#,
+    );
+    let _ = viewport.insert_adjacent_html("afterbegin", &layer);
+}
+
+/// Per-round distractors for a GroundTruth panel. Resamples whenever a round
+/// completes; `avoid` is only read when resampling.
+pub fn use_distractors(avoid: impl FnOnce() -> Vec<Rect>) -> Signal<Vec<Distractor>> {
+    let mut list = use_signal(Vec::new);
+    let mut round = use_signal(|| None::<u64>);
+    let now = super::round_index();
+    if max_distractors() > 0 && *round.peek() != Some(now) {
+        round.set(Some(now));
+        list.set(sample(&avoid()));
+    }
+    use_effect(move || inject(&list.read()));
+    use_drop(|| inject(&[]));
+    list
+}
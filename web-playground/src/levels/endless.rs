@@ -0,0 +1,50 @@
+//! `/random` — endless mode. Plays one registered level at a time and swaps
+//! to a freshly drawn one after every solved round, keeping a single score.
+
+use dioxus::prelude::*;
+use rand::Rng;
+
+use super::{fresh_rng, LevelEntry, LevelHost, LevelRegistry, LevelRun};
+
+/// Draw the next level. `weighted` biases toward harder levels (weight =
+/// difficulty); otherwise every level is equally likely.
+fn pick_level(weighted: bool) -> &'static LevelEntry {
+    let levels = LevelRegistry::all();
+    let mut rng = fresh_rng();
+    if !weighted {
+        return &levels[rng.random_range(0..levels.len())];
+    }
+    let total: u32 = levels.iter().map(|l| l.difficulty as u32).sum();
+    let mut roll = rng.random_range(0..total);
+    for level in levels {
+        let w = level.difficulty as u32;
+        if roll < w {
+            return level;
+        }
+        roll -= w;
+    }
+    &levels[levels.len() - 1]
+}
+
+/// Publish the level currently on screen as `window.__currentLevel` (slug).
+fn announce(level: &LevelEntry) {
+    document::eval(&format!("window.__currentLevel = '{}';", level.slug));
+}
+
+/// `weight=difficulty` in the query switches to difficulty-weighted draws.
+#[component]
+pub fn RandomLevel(weight: String) -> Element {
+    let weighted = weight == "difficulty";
+    let score = use_signal(|| 0u32);
+    let mut current = use_signal(move || pick_level(weighted));
+
+    use_context_provider(move || LevelRun {
+        score,
+        on_complete: Callback::new(move |_| current.set(pick_level(weighted))),
+    });
+
+    use_effect(move || announce(current()));
+
+    let level = current();
+    rsx! { LevelHost { slug: level.slug } }
+}
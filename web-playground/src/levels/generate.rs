@@ -0,0 +1,191 @@
+//! `/generate` — headless batch generation driven by the URL.
+//!
+//! `/generate?levels=all&rounds=50&seed=42` seeds the run once, then plays
+//! `rounds` rounds of each listed level (`all`, or comma-separated slugs like
+//! `level3,level7`) in registry order. Every round lands in `window.__dataset`
+//! like any other play session. Rounds are auto-solved by `window.__solver`;
+//! `&agent=external` instead waits for something else to solve each one.
+//! A round the solver can't finish is abandoned by remounting the level.
+//!
+//! Headless drivers poll either signal of the end of the run:
+//!
+//! - `window.__generationDone` turns `true`
+//! - `document.title` becomes `"generation done"`
+//!
+//! `window.__generationProgress` holds `{level, round, rounds_done, total,
+//! solved, abandoned, done}` while it runs; the title shows the same count.
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+
+use super::{LevelEntry, LevelHost, LevelRegistry, LevelRun};
+
+const DEFAULT_ROUNDS: u32 = 10;
+/// Time for a freshly mounted level or round to render and publish its
+/// ground truth before it is solved.
+const SETTLE_MS: u32 = 600;
+/// Poll interval while waiting for an external agent.
+const POLL_MS: u32 = 200;
+
+/// `levels` query value: `all` (or empty) for every level, else slugs.
+fn select_levels(levels: &str) -> Vec<&'static LevelEntry> {
+    let levels = levels.trim();
+    if levels.is_empty() || levels == "all" {
+        return LevelRegistry::all().iter().collect();
+    }
+    levels.split(',')
+        .filter_map(|slug| {
+            let found = LevelRegistry::by_slug(slug.trim());
+            if found.is_none() {
+                web_sys::console::warn_1(&format!("generate: no level {}", slug.trim()).into());
+            }
+            found
+        })
+        .collect()
+}
+
+/// Run counters, mirrored to `window.__generationProgress` and the title.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Progress {
+    level: usize,
+    round: u32,
+    rounds_done: u32,
+    total: u32,
+    solved: u32,
+    abandoned: u32,
+    done: bool,
+}
+
+impl Progress {
+    fn publish(&self, slug: &str) {
+        let json = format!(
+            r#"{{"level":"{}","round":{},"rounds_done":{},"total":{},"solved":{},"abandoned":{},"done":{}}}"#,
+            slug, self.round, self.rounds_done, self.total, self.solved, self.abandoned, self.done,
+        );
+        let Some(window) = web_sys::window() else { return };
+        if let Ok(value) = js_sys::JSON::parse(&json) {
+            let _ = Reflect::set(&window, &JsValue::from_str("__generationProgress"), &value);
+        }
+        let _ = Reflect::set(&window, &JsValue::from_str("__generationDone"), &JsValue::from_bool(self.done));
+        if let Some(document) = window.document() {
+            if self.done {
+                document.set_title("generation done");
+            } else {
+                document.set_title(&format!("generating {}/{}", self.rounds_done, self.total));
+            }
+        }
+    }
+}
+
+/// Solve the current round with `window.__solver`; whether the level took it.
+#[cfg(feature = "solver")]
+async fn auto_solve() -> bool {
+    match super::selftest::run_solver().await {
+        Ok(report) => super::selftest::field(&report, "pass").as_bool().unwrap_or(false),
+        Err(e) => {
+            web_sys::console::warn_1(&format!("generate: {e}").into());
+            false
+        }
+    }
+}
+
+#[cfg(not(feature = "solver"))]
+async fn auto_solve() -> bool {
+    web_sys::console::warn_1(&"generate: built without the solver; use &agent=external".into());
+    false
+}
+
+/// Query values of `/generate`; `seed` empty keeps the page's own seeding.
+#[component]
+pub fn Generate(levels: String, rounds: String, seed: String, agent: String) -> Element {
+    let selected = use_hook(|| select_levels(&levels));
+    let rounds: u32 = rounds.trim().parse().unwrap_or(DEFAULT_ROUNDS).max(1);
+    let external = agent == "external";
+    let seed: Option<u64> = seed.trim().parse().ok();
+
+    let score = use_signal(|| 0u32);
+    let mut completed = use_signal(|| 0u32);
+    let mut mounted = use_signal(|| true);
+    let mut progress = use_signal(Progress::default);
+
+    use_context_provider(move || LevelRun {
+        score,
+        on_complete: Callback::new(move |_| *completed.write() += 1),
+    });
+
+    let driver_levels = selected.clone();
+    use_future(move || {
+        let levels = driver_levels.clone();
+        async move {
+            if seed.is_some() {
+                super::set_seed(seed);
+            }
+            let total = levels.len() as u32 * rounds;
+            let mut p = Progress { total, ..Progress::default() };
+            for (index, entry) in levels.iter().enumerate() {
+                p.level = index;
+                mounted.set(true);
+                for round in 0..rounds {
+                    p.round = round;
+                    progress.set(p);
+                    p.publish(entry.slug);
+                    TimeoutFuture::new(SETTLE_MS).await;
+
+                    let before = *completed.peek();
+                    if external {
+                        while *completed.peek() == before {
+                            TimeoutFuture::new(POLL_MS).await;
+                        }
+                    } else {
+                        auto_solve().await;
+                    }
+                    if *completed.peek() > before {
+                        p.solved += 1;
+                    } else {
+                        // Remount so the dataset records it abandoned and a new round is drawn
+                        p.abandoned += 1;
+                        mounted.set(false);
+                        TimeoutFuture::new(50).await;
+                        mounted.set(true);
+                    }
+                    p.rounds_done += 1;
+                }
+                // Level switch: unmount the finished one before the next mounts
+                mounted.set(false);
+                TimeoutFuture::new(50).await;
+            }
+            p.done = true;
+            progress.set(p);
+            p.publish("");
+            web_sys::console::log_1(&format!(
+                "generate: done — {} rounds, {} solved, {} abandoned",
+                p.rounds_done, p.solved, p.abandoned,
+            ).into());
+        }
+    });
+
+    let p = progress();
+    let level = selected.get(p.level).filter(|_| mounted() && !p.done);
+    let label = level.map_or("", |l| l.slug);
+    let status = if p.done { "done" } else { "generating" };
+
+    rsx! {
+        if let Some(l) = level {
+            LevelHost { slug: l.slug }
+        }
+        div {
+            id: "__generate",
+            style: "position: fixed; right: 12px; bottom: 12px; padding: 6px 10px; background: rgba(26,26,46,0.9); border: 1px solid #2a2a4a; border-radius: 6px; color: #9ca3af; font-size: 12px; font-family: monospace; z-index: 1000; display: flex; gap: 10px; align-items: center;",
+            "{status} {p.rounds_done}/{p.total} \u{00B7} {label} \u{00B7} {p.solved} solved \u{00B7} {p.abandoned} abandoned"
+            if p.done {
+                button {
+                    style: "padding: 2px 8px; background: #6366f1; color: white; border: none; border-radius: 4px; font-size: 11px; cursor: pointer; font-family: monospace;",
+                    onclick: move |_| crate::dataset::download(),
+                    "Download JSONL"
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,40 @@
 use dioxus::prelude::*;
-use crate::ui_node::{UINode, ViewportTransform};
+use crate::primitives::Typography;
+use crate::ui_node::{escape_json, tr, Completion, CoordinateSpace, Outcome, Rect, Rotation, ThinkingStyle, UINode, ViewportTransform};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use js_sys::Reflect;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+/// Version of the structured payload published as `window.__groundTruth`;
+/// bumped whenever a field is added. See CHANGELOG.md for what each added.
+pub const GROUND_TRUTH_SCHEMA: u32 = 12;
+
+/// Installs `window.__groundTruthEpoch`, a counter bumped on every user
+/// input inside the page and by the solver after each action. Each bump is
+/// sent back so GroundTruth re-queries the DOM and republishes; the counter
+/// survives remounts so epochs only grow within a page.
+const EPOCH_JS: &str = r#"
+    const gte = window.__groundTruthEpoch || (window.__groundTruthEpoch = { value: 0 });
+    gte.bump = () => { gte.value++; dioxus.send(gte.value); return gte.value; };
+    if (gte.listener) for (const t of gte.events) document.removeEventListener(t, gte.listener, true);
+    gte.events = ['pointerup', 'click', 'contextmenu', 'keyup', 'input', 'change', 'wheel', 'scrollend'];
+    // Bump once the level's handlers have run and its re-render landed
+    gte.listener = () => requestAnimationFrame(() => setTimeout(() => gte.bump(), 0));
+    for (const t of gte.events) document.addEventListener(t, gte.listener, true);
+    dioxus.send(gte.value);
+"#;
+
+const EPOCH_DROP_JS: &str = r#"
+    const gte = window.__groundTruthEpoch;
+    if (gte && gte.listener) {
+        for (const t of gte.events) document.removeEventListener(t, gte.listener, true);
+        gte.listener = null;
+        gte.bump = () => ++gte.value;
+    }
+"#;
 
 /// Strip HTML tags to get plain text
 pub fn strip_tags(html: &str) -> String {
@@ -39,6 +74,15 @@ fn get_viewport_bbox() -> [f64; 4] {
     [0.0, 0.0, vp_w as f64, vp_h as f64]
 }
 
+/// Device pixel ratio of the capture: the `?device=` profile's, otherwise
+/// the browser's.
+fn device_pixel_ratio() -> f32 {
+    if let Some(device) = super::DeviceProfile::current() {
+        return device.dpr;
+    }
+    web_sys::window().map_or(1.0, |w| w.device_pixel_ratio() as f32)
+}
+
 /// Extract a label from a target element using tag-specific logic.
 /// `data-label` attribute always takes priority.
 fn extract_label(el: &web_sys::Element) -> String {
@@ -102,6 +146,18 @@ fn target_visibility(bbox: &[i32; 4], vp: &[f64; 4]) -> &'static str {
     }
 }
 
+/// Level id of the current sample, e.g. "/level12": the mounted level's
+/// slug (so `/random` and `/campaign` rounds name their level), else the
+/// route path.
+pub(super) fn current_level_id() -> String {
+    if let Some((_, slug)) = ACTIVE_LEVEL.with(|a| a.get()) {
+        return format!("/{slug}");
+    }
+    web_sys::window()
+        .and_then(|w| w.location().pathname().ok())
+        .unwrap_or_default()
+}
+
 /// Get the viewport's current scroll position [scrollLeft, scrollTop].
 fn get_viewport_scroll() -> [i32; 2] {
     if let Some(document) = web_sys::window().and_then(|w| w.document()) {
@@ -112,6 +168,83 @@ fn get_viewport_scroll() -> [i32; 2] {
     [0, 0]
 }
 
+thread_local! {
+    /// `check()` of the tree on screen, refreshed every GroundTruth render.
+    static LAST_CHECK: RefCell<Option<Completion>> = const { RefCell::new(None) };
+    /// `ui_state_json()` of the same tree, live values bound in.
+    static LAST_STATE: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// (host id, slug) of the registered level on screen.
+    static ACTIVE_LEVEL: Cell<Option<(u64, &'static str)>> = const { Cell::new(None) };
+}
+
+/// Mark `slug` as the level on screen, on behalf of host `id`.
+pub(super) fn set_active_level(id: u64, slug: &'static str) {
+    ACTIVE_LEVEL.with(|a| a.set(Some((id, slug))));
+}
+
+/// Clear the active level if host `id` still owns it; a host mounted in
+/// its place may already have taken over.
+pub(super) fn clear_active_level(id: u64) {
+    ACTIVE_LEVEL.with(|a| {
+        if a.get().is_some_and(|(owner, _)| owner == id) {
+            a.set(None);
+        }
+    });
+}
+
+/// `{round, completion, progress}` for the tree currently on screen:
+/// `round` counts solved rounds, `completion` is null without a tree.
+fn check_completion() -> JsValue {
+    let result = js_sys::Object::new();
+    let _ = Reflect::set(&result, &"round".into(), &JsValue::from_f64(super::round_index() as f64));
+    let (name, progress) = LAST_CHECK.with(|c| match &*c.borrow() {
+        Some(done) => (JsValue::from_str(done.as_str()), JsValue::from_f64(done.progress() as f64)),
+        None => (JsValue::NULL, JsValue::NULL),
+    });
+    let _ = Reflect::set(&result, &"completion".into(), &name);
+    let _ = Reflect::set(&result, &"progress".into(), &progress);
+    result.into()
+}
+
+/// `{round, controls}` for the tree currently on screen: `controls` lists
+/// every stateful node's live value (`UINode::ui_state_json`), null
+/// without a tree.
+fn get_ui_state() -> JsValue {
+    let result = js_sys::Object::new();
+    let _ = Reflect::set(&result, &"round".into(), &JsValue::from_f64(super::round_index() as f64));
+    let controls = LAST_STATE.with(|s| match &*s.borrow() {
+        Some(json) => js_sys::JSON::parse(json).unwrap_or(JsValue::NULL),
+        None => JsValue::NULL,
+    });
+    let _ = Reflect::set(&result, &"controls".into(), &controls);
+    result.into()
+}
+
+/// Install `window.__checkCompletion()`, which the solver calls after
+/// `solve()` to verify that replaying the ground truth solved the round,
+/// and `window.__getUiState()`, for scoring a rollout outside the browser.
+pub fn install_check_js() {
+    let Some(window) = web_sys::window() else { return };
+    let check = Closure::<dyn Fn() -> JsValue>::new(check_completion);
+    let _ = Reflect::set(&window, &"__checkCompletion".into(), check.as_ref().unchecked_ref());
+    let state = Closure::<dyn Fn() -> JsValue>::new(get_ui_state);
+    let _ = Reflect::set(&window, &"__getUiState".into(), state.as_ref().unchecked_ref());
+    // Both live for the whole page
+    check.forget();
+    state.forget();
+}
+
+/// Mirror the schema-v2 payload onto `window.__groundTruth` as a parsed object.
+fn publish_ground_truth(payload: &str) {
+    let Some(window) = web_sys::window() else { return };
+    match js_sys::JSON::parse(payload) {
+        Ok(value) => {
+            let _ = Reflect::set(&window, &JsValue::from_str("__groundTruth"), &value);
+        }
+        Err(_) => web_sys::console::warn_1(&"ground truth: payload is not valid JSON".into()),
+    }
+}
+
 #[component]
 pub fn GroundTruth(
     description: String,
@@ -119,14 +252,19 @@ pub fn GroundTruth(
     target_y: f32,
     target_w: f32,
     target_h: f32,
-    #[props(default)] steps: String,
     #[props(default)] tree: Option<UINode>,
+    #[props(default)] outcome: Outcome,
+    #[props(default)] typography: Option<Typography>,
+    #[props(default)] rotation: Option<Rotation>,
 ) -> Element {
     let (vp_init_w, vp_init_h) = crate::primitives::viewport_size();
     let mut vp_signal = use_signal(move || [0.0f64, 0.0, vp_init_w as f64, vp_init_h as f64]);
     let mut win_signal = use_signal(|| [0i32, 0, 0, 0]);
     let mut scroll_signal = use_signal(|| [0i32, 0]);
     let mut targets_signal = use_signal(Vec::<(String, [i32; 4])>::new);
+    let mut a11y_signal = use_signal(|| None::<super::a11y::A11yNode>);
+    let mut measured_signal = use_signal(Vec::<super::measure::Measured>::new);
+    let mut menus_signal = use_signal(Vec::<super::measure::OpenMenu>::new);
 
     // Tick counter — polls DOM periodically to catch interactive changes
     // (e.g. dropdown open/close moving class="target" between elements)
@@ -141,16 +279,31 @@ pub fn GroundTruth(
         }
     });
 
+    // Ground truth epoch: bumped after every solver action and user input,
+    // so each intermediate state is re-queried and republished at once.
+    // `settled` is the epoch the DOM was last queried at, and is what the
+    // payload reports, so a solver waiting on it never sees stale boxes.
+    // Both stay `None` until the page reports its epoch.
+    let mut epoch = use_signal(|| None::<u64>);
+    let mut settled = use_signal(|| None::<u64>);
+    use_future(move || async move {
+        let mut eval = document::eval(EPOCH_JS);
+        while let Ok(n) = eval.recv::<u64>().await {
+            epoch.set(Some(n));
+        }
+    });
+
     // Track description changes for immediate re-query on level state changes
     let mut prev_desc = use_signal(|| String::new());
     if *prev_desc.peek() != description {
         prev_desc.set(description.clone());
     }
 
-    // Re-runs on tick (200ms poll) or description change (level state)
+    // Re-runs on tick (200ms poll), description change (level state) or epoch
     use_effect(move || {
         let _ = tick.read();
         let _ = prev_desc.read();
+        let at_epoch = *epoch.read();
         let win = get_window_bbox();
         let vp = get_viewport_bbox();
         let scroll = get_viewport_scroll();
@@ -167,6 +320,23 @@ pub fn GroundTruth(
         if *targets_signal.peek() != targets {
             targets_signal.set(targets);
         }
+        let measured = super::measure::measure_labels();
+        if *measured_signal.peek() != measured {
+            measured_signal.set(measured);
+        }
+        let menus = super::measure::measure_open_menus();
+        if *menus_signal.peek() != menus {
+            menus_signal.set(menus);
+        }
+        if super::a11y::enabled() {
+            let tree = super::a11y::snapshot();
+            if *a11y_signal.peek() != tree {
+                a11y_signal.set(tree);
+            }
+        }
+        if *settled.peek() != at_epoch {
+            settled.set(at_epoch);
+        }
     });
 
     let win = *win_signal.read();
@@ -174,12 +344,138 @@ pub fn GroundTruth(
     let scroll = *scroll_signal.read();
     let dom_targets = targets_signal.read().clone();
 
-    // Resolve UINode tree with viewport transform → window-space coordinates
-    let vt = ViewportTransform::from_viewport(&vp);
-    let resolved = tree.as_ref().map(|t| t.resolve_with(&vt));
+    // Resolve UINode tree with viewport transform → window-space coordinates.
+    // `?coords=physical` reports device pixels (CSS × DPR) throughout.
+    let space = super::query_param("coords")
+        .and_then(|s| CoordinateSpace::parse(&s))
+        .unwrap_or_default();
+    let dpr = device_pixel_ratio();
+    let vt = ViewportTransform::from_viewport(&vp).in_space(space, dpr);
+    let k = vt.output_scale() as f64;
+    let px = move |v: f64| (v * k) as i32;
+    // `?thinking=terse|verbose|cot|none` picks the reasoning style for the run
+    let style = super::query_param("thinking")
+        .and_then(|s| ThinkingStyle::parse(&s))
+        .unwrap_or_default();
+    let lang = super::current_lang();
+    // A tilted tree reports the rotated hulls of its boxes
+    let tree_vt = rotation.map_or(vt, |r| vt.rotated(r));
+    // Mirror the live element values registered this round into the tree
+    let mut tree = tree;
+    if let (Some(t), Some(live)) = (tree.as_mut(), try_use_context::<super::LiveState>()) {
+        live.apply(t);
+    }
+    // Open dropdown menus list their option rows
+    if let Some(t) = tree.as_mut() {
+        super::measure::attach_open_menus(t, &menus_signal.read(), &tree_vt);
+    }
+    // Snap drifted node rects to their DOM-measured boxes before resolving
+    let drifts = tree.as_mut()
+        .map(|t| super::measure::reconcile(t, &measured_signal.read(), &tree_vt))
+        .unwrap_or_default();
+    let mut logged_drift = use_signal(Vec::<super::measure::Drift>::new);
+    if *logged_drift.peek() != drifts {
+        for d in &drifts {
+            web_sys::console::warn_1(&format!("ground truth: layout drift {}", d.describe()).into());
+        }
+        logged_drift.set(drifts.clone());
+    }
+    let resolved = tree.as_ref().map(|t| t.resolve_localized(&tree_vt, style, lang));
+    LAST_CHECK.with(|c| *c.borrow_mut() = tree.as_ref().map(|t| t.check()));
+    LAST_STATE.with(|s| *s.borrow_mut() = tree.as_ref().map(|t| t.ui_state_json()));
     let description = resolved.as_ref().map_or(description, |r| r.description.clone());
-    let steps = resolved.as_ref().map_or(steps, |r| r.steps_json());
-    let thinking = resolved.as_ref().map(|r| r.thinking.clone()).unwrap_or_default();
+    // `?distractors=N` clutter, resampled each round clear of its targets
+    let distractors = super::distractors::use_distractors(|| match &resolved {
+        Some(r) => r.targets.iter().map(|(_, rect, _)| *rect).collect(),
+        None => vec![Rect::new(target_x, target_y, target_w, target_h)],
+    });
+    let clutter = super::distractors::describe(&distractors.read(), &vt, lang);
+    let mut description = match (description.is_empty(), clutter.is_empty()) {
+        (_, true) => description,
+        (true, false) => clutter.join(", "),
+        (false, false) => format!("{}, {}", description, clutter.join(", ")),
+    };
+    // Card typography (`?typography=1`), in the primitive's own words
+    if let Some(typo) = typography {
+        let text = tr(lang, "desc.typography", &[&typo.describe()]);
+        description = if description.is_empty() { text } else { format!("{}, {}", description, text) };
+    }
+    if let Some(r) = rotation {
+        let text = tr(lang, "desc.rotation", &[&format!("{:+.0}", r.angle.degrees())]);
+        description = if description.is_empty() { text } else { format!("{}, {}", description, text) };
+    }
+    // Name the `?bg=varied` canvas style
+    if let Some(bg) = super::background::last_style() {
+        let named = tr(lang, "desc.background", &[&tr(lang, &format!("bg.{}", bg.as_str()), &[])]);
+        description = if description.is_empty() { named } else { format!("{}, {}", description, named) };
+    }
+    // Name the `?theme=` preset the round is drawn in
+    if let Some(theme) = super::current_theme() {
+        let named = tr(lang, "desc.theme", &[&tr(lang, &format!("theme.{}", theme.name), &[])]);
+        description = if description.is_empty() { named } else { format!("{}, {}", description, named) };
+    }
+    // `?recovery=1`: open with a deliberate mistake, then correct it. Decoys
+    // are distractors whose text doesn't collide with a real target label.
+    let decoys: Vec<(String, [i32; 4])> = match &resolved {
+        Some(r) if super::recovery::enabled() => distractors.read().iter()
+            .filter(|d| !d.text.is_empty() && !r.targets.iter().any(|(l, _, _)| *l == d.text))
+            .map(|d| {
+                let (x, y, w, h) = vt.apply(&d.rect);
+                (d.text.clone(), [x, y, w, h])
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    let recovery = resolved.as_ref()
+        .filter(|_| super::recovery::enabled())
+        .and_then(|r| {
+            let labels: Vec<String> = decoys.iter().map(|(l, _)| l.clone()).collect();
+            super::recovery::inject(&r.steps, &labels, lang)
+        });
+    let step_count = match (&recovery, &resolved) {
+        (Some(rec), _) => rec.steps.len(),
+        (None, Some(r)) => r.steps.len(),
+        (None, None) => 0,
+    };
+    let steps = match (&recovery, &resolved) {
+        (Some(rec), _) => crate::ui_node::actions_to_json(&rec.steps),
+        (None, Some(r)) => r.steps_json(),
+        (None, None) => String::new(),
+    };
+    // Round lifecycle: open the round on its first render, close it on unmount
+    let opened = use_hook(|| Rc::new(Cell::new(None::<u64>)));
+    // Success criteria of the round as first rendered: nodes drop `is_target`
+    // as they are completed, so the live tree would lose conditions mid-round
+    let criteria = use_hook(|| Rc::new(RefCell::new(None::<String>)));
+    let round = super::round_index();
+    if super::rounds::round_start(round, &super::rounds::gt_hash(&format!("{description}\n{steps}")), (vp[2], vp[3])) {
+        opened.set(Some(round));
+        super::stats::round_started(&current_level_id(), step_count);
+        *criteria.borrow_mut() = tree.as_ref().map(UINode::criteria_json);
+    }
+    let mut thinking = resolved.as_ref().map(|r| r.thinking.clone()).unwrap_or_default();
+    if let (Some(rec), true) = (&recovery, style != ThinkingStyle::None) {
+        thinking = if thinking.is_empty() { rec.thinking.clone() } else { format!("{} {}", thinking, rec.thinking) };
+    }
+    let missing = match &outcome {
+        Outcome::Impossible { missing } => Some(missing.clone()),
+        Outcome::Success => None,
+    };
+    if let (Some(missing), true) = (&missing, style != ThinkingStyle::None) {
+        let abstain = tr(lang, "think.impossible", &[missing]);
+        thinking = if thinking.is_empty() { abstain } else { format!("{} {}", thinking, abstain) };
+    }
+
+    // Targets with escaped labels for the structured payload
+    let targets_v2: Vec<String> = dom_targets.iter()
+        .map(|(label, t)| format!(
+            r#"{{"label":"{}","bbox":[{},{},{},{}],"area":{},"visibility":"{}"}}"#,
+            escape_json(label),
+            px(t[0] as f64), px(t[1] as f64), px(t[2] as f64), px(t[3] as f64),
+            px(t[2] as f64) * px(t[3] as f64),
+            target_visibility(t, &vp),
+        ))
+        .collect();
 
     // Build targets string: prefer DOM-queried targets, fall back to props.
     // Each target gets a "visibility" field: "visible", "partial", or "offscreen".
@@ -187,6 +483,7 @@ pub fn GroundTruth(
         let parts: Vec<String> = dom_targets.iter()
             .map(|(label, t)| {
                 let vis = target_visibility(t, &vp);
+                let t = t.map(|v| px(v as f64));
                 if label.is_empty() {
                     format!("{{\"bbox\": [{}, {}, {}, {}], \"visibility\": \"{}\"}}", t[0], t[1], t[2], t[3], vis)
                 } else {
@@ -200,31 +497,47 @@ pub fn GroundTruth(
         let (vp_w, _vp_h) = crate::primitives::viewport_size();
         let scale = if vp_w > 0.0 { vp[2] / vp_w as f64 } else { 1.0 };
         let target = [
-            (vp[0] + target_x as f64 * scale) as i32,
-            (vp[1] + target_y as f64 * scale) as i32,
-            (target_w as f64 * scale) as i32,
-            (target_h as f64 * scale) as i32,
+            px(vp[0] + target_x as f64 * scale),
+            px(vp[1] + target_y as f64 * scale),
+            px(target_w as f64 * scale),
+            px(target_h as f64 * scale),
         ];
-        format!("[{{\"bbox\": [{}, {}, {}, {}], \"visibility\": \"visible\"}}]", target[0], target[1], target[2], target[3])
+        format!("[{{\"bbox\": [{}, {}, {}, {}], \"area\": {}, \"visibility\": \"visible\"}}]", target[0], target[1], target[2], target[3], target[2] * target[3])
     };
 
-    let window_str = format!("[{}, {}, {}, {}]", win[0], win[1], win[2], win[3]);
-    let viewport_str = format!("[{}, {}, {}, {}]", vp[0] as i32, vp[1] as i32, vp[2] as i32, vp[3] as i32);
-    let scroll_str = format!("[{}, {}]", scroll[0], scroll[1]);
+    let window_str = format!("[{}, {}, {}, {}]", px(win[0] as f64), px(win[1] as f64), px(win[2] as f64), px(win[3] as f64));
+    let viewport_str = format!("[{}, {}, {}, {}]", px(vp[0]), px(vp[1]), px(vp[2]), px(vp[3]));
+    let scroll_str = format!("[{}, {}]", px(scroll[0] as f64), px(scroll[1] as f64));
+    let level_id = current_level_id();
+    // The registry lists each level's action kinds; flag steps it misses
+    #[cfg(feature = "levels")]
+    {
+        let unlisted = match (&resolved, super::LevelRegistry::by_slug(&level_id)) {
+            (Some(r), Some(entry)) => entry.unlisted(&r.steps),
+            _ => Vec::new(),
+        };
+        let mut logged_unlisted = use_signal(Vec::<super::registry::Interaction>::new);
+        if *logged_unlisted.peek() != unlisted {
+            for kind in &unlisted {
+                web_sys::console::warn_1(&format!("ground truth: {} steps not listed for {}", kind.as_str(), level_id).into());
+            }
+            logged_unlisted.set(unlisted);
+        }
+    }
+    #[cfg(feature = "exporter")]
+    let split = super::current_split(&level_id).map(|s| s.as_str());
+    #[cfg(not(feature = "exporter"))]
+    let split: Option<&str> = None;
 
     // Post-render visibility thinking: augment the UINode thinking with
     // visibility annotations based on actual DOM measurements.
-    let vis_thinking = if !dom_targets.is_empty() {
+    let vis_thinking = if !dom_targets.is_empty() && style != ThinkingStyle::None {
         let mut parts = Vec::new();
         for (label, bbox) in &dom_targets {
             let vis = target_visibility(bbox, &vp);
             match vis {
-                "offscreen" => parts.push(format!(
-                    "I cannot see \"{}\" — it is off-screen. I need to scroll to find it.", label
-                )),
-                "partial" => parts.push(format!(
-                    "\"{}\" is partially cut off by the viewport edge. I may need to scroll to see it fully.", label
-                )),
+                "offscreen" => parts.push(tr(lang, "think.offscreen", &[label])),
+                "partial" => parts.push(tr(lang, "think.partial", &[label])),
                 _ => {} // visible — no extra thinking needed
             }
         }
@@ -242,27 +555,172 @@ pub fn GroundTruth(
         format!("{} {}", thinking, vis_thinking)
     };
 
+    // Schema v2: everything above as one JSON document
+    let steps_v2 = if steps.is_empty() { "[]".to_string() } else { steps.clone() };
+    let alternatives_v2 = resolved.as_ref().map_or("[]".to_string(), |r| r.alternatives_json());
+    // Step indices only line up with the resolved steps
+    let order_v2 = match (&recovery, &resolved) {
+        (None, Some(r)) => r.order.to_json(),
+        _ => "null".to_string(),
+    };
+    let success_v2 = match (criteria.borrow().as_ref(), &outcome) {
+        (Some(c), Outcome::Success) => c.clone(),
+        _ => "null".to_string(),
+    };
+    let plan_v2 = resolved.as_ref().and_then(|r| r.plan).map_or("null".to_string(), |p| format!(r#""{p}""#));
+    let targets_v2 = if dom_targets.is_empty() { targets_str.clone() } else { format!("[{}]", targets_v2.join(",")) };
+    let seed = super::current_seed().map_or("null".to_string(), |s| s.to_string());
+    let split_v2 = split.map_or("null".to_string(), |s| format!(r#""{}""#, s));
+    let recovery_v2 = recovery.as_ref().map_or(String::new(), |r| r.payload_fields(&decoys));
+    // `?a11y=1`: simplified accessibility tree of the rendered level
+    let a11y_v2 = a11y_signal.read().as_ref().map_or(String::new(), |t| format!(r#","a11y":{}"#, t.to_json(k)));
+    let missing_v2 = missing.as_ref().map_or("null".to_string(), |m| format!(r#""{}""#, escape_json(m)));
+    let device = super::DeviceProfile::current();
+    // Latest solved round, from the completion bus
+    let last_done = try_use_context::<super::CompletionBus>().and_then(|bus| bus.last());
+    let device_v2 = device.map_or("null".to_string(), |d| d.to_json());
+    let fields = format!(
+        r#""schema":{},"level":"{}","description":"{}","steps":{},"order":{},"plan":{},"alternatives":{},"success":{},"targets":{},"thinking":"{}","thinking_style":"{}","lang":"{}","outcome":"{}","missing":{},"device":{},"difficulty":"{}","small_targets":{},"coordinate_space":"{}","dpr":{},"epoch":{},"seed":{},"seed_counter":{},"split":{},"window":{},"viewport":{},"scroll":{}"#,
+        GROUND_TRUTH_SCHEMA,
+        escape_json(&level_id),
+        escape_json(&description),
+        steps_v2,
+        order_v2,
+        plan_v2,
+        alternatives_v2,
+        success_v2,
+        targets_v2,
+        escape_json(&full_thinking),
+        style.as_str(),
+        lang.as_str(),
+        outcome.as_str(),
+        missing_v2,
+        device_v2,
+        super::Difficulty::current().name,
+        super::small_targets::enabled(),
+        space.as_str(),
+        dpr,
+        settled.read().unwrap_or(0),
+        seed,
+        super::seed_counter(),
+        split_v2,
+        window_str,
+        viewport_str,
+        scroll_str,
+    );
+    // The render time alone is no change: keep the stamp of the last publish
+    let key = format!("{fields}{recovery_v2}{a11y_v2}");
+    let mut published = use_signal(|| (String::new(), 0u64));
+    let changed = published.peek().0 != key;
+    if changed {
+        published.set((key, js_sys::Date::now() as u64));
+    }
+    let timestamp = published.peek().1;
+    // `?annotations=coco` adds detection-style annotations of the tree targets
+    let coco = match (&resolved, super::query_param("annotations").as_deref()) {
+        (Some(r), Some("coco")) => {
+            let (cw, ch) = crate::primitives::viewport_size();
+            Some(r.to_coco(timestamp, cw as u32, ch as u32))
+        }
+        _ => None,
+    };
+    let coco_v2 = coco.as_ref().map_or(String::new(), |c| format!(r#","coco":{}"#, c));
+    let payload = format!(r#"{{{fields},"timestamp":{timestamp}{coco_v2}{recovery_v2}{a11y_v2}}}"#);
+    use_drop(move || {
+        document::eval(EPOCH_DROP_JS);
+        if let Some(round) = opened.get() {
+            super::rounds::round_end(round, super::rounds::RoundEnd::Abandoned);
+        }
+        if let Some(window) = web_sys::window() {
+            let _ = Reflect::delete_property(&window, &JsValue::from_str("__groundTruth"));
+        }
+        LAST_CHECK.with(|c| *c.borrow_mut() = None);
+        LAST_STATE.with(|s| *s.borrow_mut() = None);
+        #[cfg(feature = "exporter")]
+        crate::dataset::round_abandoned();
+    });
+    if changed {
+        publish_ground_truth(&payload);
+        #[cfg(feature = "exporter")]
+        crate::dataset::observe(&payload, settled.peek().is_some());
+    }
+
+    // Debug overlay: every resolved target box, in window CSS pixels
+    let css_vt = vt.in_space(CoordinateSpace::Css, dpr);
+    let overlay: Vec<(String, (i32, i32, i32, i32))> = resolved.as_ref()
+        .map(|r| r.targets.iter().map(|(label, rect, _)| (label.clone(), css_vt.apply(rect))).collect())
+        .unwrap_or_default();
+    super::stats::set_targets(overlay.iter().map(|(_, b)| *b).collect());
+
     rsx! {
+        div {
+            id: "__gt-overlay",
+            style: "position: fixed; left: 0; top: 0; width: 0; height: 0; pointer-events: none; z-index: 99998;",
+            for (label, (x, y, w, h)) in overlay {
+                div {
+                    style: "position: fixed; left: {x}px; top: {y}px; width: {w}px; height: {h}px; box-sizing: border-box; border: 1px solid rgba(236,72,153,0.9); background: rgba(236,72,153,0.12);",
+                    span {
+                        style: "position: absolute; left: -1px; bottom: 100%; padding: 0 3px; background: rgba(236,72,153,0.9); color: white; font: 10px/14px monospace; white-space: nowrap;",
+                        "{label}"
+                    }
+                }
+            }
+        }
         div {
             id: "ground-truth",
             style: "max-width: 1024px; width: 100%; max-height: 180px; overflow-y: auto; background: #111827; border-radius: 8px; padding: 16px; margin-top: 12px; font-family: monospace; font-size: 12px; color: #9ca3af; box-sizing: border-box; word-break: break-all;",
-            h3 {
-                style: "margin: 0 0 8px 0; color: #e5e7eb; font-size: 13px;",
-                "Ground Truth"
+            div {
+                style: "display: flex; justify-content: space-between; align-items: center; margin: 0 0 8px 0;",
+                h3 {
+                    style: "margin: 0; color: #e5e7eb; font-size: 13px;",
+                    "Ground Truth"
+                }
+                if cfg!(feature = "exporter") {
+                    button {
+                        style: "padding: 2px 8px; background: #1f2937; color: #9ca3af; border: 1px solid #374151; border-radius: 4px; font-size: 11px; font-family: monospace; cursor: pointer;",
+                        onclick: move |_| {
+                            #[cfg(feature = "exporter")]
+                            crate::dataset::download();
+                        },
+                        "Download JSONL"
+                    }
+                }
             }
             div { style: "padding: 4px 0;", "{description}" }
             div { style: "padding: 4px 0; color: #6b7280;", "window: {window_str}" }
             div { style: "padding: 4px 0; color: #6b7280;", "viewport: {viewport_str}" }
+            if space == CoordinateSpace::Physical {
+                div { style: "padding: 4px 0; color: #6b7280;", "coordinates: physical pixels (dpr {dpr})" }
+            }
+            if let Some(d) = device {
+                div { style: "padding: 4px 0; color: #6b7280;", "device: {d.name} {d.width}x{d.height} @{d.dpr}x" }
+            }
             if scroll[0] != 0 || scroll[1] != 0 {
                 div { style: "padding: 4px 0; color: #6b7280;", "scroll: {scroll_str}" }
             }
             div { style: "padding: 4px 0; color: #6b7280;", "targets: {targets_str}" }
+            if let Some(split) = split {
+                div { style: "padding: 4px 0; color: #6b7280;", "split: {split}" }
+            }
             if !steps.is_empty() {
                 div { style: "padding: 4px 0; color: #6b7280;", "steps: {steps}" }
             }
+            for d in drifts.iter() {
+                div { style: "padding: 4px 0; color: #f59e0b;", "layout drift: {d.describe()}" }
+            }
+            if let Some(done) = &last_done {
+                div { style: "padding: 4px 0; color: #22c55e;", "last solved: round {done.round} of {done.level} in {done.elapsed_ms:.0} ms" }
+            }
+            if let Some(missing) = &missing {
+                div { style: "padding: 4px 0; color: #f59e0b;", "outcome: impossible (not found: {missing})" }
+            }
             if !full_thinking.is_empty() {
                 div { style: "padding: 4px 0; color: #8b5cf6;", "thinking: {full_thinking}" }
             }
+            if let Some(coco) = &coco {
+                div { style: "padding: 4px 0; color: #6b7280;", "coco: {coco}" }
+            }
+            div { id: "ground-truth-json", "data-schema": "{GROUND_TRUTH_SCHEMA}", style: "display: none;", "{payload}" }
         }
     }
 }
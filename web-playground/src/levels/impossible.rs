@@ -0,0 +1,94 @@
+//! Impossible rounds — instructions that name something not on screen.
+//!
+//! With `?impossible=<rate>` (0.0–1.0), opted-in levels roll each round and,
+//! on a hit, phrase the instruction around a label that is never rendered.
+//! Every round of those levels shows a "Report not found" button; it is the
+//! only target of an impossible round and a wrong answer otherwise, so the
+//! button's presence says nothing about which kind of round it is.
+
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::ui_node::{self, Rect, UINode};
+
+/// `data-label` of the abstain button, and the click target in impossible rounds.
+pub const NOT_FOUND_LABEL: &str = "Report not found";
+
+const BUTTON_W: f32 = 150.0;
+const BUTTON_H: f32 = 34.0;
+const BUTTON_MARGIN: f32 = 16.0;
+
+/// Fraction of rounds that should be impossible, from `?impossible=`.
+pub fn impossible_rate() -> f64 {
+    super::query_param("impossible")
+        .and_then(|s| s.parse::<f64>().ok())
+        .map_or(0.0, |r| r.clamp(0.0, 1.0))
+}
+
+/// Roll whether this round is impossible. Draws from `rng` only when the mode
+/// is on, so seeded runs without `?impossible=` replay unchanged.
+pub fn roll_impossible(rng: &mut impl Rng) -> bool {
+    let rate = impossible_rate();
+    rate > 0.0 && rng.random_bool(rate)
+}
+
+/// Viewport-local box of the abstain button (bottom-right corner).
+fn not_found_rect() -> Rect {
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    Rect::new(
+        vp_w - BUTTON_W - BUTTON_MARGIN,
+        vp_h - BUTTON_H - BUTTON_MARGIN,
+        BUTTON_W,
+        BUTTON_H,
+    )
+}
+
+/// Add the abstain button (the target when `impossible`) next to a level's
+/// tree. Returns `tree` unchanged when the mode is off and the button isn't
+/// rendered.
+pub fn with_not_found(tree: UINode, impossible: bool) -> UINode {
+    if impossible_rate() <= 0.0 {
+        return tree;
+    }
+    let report = if impossible {
+        ui_node::target_button(NOT_FOUND_LABEL, not_found_rect())
+    } else {
+        ui_node::button(NOT_FOUND_LABEL, not_found_rect())
+    };
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    ui_node::card(Rect::new(0.0, 0.0, vp_w, vp_h), vec![tree, report])
+}
+
+/// Abstain button for opted-in levels. Renders nothing unless the mode is on.
+/// `on_report` fires only when the round really is impossible; otherwise the
+/// button flashes red like any other wrong click.
+#[component]
+pub fn ReportNotFound(impossible: bool, on_report: Callback<()>) -> Element {
+    let mut wrong = use_signal(|| false);
+    if impossible_rate() <= 0.0 {
+        return rsx! {};
+    }
+    let bg = if wrong() { "#ef4444" } else { "rgba(17,24,39,0.85)" };
+
+    rsx! {
+        button {
+            class: if impossible { "target" } else { "" },
+            "data-label": NOT_FOUND_LABEL,
+            style: "position: absolute; right: {BUTTON_MARGIN}px; bottom: {BUTTON_MARGIN}px; width: {BUTTON_W}px; height: {BUTTON_H}px; background: {bg}; color: white; border: 1px solid #4b5563; border-radius: 6px; font-size: 13px; font-family: system-ui, sans-serif; cursor: pointer; z-index: 40;",
+            tabindex: "-1",
+            onclick: move |_| {
+                if impossible {
+                    on_report.call(());
+                } else {
+                    wrong.set(true);
+                    super::record_wrong(NOT_FOUND_LABEL, "");
+                    spawn(async move {
+                        gloo_timers::future::TimeoutFuture::new(600).await;
+                        wrong.set(false);
+                    });
+                }
+            },
+            "{NOT_FOUND_LABEL}"
+        }
+    }
+}
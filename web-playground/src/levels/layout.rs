@@ -0,0 +1,186 @@
+//! Layout — collision-aware placement of several boxes on one canvas.
+//!
+//! Levels that show more than one movable thing (a drop zone and its files,
+//! a card among clutter) place them here instead of looping over
+//! `safe_position_in` themselves. Each box is rejection-sampled against the
+//! ones already placed; a box that finds no free spot falls back to the first
+//! free cell of a deterministic grid, so a crowded round never stacks
+//! everything in the corner.
+//!
+//! Child rects inside a card (`stack`, `grid`, `flow`) live in
+//! `ui_node::layout` next to the tree builder and are re-exported here.
+
+use rand::Rng;
+
+use crate::primitives::viewport_size;
+use crate::ui_node::Rect;
+use super::safe_position_in;
+
+pub use crate::ui_node::layout::{flow, grid, stack, stack_height};
+
+/// Random draws per box before the grid fallback.
+const ATTEMPTS: usize = 300;
+
+/// Canvas the boxes must stay inside, with `pad` px kept clear at each edge
+/// (shrunk for boxes that barely fit, as in `safe_position_in`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub w: f32,
+    pub h: f32,
+    pub pad: f32,
+}
+
+impl Bounds {
+    pub fn new(w: f32, h: f32, pad: f32) -> Self {
+        Self { w, h, pad }
+    }
+
+    /// The scrollable canvas most levels use: 1.3× the viewport, so boxes
+    /// may land partly off-screen.
+    pub fn scrollable(pad: f32) -> Self {
+        let (vp_w, vp_h) = viewport_size();
+        Self::new(vp_w * 1.3, vp_h * 1.3, pad)
+    }
+}
+
+/// How a new box treats the boxes already placed.
+struct OverlapPolicy<'a> {
+    placed: &'a [Rect],
+    gap: f32,
+    max_ratio: f32,
+}
+
+impl OverlapPolicy<'_> {
+    fn accepts(&self, x: f32, y: f32, w: f32, h: f32) -> bool {
+        self.placed.iter().all(|r| {
+            let ix = (x + w).min(r.x + r.w) - x.max(r.x);
+            let iy = (y + h).min(r.y + r.h) - y.max(r.y);
+            if self.max_ratio <= 0.0 {
+                ix + self.gap <= 0.0 || iy + self.gap <= 0.0
+            } else {
+                ix <= 0.0 || iy <= 0.0 || ix * iy <= self.max_ratio * (w * h).min(r.w * r.h)
+            }
+        })
+    }
+
+    /// A random spot. With overlap allowed, half the draws hug the corner of
+    /// a placed box so partial occlusion actually shows up.
+    fn draw(&self, rng: &mut impl Rng, w: f32, h: f32, bounds: Bounds) -> (f32, f32) {
+        if self.max_ratio > 0.0 && !self.placed.is_empty() && rng.random_bool(0.5) {
+            let r = &self.placed[rng.random_range(0..self.placed.len())];
+            let x = r.x + r.w * rng.random_range(0.5..1.0) - w * rng.random_range(0.2..0.6);
+            let y = r.y + r.h * rng.random_range(0.5..1.0) - h * rng.random_range(0.2..0.6);
+            (x.clamp(0.0, (bounds.w - w).max(0.0)), y.clamp(0.0, (bounds.h - h).max(0.0)))
+        } else {
+            safe_position_in(rng, w, h, bounds.pad, bounds.w, bounds.h)
+        }
+    }
+
+    /// First accepted cell of a `w + gap` × `h + gap` grid from the padded
+    /// corner, row by row; the corner itself when every cell is taken.
+    fn grid(&self, w: f32, h: f32, bounds: Bounds) -> (f32, f32) {
+        let step_x = w + self.gap.max(1.0);
+        let step_y = h + self.gap.max(1.0);
+        let pad = bounds.pad.min((bounds.w - w).max(0.0) / 2.0).min((bounds.h - h).max(0.0) / 2.0);
+        let mut y = pad;
+        while y + h <= bounds.h - pad {
+            let mut x = pad;
+            while x + w <= bounds.w - pad {
+                if self.accepts(x, y, w, h) {
+                    return (x, y);
+                }
+                x += step_x;
+            }
+            y += step_y;
+        }
+        (pad, pad)
+    }
+}
+
+fn place(rng: &mut impl Rng, sizes: &[(f32, f32)], gap: f32, max_ratio: f32, bounds: Bounds) -> Vec<(f32, f32)> {
+    let mut rects: Vec<Rect> = Vec::with_capacity(sizes.len());
+    for &(w, h) in sizes {
+        let policy = OverlapPolicy { placed: &rects, gap, max_ratio };
+        let (x, y) = (0..ATTEMPTS)
+            .map(|_| policy.draw(rng, w, h, bounds))
+            .find(|&(x, y)| policy.accepts(x, y, w, h))
+            .unwrap_or_else(|| policy.grid(w, h, bounds));
+        rects.push(Rect::new(x, y, w, h));
+    }
+    rects.iter().map(|r| (r.x, r.y)).collect()
+}
+
+/// Top-left corners for boxes of `sizes`, in order, at least `gap` px apart
+/// and inside `bounds`. Earlier boxes get first pick, so put the one that
+/// matters most (a drop zone, the target card) first.
+pub fn place_non_overlapping(rng: &mut impl Rng, sizes: &[(f32, f32)], gap: f32, bounds: Bounds) -> Vec<(f32, f32)> {
+    place(rng, sizes, gap, 0.0, bounds)
+}
+
+/// Like `place_non_overlapping`, but each box may cover up to `ratio` of the
+/// smaller box in any overlapping pair (`?overlap=`). `ratio <= 0` is
+/// `place_non_overlapping` with no gap.
+pub fn place_overlapping(rng: &mut impl Rng, sizes: &[(f32, f32)], ratio: f32, bounds: Bounds) -> Vec<(f32, f32)> {
+    place(rng, sizes, 0.0, ratio.max(0.0), bounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    const SIZES: &[(f32, f32)] = &[(120.0, 80.0), (100.0, 100.0), (160.0, 60.0), (90.0, 90.0)];
+    const BOUNDS: Bounds = Bounds { w: 800.0, h: 600.0, pad: 20.0 };
+
+    fn rects(corners: &[(f32, f32)]) -> Vec<Rect> {
+        corners.iter().zip(SIZES).map(|(&(x, y), &(w, h))| Rect::new(x, y, w, h)).collect()
+    }
+
+    /// Overlap of `a` and `b` per axis; negative is the clearance between them.
+    fn overlap(a: &Rect, b: &Rect) -> (f32, f32) {
+        ((a.x + a.w).min(b.x + b.w) - a.x.max(b.x), (a.y + a.h).min(b.y + b.h) - a.y.max(b.y))
+    }
+
+    fn in_bounds(r: &Rect) -> bool {
+        r.x >= 0.0 && r.y >= 0.0 && r.x + r.w <= BOUNDS.w && r.y + r.h <= BOUNDS.h
+    }
+
+    #[test]
+    fn non_overlapping_boxes_stay_in_bounds_and_apart() {
+        for seed in 0..50 {
+            let placed = rects(&place_non_overlapping(&mut SmallRng::seed_from_u64(seed), SIZES, 12.0, BOUNDS));
+            assert!(placed.iter().all(in_bounds), "seed {seed}: {placed:?}");
+            for (i, a) in placed.iter().enumerate() {
+                for b in &placed[i + 1..] {
+                    let (ix, iy) = overlap(a, b);
+                    assert!(ix <= -12.0 || iy <= -12.0, "seed {seed}: {a:?} and {b:?} closer than the gap");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn overlapping_boxes_cover_up_to_the_ratio() {
+        let mut most = 0.0f32;
+        for seed in 0..50 {
+            let placed = rects(&place_overlapping(&mut SmallRng::seed_from_u64(seed), SIZES, 0.3, BOUNDS));
+            assert!(placed.iter().all(in_bounds), "seed {seed}: {placed:?}");
+            for (i, a) in placed.iter().enumerate() {
+                for b in &placed[i + 1..] {
+                    let (ix, iy) = overlap(a, b);
+                    let covered = ix.max(0.0) * iy.max(0.0) / (a.w * a.h).min(b.w * b.h);
+                    assert!(covered <= 0.3 + 1e-4, "seed {seed}: {covered} of {a:?} and {b:?}");
+                    most = most.max(covered);
+                }
+            }
+        }
+        assert!(most > 0.2, "boxes never came close to the ratio: {most}");
+    }
+
+    #[test]
+    fn placement_is_seeded() {
+        let draw = || place_non_overlapping(&mut SmallRng::seed_from_u64(7), SIZES, 8.0, BOUNDS);
+        assert_eq!(draw(), draw());
+    }
+}
@@ -3,14 +3,14 @@ use dioxus::prelude::*;
 use crate::Route;
 use crate::pool::{ElementPool, ElementKind};
 use crate::ui_node::{self, Rect};
-use super::{random_element, random_canvas_bg};
+use super::{Difficulty, random_element, random_canvas_bg};
 
 #[component]
 pub fn Level1() -> Element {
     let pool = use_hook(|| ElementPool::with_builtins());
 
-    let mut placed = use_signal(|| random_element(&pool, ElementKind::Button));
-    let mut score = use_signal(|| 0u32);
+    let mut placed = use_signal(|| random_element(&pool, ElementKind::Button, Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
 
     let current = placed.read();
@@ -61,8 +61,8 @@ pub fn Level1() -> Element {
                     style: "{style}",
                     cursor: "pointer",
                     onclick: move |_| {
-                        placed.set(random_element(&pool_click, ElementKind::Button));
-                        score.set(score() + 1);
+                        placed.set(random_element(&pool_click, ElementKind::Button, Difficulty::current()));
+                        score.complete();
                         bg.set(random_canvas_bg());
                     },
                     div {
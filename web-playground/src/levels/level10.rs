@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect, UINode, Visual, InputState, DropdownState, ToggleState};
-use super::{fresh_rng, random_canvas_bg};
+use crate::ui_node::{self, Rect, UINode, Visual, InputState, DropdownMode, DropdownState, ToggleState};
+use super::{Difficulty, fresh_rng, random_canvas_bg};
 
 const INPUT_LABELS: &[&str] = &[
     "Username", "Email", "Password", "First name", "Last name",
@@ -43,9 +43,9 @@ struct Level10State {
     y: f32,
 }
 
-fn random_level10() -> Level10State {
+fn random_level10(d: &Difficulty) -> Level10State {
     let mut rng = fresh_rng();
-    let input_count = rng.random_range(3..=5usize);
+    let input_count = d.count(&mut rng, 3..=5);
 
     let mut label_indices: Vec<usize> = (0..INPUT_LABELS.len()).collect();
     let mut group_indices: Vec<usize> = (0..DROPDOWN_GROUPS.len()).collect();
@@ -79,7 +79,7 @@ fn random_level10() -> Level10State {
         inputs.push(FormInput { label, kind, dropdown_options });
     }
 
-    let task_count = rng.random_range(2..=3usize).min(input_count);
+    let task_count = d.count(&mut rng, 2..=3).min(input_count);
     let mut available: Vec<usize> = (0..input_count).collect();
     let mut tasks = Vec::with_capacity(task_count);
 
@@ -111,14 +111,15 @@ fn random_level10() -> Level10State {
     let pad = 80.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, pad, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level10State { inputs, tasks, x, y }
 }
 
 #[component]
 pub fn Level10() -> Element {
-    let mut state = use_signal(|| random_level10());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level10(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut inputs_text = use_signal(|| vec![String::new(); 5]);
     let mut selections = use_signal(|| vec![String::new(); 5]);
@@ -151,57 +152,49 @@ pub fn Level10() -> Element {
         ))
         .collect();
 
-    // Clone for Submit closure
-    let tasks_check: Vec<(usize, u8, String, String)> = tasks_data.iter()
-        .map(|(idx, word, sel)| (*idx, inputs_data[*idx].1, word.clone(), sel.clone()))
-        .collect();
-
     // Ground truth
     let card_h = 140.0 + (input_count as f32 * 68.0);
+    let cur_text: Vec<String> = inputs_text.read().clone();
+    let cur_sel: Vec<String> = selections.read().clone();
+    let cur_on: Vec<bool> = toggled.read().clone();
 
-    // Build UINode tree for ground truth
+    // Build UINode tree for ground truth, carrying each field's live value
     let input_nodes: Vec<UINode> = inputs_data.iter().enumerate().map(|(i, (label, kind, opts))| {
         let has_task = tasks_data.iter().find(|(idx, _, _)| *idx == i);
         let row_y = card_y + 100.0 + i as f32 * 68.0;
         let rect = Rect::new(card_x + 20.0, row_y, 260.0, 36.0);
+        let v = Visual::new(label.as_str(), rect);
+        let v = if has_task.is_some() { v.target() } else { v };
         match kind {
-            0 => {
-                if let Some((_, word, _)) = has_task {
-                    ui_node::text_input(label.as_str(), rect, "Type here...", word.as_str())
-                } else {
-                    UINode::TextInput(
-                        Visual::new(label.as_str(), rect),
-                        InputState { placeholder: "Type here...".into(), current_value: String::new(), target_value: String::new() },
-                    )
-                }
-            }
-            1 => {
-                if let Some((_, _, sel)) = has_task {
-                    ui_node::dropdown(label.as_str(), rect, opts.clone(), sel.as_str())
-                } else {
-                    UINode::Dropdown(
-                        Visual::new(label.as_str(), rect),
-                        DropdownState { options: opts.clone(), selected: None, target_option: String::new(), trigger_label: "Choose...".into() },
-                    )
-                }
-            }
-            _ => {
-                if has_task.is_some() {
-                    ui_node::toggle(label.as_str(), rect, false)
-                } else {
-                    UINode::Toggle(
-                        Visual::new(label.as_str(), rect),
-                        ToggleState { is_on: false },
-                    )
-                }
-            }
+            0 => UINode::TextInput(v, InputState {
+                placeholder: "Type here...".into(),
+                current_value: cur_text.get(i).cloned().unwrap_or_default(),
+                target_value: has_task.map(|(_, word, _)| word.clone()).unwrap_or_default(),
+            }),
+            1 => UINode::Dropdown(v, DropdownState {
+                options: opts.clone(),
+                selected: cur_sel.get(i).filter(|s| !s.is_empty()).cloned(),
+                target_option: has_task.map(|(_, _, sel)| sel.clone()).unwrap_or_default(),
+                trigger_label: "Choose...".into(),
+                mode: DropdownMode::Click,
+                open_options: Vec::new(),
+            }),
+            _ => UINode::Toggle(v, ToggleState {
+                is_on: cur_on.get(i).copied().unwrap_or(false),
+                target_on: has_task.is_some(),
+            }),
         }
     }).collect();
     let tree = ui_node::form(
         Rect::new(card_x, card_y, 340.0, card_h),
         "Submit",
         input_nodes,
-    );
+    ).unordered();
+    // Unmet fields by index, for the Submit closure
+    let field_labels: Vec<String> = inputs_data.iter().map(|(label, _, _)| label.clone()).collect();
+    let unmet: Vec<(usize, String, String)> = tree.evaluate().unmet().into_iter()
+        .filter_map(|(label, value)| Some((field_labels.iter().position(|l| *l == label)?, label, value)))
+        .collect();
     let card_style = format!(
         "position: absolute; left: {}px; top: {}px; background: white; border-radius: 12px; padding: 20px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); width: 300px; font-family: system-ui, sans-serif;",
         card_x, card_y
@@ -341,6 +334,7 @@ pub fn Level10() -> Element {
                                                     .unwrap_or_default();
                                                 rsx! {
                                                     super::CustomSelect {
+                                                        label: label_clone.clone(),
                                                         options: opts_clone.clone(),
                                                         is_target: has_task,
                                                         target_option: task_select_val,
@@ -389,6 +383,7 @@ pub fn Level10() -> Element {
                             tabindex: "-1",
                             onclick: move |_| {
                                 wrong_btn.set(Some(false));
+                                score.wrong("Cancel", "");
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(400).await;
                                     wrong_btn.set(None);
@@ -401,25 +396,16 @@ pub fn Level10() -> Element {
                             style: "flex: 1; padding: 10px; background: {submit_bg}; color: white; border: none; border-radius: 6px; font-size: 14px; font-weight: 600; font-family: system-ui, sans-serif; cursor: pointer; transition: background 0.15s;",
                             tabindex: "-1",
                             onclick: move |_| {
-                                let mut all_correct = true;
                                 let mut bad = vec![false; 5];
-
-                                for &(idx, kind, ref word, ref sel) in tasks_check.iter() {
-                                    let correct = match kind {
-                                        0 => inputs_text.read().get(idx).map(|v| v == word).unwrap_or(false),
-                                        1 => selections.read().get(idx).map(|v| v == sel).unwrap_or(false),
-                                        _ => toggled.read().get(idx).copied().unwrap_or(false),
-                                    };
-                                    if !correct {
-                                        all_correct = false;
-                                        bad[idx] = true;
-                                    }
+                                for (idx, label, value) in &unmet {
+                                    bad[*idx] = true;
+                                    score.wrong(label, value);
                                 }
 
-                                if all_correct {
-                                    score.set(score() + 1);
+                                if unmet.is_empty() {
+                                    score.complete();
                                     bg.set(random_canvas_bg());
-                                    state.set(random_level10());
+                                    state.set(random_level10(Difficulty::current()));
                                     inputs_text.set(vec![String::new(); 5]);
                                     selections.set(vec![String::new(); 5]);
                                     toggled.set(vec![false; 5]);
@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use crate::ui_node::{self, CarouselNav, CarouselState, Rect};
+use super::{Difficulty, fresh_rng, random_canvas_bg, ordinal};
 
 const SLIDE_COLORS: &[&str] = &[
     "#e74c3c", "#3498db", "#2ecc71", "#f39c12", "#9b59b6",
@@ -26,9 +26,9 @@ struct Level11State {
     y: f32,
 }
 
-fn random_level11() -> Level11State {
+fn random_level11(d: &Difficulty) -> Level11State {
     let mut rng = fresh_rng();
-    let slide_count = rng.random_range(3..=6usize);
+    let slide_count = d.count(&mut rng, 3..=6);
     let nav_type = rng.random_range(0..6u8);
 
     let mut color_indices: Vec<usize> = (0..SLIDE_COLORS.len()).collect();
@@ -51,14 +51,82 @@ fn random_level11() -> Level11State {
     let pad = 80.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, pad, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level11State { slides, target_slide, nav_type, x, y }
 }
 
+/// Card content box: 20px padding around a 300px column.
+const CONTENT_W: f32 = 300.0;
+const SLIDE_W: f32 = 260.0;
+const SLIDE_H: f32 = 150.0;
+
+/// Rects of `sizes` in one row centered across the content column
+/// (`display: flex; justify-content: center; gap`), top edge at `y`.
+fn centered_row(x: f32, y: f32, sizes: &[f32], gap: f32) -> Vec<Rect> {
+    let total = sizes.iter().sum::<f32>() + gap * sizes.len().saturating_sub(1) as f32;
+    let mut left = x + (CONTENT_W - total) / 2.0;
+    sizes.iter().map(|&s| {
+        let r = Rect::new(left, y, s, s);
+        left += s + gap;
+        r
+    }).collect()
+}
+
+/// Ground-truth model of the carousel's navigation as rendered below
+fn carousel_state(nav_type: u8, slide_count: usize, current: usize, target_slide: usize, card_x: f32, card_y: f32) -> (Rect, CarouselState) {
+    let x = card_x + 20.0;
+    // Instruction line (15px + 12px margin), then the tabs row when present
+    let tabs_y = card_y + 20.0 + 30.0;
+    let slide_y = if nav_type == 3 { tabs_y + 36.0 } else { tabs_y };
+    let slide = Rect::new(x, slide_y, SLIDE_W, SLIDE_H);
+    let dots_y = slide_y + SLIDE_H + 8.0;
+    let nav = match nav_type {
+        0 => CarouselNav::Arrows,
+        1 | 4 => CarouselNav::Dots,
+        2 => CarouselNav::ArrowsDots,
+        3 => CarouselNav::Tabs,
+        _ => CarouselNav::Auto,
+    };
+    let arrows = matches!(nav, CarouselNav::Arrows | CarouselNav::ArrowsDots).then(|| {
+        let arrow_y = slide_y + (SLIDE_H - 28.0) / 2.0;
+        (Rect::new(x + 6.0, arrow_y, 28.0, 28.0), Rect::new(x + SLIDE_W - 34.0, arrow_y, 28.0, 28.0))
+    });
+    let indicators = match nav_type {
+        1 | 2 => {
+            let sizes: Vec<f32> = (0..slide_count).map(|i| if i == current { 10.0 } else { 8.0 }).collect();
+            centered_row(x, dots_y, &sizes, 6.0)
+        }
+        3 => (0..slide_count).map(|i| Rect::new(x + i as f32 * 36.0, tabs_y, 32.0, 28.0)).collect(),
+        4 => centered_row(x, dots_y, &vec![14.0; slide_count], 8.0),
+        _ => Vec::new(),
+    };
+    (slide, CarouselState { nav, slide_count, current, target_slide, arrows, indicators })
+}
+
+/// Ground-truth form: navigate to the target slide, then type its text.
+/// The text input depends on the carousel, so the form stays ordered.
+fn slides_form(nav_type: u8, slide_count: usize, current: usize, target_slide: usize, target_text: &str, card_x: f32, card_y: f32) -> ui_node::UINode {
+    let (slide_rect, carousel) = carousel_state(nav_type, slide_count, current, target_slide, card_x, card_y);
+    ui_node::form(
+        Rect::new(card_x, card_y, 340.0, 400.0),
+        "Submit",
+        vec![
+            ui_node::carousel("slides", slide_rect, carousel),
+            ui_node::text_input(
+                "Enter slide text",
+                Rect::new(card_x + 20.0, card_y + 300.0, 260.0, 36.0),
+                "Enter slide text...",
+                target_text,
+            ),
+        ],
+    )
+}
+
 #[component]
 pub fn Level11() -> Element {
-    let mut state = use_signal(|| random_level11());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level11(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut current = use_signal(|| 0usize);
     let mut input_text = use_signal(|| String::new());
@@ -106,29 +174,8 @@ pub fn Level11() -> Element {
     let left_opacity = if cur == 0 { "0.3" } else { "0.8" };
     let right_opacity = if cur >= slide_count - 1 { "0.3" } else { "0.8" };
 
-    let _nav_desc = match nav_type {
-        0 => "arrows",
-        1 => "dots",
-        2 => "arrows+dots",
-        3 => "numbered tabs",
-        4 => "ring dots",
-        _ => "auto-slide",
-    };
-
-    // Build UINode tree for ground truth
-    // The carousel has a text input and submit button as a form
-    let tree = ui_node::form(
-        Rect::new(card_x, card_y, 340.0, 400.0),
-        "Submit",
-        vec![
-            ui_node::text_input(
-                "Enter slide text",
-                Rect::new(card_x + 20.0, card_y + 300.0, 260.0, 36.0),
-                "Enter slide text...",
-                &target_text,
-            ),
-        ],
-    );
+    // Build UINode tree for ground truth: navigate to the slide, then type its text
+    let tree = slides_form(nav_type, slide_count, cur.min(slide_count - 1), target_slide, &target_text, card_x, card_y);
     let card_style = format!(
         "position: absolute; left: {}px; top: {}px; background: white; border-radius: 12px; padding: 20px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); width: 300px; font-family: system-ui, sans-serif;",
         card_x, card_y
@@ -189,6 +236,7 @@ pub fn Level11() -> Element {
                                     rsx! {
                                         button {
                                             class: "target",
+                                            "data-label": CarouselState::indicator_label(si),
                                             style: "width: 32px; height: 28px; background: {tab_bg}; color: {tab_color}; border: none; border-radius: 4px; font-size: 13px; font-weight: 600; cursor: pointer; font-family: monospace; transition: background 0.15s;",
                                             tabindex: "-1",
                                             onclick: move |_| current.set(si),
@@ -206,6 +254,7 @@ pub fn Level11() -> Element {
 
                         // Slide
                         div {
+                            "data-label": CarouselState::showing_label(cur),
                             style: "width: 100%; height: 100%; background: {cur_color}; display: flex; align-items: center; justify-content: center; border-radius: 8px; user-select: none;",
                             span {
                                 style: "color: white; font-size: 28px; font-weight: 700; letter-spacing: 2px; text-shadow: 0 2px 4px rgba(0,0,0,0.3);",
@@ -217,6 +266,7 @@ pub fn Level11() -> Element {
                         if nav_type == 0 || nav_type == 2 {
                             button {
                                 class: "target",
+                                "data-label": CarouselState::PREV_LABEL,
                                 style: "position: absolute; left: 6px; top: 50%; transform: translateY(-50%); width: 28px; height: 28px; background: rgba(0,0,0,0.4); color: white; border: none; border-radius: 50%; font-size: 14px; cursor: pointer; display: flex; align-items: center; justify-content: center; opacity: {left_opacity}; transition: opacity 0.15s;",
                                 tabindex: "-1",
                                 disabled: cur == 0,
@@ -229,6 +279,7 @@ pub fn Level11() -> Element {
                         if nav_type == 0 || nav_type == 2 {
                             button {
                                 class: "target",
+                                "data-label": CarouselState::NEXT_LABEL,
                                 style: "position: absolute; right: 6px; top: 50%; transform: translateY(-50%); width: 28px; height: 28px; background: rgba(0,0,0,0.4); color: white; border: none; border-radius: 50%; font-size: 14px; cursor: pointer; display: flex; align-items: center; justify-content: center; opacity: {right_opacity}; transition: opacity 0.15s;",
                                 tabindex: "-1",
                                 disabled: cur >= slide_count - 1,
@@ -250,6 +301,7 @@ pub fn Level11() -> Element {
                                     rsx! {
                                         div {
                                             class: "target",
+                                            "data-label": CarouselState::indicator_label(si),
                                             style: "width: {dot_size}; height: {dot_size}; border-radius: 50%; background: {dot_bg}; cursor: pointer; transition: all 0.15s;",
                                             onclick: move |_| current.set(si),
                                         }
@@ -271,6 +323,7 @@ pub fn Level11() -> Element {
                                     rsx! {
                                         button {
                                             class: "target",
+                                            "data-label": CarouselState::indicator_label(si),
                                             style: "width: 14px; height: 14px; border-radius: 50%; background: {ring_bg}; border: 2px solid {ring_border}; cursor: pointer; transition: all 0.15s; padding: 0;",
                                             tabindex: "-1",
                                             onclick: move |_| current.set(si),
@@ -331,16 +384,17 @@ pub fn Level11() -> Element {
                         onclick: move |_| {
                             let val = input_text.read().clone();
                             if val.eq_ignore_ascii_case(&target_text) {
-                                score.set(score() + 1);
+                                score.complete();
                                 auto_gen.set(auto_gen() + 1);
                                 bg.set(random_canvas_bg());
-                                state.set(random_level11());
+                                state.set(random_level11(Difficulty::current()));
                                 current.set(0);
                                 input_text.set(String::new());
                                 wrong.set(false);
                                 document::eval("document.activeElement?.blur()");
                             } else {
                                 wrong.set(true);
+                                score.wrong("Enter slide text", &val);
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(400).await;
                                     wrong.set(false);
@@ -363,3 +417,22 @@ pub fn Level11() -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui_node::Action;
+
+    #[test]
+    fn slide_navigation_stays_before_typing() {
+        // Arrows, three slides, on the first, target the third
+        let gt = slides_form(0, 3, 0, 2, "DELTA", 100.0, 80.0).resolve();
+        let typed = gt.steps.iter().position(|a| matches!(a, Action::Type { .. })).unwrap();
+        assert!(typed >= 2, "two arrow clicks come first: {:?}", gt.steps);
+        assert!(gt.order.groups.is_empty());
+        // A plain chain: every navigation click comes before the typing
+        for i in 0..typed {
+            assert!(gt.order.edges.contains(&(i, i + 1)));
+        }
+    }
+}
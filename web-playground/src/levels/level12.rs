@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::Route;
 use crate::ui_node::{self, Rect, UINode, Visual, InputState};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use super::{Difficulty, fresh_rng, random_canvas_bg, ordinal};
 
 const FIELD_NAMES: &[&str] = &[
     "Name", "Email", "Phone", "Address", "City", "State",
@@ -34,10 +34,10 @@ struct Level12State {
     y: f32,
 }
 
-fn random_level12() -> Level12State {
+fn random_level12(d: &Difficulty) -> Level12State {
     let mut rng = fresh_rng();
-    let cols = rng.random_range(4..=6usize);
-    let rows = rng.random_range(3..=5usize);
+    let cols = d.count(&mut rng, 4..=6);
+    let rows = d.count(&mut rng, 3..=5);
     let total = cols * rows;
     let input_count = rng.random_range((total * 3 / 4).max(10)..=total);
 
@@ -94,19 +94,20 @@ fn random_level12() -> Level12State {
     let grid_w = cols as f32 * cell_w + (cols as f32 - 1.0) * gap;
     let card_w = grid_w + 2.0 * pad_inner;
     let row_h: f32 = if rows <= 3 { 65.0 } else { 55.0 };
-    let card_h = rows as f32 * row_h + (rows as f32 - 1.0) * gap + 110.0;
+    let card_h = super::layout::stack_height(&vec![row_h; rows], gap) + 110.0;
 
     let margin = 60.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, margin, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level12State { cols, rows, cells, target_input, target_word, mode, x, y }
 }
 
 #[component]
 pub fn Level12() -> Element {
-    let mut state = use_signal(|| random_level12());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level12(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_count = state.read().cells.iter().filter(|c| c.is_some()).count();
     let mut inputs_text = use_signal(move || vec![String::new(); initial_count]);
@@ -143,6 +144,7 @@ pub fn Level12() -> Element {
 
     let target_cell = cells.iter().filter_map(|c| c.as_ref()).nth(target_input).unwrap();
     let target_name = target_cell.name.clone();
+    let wrong_target = target_name.clone();
     let target_ord = ordinal(target_input + 1);
     let wf = wrong_field();
     let is_wrong = wrong();
@@ -164,7 +166,8 @@ pub fn Level12() -> Element {
     let _input_count = cells.iter().filter(|c| c.is_some()).count();
     let card_total_w = content_w + 32.0;
     let row_h: f32 = if rows <= 3 { 65.0 } else { 55.0 };
-    let card_h = rows as f32 * row_h + (rows as f32 - 1.0) * 8.0 + 110.0;
+    let card_h = super::layout::stack_height(&vec![row_h; rows], 8.0) + 110.0;
+    let cell_rects = super::layout::grid(Rect::new(card_x + 16.0, card_y + 70.0, content_w, 0.0), cols, &vec![row_h; total_cells], 8.0);
 
     // Build UINode tree for ground truth
     let input_nodes: Vec<UINode> = {
@@ -172,14 +175,7 @@ pub fn Level12() -> Element {
         let mut nodes = Vec::new();
         for (ci, c) in cells.iter().enumerate() {
             if let Some(cell) = c {
-                let r = ci / cols;
-                let col = ci % cols;
-                let cell_rect = Rect::new(
-                    card_x + 16.0 + col as f32 * (cell_w + 8.0),
-                    card_y + 70.0 + r as f32 * (row_h + 8.0),
-                    cell_w,
-                    row_h,
-                );
+                let cell_rect = cell_rects[ci];
                 let ph = if cell.has_label { String::new() } else { cell.name.clone() };
                 if ii == target_input {
                     nodes.push(ui_node::text_input(&cell.name, cell_rect, &ph, &target_word));
@@ -198,7 +194,7 @@ pub fn Level12() -> Element {
         Rect::new(card_x, card_y, card_total_w, card_h),
         "Submit",
         input_nodes,
-    );
+    ).unordered();
     rsx! {
         div {
             style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
@@ -317,9 +313,9 @@ pub fn Level12() -> Element {
                         onclick: move |_| {
                             let val = inputs_text.read().get(target_input).cloned().unwrap_or_default();
                             if val.eq_ignore_ascii_case(&target_word) {
-                                score.set(score() + 1);
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level12();
+                                let new_st = random_level12(Difficulty::current());
                                 let count = new_st.cells.iter().filter(|c| c.is_some()).count();
                                 state.set(new_st);
                                 inputs_text.set(vec![String::new(); count]);
@@ -329,6 +325,7 @@ pub fn Level12() -> Element {
                             } else {
                                 wrong.set(true);
                                 wrong_field.set(Some(target_input));
+                                score.wrong(&wrong_target, &val);
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect, UINode, Visual, InputState};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use crate::ui_node::{self, Rect};
+use super::{Difficulty, fresh_rng, random_canvas_bg, ordinal};
 
 const COLUMN_NAMES: &[&str] = &[
     "Name", "Email", "Phone", "City", "Code", "Notes",
@@ -36,10 +36,10 @@ struct Level13State {
     y: f32,
 }
 
-fn random_level13() -> Level13State {
+fn random_level13(d: &Difficulty) -> Level13State {
     let mut rng = fresh_rng();
-    let cols = rng.random_range(3..=6usize);
-    let body_rows = rng.random_range(4..=7usize);
+    let cols = d.count(&mut rng, 3..=6);
+    let body_rows = d.count(&mut rng, 4..=7);
     let total = cols * body_rows;
 
     // Column headers
@@ -93,14 +93,15 @@ fn random_level13() -> Level13State {
     let margin = 50.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, margin, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level13State { cols, body_rows, headers, placeholders, target_row, target_col, target_word, mode, x, y }
 }
 
 #[component]
 pub fn Level13() -> Element {
-    let mut state = use_signal(|| random_level13());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level13(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_total = { let s = state.read(); s.cols * s.body_rows };
     let mut inputs_text = use_signal(move || vec![String::new(); initial_total]);
@@ -123,6 +124,7 @@ pub fn Level13() -> Element {
     let target_idx = target_row * cols + target_col;
     let target_ord = ordinal(target_idx + 1);
     let target_header = headers[target_col].clone();
+    let wrong_target = target_header.clone();
     let target_ph = placeholders[target_idx].clone();
     let wf = wrong_field();
     let is_wrong = wrong();
@@ -140,37 +142,24 @@ pub fn Level13() -> Element {
     let card_total_w = content_w + 32.0;
     let card_h = (body_rows + 1) as f32 * 34.0 + 110.0;
 
-    // Build UINode tree for ground truth
+    // Build UINode tree for ground truth: body cells start one header row below the title
     let row_h: f32 = 34.0;
-    let input_nodes: Vec<UINode> = {
-        let mut nodes = Vec::new();
-        for ri in 0..body_rows {
-            for ci in 0..cols {
-                let cell_idx = ri * cols + ci;
-                let ph = &placeholders[cell_idx];
-                let cell_rect = Rect::new(
-                    card_x + 16.0 + ci as f32 * col_w,
-                    card_y + 70.0 + (ri + 1) as f32 * row_h,
-                    col_w,
-                    row_h,
-                );
-                if cell_idx == target_idx {
-                    nodes.push(ui_node::text_input(&headers[ci], cell_rect, ph.as_str(), &target_word));
-                } else {
-                    nodes.push(UINode::TextInput(
-                        Visual::new(&headers[ci], cell_rect),
-                        InputState { placeholder: ph.clone(), current_value: String::new(), target_value: String::new() },
-                    ));
-                }
-            }
-        }
-        nodes
-    };
-    let tree = ui_node::form(
-        Rect::new(card_x, card_y, card_total_w, card_h),
-        "Submit",
-        input_nodes,
-    );
+    let mut tree = ui_node::fluent::form("Submit")
+        .unordered()
+        .at(Rect::new(card_x, card_y, card_total_w, card_h))
+        .padding(16.0)
+        .top(70.0 + row_h)
+        .grid(cols, 0.0)
+        .item_height(row_h);
+    for (cell_idx, ph) in placeholders.iter().enumerate() {
+        let header = &headers[cell_idx % cols];
+        tree = if cell_idx == target_idx {
+            tree.target_text_input(header, ph.as_str(), &target_word)
+        } else {
+            tree.text_input(header, ph.as_str())
+        };
+    }
+    let tree = tree.build();
     rsx! {
         div {
             style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
@@ -304,9 +293,9 @@ pub fn Level13() -> Element {
                         onclick: move |_| {
                             let val = inputs_text.read().get(target_idx).cloned().unwrap_or_default();
                             if val.eq_ignore_ascii_case(&target_word) {
-                                score.set(score() + 1);
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level13();
+                                let new_st = random_level13(Difficulty::current());
                                 let count = new_st.cols * new_st.body_rows;
                                 state.set(new_st);
                                 inputs_text.set(vec![String::new(); count]);
@@ -316,6 +305,7 @@ pub fn Level13() -> Element {
                             } else {
                                 wrong.set(true);
                                 wrong_field.set(Some(target_idx));
+                                score.wrong(&wrong_target, &val);
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect, UINode, Visual, CheckState};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use crate::ui_node::{self, CheckState, GroupTarget, Rect, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, tr_ordinal};
 
 const LEGAL_PARAGRAPHS: &[&str] = &[
     "By accessing or using this service, you acknowledge that you have read, understood, and agree to be bound by these terms and conditions. These terms constitute a legally binding agreement between you and the service provider. Any modifications to these terms will be effective upon posting.",
@@ -59,14 +59,16 @@ struct Level14State {
     y: f32,
     card_w: f32,
     card_h: f32,
+    /// Native `<input type=checkbox>` boxes instead of styled ones
+    native: bool,
 }
 
-fn random_level14() -> Level14State {
+fn random_level14(d: &Difficulty) -> Level14State {
     let mut rng = fresh_rng();
 
     let title = AGREEMENT_TITLES[rng.random_range(0..AGREEMENT_TITLES.len())].to_string();
-    let para_count = rng.random_range(10..=14usize);
-    let cb_count = rng.random_range(3..=5usize);
+    let para_count = d.count(&mut rng, 10..=14);
+    let cb_count = d.count(&mut rng, 3..=5);
 
     // Checkbox positions: after paragraphs 2..para_count-2 (ensure text above & below)
     let mut available: Vec<usize> = (2..para_count.saturating_sub(2)).collect();
@@ -133,14 +135,16 @@ fn random_level14() -> Level14State {
     let margin = 40.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, margin, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    let native = super::random_native_inputs(&mut rng);
 
-    Level14State { title, sections, checkbox_count: cb_count, target_checkboxes, mode, target_label, x, y, card_w, card_h }
+    Level14State { title, sections, checkbox_count: cb_count, target_checkboxes, mode, target_label, x, y, card_w, card_h, native }
 }
 
 #[component]
 pub fn Level14() -> Element {
-    let mut state = use_signal(|| random_level14());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level14(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_cb = state.read().checkbox_count;
     let mut checks = use_signal(move || vec![false; initial_cb]);
@@ -157,6 +161,7 @@ pub fn Level14() -> Element {
     let card_y = st.y;
     let card_w = st.card_w;
     let card_h = st.card_h;
+    let native = st.native;
     drop(st);
 
     let is_wrong = wrong();
@@ -179,9 +184,9 @@ pub fn Level14() -> Element {
     };
 
     let instruction = match mode {
-        0 => "Check all checkboxes and click Accept".to_string(),
-        1 => format!("Check the {} checkbox and click Accept", ordinal(target_checkboxes[0] + 1)),
-        _ => format!("Check \"{}\" and click Accept", target_label),
+        0 => tr("instr.check_all_accept", &[]),
+        1 => tr("instr.check_nth_accept", &[&tr_ordinal(target_checkboxes[0] + 1)]),
+        _ => tr("instr.check_label_accept", &[&target_label]),
     };
 
     let card_style = format!(
@@ -191,30 +196,22 @@ pub fn Level14() -> Element {
     let submit_bg = if is_wrong { "#ef4444" } else { "#4f46e5" };
 
     // Build UINode tree for ground truth
-    let checkbox_nodes: Vec<UINode> = {
-        let mut idx = 0usize;
-        let mut nodes = Vec::new();
-        for (_, cb) in sections.iter() {
-            if let Some(label) = cb {
-                let is_target = target_checkboxes.contains(&idx);
-                let cb_rect = Rect::new(card_x + 16.0, card_y + 60.0 + idx as f32 * 50.0, card_w - 32.0, 40.0);
-                if is_target {
-                    nodes.push(ui_node::checkbox(label.as_str(), cb_rect, false));
-                } else {
-                    nodes.push(UINode::Checkbox(
-                        Visual::new(label.as_str(), cb_rect),
-                        CheckState { is_checked: false },
-                    ));
-                }
-                idx += 1;
-            }
-        }
-        nodes
+    let labels: Vec<&String> = sections.iter().filter_map(|(_, cb)| cb.as_ref()).collect();
+    let heights = vec![40.0; labels.len()];
+    let group_rect = Rect::new(card_x + 16.0, card_y + 60.0, card_w - 32.0, super::layout::stack_height(&heights, 10.0));
+    let box_rects = super::layout::stack(group_rect, &heights, 10.0);
+    let boxes: Vec<(Visual, CheckState)> = labels.iter().zip(box_rects).enumerate()
+        .map(|(i, (label, rect))| (Visual::new(label.as_str(), rect), CheckState { is_checked: checks_snap.get(i).copied().unwrap_or(false) }))
+        .collect();
+    let group_target = match mode {
+        0 => GroupTarget::All,
+        1 => GroupTarget::Ordinal(target_checkboxes[0]),
+        _ => GroupTarget::ByLabel(target_label.clone()),
     };
     let tree = ui_node::form(
         Rect::new(card_x, card_y, card_w + 32.0, card_h),
         "Accept",
-        checkbox_nodes,
+        vec![ui_node::checkbox_group(title.as_str(), group_rect, boxes, group_target)],
     );
     rsx! {
         div {
@@ -269,6 +266,7 @@ pub fn Level14() -> Element {
                                 let cb_label = sections[si].1.clone().unwrap_or_default();
                                 let cb_idx = section_cb_idx[si].unwrap_or(0);
                                 let is_checked = has_cb && checks_snap.get(cb_idx).copied().unwrap_or(false);
+                                let (box_border, box_bg) = if is_checked { ("#4f46e5", "#4f46e5") } else { ("#9ca3af", "white") };
 
                                 rsx! {
                                     div {
@@ -287,11 +285,18 @@ pub fn Level14() -> Element {
                                                         *v = !*v;
                                                     }
                                                 },
-                                                input {
-                                                    r#type: "checkbox",
-                                                    tabindex: "-1",
-                                                    checked: is_checked,
-                                                    style: "width: 16px; height: 16px; margin-top: 1px; accent-color: #4f46e5; pointer-events: none; flex-shrink: 0;",
+                                                if native {
+                                                    input {
+                                                        r#type: "checkbox",
+                                                        tabindex: "-1",
+                                                        checked: is_checked,
+                                                        style: "width: 16px; height: 16px; margin-top: 1px; accent-color: #4f46e5; pointer-events: none; flex-shrink: 0;",
+                                                    }
+                                                } else {
+                                                    div {
+                                                        style: "width: 16px; height: 16px; margin-top: 1px; box-sizing: border-box; border: 2px solid {box_border}; border-radius: 3px; background: {box_bg}; color: white; font-size: 11px; font-weight: 700; line-height: 12px; text-align: center; flex-shrink: 0; transition: background 0.15s;",
+                                                        if is_checked { "\u{2713}" }
+                                                    }
                                                 }
                                                 span {
                                                     style: "font-size: 12px; color: #374151; user-select: none;",
@@ -312,12 +317,13 @@ pub fn Level14() -> Element {
                         tabindex: "-1",
                         onclick: move |_| {
                             let vals = checks.read();
-                            let ok = target_checkboxes.iter().all(|&i| vals.get(i).copied().unwrap_or(false));
+                            let checked = target_checkboxes.iter().filter(|&&i| vals.get(i).copied().unwrap_or(false)).count();
+                            let ok = checked == target_checkboxes.len();
                             drop(vals);
                             if ok {
-                                score.set(score() + 1);
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level14();
+                                let new_st = random_level14(Difficulty::current());
                                 let count = new_st.checkbox_count;
                                 state.set(new_st);
                                 checks.set(vec![false; count]);
@@ -325,6 +331,7 @@ pub fn Level14() -> Element {
                                 document::eval("document.activeElement?.blur()");
                             } else {
                                 wrong.set(true);
+                                score.wrong("Accept", &format!("{checked}/{} required checked", target_checkboxes.len()));
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
@@ -2,8 +2,9 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg};
+use crate::ui_node::{self, Rect, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg};
+use super::layout::{self, Bounds};
 
 const FILE_POOL: &[(&str, &str, &str)] = &[
     ("report", "pdf", "#ef4444"),
@@ -31,6 +32,12 @@ const FILE_POOL: &[(&str, &str, &str)] = &[
 const FILE_W: f32 = 80.0;
 const FILE_H: f32 = 96.0;
 
+/// CSS stacking layer of file `i`: later files paint over earlier ones, all
+/// above the drop zone and below the drag overlay.
+fn file_z(i: usize) -> i32 {
+    10 + i as i32
+}
+
 #[derive(Clone)]
 struct FileIcon {
     name: String,
@@ -49,16 +56,15 @@ struct Level15State {
     drop_h: f32,
 }
 
-fn random_level15() -> Level15State {
+fn random_level15(d: &Difficulty) -> Level15State {
     let mut rng = fresh_rng();
-    let file_count = rng.random_range(2..=5usize);
+    let file_count = d.count(&mut rng, 2..=5);
 
     let drop_w = rng.random_range(180.0..=240.0f32);
     let drop_h = rng.random_range(140.0..=180.0f32);
 
-    let margin = 50.0;
     let gap = 30.0;
-    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let overlap = super::overlap_ratio();
 
     // Sizes: drop zone first, then file icons
     let mut sizes: Vec<(f32, f32)> = vec![(drop_w, drop_h)];
@@ -66,24 +72,13 @@ fn random_level15() -> Level15State {
         sizes.push((FILE_W, FILE_H));
     }
 
-    // Place items without overlap
-    let mut rects: Vec<(f32, f32, f32, f32)> = Vec::new();
-    let mut all_pos: Vec<(f32, f32)> = Vec::new();
-    for &(w, h) in &sizes {
-        let mut pos = (margin, margin);
-        for _ in 0..300 {
-            let (x, y) = super::safe_position_in(&mut rng, w, h, margin, vp_w * 1.3, vp_h * 1.3);
-            let ok = rects.iter().all(|&(rx, ry, rw, rh)| {
-                x >= rx + rw + gap || x + w + gap <= rx || y >= ry + rh + gap || y + h + gap <= ry
-            });
-            if ok {
-                pos = (x, y);
-                break;
-            }
-        }
-        rects.push((pos.0, pos.1, w, h));
-        all_pos.push(pos);
-    }
+    // Place items apart, or partially overlapping under `?overlap=`
+    let bounds = Bounds::scrollable(50.0);
+    let all_pos = if overlap > 0.0 {
+        layout::place_overlapping(&mut rng, &sizes, overlap, bounds)
+    } else {
+        layout::place_non_overlapping(&mut rng, &sizes, gap, bounds)
+    };
 
     let (drop_x, drop_y) = all_pos[0];
 
@@ -121,8 +116,8 @@ fn snap_back(state: &Signal<Level15State>, file_pos: &mut Signal<Vec<(f32, f32)>
 
 #[component]
 pub fn Level15() -> Element {
-    let mut state = use_signal(|| random_level15());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level15(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_pos = {
         let s = state.read();
@@ -144,6 +139,7 @@ pub fn Level15() -> Element {
 
     let file_count = files.len();
     let target_name = format!("{}.{}", files[target].name, files[target].ext);
+    let file_names: Vec<String> = files.iter().map(|f| format!("{}.{}", f.name, f.ext)).collect();
     let is_wrong = wrong();
     let cur_drag = drag_idx();
     let positions: Vec<(f32, f32)> = file_pos.read().clone();
@@ -163,14 +159,18 @@ pub fn Level15() -> Element {
     let dz_arrow = if is_wrong { "#ef4444" } else if drag_over { "#4f46e5" } else { "#9ca3af" };
     let viewport_style = format!("{} user-select: none;", super::viewport_style(&bg(), true));
 
-    // Ground truth via UINode tree
+    // Ground truth: every icon at its resting spot with its stacking layer,
+    // which matters when `?overlap=` lets icons cover each other and the zone
     let (vp_w, vp_h) = crate::primitives::viewport_size();
-    let tree = ui_node::card(
+    let icons: Vec<Visual> = files.iter().enumerate()
+        .map(|(i, f)| Visual::new(&file_names[i], Rect::new(f.orig_x, f.orig_y, FILE_W, FILE_H)).z_index(file_z(i)))
+        .collect();
+    let tree = ui_node::file_drag_task(
+        "upload",
         Rect::new(0.0, 0.0, vp_w, vp_h),
-        vec![
-            ui_node::drag_source(&target_name, Rect::new(files[target].orig_x, files[target].orig_y, FILE_W, FILE_H)),
-            ui_node::drop_zone("Upload Zone", Rect::new(drop_x, drop_y, drop_w, drop_h)),
-        ],
+        icons,
+        target,
+        Visual::new("Upload Zone", Rect::new(drop_x, drop_y, drop_w, drop_h)),
     );
     rsx! {
         div {
@@ -243,7 +243,7 @@ pub fn Level15() -> Element {
                         let f = files[fi].clone();
                         let (fx, fy) = positions.get(fi).copied().unwrap_or((0.0, 0.0));
                         let is_me = cur_drag == Some(fi);
-                        let z = if is_me { "200" } else { "10" };
+                        let z = if is_me { 200 } else { file_z(fi) };
                         let pe = if is_me { "none" } else { "auto" };
                         let opacity = if is_me { "0.85" } else { "1" };
                         let shadow = if is_me { "0 8px 24px rgba(0,0,0,0.5)" } else { "0 2px 8px rgba(0,0,0,0.3)" };
@@ -323,9 +323,9 @@ pub fn Level15() -> Element {
                                     && cy >= drop_y && cy <= drop_y + drop_h;
 
                                 if in_zone && fi == target {
-                                    score.set(score() + 1);
+                                    score.complete();
                                     bg.set(random_canvas_bg());
-                                    let new_st = random_level15();
+                                    let new_st = random_level15(Difficulty::current());
                                     let new_pos: Vec<(f32, f32)> = new_st.files.iter().map(|f| (f.orig_x, f.orig_y)).collect();
                                     state.set(new_st);
                                     file_pos.set(new_pos);
@@ -333,6 +333,7 @@ pub fn Level15() -> Element {
                                 } else {
                                     if in_zone {
                                         wrong.set(true);
+                                        score.wrong(&file_names[fi], "dropped");
                                         spawn(async move {
                                             gloo_timers::future::TimeoutFuture::new(600).await;
                                             wrong.set(false);
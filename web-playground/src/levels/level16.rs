@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::Route;
 use crate::ui_node::{self, Rect, Visual, UINode, SliderState};
-use super::{fresh_rng, random_canvas_bg};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, tr_ordinal};
 
 const SLIDER_LABELS: &[&str] = &[
     "Volume", "Brightness", "Contrast", "Opacity", "Speed",
@@ -34,11 +34,13 @@ struct Level16State {
     x: f32,
     y: f32,
     card_w: f32,
+    /// Native `<input type=range>` tracks instead of the drag sliders
+    native: bool,
 }
 
-fn random_level16() -> Level16State {
+fn random_level16(d: &Difficulty) -> Level16State {
     let mut rng = fresh_rng();
-    let count = rng.random_range(1..=4usize);
+    let count = d.count(&mut rng, 1..=4);
 
     let mut label_pool: Vec<usize> = (0..SLIDER_LABELS.len()).collect();
     let mut color_pool: Vec<usize> = (0..TRACK_COLORS.len()).collect();
@@ -89,14 +91,16 @@ fn random_level16() -> Level16State {
     let card_h = count as f32 * slider_h + 120.0;
     let margin = 50.0;
     let (x, y) = super::safe_position(&mut rng, card_w, card_h, margin);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    let native = super::random_native_inputs(&mut rng);
 
-    Level16State { sliders, target_slider, mode, x, y, card_w }
+    Level16State { sliders, target_slider, mode, x, y, card_w, native }
 }
 
 #[component]
 pub fn Level16() -> Element {
-    let mut state = use_signal(|| random_level16());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level16(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_vals: Vec<i32> = state.read().sliders.iter().map(|s| s.current_val).collect();
     let mut values = use_signal(move || initial_vals);
@@ -119,6 +123,7 @@ pub fn Level16() -> Element {
     let card_x = st.x;
     let card_y = st.y;
     let card_w = st.card_w;
+    let native = st.native;
     drop(st);
 
     let slider_count = sliders.len();
@@ -131,10 +136,10 @@ pub fn Level16() -> Element {
     let target_val = sliders[target_slider].target_val;
     let instruction = match mode {
         1 => {
-            let ord = super::ordinal(target_slider + 1);
-            format!("Set the {} slider to {}", ord, target_val)
+            let ord = tr_ordinal(target_slider + 1);
+            tr("instr.set_nth_slider", &[&ord, &target_val])
         }
-        _ => format!("Set \"{}\" to {}", target_label, target_val),
+        _ => tr("instr.set_label_to", &[&target_label, &target_val]),
     };
 
     let slider_h = 72.0;
@@ -149,40 +154,47 @@ pub fn Level16() -> Element {
     let thumb_w: f32 = 18.0;
     let usable_w = track_w - thumb_w;
 
-    // Build UINode tree for ground truth
-    let slider_nodes: Vec<UINode> = sliders.iter().enumerate().map(|(i, s)| {
+    // Build UINode tree for ground truth: 28px tracks on a `slider_h` pitch
+    let mut tree = ui_node::fluent::form("Submit")
+        .unordered()
+        .at(Rect::new(card_x, card_y, card_w, card_h))
+        .padding(16.0)
+        .top(60.0)
+        .stack(slider_h - 28.0)
+        .item_height(28.0);
+    for (i, s) in sliders.iter().enumerate() {
         let is_target = i == target_slider;
         let val = cur_vals.get(i).copied().unwrap_or(s.current_val);
         let ratio = if s.max > s.min { (val - s.min) as f32 / (s.max - s.min) as f32 } else { 0.0 };
         let thumb_left = ratio * usable_w;
         let target_ratio = if s.max > s.min { (s.target_val - s.min) as f32 / (s.max - s.min) as f32 } else { 0.0 };
         let target_thumb_left = target_ratio * usable_w;
-        let row_y = 60.0 + i as f32 * slider_h;
-
-        let mut node = UINode::Slider(
-            Visual::new(&s.label, Rect::new(card_x + 16.0, card_y + row_y, track_w, 28.0))
-                .color(&s.track_color),
-            SliderState {
-                min: s.min,
-                max: s.max,
-                step: s.step,
-                current_val: val,
-                target_val: s.target_val,
-                thumb_rect: Rect::new(card_x + 16.0 + thumb_left, card_y + row_y + 4.0, thumb_w, 20.0),
-                target_thumb_rect: Rect::new(card_x + 16.0 + target_thumb_left, card_y + row_y + 4.0, thumb_w, 20.0),
-            },
-        );
-        if is_target {
-            node.visual_mut().is_target = true;
-        }
-        node
-    }).collect();
-
-    let tree = ui_node::form(
-        Rect::new(card_x, card_y, card_w, card_h),
-        "Submit",
-        slider_nodes,
-    );
+        let (label, track_color) = (s.label.clone(), s.track_color.clone());
+        let (min, max, step, target_val) = (s.min, s.max, s.step, s.target_val);
+
+        tree = tree.node(move |track| {
+            let mut node = UINode::Slider(
+                Visual::new(label, track).color(&track_color),
+                SliderState {
+                    min,
+                    max,
+                    step,
+                    current_val: val,
+                    target_val,
+                    thumb_rect: Rect::new(track.x + thumb_left, track.y + 4.0, thumb_w, 20.0),
+                    target_thumb_rect: Rect::new(track.x + target_thumb_left, track.y + 4.0, thumb_w, 20.0),
+                    tolerance: 0,
+                    native,
+                },
+            );
+            if is_target {
+                node.visual_mut().is_target = true;
+            }
+            node
+        });
+    }
+    let tree = tree.build();
+    let unmet = tree.evaluate().unmet();
     rsx! {
         div {
             style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
@@ -257,78 +269,86 @@ pub fn Level16() -> Element {
                                         }
                                     }
 
-                                    // Track container
-                                    div {
-                                        style: "position: relative; height: 28px; cursor: pointer;",
-                                        tabindex: "-1",
-
-                                        // Track background
-                                        div {
-                                            style: "position: absolute; top: 10px; left: 0; right: 0; height: 8px; background: #e5e7eb; border-radius: 4px; pointer-events: none;",
+                                    if native {
+                                        input {
+                                            r#type: "range",
+                                            class: if is_target_slider { "target" } else { "" },
+                                            "data-label": "{label}",
+                                            tabindex: "-1",
+                                            min: "{min}",
+                                            max: "{max}",
+                                            step: "{step}",
+                                            value: "{val}",
+                                            style: "display: block; width: 100%; height: 28px; margin: 0; accent-color: {track_color}; cursor: pointer;",
+                                            oninput: move |e: Event<FormData>| {
+                                                if let Ok(n) = e.value().parse::<i32>()
+                                                    && let Some(val) = values.write().get_mut(si)
+                                                {
+                                                    *val = n.clamp(min, max);
+                                                }
+                                            },
                                         }
-
-                                        // Track fill
+                                    } else {
+                                        // Track container
                                         div {
-                                            style: "position: absolute; top: 10px; left: 0; width: {fill_w}px; height: 8px; background: {track_color}; border-radius: 4px; pointer-events: none; transition: width 0.05s;",
-                                        }
+                                            style: "position: relative; height: 28px; cursor: pointer;",
+                                            tabindex: "-1",
+
+                                            // Track background
+                                            div {
+                                                style: "position: absolute; top: 10px; left: 0; right: 0; height: 8px; background: #e5e7eb; border-radius: 4px; pointer-events: none;",
+                                            }
 
-                                        // Tick marks
-                                        if show_ticks {
-                                            {
-                                                let steps = (max - min) / step;
-                                                rsx! {
-                                                    for ti in 0..=steps {
-                                                        {
-                                                            let t_ratio = ti as f32 / steps as f32;
-                                                            let t_left = t_ratio * usable_w + thumb_w / 2.0;
-                                                            rsx! {
-                                                                div {
-                                                                    style: "position: absolute; top: 22px; left: {t_left}px; width: 1px; height: 6px; background: #d1d5db; pointer-events: none;",
+                                            // Track fill
+                                            div {
+                                                style: "position: absolute; top: 10px; left: 0; width: {fill_w}px; height: 8px; background: {track_color}; border-radius: 4px; pointer-events: none; transition: width 0.05s;",
+                                            }
+
+                                            // Tick marks
+                                            if show_ticks {
+                                                {
+                                                    let steps = (max - min) / step;
+                                                    rsx! {
+                                                        for ti in 0..=steps {
+                                                            {
+                                                                let t_ratio = ti as f32 / steps as f32;
+                                                                let t_left = t_ratio * usable_w + thumb_w / 2.0;
+                                                                rsx! {
+                                                                    div {
+                                                                        style: "position: absolute; top: 22px; left: {t_left}px; width: 1px; height: 6px; background: #d1d5db; pointer-events: none;",
+                                                                    }
                                                                 }
                                                             }
                                                         }
                                                     }
                                                 }
                                             }
-                                        }
-
-                                        // Thumb
-                                        div {
-                                            style: "position: absolute; top: 4px; left: {thumb_left}px; width: {thumb_w}px; height: 20px; background: white; border: 2px solid {track_color}; border-radius: 10px; box-shadow: 0 1px 4px rgba(0,0,0,0.2); pointer-events: none; transition: left 0.05s;",
-                                        }
 
-                                        // Ground truth drag markers
-                                        if is_target_slider {
-                                            div {
-                                                class: "target",
-                                                "data-label": "drag-from: {label}",
-                                                style: "position: absolute; top: 4px; left: {thumb_left}px; width: {thumb_w}px; height: 20px; pointer-events: none;",
-                                            }
+                                            // Thumb
                                             div {
-                                                class: "target",
-                                                "data-label": "drag-to: {label}",
-                                                style: "position: absolute; top: 4px; left: {target_thumb_left}px; width: {thumb_w}px; height: 20px; pointer-events: none;",
+                                                style: "position: absolute; top: 4px; left: {thumb_left}px; width: {thumb_w}px; height: 20px; background: white; border: 2px solid {track_color}; border-radius: 10px; box-shadow: 0 1px 4px rgba(0,0,0,0.2); pointer-events: none; transition: left 0.05s;",
                                             }
-                                        }
 
-                                        // Invisible hit area for mouse events
-                                        div {
-                                            style: "position: absolute; inset: 0; z-index: 1;",
-                                            onmousedown: move |e: Event<MouseData>| {
-                                                e.prevent_default();
-                                                drag_idx.set(Some(si));
-                                                let coords = e.element_coordinates();
-                                                let mx = coords.x as f32;
-                                                let raw_ratio = ((mx - thumb_w / 2.0) / usable_w).clamp(0.0, 1.0);
-                                                let steps = (max - min) / step;
-                                                let snapped = min + (raw_ratio * steps as f32).round() as i32 * step;
-                                                let mut v = values.write();
-                                                if let Some(val) = v.get_mut(si) {
-                                                    *val = snapped.clamp(min, max);
+                                            // Ground truth drag markers
+                                            if is_target_slider {
+                                                div {
+                                                    class: "target",
+                                                    "data-label": "drag-from: {label}",
+                                                    style: "position: absolute; top: 4px; left: {thumb_left}px; width: {thumb_w}px; height: 20px; pointer-events: none;",
                                                 }
-                                            },
-                                            onmousemove: move |e: Event<MouseData>| {
-                                                if cur_drag == Some(si) {
+                                                div {
+                                                    class: "target",
+                                                    "data-label": "drag-to: {label}",
+                                                    style: "position: absolute; top: 4px; left: {target_thumb_left}px; width: {thumb_w}px; height: 20px; pointer-events: none;",
+                                                }
+                                            }
+
+                                            // Invisible hit area for mouse events
+                                            div {
+                                                style: "position: absolute; inset: 0; z-index: 1;",
+                                                onmousedown: move |e: Event<MouseData>| {
+                                                    e.prevent_default();
+                                                    drag_idx.set(Some(si));
                                                     let coords = e.element_coordinates();
                                                     let mx = coords.x as f32;
                                                     let raw_ratio = ((mx - thumb_w / 2.0) / usable_w).clamp(0.0, 1.0);
@@ -338,14 +358,27 @@ pub fn Level16() -> Element {
                                                     if let Some(val) = v.get_mut(si) {
                                                         *val = snapped.clamp(min, max);
                                                     }
-                                                }
-                                            },
-                                            onmouseup: move |_| {
-                                                drag_idx.set(None);
-                                            },
-                                            onmouseleave: move |_| {
-                                                drag_idx.set(None);
-                                            },
+                                                },
+                                                onmousemove: move |e: Event<MouseData>| {
+                                                    if cur_drag == Some(si) {
+                                                        let coords = e.element_coordinates();
+                                                        let mx = coords.x as f32;
+                                                        let raw_ratio = ((mx - thumb_w / 2.0) / usable_w).clamp(0.0, 1.0);
+                                                        let steps = (max - min) / step;
+                                                        let snapped = min + (raw_ratio * steps as f32).round() as i32 * step;
+                                                        let mut v = values.write();
+                                                        if let Some(val) = v.get_mut(si) {
+                                                            *val = snapped.clamp(min, max);
+                                                        }
+                                                    }
+                                                },
+                                                onmouseup: move |_| {
+                                                    drag_idx.set(None);
+                                                },
+                                                onmouseleave: move |_| {
+                                                    drag_idx.set(None);
+                                                },
+                                            }
                                         }
                                     }
 
@@ -372,11 +405,10 @@ pub fn Level16() -> Element {
                         style: "width: 100%; padding: 10px; background: {submit_bg}; color: white; border: none; border-radius: 6px; font-size: 14px; font-weight: 600; font-family: system-ui, sans-serif; cursor: pointer; box-sizing: border-box; transition: background 0.15s; margin-top: 8px;",
                         tabindex: "-1",
                         onclick: move |_| {
-                            let v = values.read().get(target_slider).copied().unwrap_or(0);
-                            if v == target_val {
-                                score.set(score() + 1);
+                            if unmet.is_empty() {
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level16();
+                                let new_st = random_level16(Difficulty::current());
                                 let new_vals: Vec<i32> = new_st.sliders.iter().map(|s| s.current_val).collect();
                                 state.set(new_st);
                                 values.set(new_vals);
@@ -384,6 +416,9 @@ pub fn Level16() -> Element {
                                 drag_idx.set(None);
                             } else {
                                 wrong.set(true);
+                                for (label, value) in &unmet {
+                                    score.wrong(label, value);
+                                }
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use crate::ui_node::{self, LiveValue, Rect};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, tr_ordinal};
 
 const GROUP_NAMES: &[&str] = &[
     "Size", "Color", "Plan", "Priority", "Shipping",
@@ -34,6 +34,15 @@ const ACCENT_COLORS: &[&str] = &[
     "#dc2626", "#7c3aed", "#db2777", "#0d9488", "#ea580c",
 ];
 
+/// Instruction line (14px) plus its 16px bottom margin.
+const INSTRUCTION_H: f32 = 33.0;
+/// Group label line (13px) plus its 8px bottom margin.
+const GROUP_LABEL_H: f32 = 24.0;
+/// Option row: 6px padding around the 22px radio circle (18px + border).
+const OPTION_H: f32 = 34.0;
+/// Space between groups.
+const GROUP_GAP: f32 = 16.0;
+
 #[derive(Clone)]
 struct RadioGroup {
     name: String,
@@ -49,11 +58,13 @@ struct Level17State {
     x: f32,
     y: f32,
     card_w: f32,
+    /// Native `<input type=radio>` circles instead of styled ones
+    native: bool,
 }
 
-fn random_level17() -> Level17State {
+fn random_level17(d: &Difficulty) -> Level17State {
     let mut rng = fresh_rng();
-    let group_count = rng.random_range(1..=4usize);
+    let group_count = d.count(&mut rng, 1..=4);
 
     let mut group_pool: Vec<usize> = (0..GROUP_NAMES.len()).collect();
     let mut color_pool: Vec<usize> = (0..ACCENT_COLORS.len()).collect();
@@ -97,20 +108,24 @@ fn random_level17() -> Level17State {
 
     let margin = 50.0;
     let (x, y) = super::safe_position(&mut rng, card_w, card_h, margin);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    let native = super::random_native_inputs(&mut rng);
 
-    Level17State { groups, target_group, target_option, mode, x, y, card_w }
+    Level17State { groups, target_group, target_option, mode, x, y, card_w, native }
 }
 
 #[component]
 pub fn Level17() -> Element {
-    let mut state = use_signal(|| random_level17());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level17(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_sel: Vec<Option<usize>> = {
         let s = state.read();
         vec![None; s.groups.len()]
     };
     let mut selections = use_signal(move || initial_sel);
+    // The tree doesn't carry selections; options register theirs here
+    let mut live = super::use_live_state();
     let mut wrong = use_signal(|| false);
 
     let st = state.read();
@@ -121,6 +136,7 @@ pub fn Level17() -> Element {
     let card_x = st.x;
     let card_y = st.y;
     let card_w = st.card_w;
+    let native = st.native;
     drop(st);
 
     let group_count = groups.len();
@@ -130,21 +146,23 @@ pub fn Level17() -> Element {
 
     let target_group_name = groups[target_group].name.clone();
     let target_option_name = groups[target_group].options[target_option].clone();
+    let wrong_target = target_group_name.clone();
+    let target_options = groups[target_group].options.clone();
 
     let instruction = match mode {
         1 => {
-            let g_ord = ordinal(target_group + 1);
-            format!("In the {} group, select \"{}\"", g_ord, target_option_name)
+            let g_ord = tr_ordinal(target_group + 1);
+            tr("instr.group_nth_select", &[&g_ord, &target_option_name])
         }
         2 => {
-            let o_ord = ordinal(target_option + 1);
-            format!("In \"{}\", select the {} option", target_group_name, o_ord)
+            let o_ord = tr_ordinal(target_option + 1);
+            tr("instr.group_select_nth", &[&target_group_name, &o_ord])
         }
         _ => {
             if group_count == 1 {
-                format!("Select \"{}\"", target_option_name)
+                tr("instr.select", &[&target_option_name])
             } else {
-                format!("In \"{}\", select \"{}\"", target_group_name, target_option_name)
+                tr("instr.group_select", &[&target_group_name, &target_option_name])
             }
         }
     };
@@ -160,12 +178,20 @@ pub fn Level17() -> Element {
     let submit_bg = if is_wrong { "#ef4444" } else { "#4f46e5" };
 
     // Ground truth via UINode tree
-    let radio_nodes: Vec<_> = groups.iter().enumerate().map(|(gi, g)| {
+    let heights: Vec<f32> = groups.iter().map(|g| GROUP_LABEL_H + g.options.len() as f32 * OPTION_H).collect();
+    let blocks = ui_node::layout::stack(
+        Rect::new(card_x + 16.0, card_y + 16.0 + INSTRUCTION_H, card_w - 32.0, 0.0),
+        &heights,
+        GROUP_GAP,
+    );
+    let radio_nodes: Vec<_> = groups.iter().zip(blocks).enumerate().map(|(gi, (g, block))| {
         let target_opt_idx = if gi == target_group { target_option } else { 0 };
-        let mut node = ui_node::radio_group(
+        let options_top = Rect::new(block.x, block.y + GROUP_LABEL_H, block.w, 0.0);
+        let mut node = ui_node::radio_group_with_rects(
             &g.name,
-            Rect::new(card_x + 16.0, card_y + 40.0 + gi as f32 * (group_h + g.options.len() as f32 * opt_h), card_w - 32.0, group_h + g.options.len() as f32 * opt_h),
+            block,
             g.options.clone(),
+            ui_node::layout::stack(options_top, &vec![OPTION_H; g.options.len()], 0.0),
             target_opt_idx,
         );
         if gi != target_group {
@@ -177,7 +203,7 @@ pub fn Level17() -> Element {
         Rect::new(card_x, card_y, card_w, card_h),
         "Submit",
         radio_nodes,
-    );
+    ).unordered();
     rsx! {
         div {
             style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
@@ -243,6 +269,7 @@ pub fn Level17() -> Element {
                                             let inner_bg = if is_sel { g.accent.clone() } else { "transparent".to_string() };
                                             let text_color = if is_sel { "#111827" } else { "#4b5563" };
                                             let is_target = gi == target_group && oi == target_option;
+                                            let group_name = g.name.clone();
 
                                             rsx! {
                                                 div {
@@ -255,13 +282,24 @@ pub fn Level17() -> Element {
                                                         if let Some(v) = s.get_mut(gi) {
                                                             *v = Some(oi);
                                                         }
+                                                        live.set(group_name.clone(), LiveValue::Index(oi));
                                                     },
 
-                                                    // Radio circle
-                                                    div {
-                                                        style: "width: 18px; height: 18px; border-radius: 50%; border: 2px solid {outer_border}; display: flex; align-items: center; justify-content: center; flex-shrink: 0; transition: border-color 0.15s;",
+                                                    // Radio circle, the same 22px either way
+                                                    if native {
+                                                        input {
+                                                            r#type: "radio",
+                                                            name: "level17-group-{gi}",
+                                                            tabindex: "-1",
+                                                            checked: is_sel,
+                                                            style: "width: 22px; height: 22px; margin: 0; accent-color: {g.accent}; pointer-events: none; flex-shrink: 0;",
+                                                        }
+                                                    } else {
                                                         div {
-                                                            style: "width: 10px; height: 10px; border-radius: 50%; background: {inner_bg}; transition: background 0.15s;",
+                                                            style: "width: 18px; height: 18px; border-radius: 50%; border: 2px solid {outer_border}; display: flex; align-items: center; justify-content: center; flex-shrink: 0; transition: border-color 0.15s;",
+                                                            div {
+                                                                style: "width: 10px; height: 10px; border-radius: 50%; background: {inner_bg}; transition: background 0.15s;",
+                                                            }
                                                         }
                                                     }
 
@@ -287,15 +325,17 @@ pub fn Level17() -> Element {
                         onclick: move |_| {
                             let sel = selections.read().get(target_group).copied().flatten();
                             if sel == Some(target_option) {
-                                score.set(score() + 1);
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level17();
+                                let new_st = random_level17(Difficulty::current());
                                 let count = new_st.groups.len();
                                 state.set(new_st);
                                 selections.set(vec![None; count]);
                                 wrong.set(false);
                             } else {
                                 wrong.set(true);
+                                let picked = sel.and_then(|o| target_options.get(o)).cloned().unwrap_or_default();
+                                score.wrong(&wrong_target, &picked);
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::Route;
 use crate::ui_node::{self, Rect, UINode, Visual, StepperState};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, tr_ordinal};
 
 const STEPPER_LABELS: &[&str] = &[
     "Quantity", "Guests", "Adults", "Children", "Rooms",
@@ -16,6 +16,34 @@ const ACCENT_COLORS: &[&str] = &[
     "#dc2626", "#7c3aed", "#db2777", "#0d9488", "#ea580c",
 ];
 
+/// Instruction line (14px) plus its 16px bottom margin.
+const INSTRUCTION_H: f32 = 33.0;
+/// Stepper label line (13px) plus its 8px bottom margin.
+const LABEL_H: f32 = 24.0;
+/// Space between stepper blocks.
+const BLOCK_GAP: f32 = 12.0;
+
+/// Button size, flex gap and value box width of each stepper style, as in
+/// the CSS below.
+fn style_metrics(style: u8) -> (f32, f32, f32) {
+    match style {
+        0 => (36.0, 12.0, 48.0),
+        1 => (32.0, 10.0, 44.0),
+        // min-width 36 + 8px padding either side + 1px border
+        _ => (28.0, 6.0, 54.0),
+    }
+}
+
+/// Minus and plus button rects of a stepper whose block (label + row) is
+/// `block`: the row is centered under the label.
+fn button_rects(style: u8, block: Rect) -> (Rect, Rect) {
+    let (btn, gap, value_w) = style_metrics(style);
+    let row_w = 2.0 * btn + 2.0 * gap + value_w;
+    let left = block.x + (block.w - row_w) / 2.0;
+    let y = block.y + LABEL_H;
+    (Rect::new(left, y, btn, btn), Rect::new(left + row_w - btn, y, btn, btn))
+}
+
 #[derive(Clone)]
 struct StepperInfo {
     label: String,
@@ -37,9 +65,9 @@ struct Level18State {
     card_w: f32,
 }
 
-fn random_level18() -> Level18State {
+fn random_level18(d: &Difficulty) -> Level18State {
     let mut rng = fresh_rng();
-    let count = rng.random_range(1..=4usize);
+    let count = d.count(&mut rng, 1..=4);
 
     let mut label_pool: Vec<usize> = (0..STEPPER_LABELS.len()).collect();
     let mut color_pool: Vec<usize> = (0..ACCENT_COLORS.len()).collect();
@@ -85,14 +113,15 @@ fn random_level18() -> Level18State {
     let card_h = count as f32 * stepper_h + 100.0;
     let margin = 50.0;
     let (x, y) = super::safe_position(&mut rng, card_w, card_h, margin);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level18State { steppers, target_stepper, mode, x, y, card_w }
 }
 
 #[component]
 pub fn Level18() -> Element {
-    let mut state = use_signal(|| random_level18());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level18(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_vals: Vec<i32> = state.read().steppers.iter().map(|s| s.start_val).collect();
     let mut values = use_signal(move || initial_vals);
@@ -117,14 +146,14 @@ pub fn Level18() -> Element {
 
     let instruction = match mode {
         1 => {
-            let ord = ordinal(target_stepper + 1);
-            format!("Set the {} stepper to {}", ord, target_val)
+            let ord = tr_ordinal(target_stepper + 1);
+            tr("instr.set_nth_stepper", &[&ord, &target_val])
         }
         _ => {
             if stepper_count == 1 {
-                format!("Set to {}", target_val)
+                tr("instr.set_to", &[&target_val])
             } else {
-                format!("Set \"{}\" to {}", target_label, target_val)
+                tr("instr.set_label_to", &[&target_label, &target_val])
             }
         }
     };
@@ -138,19 +167,28 @@ pub fn Level18() -> Element {
     let submit_bg = if is_wrong { "#ef4444" } else { "#4f46e5" };
 
     // Ground truth via UINode tree
-    let stepper_nodes: Vec<UINode> = steppers.iter().enumerate().map(|(i, s)| {
+    let heights: Vec<f32> = steppers.iter().map(|s| LABEL_H + style_metrics(s.style).0).collect();
+    let blocks = ui_node::layout::stack(
+        Rect::new(card_x + 16.0, card_y + 16.0 + INSTRUCTION_H, card_w - 32.0, 0.0),
+        &heights,
+        BLOCK_GAP,
+    );
+    let stepper_nodes: Vec<UINode> = steppers.iter().zip(blocks).enumerate().map(|(i, (s, block))| {
         let cv = cur_vals.get(i).copied().unwrap_or(s.start_val);
-        let row_y = 40.0 + i as f32 * stepper_h;
+        let (minus_rect, plus_rect) = button_rects(s.style, block);
         let mut node = UINode::Stepper(
-            Visual::new(&s.label, Rect::new(card_x + 16.0, card_y + row_y, card_w - 32.0, stepper_h)),
+            Visual::new(&s.label, block),
             StepperState {
                 min: s.min,
                 max: s.max,
                 step: s.step,
                 current_val: cv,
                 target_val: s.target_val,
+                start_val: s.start_val,
                 minus_label: format!("\u{2212}: {}", s.label),
                 plus_label: format!("+: {}", s.label),
+                minus_rect,
+                plus_rect,
             },
         );
         if i == target_stepper {
@@ -162,7 +200,8 @@ pub fn Level18() -> Element {
         Rect::new(card_x, card_y, card_w, card_h),
         "Submit",
         stepper_nodes,
-    );
+    ).unordered();
+    let unmet = tree.evaluate().unmet();
     rsx! {
         div {
             style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
@@ -310,17 +349,19 @@ pub fn Level18() -> Element {
                         style: "width: 100%; padding: 10px; background: {submit_bg}; color: white; border: none; border-radius: 6px; font-size: 14px; font-weight: 600; font-family: system-ui, sans-serif; cursor: pointer; box-sizing: border-box; transition: background 0.15s; margin-top: 16px;",
                         tabindex: "-1",
                         onclick: move |_| {
-                            let v = values.read().get(target_stepper).copied().unwrap_or(0);
-                            if v == target_val {
-                                score.set(score() + 1);
+                            if unmet.is_empty() {
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level18();
+                                let new_st = random_level18(Difficulty::current());
                                 let new_vals: Vec<i32> = new_st.steppers.iter().map(|s| s.start_val).collect();
                                 state.set(new_st);
                                 values.set(new_vals);
                                 wrong.set(false);
                             } else {
                                 wrong.set(true);
+                                for (label, value) in &unmet {
+                                    score.wrong(label, value);
+                                }
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::Route;
 use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, tr_ordinal};
 
 const RATING_LABELS: &[&str] = &[
     "Quality", "Service", "Value", "Cleanliness", "Comfort",
@@ -35,9 +35,9 @@ struct Level19State {
     card_w: f32,
 }
 
-fn random_level19() -> Level19State {
+fn random_level19(d: &Difficulty) -> Level19State {
     let mut rng = fresh_rng();
-    let count = rng.random_range(1..=3usize);
+    let count = d.count(&mut rng, 1..=3);
 
     let mut label_pool: Vec<usize> = (0..RATING_LABELS.len()).collect();
     let mut color_pool: Vec<usize> = (0..STAR_COLORS.len()).collect();
@@ -76,17 +76,18 @@ fn random_level19() -> Level19State {
 
     let card_w = rng.random_range(280.0..=420.0f32);
     let row_h = 60.0;
-    let card_h = count as f32 * row_h + 120.0;
+    let card_h = super::layout::stack_height(&vec![row_h; count], 0.0) + 120.0;
     let margin = 50.0;
     let (x, y) = super::safe_position(&mut rng, card_w, card_h, margin);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level19State { ratings, target_rating, mode, x, y, card_w }
 }
 
 #[component]
 pub fn Level19() -> Element {
-    let mut state = use_signal(|| random_level19());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level19(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_vals: Vec<usize> = state.read().ratings.iter().map(|r| r.start_val).collect();
     let mut values = use_signal(move || initial_vals);
@@ -106,25 +107,27 @@ pub fn Level19() -> Element {
     let cur_vals: Vec<usize> = values.read().clone();
 
     let target_label = ratings[target_rating].label.clone();
+    let wrong_target = target_label.clone();
     let target_val = ratings[target_rating].target_val;
     let target_max = ratings[target_rating].max_stars;
 
     let instruction = match mode {
         1 => {
-            let ord = ordinal(target_rating + 1);
-            format!("Rate the {} one {} out of {}", ord, target_val, target_max)
+            let ord = tr_ordinal(target_rating + 1);
+            tr("instr.rate_nth", &[&ord, &target_val, &target_max])
         }
         _ => {
             if rating_count == 1 {
-                format!("Rate {} out of {}", target_val, target_max)
+                tr("instr.rate", &[&target_val, &target_max])
             } else {
-                format!("Rate \"{}\" {} out of {}", target_label, target_val, target_max)
+                tr("instr.rate_label", &[&target_label, &target_val, &target_max])
             }
         }
     };
 
     let row_h = 60.0;
-    let card_h = rating_count as f32 * row_h + 120.0;
+    let card_h = super::layout::stack_height(&vec![row_h; rating_count], 0.0) + 120.0;
+    let row_rects = super::layout::stack(Rect::new(card_x + 16.0, card_y + 40.0, card_w - 32.0, card_h), &vec![row_h; rating_count], 0.0);
     let card_style = format!(
         "position: absolute; left: {}px; top: {}px; background: white; border-radius: 12px; padding: 16px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); font-family: system-ui, sans-serif; width: {}px; box-sizing: border-box;",
         card_x, card_y, card_w
@@ -134,10 +137,9 @@ pub fn Level19() -> Element {
     // Ground truth via UINode tree
     let star_nodes: Vec<_> = ratings.iter().enumerate().map(|(i, r)| {
         let cv = cur_vals.get(i).copied().unwrap_or(r.start_val);
-        let row_y = 40.0 + i as f32 * row_h;
         let mut node = ui_node::star_rating(
             &r.label,
-            Rect::new(card_x + 16.0, card_y + row_y, card_w - 32.0, row_h),
+            row_rects[i],
             cv,
             r.target_val,
             r.max_stars,
@@ -151,7 +153,7 @@ pub fn Level19() -> Element {
         Rect::new(card_x, card_y, card_w, card_h),
         "Submit",
         star_nodes,
-    );
+    ).unordered();
     let description = String::new();
     let viewport_style = super::viewport_style(&bg(), false);
 
@@ -257,15 +259,16 @@ pub fn Level19() -> Element {
                         onclick: move |_| {
                             let v = values.read().get(target_rating).copied().unwrap_or(0);
                             if v == target_val {
-                                score.set(score() + 1);
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level19();
+                                let new_st = random_level19(Difficulty::current());
                                 let new_vals: Vec<usize> = new_st.ratings.iter().map(|r| r.start_val).collect();
                                 state.set(new_st);
                                 values.set(new_vals);
                                 wrong.set(false);
                             } else {
                                 wrong.set(true);
+                                score.wrong(&wrong_target, &v.to_string());
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
@@ -3,31 +3,34 @@ use rand::Rng;
 
 use crate::Route;
 use crate::pool::{ElementPool, ElementKind};
-use crate::primitives::Position;
-use crate::transform::{PlacedElement, Sampler};
+use crate::primitives::{Position, Scale};
+use crate::transform::PlacedElement;
 use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg};
+use super::{Difficulty, fresh_rng, random_canvas_bg};
 
-fn random_toggle(pool: &ElementPool) -> PlacedElement {
+fn random_toggle(pool: &ElementPool, d: &Difficulty) -> PlacedElement {
     let mut rng = fresh_rng();
     let kinds = [ElementKind::Toggle, ElementKind::Checkbox];
     let kind = kinds[rng.random_range(0..kinds.len())];
-    let snippet = Sampler::pick_kind(&mut rng, pool, kind)
+    let snippet = super::pick_tagged(&mut rng, pool, kind)
         .expect("pool has toggles/checkboxes");
 
     let pad = 150.0;
-    let (x, y) = super::safe_position(&mut rng, snippet.approx_width, snippet.approx_height, pad);
+    let scale = d.target_scale(&mut rng);
+    let (w, h) = snippet.size();
+    let (x, y) = super::safe_position(&mut rng, w * scale, h * scale, pad);
+    let (x, y) = d.jitter(&mut rng, (x, y));
     let pos = Position::new(x, y);
 
-    PlacedElement::new(snippet, pos)
+    PlacedElement::new(snippet, pos).with_scale(Scale::new(scale))
 }
 
 #[component]
 pub fn Level2() -> Element {
     let pool = use_hook(|| ElementPool::with_builtins());
 
-    let mut placed = use_signal(|| random_toggle(&pool));
-    let mut score = use_signal(|| 0u32);
+    let mut placed = use_signal(|| random_toggle(&pool, Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut is_active = use_signal(|| false);
     let mut bg = use_signal(|| random_canvas_bg());
 
@@ -86,8 +89,8 @@ pub fn Level2() -> Element {
                     cursor: "pointer",
                     onclick: move |_| {
                         is_active.toggle();
-                        score.set(score() + 1);
-                        placed.set(random_toggle(&pool_click));
+                        score.complete();
+                        placed.set(random_toggle(&pool_click, Difficulty::current()));
                         bg.set(random_canvas_bg());
                     },
                     div {
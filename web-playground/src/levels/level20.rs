@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::Route;
 use crate::ui_node::{self, UINode, Visual, Rect};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, tr_ordinal};
 
 const TAB_LABELS: &[&str] = &[
     "General", "Settings", "Profile", "Account", "Security",
@@ -53,9 +53,9 @@ struct Level20State {
     card_h: f32,
 }
 
-fn random_level20() -> Level20State {
+fn random_level20(d: &Difficulty) -> Level20State {
     let mut rng = fresh_rng();
-    let count = rng.random_range(3..=5usize);
+    let count = d.count(&mut rng, 3..=5);
 
     let mut label_pool: Vec<usize> = (0..TAB_LABELS.len()).collect();
     let mut content_pool: Vec<usize> = (0..TAB_CONTENTS.len()).collect();
@@ -85,14 +85,15 @@ fn random_level20() -> Level20State {
     let card_h = rng.random_range(280.0..=400.0f32);
     let margin = 50.0;
     let (x, y) = super::safe_position(&mut rng, card_w, card_h, margin);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level20State { tabs, target_tab, initial_tab, mode, style, accent, x, y, card_w, card_h }
 }
 
 #[component]
 pub fn Level20() -> Element {
-    let mut state = use_signal(|| random_level20());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level20(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_tab = state.read().initial_tab;
     let mut active = use_signal(move || initial_tab);
@@ -115,14 +116,15 @@ pub fn Level20() -> Element {
     let cur_active = active();
 
     let target_label = tabs[target_tab].label.clone();
+    let tab_labels: Vec<String> = tabs.iter().map(|t| t.label.clone()).collect();
 
     let instruction = match mode {
         1 => {
-            let ord = ordinal(target_tab + 1);
-            format!("Switch to the {} tab", ord)
+            let ord = tr_ordinal(target_tab + 1);
+            tr("instr.switch_nth_tab", &[&ord])
         }
         _ => {
-            format!("Switch to the \"{}\" tab", target_label)
+            tr("instr.switch_tab", &[&target_label])
         }
     };
 
@@ -275,15 +277,16 @@ pub fn Level20() -> Element {
                             tabindex: "-1",
                             onclick: move |_| {
                                 if cur_active == target_tab {
-                                    score.set(score() + 1);
+                                    score.complete();
                                     bg.set(random_canvas_bg());
-                                    let new_st = random_level20();
+                                    let new_st = random_level20(Difficulty::current());
                                     let new_active = new_st.initial_tab;
                                     state.set(new_st);
                                     active.set(new_active);
                                     wrong.set(false);
                                 } else {
                                     wrong.set(true);
+                                    score.wrong(&tab_labels[target_tab], &tab_labels[cur_active]);
                                     spawn(async move {
                                         gloo_timers::future::TimeoutFuture::new(600).await;
                                         wrong.set(false);
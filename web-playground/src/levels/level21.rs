@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, UINode, Visual, Rect};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use crate::ui_node::{self, AccordionState, UINode, Visual, Rect};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, tr_ordinal};
 
 const SECTION_LABELS: &[&str] = &[
     "Personal Information", "Payment Details", "Shipping Address",
@@ -54,9 +54,9 @@ struct Level21State {
     card_w: f32,
 }
 
-fn random_level21() -> Level21State {
+fn random_level21(d: &Difficulty) -> Level21State {
     let mut rng = fresh_rng();
-    let count = rng.random_range(3..=6usize);
+    let count = d.count(&mut rng, 3..=6);
 
     let mut label_pool: Vec<usize> = (0..SECTION_LABELS.len()).collect();
     let mut content_pool: Vec<usize> = (0..SECTION_CONTENTS.len()).collect();
@@ -87,17 +87,15 @@ fn random_level21() -> Level21State {
     // Estimate height: header ~44px each, open content ~80px
     let open_count = initially_open.iter().filter(|&&o| o).count();
     let card_h = count as f32 * 48.0 + open_count as f32 * 80.0 + 120.0;
-    let margin = 50.0;
-    let (vp_w, vp_h) = crate::primitives::viewport_size();
-    let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, margin, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = super::layout::place_non_overlapping(&mut rng, &[(card_w, card_h)], 0.0, super::layout::Bounds::scrollable(50.0))[0];
 
     Level21State { sections, target_section, initially_open, mode, style, accent, x, y, card_w }
 }
 
 #[component]
 pub fn Level21() -> Element {
-    let mut state = use_signal(|| random_level21());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level21(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let initial_open: Vec<bool> = state.read().initially_open.clone();
     let mut open = use_signal(move || initial_open);
@@ -119,14 +117,15 @@ pub fn Level21() -> Element {
     let cur_open: Vec<bool> = open.read().clone();
 
     let target_label = sections[target_section].label.clone();
+    let wrong_target = target_label.clone();
 
     let instruction = match mode {
         1 => {
-            let ord = ordinal(target_section + 1);
-            format!("Expand the {} section", ord)
+            let ord = tr_ordinal(target_section + 1);
+            tr("instr.expand_nth", &[&ord])
         }
         _ => {
-            format!("Expand \"{}\"", target_label)
+            tr("instr.expand", &[&target_label])
         }
     };
 
@@ -146,7 +145,11 @@ pub fn Level21() -> Element {
             ui_node::accordion(&s.label, sec_rect)
         } else {
             // Non-target accordion section
-            UINode::Accordion(Visual::new(&s.label, sec_rect))
+            UINode::Accordion(
+                Visual::new(&s.label, sec_rect),
+                AccordionState { body: Rect::new(sec_rect.x, sec_rect.y + sec_rect.h, sec_rect.w, 0.0) },
+                Vec::new(),
+            )
         }
     }).collect();
     let tree = ui_node::form(card_rect, "Submit", children);
@@ -278,15 +281,16 @@ pub fn Level21() -> Element {
                         onclick: move |_| {
                             let is_target_open = open.read().get(target_section).copied().unwrap_or(false);
                             if is_target_open {
-                                score.set(score() + 1);
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level21();
+                                let new_st = random_level21(Difficulty::current());
                                 let new_open = new_st.initially_open.clone();
                                 state.set(new_st);
                                 open.set(new_open);
                                 wrong.set(false);
                             } else {
                                 wrong.set(true);
+                                score.wrong(&wrong_target, "collapsed");
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
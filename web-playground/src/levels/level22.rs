@@ -2,8 +2,9 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, UINode, Visual, Rect};
-use super::{fresh_rng, random_canvas_bg};
+use crate::ui_node::{self, Outcome, UINode, Visual, Rect};
+use super::{Difficulty, fresh_rng, roll_time_limit, RoundTimer, random_canvas_bg, tr};
+use super::{roll_impossible, with_not_found, ReportNotFound};
 
 struct DialogScenario {
     title: &'static str,
@@ -41,9 +42,14 @@ struct Level22State {
     modal_y: f32,
     has_close: bool,
     target_is_close: bool,
+    /// Impossible round: the instruction names this button, which isn't rendered.
+    missing: Option<&'static str>,
+
+    /// Countdown for this round (`?timer=`), in ms.
+    time_limit: Option<u32>,
 }
 
-fn random_level22() -> Level22State {
+fn random_level22(d: &Difficulty) -> Level22State {
     let mut rng = fresh_rng();
     let scenario_idx = rng.random_range(0..SCENARIOS.len());
     let scenario = &SCENARIOS[scenario_idx];
@@ -54,6 +60,7 @@ fn random_level22() -> Level22State {
     let margin = 60.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (modal_x, modal_y) = super::safe_position_in(&mut rng, modal_w, modal_h, margin, vp_w * 1.3, vp_h * 1.3);
+    let (modal_x, modal_y) = d.jitter(&mut rng, (modal_x, modal_y));
 
     let has_close = rng.random_bool(0.5);
 
@@ -63,13 +70,24 @@ fn random_level22() -> Level22State {
     let target_is_close = has_close && target_idx == scenario.buttons.len();
     let target_button = if target_is_close { 0 } else { target_idx };
 
-    Level22State { scenario_idx, target_button, style, accent, modal_w, modal_x, modal_y, has_close, target_is_close }
+    // Impossible round: ask for a button from another dialog
+    let missing = roll_impossible(&mut rng).then(|| {
+        let decoys: Vec<&'static str> = SCENARIOS.iter()
+            .flat_map(|s| s.buttons.iter().copied())
+            .filter(|b| !scenario.buttons.contains(b))
+            .collect();
+        decoys[rng.random_range(0..decoys.len())]
+    });
+    let target_is_close = target_is_close && missing.is_none();
+
+    let time_limit = roll_time_limit(&mut rng);
+    Level22State { scenario_idx, target_button, style, accent, modal_w, modal_x, modal_y, has_close, target_is_close, missing, time_limit }
 }
 
 #[component]
 pub fn Level22() -> Element {
-    let mut state = use_signal(|| random_level22());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level22(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut wrong = use_signal(|| false);
 
@@ -86,17 +104,23 @@ pub fn Level22() -> Element {
     let modal_x = st.modal_x;
     let modal_y = st.modal_y;
     let has_close = st.has_close;
+    let missing = st.missing;
+    let time_limit = st.time_limit;
     drop(st);
 
     let btn_count = buttons.len();
     let is_wrong = wrong();
+    let impossible = missing.is_some();
+    let is_target_button = move |bi: usize| !impossible && !target_is_close && bi == target_button;
 
-    let target_label = if target_is_close {
+    let target_label = if let Some(m) = missing {
+        format!("\"{}\"", m)
+    } else if target_is_close {
         "the close button (X)".to_string()
     } else {
         format!("\"{}\"", buttons[target_button])
     };
-    let instruction = format!("Click {}", target_label);
+    let instruction = tr("instr.click", &[&target_label]);
 
     // Modal styling
     let border_radius = match style { 0 => "16px", 1 => "8px", _ => "12px" };
@@ -126,14 +150,15 @@ pub fn Level22() -> Element {
 
     // Dialog buttons
     for (i, b) in buttons.iter().enumerate() {
-        if !target_is_close && i == target_button {
+        if is_target_button(i) {
             children.push(UINode::ModalButton(Visual::new(*b, modal_rect).target()));
         } else {
             children.push(UINode::ModalButton(Visual::new(*b, modal_rect)));
         }
     }
 
-    let tree = ui_node::card(modal_rect, children);
+    let tree = with_not_found(ui_node::card(modal_rect, children), impossible);
+    let outcome = missing.map_or(Outcome::Success, Outcome::impossible);
     let description = String::new();
     let viewport_style = super::viewport_style(&bg(), true);
 
@@ -166,6 +191,16 @@ pub fn Level22() -> Element {
                 id: "viewport",
                 style: "{viewport_style}",
 
+                RoundTimer {
+                    round: super::round_index(),
+                    limit_ms: time_limit,
+                    on_expire: move |_| {
+                        bg.set(random_canvas_bg());
+                        state.set(random_level22(Difficulty::current()));
+                        wrong.set(false);
+                    },
+                }
+
                 // Fake background content (dimmed by overlay)
                 div {
                     style: "position: absolute; left: 200px; top: 300px; width: 300px; background: white; border-radius: 12px; padding: 24px; box-shadow: 0 2px 12px rgba(0,0,0,0.2); font-family: system-ui, sans-serif; z-index: 1;",
@@ -190,6 +225,16 @@ pub fn Level22() -> Element {
                     }
                 }
 
+                ReportNotFound {
+                    impossible: impossible,
+                    on_report: move |_| {
+                        score.complete();
+                        bg.set(random_canvas_bg());
+                        state.set(random_level22(Difficulty::current()));
+                        wrong.set(false);
+                    },
+                }
+
                 // Modal dialog
                 div {
                     style: "{modal_style}",
@@ -206,12 +251,13 @@ pub fn Level22() -> Element {
                                     tabindex: "-1",
                                     onclick: move |_| {
                                         if target_is_close {
-                                            score.set(score() + 1);
+                                            score.complete();
                                             bg.set(random_canvas_bg());
-                                            state.set(random_level22());
+                                            state.set(random_level22(Difficulty::current()));
                                             wrong.set(false);
                                         } else {
                                             wrong.set(true);
+                                            score.wrong("close", "");
                                             spawn(async move {
                                                 gloo_timers::future::TimeoutFuture::new(600).await;
                                                 wrong.set(false);
@@ -246,14 +292,14 @@ pub fn Level22() -> Element {
                                 let is_primary = bi == 0;
                                 let accent_c = accent.clone();
 
-                                let btn_bg = if is_wrong && !target_is_close && bi == target_button {
+                                let btn_bg = if is_wrong && is_target_button(bi) {
                                     "#ef4444".to_string()
                                 } else if is_primary {
                                     accent_c
                                 } else {
                                     "#f3f4f6".to_string()
                                 };
-                                let btn_color = if is_wrong && !target_is_close && bi == target_button {
+                                let btn_color = if is_wrong && is_target_button(bi) {
                                     "white".to_string()
                                 } else if is_primary {
                                     "white".to_string()
@@ -263,20 +309,22 @@ pub fn Level22() -> Element {
                                 let btn_border = if is_primary { "none" } else { "1px solid #e5e7eb" };
                                 let btn_radius = match style { 0 => "10px", 1 => "4px", _ => "6px" };
 
+                                let wrong_label = label.to_string();
                                 rsx! {
                                     button {
-                                        class: if !target_is_close && bi == target_button { "target" } else { "" },
+                                        class: if is_target_button(bi) { "target" } else { "" },
                                         "data-label": "{label}",
                                         style: "padding: 8px 18px; background: {btn_bg}; color: {btn_color}; border: {btn_border}; border-radius: {btn_radius}; font-size: 14px; font-weight: 500; cursor: pointer; font-family: system-ui, sans-serif; transition: background 0.15s;",
                                         tabindex: "-1",
                                         onclick: move |_| {
-                                            if !target_is_close && bi == target_button {
-                                                score.set(score() + 1);
+                                            if is_target_button(bi) {
+                                                score.complete();
                                                 bg.set(random_canvas_bg());
-                                                state.set(random_level22());
+                                                state.set(random_level22(Difficulty::current()));
                                                 wrong.set(false);
                                             } else {
                                                 wrong.set(true);
+                                                score.wrong(&wrong_label, "");
                                                 spawn(async move {
                                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                                     wrong.set(false);
@@ -299,6 +347,7 @@ pub fn Level22() -> Element {
                 target_w: modal_w,
                 target_h: modal_h_est,
                 tree: Some(tree.clone()),
+                outcome: outcome,
             }
         }
     }
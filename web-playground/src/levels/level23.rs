@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::Route;
 use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
 
 /// Context-menu scenarios: a trigger element + menu items.
 struct MenuScenario {
@@ -37,6 +37,28 @@ const TRIGGER_ICONS: &[&str] = &[
     "\u{1F5A5}", "\u{1F4E6}",
 ];
 
+/// Menu item: 8px vertical padding around a 13px line.
+const MENU_ITEM_H: f32 = 32.0;
+/// Separator: 1px rule with 4px margin above and below.
+const SEPARATOR_H: f32 = 9.0;
+
+/// Item rects of a menu whose content box is `content`, with the separator
+/// (if any) after item `sep_after`.
+fn menu_item_rects(content: Rect, item_count: usize, sep_after: usize) -> Vec<Rect> {
+    let mut heights = Vec::with_capacity(item_count + 1);
+    for i in 0..item_count {
+        heights.push(MENU_ITEM_H);
+        if i == sep_after {
+            heights.push(SEPARATOR_H);
+        }
+    }
+    let mut rects = ui_node::layout::stack(content, &heights, 0.0);
+    if sep_after < item_count {
+        rects.remove(sep_after + 1);
+    }
+    rects
+}
+
 struct Level23State {
     scenario_idx: usize,
     target_item: usize,
@@ -50,7 +72,7 @@ struct Level23State {
     has_icons: bool,
 }
 
-fn random_level23() -> Level23State {
+fn random_level23(d: &Difficulty) -> Level23State {
     let mut rng = fresh_rng();
     let scenario_idx = rng.random_range(0..SCENARIOS.len());
     let scenario = &SCENARIOS[scenario_idx];
@@ -68,6 +90,7 @@ fn random_level23() -> Level23State {
     let margin = 60.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (trigger_x, trigger_y) = super::safe_position_in(&mut rng, trigger_w + menu_w, trigger_h + menu_h, margin, vp_w * 1.3, vp_h * 1.3);
+    let (trigger_x, trigger_y) = d.jitter(&mut rng, (trigger_x, trigger_y));
 
     // Menu appears near the trigger (like a real right-click menu)
     let menu_offset_x = rng.random_range(10.0..40.0f32);
@@ -92,8 +115,8 @@ const ITEM_ICONS: &[&str] = &[
 
 #[component]
 pub fn Level23() -> Element {
-    let mut state = use_signal(|| random_level23());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level23(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut wrong = use_signal(|| false);
     let mut menu_open = use_signal(|| true);
@@ -118,7 +141,7 @@ pub fn Level23() -> Element {
     let is_open = menu_open();
 
     let target_label = items[target_item];
-    let instruction = format!("Right-click \"{}\", then click \"{}\"", trigger_label, target_label);
+    let instruction = tr("instr.right_click_then", &[&trigger_label, &target_label]);
 
     // Trigger element styling
     let trigger_icon = TRIGGER_ICONS[scenario_idx];
@@ -156,14 +179,20 @@ pub fn Level23() -> Element {
     // Separator index: place one separator roughly in the middle
     let sep_after = if has_separator { item_count / 2 } else { usize::MAX };
 
-    // Ground truth via UINode tree
-    let item_h_est = 36.0f32;
-    let menu_h_est = item_count as f32 * item_h_est + 16.0;
-    let tree = ui_node::context_menu(
+    // Ground truth via UINode tree: items sit inside the menu's padding and border
+    let menu_inset = 6.0 + if menu_border == "none" { 0.0 } else { 1.0 };
+    let item_rects = menu_item_rects(
+        Rect::new(menu_x + menu_inset, menu_y + menu_inset, menu_w - 2.0 * menu_inset, 0.0),
+        item_count,
+        sep_after,
+    );
+    let menu_h_est = item_rects.last().map_or(0.0, |r| r.y + r.h - menu_y) + menu_inset;
+    let tree = ui_node::context_menu_with_rects(
         Rect::new(trigger_x, trigger_y, trigger_w, trigger_h),
         trigger_label,
         items.iter().map(|s| s.to_string()).collect(),
         target_label,
+        item_rects,
     );
     let description = String::new();
     let viewport_style = super::viewport_style(&bg(), true);
@@ -209,7 +238,7 @@ pub fn Level23() -> Element {
                 // Trigger element
                 div {
                     style: "{trigger_style}",
-                    "data-label": "trigger",
+                    "data-label": "{trigger_label}",
                     oncontextmenu: move |evt| {
                         evt.prevent_default();
                         menu_open.set(true);
@@ -248,6 +277,7 @@ pub fn Level23() -> Element {
                                     hover_bg, item_radius
                                 );
 
+                                let wrong_label = label.to_string();
                                 rsx! {
                                     button {
                                         class: if mi == target_item { "target" } else { "" },
@@ -256,13 +286,14 @@ pub fn Level23() -> Element {
                                         tabindex: "-1",
                                         onclick: move |_| {
                                             if mi == target_item {
-                                                score.set(score() + 1);
+                                                score.complete();
                                                 bg.set(random_canvas_bg());
-                                                state.set(random_level23());
+                                                state.set(random_level23(Difficulty::current()));
                                                 wrong.set(false);
                                                 menu_open.set(true);
                                             } else {
                                                 wrong.set(true);
+                                                score.wrong(&wrong_label, "");
                                                 spawn(async move {
                                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                                     wrong.set(false);
@@ -2,8 +2,9 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg};
+use crate::ui_node::{self, Outcome, Rect};
+use super::{Difficulty, fresh_rng, roll_time_limit, RoundTimer, random_canvas_bg, tr};
+use super::{roll_impossible, with_not_found, ReportNotFound};
 
 /// Each scenario has a search placeholder and a pool of suggestions.
 struct SearchScenario {
@@ -61,9 +62,14 @@ struct Level24State {
     card_y: f32,
     card_w: f32,
     prefill: String,
+    /// Impossible round: the instruction names this suggestion, which isn't listed.
+    missing: Option<&'static str>,
+
+    /// Countdown for this round (`?timer=`), in ms.
+    time_limit: Option<u32>,
 }
 
-fn random_level24() -> Level24State {
+fn random_level24(d: &Difficulty) -> Level24State {
     let mut rng = fresh_rng();
     let scenario_idx = rng.random_range(0..SCENARIOS.len());
     let scenario = &SCENARIOS[scenario_idx];
@@ -71,7 +77,7 @@ fn random_level24() -> Level24State {
     let accent = ACCENT_COLORS[rng.random_range(0..ACCENT_COLORS.len())].to_string();
 
     // Pick 4-7 suggestions to show in dropdown
-    let count = rng.random_range(4..=7usize).min(scenario.suggestions.len());
+    let count = d.count(&mut rng, 4..=7).min(scenario.suggestions.len());
     let mut pool: Vec<usize> = (0..scenario.suggestions.len()).collect();
     let mut visible_items = Vec::new();
     for _ in 0..count {
@@ -89,16 +95,19 @@ fn random_level24() -> Level24State {
     let card_w = rng.random_range(280.0..=400.0f32);
     let item_h = 40.0f32;
     let card_h = 52.0 + count as f32 * item_h + 16.0;
-    let (vp_w, vp_h) = crate::primitives::viewport_size();
-    let (card_x, card_y) = super::safe_position_in(&mut rng, card_w, card_h, 60.0, vp_w * 1.3, vp_h * 1.3);
+    let (card_x, card_y) = super::layout::place_non_overlapping(&mut rng, &[(card_w, card_h)], 0.0, super::layout::Bounds::scrollable(60.0))[0];
+
+    // `pool` now holds only the suggestions that aren't listed
+    let missing = roll_impossible(&mut rng).then(|| scenario.suggestions[pool[rng.random_range(0..pool.len())]]);
 
-    Level24State { scenario_idx, visible_items, target_item, style, accent, card_x, card_y, card_w, prefill }
+    let time_limit = roll_time_limit(&mut rng);
+    Level24State { scenario_idx, visible_items, target_item, style, accent, card_x, card_y, card_w, prefill, missing, time_limit }
 }
 
 #[component]
 pub fn Level24() -> Element {
-    let mut state = use_signal(|| random_level24());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level24(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut wrong = use_signal(|| false);
 
@@ -113,39 +122,43 @@ pub fn Level24() -> Element {
     let card_y = st.card_y;
     let card_w = st.card_w;
     let prefill = st.prefill.clone();
+    let missing = st.missing;
+    let time_limit = st.time_limit;
     drop(st);
+    let impossible = missing.is_some();
+    let is_target = move |di: usize| !impossible && di == target_item;
 
     let item_count = visible_items.len();
     let is_wrong = wrong();
 
     let scenario2 = &SCENARIOS[state.read().scenario_idx];
     let target_text = scenario2.suggestions[visible_items[target_item]];
-    let instruction = format!("Select \"{}\"", target_text);
+    let instruction = tr("instr.select", &[&missing.unwrap_or(target_text)]);
 
     // Card styling
     let border_radius = match style { 0 => "16px", 1 => "6px", _ => "10px" };
     let card_style = format!(
         "position: absolute; left: {}px; top: {}px; width: {}px; \
-         background: white; border-radius: {}; \
-         box-shadow: 0 4px 24px rgba(0,0,0,0.3); \
-         font-family: system-ui, sans-serif; box-sizing: border-box; padding: 12px;",
+         background: var(--theme-surface, white); border-radius: var(--theme-radius, {}); \
+         box-shadow: var(--theme-shadow, 0 4px 24px rgba(0,0,0,0.3)); \
+         font-family: var(--theme-font, system-ui, sans-serif); box-sizing: border-box; padding: 12px;",
         card_x, card_y, card_w, border_radius
     );
 
     // Input styling
     let input_radius = match style { 0 => "12px", 1 => "4px", _ => "8px" };
     let input_style = format!(
-        "width: 100%; padding: 10px 14px; border: 2px solid {}; border-radius: {}; \
-         font-size: 14px; color: #111827; outline: none; box-sizing: border-box; \
-         font-family: system-ui, sans-serif; background: #fafafa;",
+        "width: 100%; padding: 10px 14px; border: 2px solid var(--theme-accent, {}); border-radius: {}; \
+         font-size: 14px; color: var(--theme-text, #111827); outline: none; box-sizing: border-box; \
+         font-family: inherit; background: var(--theme-surface, #fafafa);",
         accent, input_radius
     );
 
     // Dropdown styling
     let dropdown_radius = match style { 0 => "12px", 1 => "4px", _ => "8px" };
     let dropdown_style = format!(
-        "margin-top: 4px; border: 1px solid #e5e7eb; border-radius: {}; \
-         overflow: hidden; background: white;",
+        "margin-top: 4px; border: 1px solid var(--theme-border, #e5e7eb); border-radius: {}; \
+         overflow: hidden; background: var(--theme-surface, white);",
         dropdown_radius
     );
 
@@ -157,13 +170,19 @@ pub fn Level24() -> Element {
     let suggestion_y_start = card_y + 56.0; // after input area
     let tree = ui_node::card(
         Rect::new(card_x, card_y, card_w, card_h_est),
-        vec![
-            ui_node::target_button(
-                target_text,
-                Rect::new(card_x, suggestion_y_start + target_item as f32 * item_h_est, card_w, item_h_est),
-            ),
-        ],
+        if impossible {
+            vec![]
+        } else {
+            vec![
+                ui_node::target_button(
+                    target_text,
+                    Rect::new(card_x, suggestion_y_start + target_item as f32 * item_h_est, card_w, item_h_est),
+                ),
+            ]
+        },
     );
+    let tree = with_not_found(tree, impossible);
+    let outcome = missing.map_or(Outcome::Success, Outcome::impossible);
     let description = String::new();
     let viewport_style = super::viewport_style(&bg(), true);
 
@@ -196,6 +215,16 @@ pub fn Level24() -> Element {
                 id: "viewport",
                 style: "{viewport_style}",
 
+                RoundTimer {
+                    round: super::round_index(),
+                    limit_ms: time_limit,
+                    on_expire: move |_| {
+                        bg.set(random_canvas_bg());
+                        state.set(random_level24(Difficulty::current()));
+                        wrong.set(false);
+                    },
+                }
+
                 // Instruction
                 div {
                     style: "position: absolute; left: 0; right: 0; top: 16px; text-align: center; z-index: 30;",
@@ -205,6 +234,16 @@ pub fn Level24() -> Element {
                     }
                 }
 
+                ReportNotFound {
+                    impossible: impossible,
+                    on_report: move |_| {
+                        score.complete();
+                        bg.set(random_canvas_bg());
+                        state.set(random_level24(Difficulty::current()));
+                        wrong.set(false);
+                    },
+                }
+
                 // Search card
                 div {
                     style: "{card_style}",
@@ -214,8 +253,8 @@ pub fn Level24() -> Element {
                         style: "position: relative;",
                         div {
                             style: "{input_style}",
-                            span { style: "color: #111827;", "{prefill}" }
-                            span { style: "color: #9ca3af;", "{placeholder}" }
+                            span { style: "color: var(--theme-text, #111827);", "{prefill}" }
+                            span { style: "color: var(--theme-muted, #9ca3af);", "{placeholder}" }
                         }
                         // Search icon
                         div {
@@ -234,7 +273,7 @@ pub fn Level24() -> Element {
                                 let label = scenario2.suggestions[si];
                                 let accent_c = accent.clone();
 
-                                let item_bg = if is_wrong && di == target_item {
+                                let item_bg = if is_wrong && is_target(di) {
                                     "#fecaca".to_string()
                                 } else {
                                     "transparent".to_string()
@@ -250,26 +289,28 @@ pub fn Level24() -> Element {
                                 let item_style = format!(
                                     "display: flex; align-items: center; width: 100%; padding: 10px 14px; \
                                      background: {}; border: none; border-radius: {}; font-size: 14px; \
-                                     color: #374151; cursor: pointer; text-align: left; \
-                                     font-family: system-ui, sans-serif; box-sizing: border-box; \
+                                     color: var(--theme-text, #374151); cursor: pointer; text-align: left; \
+                                     font-family: inherit; box-sizing: border-box; \
                                      transition: background 0.1s;",
                                     item_bg, item_radius
                                 );
 
+                                let wrong_label = label.to_string();
                                 rsx! {
                                     button {
-                                        class: if di == target_item { "target" } else { "" },
+                                        class: if is_target(di) { "target" } else { "" },
                                         "data-label": "{label}",
                                         style: "{item_style}",
                                         tabindex: "-1",
                                         onclick: move |_| {
-                                            if di == target_item {
-                                                score.set(score() + 1);
+                                            if is_target(di) {
+                                                score.complete();
                                                 bg.set(random_canvas_bg());
-                                                state.set(random_level24());
+                                                state.set(random_level24(Difficulty::current()));
                                                 wrong.set(false);
                                             } else {
                                                 wrong.set(true);
+                                                score.wrong(&wrong_label, "");
                                                 spawn(async move {
                                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                                     wrong.set(false);
@@ -278,7 +319,7 @@ pub fn Level24() -> Element {
                                         },
                                         if match_len > 0 {
                                             span {
-                                                style: "font-weight: 700; color: {accent_c};",
+                                                style: "font-weight: 700; color: var(--theme-accent, {accent_c});",
                                                 "{matched}"
                                             }
                                             span { "{rest}" }
@@ -300,6 +341,7 @@ pub fn Level24() -> Element {
                 target_w: card_w,
                 target_h: card_h_est,
                 tree: Some(tree.clone()),
+                outcome: outcome,
             }
         }
     }
@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use crate::ui_node::{self, Rect, SortableState};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, tr_ordinal};
 
 struct ListScenario {
     title: &'static str,
@@ -62,12 +62,12 @@ struct Level25State {
     card_w: f32,
 }
 
-fn random_level25() -> Level25State {
+fn random_level25(d: &Difficulty) -> Level25State {
     let mut rng = fresh_rng();
     let scenario_idx = rng.random_range(0..SCENARIOS.len());
     let scenario = &SCENARIOS[scenario_idx];
 
-    let count = rng.random_range(5..=7usize).min(scenario.items.len());
+    let count = d.count(&mut rng, 5..=7).min(scenario.items.len());
     let mut pool: Vec<usize> = (0..scenario.items.len()).collect();
     let mut order = Vec::new();
     for _ in 0..count {
@@ -87,18 +87,19 @@ fn random_level25() -> Level25State {
     let accent = ACCENT_COLORS[rng.random_range(0..ACCENT_COLORS.len())].to_string();
 
     let card_w = rng.random_range(280.0..=380.0f32);
-    let list_h = count as f32 * (ITEM_H + ITEM_GAP) - ITEM_GAP;
+    let list_h = super::layout::stack_height(&vec![ITEM_H; count], ITEM_GAP);
     let card_h = LIST_TOP + list_h + 16.0 + 56.0; // list + bottom padding + submit button
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (card_x, card_y) = super::safe_position_in(&mut rng, card_w, card_h, 60.0, vp_w * 1.3, vp_h * 1.3);
+    let (card_x, card_y) = d.jitter(&mut rng, (card_x, card_y));
 
     Level25State { scenario_idx, order, target_item, target_pos, style, accent, card_x, card_y, card_w }
 }
 
 #[component]
 pub fn Level25() -> Element {
-    let mut state = use_signal(|| random_level25());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level25(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut wrong = use_signal(|| false);
     let initial_order = state.read().order.clone();
@@ -128,14 +129,15 @@ pub fn Level25() -> Element {
     let cur_drag = drag_idx();
 
     let target_label = scenario.items[target_item];
-    let target_ord = ordinal(target_pos + 1);
-    let instruction = format!("Move \"{}\" to {} position", target_label, target_ord);
+    let target_ord = tr_ordinal(target_pos + 1);
+    let instruction = tr("instr.move_to_position", &[&target_label, &target_ord]);
 
     let is_correct = cur_order.get(target_pos) == Some(&target_item);
+    let placed_at = cur_order.iter().position(|&i| i == target_item).map_or(0, |p| p + 1);
 
     let border_radius = match style { 0 => "16px", 1 => "6px", _ => "10px" };
     let item_radius = match style { 0 => "10px", 1 => "4px", _ => "6px" };
-    let list_h = item_count as f32 * (ITEM_H + ITEM_GAP) - ITEM_GAP;
+    let list_h = super::layout::stack_height(&vec![ITEM_H; item_count], ITEM_GAP);
     let card_style = format!(
         "position: absolute; left: {}px; top: {}px; width: {}px; \
          background: white; border-radius: {}; \
@@ -146,19 +148,20 @@ pub fn Level25() -> Element {
 
     let submit_bg = if is_wrong { "#ef4444" } else { &accent };
 
-    // Ground truth
+    // Ground truth: the list's slots sit in the card's content box below the title and hint
     let card_h_est = LIST_TOP + list_h + 16.0 + 56.0;
-    let card_rect = Rect::new(card_x, card_y, card_w, card_h_est);
-    let children: Vec<_> = cur_order.iter().map(|&si| {
-        let label = scenario.items[si];
-        let item_rect = Rect::new(card_x, card_y, card_w, card_h_est);
-        if si == target_item {
-            ui_node::target_button(label, item_rect)
-        } else {
-            ui_node::button(label, item_rect)
-        }
-    }).collect();
-    let tree = ui_node::form(card_rect, "Submit", children);
+    let list_rect = Rect::new(card_x + 16.0, card_y + LIST_TOP, card_w - 32.0, list_h);
+    let sortable = SortableState {
+        items: cur_order.iter().map(|&si| scenario.items[si].to_string()).collect(),
+        slots: ui_node::layout::stack(list_rect, &vec![ITEM_H; item_count], ITEM_GAP),
+        target_item: target_label.to_string(),
+        target_pos,
+    };
+    let tree = ui_node::form(
+        Rect::new(card_x, card_y, card_w, card_h_est),
+        "Submit",
+        vec![ui_node::sortable_list(title, list_rect, sortable)],
+    );
     let description = String::new();
     let viewport_style = format!("{} user-select: none;", super::viewport_style(&bg(), true));
 
@@ -303,9 +306,9 @@ pub fn Level25() -> Element {
                         tabindex: "-1",
                         onclick: move |_| {
                             if is_correct {
-                                score.set(score() + 1);
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level25();
+                                let new_st = random_level25(Difficulty::current());
                                 let new_order = new_st.order.clone();
                                 state.set(new_st);
                                 order.set(new_order);
@@ -313,6 +316,7 @@ pub fn Level25() -> Element {
                                 wrong.set(false);
                             } else {
                                 wrong.set(true);
+                                score.wrong("Submit", &format!("{target_label} at position {placed_at}"));
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::Route;
 use crate::ui_node::{self, UINode, Visual, Rect, TagState};
-use super::{fresh_rng, random_canvas_bg};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
 
 struct TagScenario {
     title: &'static str,
@@ -62,13 +62,20 @@ struct Level26State {
     card_w: f32,
 }
 
-fn random_level26() -> Level26State {
+/// Rendered chip size: 13px label, 14px side padding, 1.5px border, and the
+/// ✕ mark (6px gap + 11px glyph) while selected.
+fn chip_size(label: &str, selected: bool) -> (f32, f32) {
+    let mark = if selected { 17.0 } else { 0.0 };
+    (label.chars().count() as f32 * 7.0 + 31.0 + mark, 31.0)
+}
+
+fn random_level26(d: &Difficulty) -> Level26State {
     let mut rng = fresh_rng();
     let scenario_idx = rng.random_range(0..SCENARIOS.len());
     let scenario = &SCENARIOS[scenario_idx];
 
     // Pick 6-9 tags to show
-    let count = rng.random_range(6..=9usize).min(scenario.tags.len());
+    let count = d.count(&mut rng, 6..=9).min(scenario.tags.len());
     let mut pool: Vec<usize> = (0..scenario.tags.len()).collect();
     let mut available = Vec::new();
     for _ in 0..count {
@@ -95,7 +102,7 @@ fn random_level26() -> Level26State {
             }
             // Pick 1-3 targets from unselected
             let unselected: Vec<usize> = (0..count).filter(|i| !initially_selected[*i]).collect();
-            let target_count = rng.random_range(1..=3usize).min(unselected.len());
+            let target_count = d.count(&mut rng, 1..=3).min(unselected.len());
             let mut unsel_pool = unselected;
             for _ in 0..target_count {
                 let idx = rng.random_range(0..unsel_pool.len());
@@ -119,7 +126,7 @@ fn random_level26() -> Level26State {
             }
             // Pick 1-2 targets from selected
             let selected_indices: Vec<usize> = (0..count).filter(|i| initially_selected[*i]).collect();
-            let target_count = rng.random_range(1..=2usize).min(selected_indices.len());
+            let target_count = d.count(&mut rng, 1..=2).min(selected_indices.len());
             let mut sel_pool = selected_indices;
             for _ in 0..target_count {
                 let idx = rng.random_range(0..sel_pool.len());
@@ -135,14 +142,15 @@ fn random_level26() -> Level26State {
     let card_h = 280.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (card_x, card_y) = super::safe_position_in(&mut rng, card_w, card_h, 60.0, vp_w * 1.3, vp_h * 1.3);
+    let (card_x, card_y) = d.jitter(&mut rng, (card_x, card_y));
 
     Level26State { scenario_idx, available, initially_selected, target_tags, mode, style, accent, card_x, card_y, card_w }
 }
 
 #[component]
 pub fn Level26() -> Element {
-    let mut state = use_signal(|| random_level26());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level26(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut wrong = use_signal(|| false);
     let initial_sel = state.read().initially_selected.clone();
@@ -170,21 +178,19 @@ pub fn Level26() -> Element {
     let instruction = match mode {
         TagMode::Add => {
             let labels = target_labels.iter().map(|l| format!("\"{}\"", l)).collect::<Vec<_>>().join(", ");
-            format!("Select {}", labels)
+            tr("instr.select_many", &[&labels])
         }
         TagMode::Remove => {
             let labels = target_labels.iter().map(|l| format!("\"{}\"", l)).collect::<Vec<_>>().join(", ");
-            format!("Remove {}", labels)
+            tr("instr.remove_many", &[&labels])
         }
     };
 
-    // Check if goal is met
-    let is_correct = target_tags.iter().all(|&ti| {
-        match mode {
-            TagMode::Add => cur_sel.get(ti).copied().unwrap_or(false),
-            TagMode::Remove => !cur_sel.get(ti).copied().unwrap_or(true),
-        }
-    });
+    let selected_now = cur_sel.iter().enumerate()
+        .filter(|(_, on)| **on)
+        .map(|(i, _)| scenario.tags[available[i]])
+        .collect::<Vec<_>>()
+        .join(", ");
 
     let border_radius = match style { 0 => "16px", 1 => "6px", _ => "10px" };
     let chip_radius = match style { 0 => "20px", 1 => "4px", _ => "8px" };
@@ -200,19 +206,26 @@ pub fn Level26() -> Element {
 
     // Ground truth — build UINode tree
     let card_rect = Rect::new(card_x, card_y, card_w, 280.0);
+    let chip_sizes: Vec<(f32, f32)> = available.iter().enumerate().map(|(i, &si)| {
+        let is_sel = cur_sel.get(i).copied().unwrap_or(false);
+        chip_size(scenario.tags[si], is_sel)
+    }).collect();
+    // Chips start below the title and hint
+    let chip_rects = super::layout::flow(Rect::new(card_x + 16.0, card_y + 66.0, card_w - 32.0, 0.0), &chip_sizes, 8.0);
     let children: Vec<UINode> = available.iter().enumerate().map(|(i, &si)| {
         let label = scenario.tags[si];
         let is_sel = cur_sel.get(i).copied().unwrap_or(false);
-        let tag_rect = Rect::new(card_x, card_y, card_w, 280.0);
+        let tag_rect = chip_rects[i];
         if target_tags.contains(&i) {
-            // Target tag — use the builder which sets is_target = true
-            ui_node::tag(label, tag_rect, is_sel)
+            // Target tag: selected when adding, cleared when removing
+            ui_node::tag_to(label, tag_rect, is_sel, mode == TagMode::Add)
         } else {
             // Non-target tag
-            UINode::Tag(Visual::new(label, tag_rect), TagState { is_selected: is_sel })
+            UINode::Tag(Visual::new(label, tag_rect), TagState { is_selected: is_sel, target_selected: is_sel })
         }
     }).collect();
-    let tree = ui_node::form(card_rect, "Submit", children);
+    let tree = ui_node::form(card_rect, "Submit", children).unordered();
+    let is_correct = tree.evaluate().is_complete();
     let description = String::new();
     let viewport_style = super::viewport_style(&bg(), true);
 
@@ -335,15 +348,16 @@ pub fn Level26() -> Element {
                         tabindex: "-1",
                         onclick: move |_| {
                             if is_correct {
-                                score.set(score() + 1);
+                                score.complete();
                                 bg.set(random_canvas_bg());
-                                let new_st = random_level26();
+                                let new_st = random_level26(Difficulty::current());
                                 let new_sel = new_st.initially_selected.clone();
                                 state.set(new_st);
                                 selected.set(new_sel);
                                 wrong.set(false);
                             } else {
                                 wrong.set(true);
+                                score.wrong("Submit", &selected_now);
                                 spawn(async move {
                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                     wrong.set(false);
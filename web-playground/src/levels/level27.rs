@@ -1,9 +1,14 @@
+//! Toast dismiss: close one notification in a stack. The auto-dismiss
+//! variant (level 28) may name the target by its kind and give every toast
+//! a lifetime, failing the round if the target times out first.
+
 use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, UINode, Visual, Rect, ToastState};
-use super::{fresh_rng, random_canvas_bg};
+use crate::ui_node::{self, Outcome, UINode, Visual, Rect, ToastState};
+use super::{Difficulty, fresh_rng, roll_time_limit, RoundTimer, random_canvas_bg, tr};
+use super::{roll_impossible, with_not_found, ReportNotFound};
 
 #[derive(Clone, Copy, PartialEq)]
 enum ToastKind {
@@ -73,6 +78,8 @@ struct ToastInfo {
     message: String,
     kind: ToastKind,
     y: f32,
+    /// Auto-dismiss delay in ms (None = toast stays until closed).
+    lifetime_ms: Option<u32>,
 }
 
 struct Level27State {
@@ -82,13 +89,20 @@ struct Level27State {
     stack_x: f32,
     stack_start_y: f32,
     toast_w: f32,
+    /// The instruction names the target's kind, which is unique in the stack.
+    by_kind: bool,
+    /// Impossible round: the instruction names this message, which isn't shown.
+    missing: Option<&'static str>,
+
+    /// Countdown for this round (`?timer=`), in ms.
+    time_limit: Option<u32>,
 }
 
-fn random_level27() -> Level27State {
+fn random_level27(d: &Difficulty, auto_dismiss: bool) -> Level27State {
     let mut rng = fresh_rng();
 
-    // Pick 3-6 toasts
-    let count = rng.random_range(3..=6usize);
+    // Pick 3-6 toasts, or 2-4 when they time out
+    let count = if auto_dismiss { d.count(&mut rng, 2..=4) } else { d.count(&mut rng, 3..=6) };
     let mut msg_pool: Vec<usize> = (0..MESSAGES.len()).collect();
     let mut toasts = Vec::new();
 
@@ -98,6 +112,7 @@ fn random_level27() -> Level27State {
     let stack_h = count as f32 * (toast_h + gap);
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (stack_x, stack_start_y) = super::safe_position_in(&mut rng, toast_w, stack_h, 60.0, vp_w * 1.3, vp_h * 1.3);
+    let (stack_x, stack_start_y) = d.jitter(&mut rng, (stack_x, stack_start_y));
 
     for i in 0..count {
         let mi = rng.random_range(0..msg_pool.len());
@@ -105,23 +120,91 @@ fn random_level27() -> Level27State {
         let (message, kind) = MESSAGES[msg_idx];
         // Y relative to the stack container, not the viewport
         let y = i as f32 * (toast_h + gap);
-        toasts.push(ToastInfo { message: message.to_string(), kind, y });
+        toasts.push(ToastInfo { message: message.to_string(), kind, y, lifetime_ms: None });
     }
 
     let target_idx = rng.random_range(0..count);
     let style = rng.random_range(0..3u8);
-
-    Level27State { toasts, target_idx, style, stack_x, stack_start_y, toast_w }
+    // `msg_pool` now holds only messages that weren't picked
+    let missing = roll_impossible(&mut rng).then(|| MESSAGES[msg_pool[rng.random_range(0..msg_pool.len())]].0);
+
+    let time_limit = roll_time_limit(&mut rng);
+
+    let mut by_kind = false;
+    if auto_dismiss {
+        // By-kind targeting only works when the target's kind is unique in the stack
+        let kind = toasts[target_idx].kind;
+        let unique = toasts.iter().filter(|t| t.kind == kind).count() == 1;
+        by_kind = rng.random_bool(0.5) && unique && missing.is_none();
+        // Timed rounds: the target must be closed before it disappears on its own
+        if rng.random_bool(0.4) {
+            for (i, t) in toasts.iter_mut().enumerate() {
+                // Target lives long enough to be reachable; others may vanish sooner
+                let lo = if i == target_idx { 6000 } else { 2500 };
+                t.lifetime_ms = Some(rng.random_range(lo..=9000u32));
+            }
+        }
+    }
+    Level27State { toasts, target_idx, style, stack_x, stack_start_y, toast_w, by_kind, missing, time_limit }
 }
 
 #[component]
 pub fn Level27() -> Element {
-    let mut state = use_signal(|| random_level27());
-    let mut score = use_signal(|| 0u32);
-    let mut bg = use_signal(|| random_canvas_bg());
+    rsx! { ToastLevel { auto_dismiss: false } }
+}
+
+/// The toast stack; `auto_dismiss` is the level 28 variant.
+#[component]
+pub fn ToastLevel(auto_dismiss: bool) -> Element {
+    let mut state = use_signal(|| random_level27(Difficulty::current(), auto_dismiss));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
     let mut wrong = use_signal(|| false);
     let initial_visible: Vec<bool> = vec![true; state.read().toasts.len()];
     let mut visible = use_signal(move || initial_visible);
+    let mut round = use_signal(|| 0u32);
+
+    // Re-randomize; bumping `round` cancels any pending auto-dismiss timers.
+    let mut next_round = move |scored: bool| {
+        if scored {
+            score.complete();
+        }
+        bg.set(random_canvas_bg());
+        let new_st = random_level27(Difficulty::current(), auto_dismiss);
+        visible.set(vec![true; new_st.toasts.len()]);
+        state.set(new_st);
+        round.set(round() + 1);
+        wrong.set(false);
+    };
+
+    // Auto-dismiss timers, one per toast, scoped to the current round
+    use_effect(move || {
+        let r = round();
+        let st = state.read();
+        let target = st.missing.is_none().then_some(st.target_idx);
+        let lifetimes: Vec<(usize, u32)> = st.toasts.iter().enumerate()
+            .filter_map(|(i, t)| t.lifetime_ms.map(|ms| (i, ms)))
+            .collect();
+        drop(st);
+
+        for (i, ms) in lifetimes {
+            spawn(async move {
+                gloo_timers::future::TimeoutFuture::new(ms).await;
+                if *round.peek() != r { return; }
+                if let Some(val) = visible.write().get_mut(i) {
+                    *val = false;
+                }
+                if target == Some(i) {
+                    // Target expired before it was dismissed — round failed
+                    wrong.set(true);
+                    gloo_timers::future::TimeoutFuture::new(600).await;
+                    if *round.peek() == r {
+                        next_round(false);
+                    }
+                }
+            });
+        }
+    });
 
     let st = state.read();
     let toasts: Vec<ToastInfo> = st.toasts.clone();
@@ -130,41 +213,59 @@ pub fn Level27() -> Element {
     let stack_x = st.stack_x;
     let stack_start_y = st.stack_start_y;
     let toast_w = st.toast_w;
+    let by_kind = st.by_kind;
+    let missing = st.missing;
+    let time_limit = st.time_limit;
     drop(st);
+    let impossible = missing.is_some();
+    let is_target = move |ti: usize| !impossible && ti == target_idx;
 
     let toast_count = toasts.len();
     let is_wrong = wrong();
+    let cur_round = round();
     let cur_visible: Vec<bool> = visible.read().clone();
+    let timed = toasts.iter().any(|t| t.lifetime_ms.is_some());
 
     let target_toast = &toasts[target_idx];
     let target_msg = target_toast.message.clone();
-    let _target_kind = target_toast.kind;
     // Target Y in viewport coords for ground truth
     let target_y = stack_start_y + target_toast.y;
-    let instruction = format!("Dismiss the \"{}\" notification", target_msg);
+    let instruction = if by_kind {
+        tr("instr.dismiss_kind", &[&target_toast.kind.label()])
+    } else {
+        tr("instr.dismiss", &[&missing.unwrap_or(&target_msg)])
+    };
+    let instruction = if timed { tr("instr.before_disappears", &[&instruction]) } else { instruction };
 
     let border_radius = match style { 0 => "14px", 1 => "4px", _ => "8px" };
 
-    // Ground truth — build UINode tree (viewport-absolute coords)
+    // Ground truth — only toasts still on screen (viewport-absolute coords)
     let stack_h_est = toast_count as f32 * 72.0;
     let card_rect = Rect::new(stack_x, stack_start_y, toast_w, stack_h_est);
-    let children: Vec<UINode> = toasts.iter().enumerate().map(|(i, t)| {
-        let toast_rect = Rect::new(stack_x, stack_start_y + t.y, toast_w, 60.0);
-        let kind_label = t.kind.label();
-        if i == target_idx {
-            ui_node::toast(&t.message, toast_rect, kind_label, &t.message)
-        } else {
-            UINode::Toast(
-                Visual::new(&t.message, toast_rect),
-                ToastState { kind: kind_label.to_string(), message: t.message.clone() },
-            )
-        }
-    }).collect();
-    let tree = ui_node::card(card_rect, children);
+    let children: Vec<UINode> = toasts.iter().enumerate()
+        .filter(|(i, _)| cur_visible.get(*i).copied().unwrap_or(false))
+        .map(|(i, t)| {
+            let toast_rect = Rect::new(stack_x, stack_start_y + t.y, toast_w, 60.0);
+            let kind_label = t.kind.label();
+            if is_target(i) {
+                ui_node::toast(&t.message, toast_rect, kind_label, &t.message)
+            } else {
+                UINode::Toast(
+                    Visual::new(&t.message, toast_rect),
+                    ToastState { kind: kind_label.to_string(), message: t.message.clone() },
+                )
+            }
+        })
+        .collect();
+    let tree = with_not_found(ui_node::card(card_rect, children), impossible);
+    let outcome = missing.map_or(Outcome::Success, Outcome::impossible);
     let description = String::new();
     let viewport_style = super::viewport_style(&bg(), true);
+    let (heading, title) = if auto_dismiss { ("Level 28", "Toast Auto-Dismiss") } else { ("Level 27", "Toast Dismiss") };
 
     rsx! {
+        style { "@keyframes toast-lifetime {{ from {{ width: 100%; }} to {{ width: 0%; }} }}" }
+
         div {
             style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
 
@@ -177,11 +278,11 @@ pub fn Level27() -> Element {
                 }
                 h2 {
                     style: "color: #e5e7eb; margin: 0; font-size: 20px;",
-                    "Level 27"
+                    "{heading}"
                 }
                 span {
                     style: "color: #6b7280; font-size: 14px;",
-                    "Toast Dismiss"
+                    "{title}"
                 }
                 span {
                     style: "color: #22c55e; font-size: 14px; font-family: monospace;",
@@ -193,6 +294,12 @@ pub fn Level27() -> Element {
                 id: "viewport",
                 style: "{viewport_style}",
 
+                RoundTimer {
+                    round: super::round_index(),
+                    limit_ms: time_limit,
+                    on_expire: move |_| next_round(false),
+                }
+
                 // Instruction
                 div {
                     style: "position: absolute; left: 0; right: 0; top: 16px; text-align: center; z-index: 30;",
@@ -202,6 +309,11 @@ pub fn Level27() -> Element {
                     }
                 }
 
+                ReportNotFound {
+                    impossible: impossible,
+                    on_report: move |_| next_round(true),
+                }
+
                 // Toast stack — single container so fixup centers them as a group
                 div {
                     style: "position: absolute; left: {stack_x}px; top: {stack_start_y}px; width: {toast_w}px; height: {stack_h_est}px;",
@@ -217,9 +329,9 @@ pub fn Level27() -> Element {
 
                             let kind_color = toast.kind.color();
                             let kind_icon = toast.kind.icon();
-                            let wrong_flash = is_wrong && ti == target_idx;
+                            let wrong_flash = is_wrong && is_target(ti);
 
-                            let toast_bg = if wrong_flash { "#fef2f2" } else { "white" };
+                            let toast_bg = if wrong_flash { "#fef2f2" } else { "var(--theme-surface, white)" };
                             let left_border = if wrong_flash {
                                 "4px solid #ef4444".to_string()
                             } else {
@@ -235,10 +347,10 @@ pub fn Level27() -> Element {
                             // Positions are relative to the stack container
                             let toast_style = format!(
                                 "position: absolute; left: 0; top: {}px; width: 100%; \
-                                 background: {}; border-radius: {}; border-left: {}; \
-                                 box-shadow: {}; padding: 14px 16px; \
-                                 display: flex; align-items: center; gap: 12px; \
-                                 font-family: system-ui, sans-serif; box-sizing: border-box; \
+                                 background: {}; border-radius: var(--theme-radius, {}); border-left: {}; \
+                                 box-shadow: var(--theme-shadow, {}); padding: 14px 16px; \
+                                 display: flex; align-items: center; gap: 12px; overflow: hidden; \
+                                 font-family: var(--theme-font, system-ui, sans-serif); box-sizing: border-box; \
                                  transition: opacity 0.2s;",
                                 toast.y, toast_bg, border_radius,
                                 left_border, shadow
@@ -246,8 +358,10 @@ pub fn Level27() -> Element {
 
                             let icon_bg = format!("{}1a", kind_color);
 
+                            let wrong_label = format!("dismiss: {}", toast.message);
                             rsx! {
                                 div {
+                                    key: "{cur_round}-{ti}",
                                     style: "{toast_style}",
 
                                     div {
@@ -256,34 +370,32 @@ pub fn Level27() -> Element {
                                     }
 
                                     div {
-                                        style: "flex: 1; font-size: 13px; color: #374151; line-height: 1.4;",
+                                        style: "flex: 1; font-size: 13px; color: var(--theme-text, #374151); line-height: 1.4;",
                                         "{toast.message}"
                                     }
 
                                     button {
-                                        class: if ti == target_idx { "target" } else { "" },
+                                        class: if is_target(ti) { "target" } else { "" },
                                         "data-label": "dismiss: {toast.message}",
-                                        style: "width: 24px; height: 24px; border: none; background: transparent; border-radius: 4px; font-size: 14px; color: #9ca3af; cursor: pointer; display: flex; align-items: center; justify-content: center; flex-shrink: 0; font-family: system-ui, sans-serif; transition: background 0.1s;",
+                                        style: "width: 24px; height: 24px; border: none; background: transparent; border-radius: 4px; font-size: 14px; color: var(--theme-muted, #9ca3af); cursor: pointer; display: flex; align-items: center; justify-content: center; flex-shrink: 0; font-family: inherit; transition: background 0.1s;",
                                         tabindex: "-1",
                                         onclick: move |_| {
-                                            if ti == target_idx {
+                                            if is_target(ti) {
                                                 let mut v = visible.write();
                                                 if let Some(val) = v.get_mut(ti) {
                                                     *val = false;
                                                 }
                                                 drop(v);
+                                                let r = round();
                                                 spawn(async move {
                                                     gloo_timers::future::TimeoutFuture::new(300).await;
-                                                    score.set(score() + 1);
-                                                    bg.set(random_canvas_bg());
-                                                    let new_st = random_level27();
-                                                    let new_vis = vec![true; new_st.toasts.len()];
-                                                    state.set(new_st);
-                                                    visible.set(new_vis);
-                                                    wrong.set(false);
+                                                    if round() == r {
+                                                        next_round(true);
+                                                    }
                                                 });
                                             } else {
                                                 wrong.set(true);
+                                                score.wrong(&wrong_label, "");
                                                 spawn(async move {
                                                     gloo_timers::future::TimeoutFuture::new(600).await;
                                                     wrong.set(false);
@@ -292,6 +404,13 @@ pub fn Level27() -> Element {
                                         },
                                         "\u{2715}"
                                     }
+
+                                    // Remaining-lifetime bar for auto-dismissing toasts
+                                    if let Some(ms) = toast.lifetime_ms {
+                                        div {
+                                            style: "position: absolute; left: 0; bottom: 0; height: 3px; background: {kind_color}; opacity: 0.6; animation: toast-lifetime {ms}ms linear forwards;",
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -306,6 +425,7 @@ pub fn Level27() -> Element {
                 target_w: toast_w,
                 target_h: 60.0,
                 tree: Some(tree.clone()),
+                outcome: outcome,
             }
         }
     }
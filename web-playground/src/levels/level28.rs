@@ -0,0 +1,7 @@
+use dioxus::prelude::*;
+
+/// Level 27's toast stack with auto-dismissing toasts and by-kind targets.
+#[component]
+pub fn Level28() -> Element {
+    rsx! { super::level27::ToastLevel { auto_dismiss: true } }
+}
@@ -0,0 +1,283 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, AccordionState, UINode, Visual, Rect};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+struct FaqEntry {
+    question: &'static str,
+    answer: &'static str,
+    action: &'static str,
+}
+
+const FAQ: &[FaqEntry] = &[
+    FaqEntry { question: "How do I reset my password?", answer: "Request a reset link and follow the instructions sent to your email.", action: "Send reset link" },
+    FaqEntry { question: "Can I change my username?", answer: "Usernames can be changed once every 30 days from your profile.", action: "Edit profile" },
+    FaqEntry { question: "How do I cancel my subscription?", answer: "You can cancel anytime. Access continues until the end of the billing period.", action: "Manage subscription" },
+    FaqEntry { question: "Where can I find my invoices?", answer: "All invoices are stored in the billing section of your account.", action: "View invoices" },
+    FaqEntry { question: "Is my data encrypted?", answer: "Yes. Data is encrypted at rest and in transit using industry standards.", action: "Read security docs" },
+    FaqEntry { question: "How do I contact support?", answer: "Our team replies within one business day, Monday through Friday.", action: "Contact support" },
+    FaqEntry { question: "Can I export my data?", answer: "Exports are generated as a ZIP archive and emailed to you.", action: "Request export" },
+    FaqEntry { question: "Do you offer refunds?", answer: "Refunds are available within 14 days of purchase for annual plans.", action: "Request refund" },
+    FaqEntry { question: "How do I invite teammates?", answer: "Team admins can invite members by email from the team settings page.", action: "Invite members" },
+    FaqEntry { question: "Which browsers are supported?", answer: "The latest two versions of Chrome, Firefox, Safari and Edge are supported.", action: "See requirements" },
+    FaqEntry { question: "How do I delete my account?", answer: "Deleting your account removes all data permanently after 7 days.", action: "Delete account" },
+    FaqEntry { question: "Can I use the app offline?", answer: "The desktop app syncs changes automatically once you reconnect.", action: "Download app" },
+];
+
+const ACCENT_COLORS: &[&str] = &[
+    "#4f46e5", "#2563eb", "#0891b2", "#059669", "#d97706",
+    "#dc2626", "#7c3aed", "#db2777", "#0d9488", "#ea580c",
+];
+
+const CARD_PAD: f32 = 20.0;
+const TITLE_H: f32 = 40.0;
+const HEADER_H: f32 = 48.0;
+const ANSWER_H: f32 = 96.0;
+const ACTION_H: f32 = 32.0;
+
+struct Level29State {
+    entries: Vec<usize>,
+    target: usize,
+    initially_open: Vec<bool>,
+    action_style: u8, // 0=text link, 1=outlined button, 2=filled button
+    accent: String,
+    x: f32,
+    y: f32,
+    card_w: f32,
+}
+
+fn random_level29(d: &Difficulty) -> Level29State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 4..=6);
+
+    let mut pool: Vec<usize> = (0..FAQ.len()).collect();
+    let entries: Vec<usize> = (0..count)
+        .map(|_| pool.remove(rng.random_range(0..pool.len())))
+        .collect();
+    let target = rng.random_range(0..count);
+
+    // A few other sections may already be open, exposing decoy actions
+    let initially_open: Vec<bool> = (0..count)
+        .map(|i| i != target && rng.random_bool(0.2))
+        .collect();
+
+    let action_style = rng.random_range(0..3u8);
+    let accent = ACCENT_COLORS[rng.random_range(0..ACCENT_COLORS.len())].to_string();
+
+    let card_w = rng.random_range(380.0..=480.0f32);
+    let card_h = CARD_PAD * 2.0 + TITLE_H + count as f32 * HEADER_H + ANSWER_H * 2.0;
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+
+    Level29State { entries, target, initially_open, action_style, accent, x, y, card_w }
+}
+
+#[component]
+pub fn Level29() -> Element {
+    let mut state = use_signal(|| random_level29(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let initial_open: Vec<bool> = state.read().initially_open.clone();
+    let mut open = use_signal(move || initial_open);
+    let mut wrong = use_signal(|| false);
+
+    let st = state.read();
+    let entries: Vec<usize> = st.entries.clone();
+    let target = st.target;
+    let action_style = st.action_style;
+    let accent = st.accent.clone();
+    let card_x = st.x;
+    let card_y = st.y;
+    let card_w = st.card_w;
+    drop(st);
+
+    let count = entries.len();
+    let is_wrong = wrong();
+    let cur_open: Vec<bool> = open.read().clone();
+    let target_entry = &FAQ[entries[target]];
+    let instruction = tr("instr.open_and_click", &[&target_entry.question, &target_entry.action]);
+
+    // Section offsets from the fixed header/answer heights used below
+    let inner_w = card_w - CARD_PAD * 2.0;
+    let mut section_y = Vec::with_capacity(count);
+    let mut y = card_y + CARD_PAD + TITLE_H;
+    for is_open in cur_open.iter().take(count) {
+        section_y.push(y);
+        y += HEADER_H + if *is_open { ANSWER_H } else { 0.0 };
+    }
+    let card_h = y + CARD_PAD - card_y;
+
+    // Ground truth — each section carries its revealed action as a child
+    let card_rect = Rect::new(card_x, card_y, card_w, card_h);
+    let children: Vec<UINode> = entries.iter().enumerate().map(|(i, &ei)| {
+        let e = &FAQ[ei];
+        let header_rect = Rect::new(card_x + CARD_PAD, section_y[i], inner_w, HEADER_H);
+        let body = Rect::new(card_x + CARD_PAD, section_y[i] + HEADER_H, inner_w, ANSWER_H);
+        let section = |children| UINode::Accordion(Visual::new(e.question, header_rect), AccordionState { body }, children);
+        let action_rect = Rect::new(
+            card_x + CARD_PAD,
+            section_y[i] + HEADER_H + ANSWER_H - ACTION_H - 12.0,
+            inner_w * 0.5,
+            ACTION_H,
+        );
+        let is_open = cur_open.get(i).copied().unwrap_or(false);
+        if i == target && !is_open {
+            ui_node::accordion_with(e.question, header_rect, body, vec![
+                ui_node::target_button(e.action, action_rect),
+            ])
+        } else if i == target {
+            // Already open: only the revealed action is left to click
+            section(vec![ui_node::target_button(e.action, action_rect)])
+        } else if is_open {
+            section(vec![ui_node::button(e.action, action_rect)])
+        } else {
+            section(Vec::new())
+        }
+    }).collect();
+    let tree = ui_node::card(card_rect, children);
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    let card_style = format!(
+        "position: absolute; left: {}px; top: {}px; width: {}px; padding: {}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); font-family: system-ui, sans-serif; box-sizing: border-box;",
+        card_x, card_y, card_w, CARD_PAD
+    );
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 29"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Accordion FAQ"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "{card_style}",
+
+                    div {
+                        style: "height: {TITLE_H}px; display: flex; flex-direction: column; justify-content: center;",
+                        div {
+                            style: "font-size: 16px; font-weight: 600; color: #111827;",
+                            "Frequently Asked Questions"
+                        }
+                        div {
+                            style: "font-size: 12px; color: #6b7280;",
+                            "{instruction}"
+                        }
+                    }
+
+                    for si in 0..count {
+                        {
+                            let e = &FAQ[entries[si]];
+                            let is_open = cur_open.get(si).copied().unwrap_or(false);
+                            let chevron = if is_open { "\u{2212}" } else { "+" };
+                            let q_color = if is_open { accent.clone() } else { "#111827".to_string() };
+                            let is_target_section = si == target;
+                            let flash = is_wrong && is_target_section;
+                            let action_css = match action_style {
+                                0 => format!("background: none; border: none; padding: 0; color: {}; text-decoration: underline;", accent),
+                                1 => format!("background: white; border: 1px solid {}; border-radius: 6px; padding: 0 14px; color: {};", accent, accent),
+                                _ => format!("background: {}; border: none; border-radius: 6px; padding: 0 14px; color: white;", accent),
+                            };
+                            let action_bg = if flash { "background: #ef4444; color: white;" } else { "" };
+
+                            let wrong_label = e.action.to_string();
+                            rsx! {
+                                div {
+                                    style: "border-bottom: 1px solid #e5e7eb;",
+
+                                    button {
+                                        class: if is_target_section && !is_open { "target" } else { "" },
+                                        "data-label": "{e.question}",
+                                        style: "display: flex; justify-content: space-between; align-items: center; width: 100%; height: {HEADER_H}px; padding: 0; background: none; border: none; cursor: pointer; text-align: left; font-family: system-ui, sans-serif;",
+                                        tabindex: "-1",
+                                        onclick: move |_| {
+                                            if let Some(val) = open.write().get_mut(si) {
+                                                *val = !*val;
+                                            }
+                                        },
+                                        span {
+                                            style: "font-size: 14px; font-weight: 500; color: {q_color};",
+                                            "{e.question}"
+                                        }
+                                        span {
+                                            style: "font-size: 16px; color: #9ca3af;",
+                                            "{chevron}"
+                                        }
+                                    }
+
+                                    if is_open {
+                                        div {
+                                            style: "height: {ANSWER_H}px; display: flex; flex-direction: column; justify-content: space-between; padding-bottom: 12px; box-sizing: border-box;",
+                                            div {
+                                                style: "font-size: 13px; color: #6b7280; line-height: 1.4;",
+                                                "{e.answer}"
+                                            }
+                                            button {
+                                                class: if is_target_section { "target" } else { "" },
+                                                "data-label": "{e.action}",
+                                                style: "align-self: flex-start; height: {ACTION_H}px; font-size: 13px; font-weight: 500; cursor: pointer; font-family: system-ui, sans-serif; {action_css} {action_bg}",
+                                                tabindex: "-1",
+                                                onclick: move |_| {
+                                                    if is_target_section {
+                                                        score.complete();
+                                                        bg.set(random_canvas_bg());
+                                                        let new_st = random_level29(Difficulty::current());
+                                                        open.set(new_st.initially_open.clone());
+                                                        state.set(new_st);
+                                                        wrong.set(false);
+                                                    } else {
+                                                        wrong.set(true);
+                                                        score.wrong(&wrong_label, "");
+                                                        spawn(async move {
+                                                            gloo_timers::future::TimeoutFuture::new(600).await;
+                                                            wrong.set(false);
+                                                        });
+                                                    }
+                                                },
+                                                "{e.action}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: card_x,
+                target_y: card_y,
+                target_w: card_w,
+                target_h: card_h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::Route;
 use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg};
+use super::{Difficulty, fresh_rng, random_canvas_bg};
 
 const WORDS: &[&str] = &[
     "hello", "world", "search", "login", "submit", "click", "enter",
@@ -49,13 +49,14 @@ struct Level3State {
     style_idx: usize,
 }
 
-fn random_level3() -> Level3State {
+fn random_level3(d: &Difficulty) -> Level3State {
     let mut rng = fresh_rng();
     let word_idx = rng.random_range(0..WORDS.len());
     let style_idx = rng.random_range(0..INPUT_STYLES.len());
     let is = &INPUT_STYLES[style_idx];
     let pad = 150.0;
     let (x, y) = super::safe_position(&mut rng, is.width, is.height, pad);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level3State {
         word: WORDS[word_idx].to_string(),
@@ -67,8 +68,8 @@ fn random_level3() -> Level3State {
 
 #[component]
 pub fn Level3() -> Element {
-    let mut state = use_signal(|| random_level3());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level3(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut input_value = use_signal(|| String::new());
     let mut bg = use_signal(|| random_canvas_bg());
 
@@ -144,8 +145,8 @@ pub fn Level3() -> Element {
                             let val = e.value();
                             input_value.set(val.clone());
                             if val == target_word {
-                                score.set(score() + 1);
-                                state.set(random_level3());
+                                score.complete();
+                                state.set(random_level3(Difficulty::current()));
                                 input_value.set(String::new());
                                 bg.set(random_canvas_bg());
                                 document::eval("document.activeElement?.blur()");
@@ -0,0 +1,287 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, use_hotkeys};
+
+const COMMANDS: &[&str] = &[
+    "Format Document", "Toggle Sidebar", "Open Settings", "Git: Commit",
+    "Reload Window", "Go to Line", "Toggle Terminal", "Split Editor",
+    "Close All Editors", "Change Language Mode", "Toggle Word Wrap", "Find in Files",
+    "Sort Lines Ascending", "Rename Symbol", "Fold All", "Select Color Theme",
+];
+
+const HOTKEYS: &[&str] = &["Ctrl+K", "Ctrl+Shift+P", "Ctrl+P"];
+
+const CODE_LINES: &[&str] = &[
+    "fn main() {",
+    "    let config = Config::load()?;",
+    "    let server = Server::bind(config.addr);",
+    "    server.run().await",
+    "}",
+    "",
+    "impl Config {",
+    "    fn load() -> Result<Self> {",
+    "        toml::from_str(&read(\"app.toml\")?)",
+    "    }",
+    "}",
+];
+
+const INPUT_LABEL: &str = "command palette";
+const PALETTE_MAX_ROWS: usize = 6;
+
+struct Level30State {
+    hotkey: &'static str,
+    target: &'static str,
+    mode: u8, // 0=hotkey in instruction, 1=hotkey only in status bar
+    dark: bool,
+    x: f32,
+    y: f32,
+    card_w: f32,
+    card_h: f32,
+}
+
+fn random_level30(d: &Difficulty) -> Level30State {
+    let mut rng = fresh_rng();
+    let hotkey = HOTKEYS[rng.random_range(0..HOTKEYS.len())];
+    let target = COMMANDS[rng.random_range(0..COMMANDS.len())];
+    let mode = rng.random_range(0..2u8);
+    let dark = rng.random_bool(0.5);
+    let card_w = rng.random_range(480.0..=580.0f32);
+    let card_h = 340.0;
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, 40.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level30State { hotkey, target, mode, dark, x, y, card_w, card_h }
+}
+
+/// Commands matching the query; an exact (case-insensitive) match ranks first.
+fn filter_commands(query: &str) -> Vec<&'static str> {
+    let q = query.trim().to_lowercase();
+    let mut matches: Vec<&'static str> = COMMANDS.iter().copied()
+        .filter(|c| c.to_lowercase().contains(&q))
+        .collect();
+    matches.sort_by_key(|c| c.to_lowercase() != q);
+    matches.truncate(PALETTE_MAX_ROWS);
+    matches
+}
+
+#[component]
+pub fn Level30() -> Element {
+    let mut state = use_signal(|| random_level30(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut palette_open = use_signal(|| false);
+    let mut query = use_signal(String::new);
+    let mut wrong = use_signal(|| false);
+
+    use_hotkeys(move |combo| {
+        let hotkey = state.peek().hotkey;
+        if combo == hotkey {
+            let was_open = *palette_open.peek();
+            palette_open.set(!was_open);
+            query.set(String::new());
+        } else if combo == "Escape" {
+            palette_open.set(false);
+        }
+    });
+
+    let mut run_command = move |cmd: &str| {
+        let target = state.peek().target;
+        palette_open.set(false);
+        query.set(String::new());
+        if cmd == target {
+            score.complete();
+            bg.set(random_canvas_bg());
+            state.set(random_level30(Difficulty::current()));
+            wrong.set(false);
+        } else {
+            wrong.set(true);
+            score.wrong(cmd, "");
+            spawn(async move {
+                gloo_timers::future::TimeoutFuture::new(600).await;
+                wrong.set(false);
+            });
+        }
+    };
+
+    let st = state.read();
+    let hotkey = st.hotkey;
+    let target = st.target;
+    let mode = st.mode;
+    let dark = st.dark;
+    let card_x = st.x;
+    let card_y = st.y;
+    let card_w = st.card_w;
+    let card_h = st.card_h;
+    drop(st);
+
+    let is_open = palette_open();
+    let q = query.read().clone();
+    let matches = filter_commands(&q);
+    let is_wrong = wrong();
+
+    let instruction = match mode {
+        1 => tr("instr.run_from_palette", &[&target]),
+        _ => tr("instr.press_and_run", &[&hotkey, &target]),
+    };
+
+    let (surface, sidebar, text, muted, border) = if dark {
+        ("#1e1e1e", "#252526", "#d4d4d4", "#858585", "#3c3c3c")
+    } else {
+        ("#ffffff", "#f3f3f3", "#1f2937", "#6b7280", "#e5e7eb")
+    };
+    let status_bg = if is_wrong { "#ef4444" } else { "#4f46e5" };
+
+    // Palette geometry (viewport coords) — matches the absolute CSS below
+    let palette_w = (card_w - 60.0).min(420.0);
+    let palette_x = card_x + (card_w - palette_w) / 2.0;
+    let palette_y = card_y + 40.0;
+    let input_rect = Rect::new(palette_x + 10.0, palette_y + 10.0, palette_w - 20.0, 36.0);
+
+    // Ground truth
+    let card_rect = Rect::new(card_x, card_y, card_w, card_h);
+    let mut palette = ui_node::command_palette(
+        INPUT_LABEL,
+        card_rect,
+        hotkey,
+        COMMANDS.iter().map(|c| c.to_string()).collect(),
+        target,
+        input_rect,
+    );
+    if let ui_node::UINode::CommandPalette(_, ps) = &mut palette {
+        ps.is_open = is_open;
+        ps.query = q.clone();
+    }
+    let tree = ui_node::card(card_rect, vec![palette]);
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 30"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Command Palette"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                // Instruction
+                div {
+                    style: "position: absolute; left: 0; right: 0; top: 16px; text-align: center; z-index: 30;",
+                    div {
+                        style: "display: inline-block; background: rgba(0,0,0,0.7); padding: 8px 16px; border-radius: 8px; color: white; font-size: 14px; font-weight: 500;",
+                        "{instruction}"
+                    }
+                }
+
+                // Editor window
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {card_w}px; height: {card_h}px; background: {surface}; border: 1px solid {border}; border-radius: 8px; overflow: hidden; display: flex; flex-direction: column; box-shadow: 0 8px 32px rgba(0,0,0,0.35);",
+
+                    // Tab strip
+                    div {
+                        style: "height: 32px; background: {sidebar}; border-bottom: 1px solid {border}; display: flex; align-items: center; gap: 16px; padding: 0 12px; font-size: 12px; color: {muted}; font-family: monospace;",
+                        span { style: "color: {text};", "main.rs" }
+                        span { "config.rs" }
+                        span { "Cargo.toml" }
+                    }
+
+                    div {
+                        style: "flex: 1; display: flex; min-height: 0;",
+                        div {
+                            style: "width: 44px; background: {sidebar}; border-right: 1px solid {border};",
+                        }
+                        div {
+                            style: "flex: 1; padding: 10px 14px; font-family: monospace; font-size: 12px; line-height: 20px; color: {text}; white-space: pre; overflow: hidden;",
+                            for (li, line) in CODE_LINES.iter().enumerate() {
+                                div {
+                                    span { style: "color: {muted}; display: inline-block; width: 24px;", "{li + 1}" }
+                                    "{line}"
+                                }
+                            }
+                        }
+                    }
+
+                    // Status bar shows the shortcut
+                    div {
+                        style: "height: 24px; background: {status_bg}; color: white; font-size: 11px; display: flex; align-items: center; justify-content: space-between; padding: 0 10px; font-family: monospace; transition: background 0.15s;",
+                        span { "Rust \u{2022} UTF-8" }
+                        span { "Command Palette: {hotkey}" }
+                    }
+                }
+
+                // Palette overlay — rendered only after the hotkey
+                if is_open {
+                    div {
+                        style: "position: absolute; left: {palette_x}px; top: {palette_y}px; width: {palette_w}px; background: {surface}; border: 1px solid {border}; border-radius: 8px; box-shadow: 0 12px 40px rgba(0,0,0,0.45); padding: 10px; box-sizing: border-box; z-index: 20;",
+                        input {
+                            class: "target",
+                            "data-label": INPUT_LABEL,
+                            r#type: "text",
+                            placeholder: "Type a command",
+                            value: "{q}",
+                            style: "width: 100%; height: 36px; padding: 0 10px; border: 1px solid #4f46e5; border-radius: 6px; background: {sidebar}; color: {text}; font-size: 14px; font-family: system-ui, sans-serif; box-sizing: border-box; outline: none;",
+                            onmounted: move |e| async move {
+                                let _ = e.set_focus(true).await;
+                            },
+                            oninput: move |e: Event<FormData>| query.set(e.value()),
+                            onkeydown: move |e: Event<KeyboardData>| {
+                                if e.key() == Key::Enter {
+                                    let q = query.peek().clone();
+                                    if let Some(cmd) = filter_commands(&q).first() {
+                                        run_command(cmd);
+                                    }
+                                }
+                            },
+                        }
+                        for (mi, cmd) in matches.iter().enumerate() {
+                            {
+                                let cmd: &'static str = cmd;
+                                let row_bg = if mi == 0 { "rgba(79,70,229,0.18)" } else { "transparent" };
+                                rsx! {
+                                    div {
+                                        "data-label": "{cmd}",
+                                        style: "margin-top: 4px; padding: 6px 10px; border-radius: 4px; background: {row_bg}; color: {text}; font-size: 13px; cursor: pointer;",
+                                        onclick: move |_| run_command(cmd),
+                                        "{cmd}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: input_rect.x,
+                target_y: input_rect.y,
+                target_w: input_rect.w,
+                target_h: input_rect.h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
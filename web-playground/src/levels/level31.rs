@@ -0,0 +1,311 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, Visual, UINode, SliderState};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const SUBJECT_COLORS: &[&str] = &[
+    "#e74c3c", "#3498db", "#2ecc71", "#f39c12", "#9b59b6",
+    "#1abc9c", "#e67e22", "#34495e",
+];
+
+const TRACK_COLORS: &[&str] = &[
+    "#4f46e5", "#2563eb", "#0891b2", "#059669", "#d97706",
+    "#dc2626", "#7c3aed", "#db2777",
+];
+
+const ZOOM_MIN: i32 = 10;
+const ZOOM_MAX: i32 = 400;
+const ZOOM_STEP: i32 = 5;
+
+const CARD_PAD: f32 = 16.0;
+const INSTR_H: f32 = 32.0;
+const FRAME_W: f32 = 240.0;
+const FRAME_H: f32 = 160.0;
+const FRAME_GAP: f32 = 16.0;
+const LABEL_H: f32 = 24.0;
+const TRACK_H: f32 = 28.0;
+const THUMB_W: f32 = 18.0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Goal {
+    /// Whole circle visible and reasonably large.
+    Fit,
+    /// Square covers the entire frame with no background showing.
+    Cover,
+}
+
+struct Level31State {
+    goal: Goal,
+    /// Natural size of the subject at 100% zoom.
+    subject_px: f32,
+    band: (i32, i32),
+    start_val: i32,
+    subject_color: String,
+    track_color: String,
+    x: f32,
+    y: f32,
+    card_w: f32,
+}
+
+fn snap_up(v: f32) -> i32 {
+    ((v / ZOOM_STEP as f32).ceil() as i32 * ZOOM_STEP).clamp(ZOOM_MIN, ZOOM_MAX)
+}
+
+fn snap_down(v: f32) -> i32 {
+    ((v / ZOOM_STEP as f32).floor() as i32 * ZOOM_STEP).clamp(ZOOM_MIN, ZOOM_MAX)
+}
+
+/// Acceptable zoom band (inclusive) for a subject of `px` natural size.
+fn zoom_band(goal: Goal, px: f32) -> (i32, i32) {
+    match goal {
+        // Diameter between 60% of the short side and just inside the frame
+        Goal::Fit => (
+            snap_up(FRAME_H * 0.6 / px * 100.0),
+            snap_down((FRAME_H - 4.0) / px * 100.0),
+        ),
+        // Side at least the long side of the frame, without zooming far past it
+        Goal::Cover => (
+            snap_up(FRAME_W / px * 100.0),
+            snap_down(FRAME_W * 1.2 / px * 100.0),
+        ),
+    }
+}
+
+fn random_level31(d: &Difficulty) -> Level31State {
+    let mut rng = fresh_rng();
+    let goal = if rng.random_bool(0.5) { Goal::Fit } else { Goal::Cover };
+    let subject_px = rng.random_range(70.0..=130.0f32);
+    let band = zoom_band(goal, subject_px);
+
+    // Start outside the band so the slider always has to move
+    let mut start_val = band.0;
+    while start_val >= band.0 && start_val <= band.1 {
+        start_val = ZOOM_MIN + rng.random_range(0..=(ZOOM_MAX - ZOOM_MIN) / ZOOM_STEP) * ZOOM_STEP;
+    }
+
+    let subject_color = SUBJECT_COLORS[rng.random_range(0..SUBJECT_COLORS.len())].to_string();
+    let track_color = TRACK_COLORS[rng.random_range(0..TRACK_COLORS.len())].to_string();
+    let card_w = rng.random_range(300.0..=380.0f32);
+    let card_h = CARD_PAD * 2.0 + INSTR_H + FRAME_H + FRAME_GAP + LABEL_H + TRACK_H + 60.0;
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+
+    Level31State { goal, subject_px, band, start_val, subject_color, track_color, x, y, card_w }
+}
+
+#[component]
+pub fn Level31() -> Element {
+    let mut state = use_signal(|| random_level31(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let initial_val = state.read().start_val;
+    let mut value = use_signal(move || initial_val);
+    let mut dragging = use_signal(|| false);
+    let mut wrong = use_signal(|| false);
+
+    let st = state.read();
+    let goal = st.goal;
+    let subject_px = st.subject_px;
+    let (band_lo, band_hi) = st.band;
+    let subject_color = st.subject_color.clone();
+    let track_color = st.track_color.clone();
+    let card_x = st.x;
+    let card_y = st.y;
+    let card_w = st.card_w;
+    drop(st);
+
+    let val = value();
+    let is_wrong = wrong();
+    let is_dragging = dragging();
+
+    let instruction = match goal {
+        Goal::Fit => tr("instr.zoom_fit", &[]),
+        Goal::Cover => tr("instr.zoom_cover", &[]),
+    };
+
+    // Ground truth aims for the middle of the accepted band
+    let target_val = snap_down((band_lo + band_hi) as f32 / 2.0);
+    let tolerance = (target_val - band_lo).min(band_hi - target_val);
+
+    let track_w = card_w - CARD_PAD * 2.0;
+    let usable_w = track_w - THUMB_W;
+    let ratio_of = |v: i32| (v - ZOOM_MIN) as f32 / (ZOOM_MAX - ZOOM_MIN) as f32;
+    let thumb_left = ratio_of(val) * usable_w;
+    let target_thumb_left = ratio_of(target_val) * usable_w;
+    let fill_w = thumb_left + THUMB_W / 2.0;
+
+    let track_x = card_x + CARD_PAD;
+    let track_y = card_y + CARD_PAD + INSTR_H + FRAME_H + FRAME_GAP + LABEL_H;
+    let card_h = CARD_PAD * 2.0 + INSTR_H + FRAME_H + FRAME_GAP + LABEL_H + TRACK_H + 60.0;
+
+    let slider = UINode::Slider(
+        Visual::new("Zoom", Rect::new(track_x, track_y, track_w, TRACK_H))
+            .color(&track_color)
+            .target(),
+        SliderState {
+            min: ZOOM_MIN,
+            max: ZOOM_MAX,
+            step: ZOOM_STEP,
+            current_val: val,
+            target_val,
+            thumb_rect: Rect::new(track_x + thumb_left, track_y + 4.0, THUMB_W, 20.0),
+            target_thumb_rect: Rect::new(track_x + target_thumb_left, track_y + 4.0, THUMB_W, 20.0),
+            tolerance,
+            native: false,
+        },
+    );
+    let tree = ui_node::form(Rect::new(card_x, card_y, card_w, card_h), "Apply", vec![slider]);
+
+    // Live preview — subject scaled around the frame centre
+    let size = subject_px * val as f32 / 100.0;
+    let subject_left = (FRAME_W - size) / 2.0;
+    let subject_top = (FRAME_H - size) / 2.0;
+    let subject_radius = if goal == Goal::Fit { "50%" } else { "6px" };
+    let frame_x = (track_w - FRAME_W) / 2.0;
+
+    let card_style = format!(
+        "position: absolute; left: {}px; top: {}px; width: {}px; padding: {}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); font-family: system-ui, sans-serif; box-sizing: border-box;",
+        card_x, card_y, card_w, CARD_PAD
+    );
+    let submit_bg = if is_wrong { "#ef4444" } else { "#4f46e5" };
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    let set_from_x = move |mx: f32| {
+        let raw = ((mx - THUMB_W / 2.0) / usable_w).clamp(0.0, 1.0);
+        let steps = (ZOOM_MAX - ZOOM_MIN) / ZOOM_STEP;
+        ZOOM_MIN + (raw * steps as f32).round() as i32 * ZOOM_STEP
+    };
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 31"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Zoom Preview"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "{card_style}",
+
+                    div {
+                        style: "height: {INSTR_H}px; font-size: 14px; color: #374151; font-weight: 500;",
+                        "{instruction}"
+                    }
+
+                    // Preview frame
+                    div {
+                        style: "position: relative; left: {frame_x}px; width: {FRAME_W}px; height: {FRAME_H}px; margin-bottom: {FRAME_GAP}px; background: repeating-conic-gradient(#f3f4f6 0% 25%, #e5e7eb 0% 50%) 0 0 / 16px 16px; border: 2px solid #111827; box-sizing: content-box; overflow: hidden;",
+                        div {
+                            style: "position: absolute; left: {subject_left}px; top: {subject_top}px; width: {size}px; height: {size}px; background: {subject_color}; border-radius: {subject_radius}; transition: all 0.05s;",
+                        }
+                    }
+
+                    div {
+                        style: "height: {LABEL_H}px; display: flex; justify-content: space-between; font-size: 12px; color: #374151;",
+                        span { style: "font-weight: 500;", "Zoom" }
+                        span { style: "font-family: monospace; color: #6b7280;", "{val}%" }
+                    }
+
+                    // Track
+                    div {
+                        style: "position: relative; height: {TRACK_H}px; cursor: pointer;",
+                        div {
+                            style: "position: absolute; top: 10px; left: 0; right: 0; height: 8px; background: #e5e7eb; border-radius: 4px; pointer-events: none;",
+                        }
+                        div {
+                            style: "position: absolute; top: 10px; left: 0; width: {fill_w}px; height: 8px; background: {track_color}; border-radius: 4px; pointer-events: none;",
+                        }
+                        div {
+                            style: "position: absolute; top: 4px; left: {thumb_left}px; width: {THUMB_W}px; height: 20px; background: white; border: 2px solid {track_color}; border-radius: 10px; box-sizing: border-box; box-shadow: 0 1px 4px rgba(0,0,0,0.2); pointer-events: none;",
+                        }
+                        div {
+                            class: "target",
+                            "data-label": "drag-from: Zoom",
+                            style: "position: absolute; top: 4px; left: {thumb_left}px; width: {THUMB_W}px; height: 20px; pointer-events: none;",
+                        }
+                        div {
+                            class: "target",
+                            "data-label": "drag-to: Zoom",
+                            style: "position: absolute; top: 4px; left: {target_thumb_left}px; width: {THUMB_W}px; height: 20px; pointer-events: none;",
+                        }
+                        div {
+                            style: "position: absolute; inset: 0; z-index: 1;",
+                            onmousedown: move |e: Event<MouseData>| {
+                                e.prevent_default();
+                                dragging.set(true);
+                                value.set(set_from_x(e.element_coordinates().x as f32));
+                            },
+                            onmousemove: move |e: Event<MouseData>| {
+                                if is_dragging {
+                                    value.set(set_from_x(e.element_coordinates().x as f32));
+                                }
+                            },
+                            onmouseup: move |_| dragging.set(false),
+                            onmouseleave: move |_| dragging.set(false),
+                        }
+                    }
+
+                    button {
+                        class: "target",
+                        "data-label": "Apply",
+                        style: "width: 100%; padding: 10px; margin-top: 20px; background: {submit_bg}; color: white; border: none; border-radius: 6px; font-size: 14px; font-weight: 600; font-family: system-ui, sans-serif; cursor: pointer; transition: background 0.15s;",
+                        tabindex: "-1",
+                        onclick: move |_| {
+                            let v = value();
+                            if v >= band_lo && v <= band_hi {
+                                score.complete();
+                                bg.set(random_canvas_bg());
+                                let new_st = random_level31(Difficulty::current());
+                                value.set(new_st.start_val);
+                                state.set(new_st);
+                                dragging.set(false);
+                                wrong.set(false);
+                            } else {
+                                wrong.set(true);
+                                score.wrong("Zoom", &v.to_string());
+                                spawn(async move {
+                                    gloo_timers::future::TimeoutFuture::new(600).await;
+                                    wrong.set(false);
+                                });
+                            }
+                        },
+                        "Apply"
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: String::new(),
+                target_x: track_x,
+                target_y: track_y,
+                target_w: track_w,
+                target_h: TRACK_H,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
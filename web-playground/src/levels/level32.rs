@@ -0,0 +1,321 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, UINode, Visual, InputState};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const PHRASES: &[&str] = &[
+    "quarterly report", "meeting notes", "draft reply", "release plan",
+    "thank you", "action items", "see attached", "final version",
+    "todo list", "weekly update", "project kickoff", "read carefully",
+];
+
+const STYLES: &[&str] = &["Bold", "Italic", "Underline"];
+const STYLE_GLYPHS: &[&str] = &["B", "I", "U"];
+const SIZES: &[&str] = &["12px", "14px", "18px", "24px", "32px"];
+const DEFAULT_SIZE: usize = 1;
+
+const SIZE_LABEL: &str = "Font size";
+const EDITOR_LABEL: &str = "editor";
+const SUBMIT_LABEL: &str = "Done";
+
+const CARD_PAD: f32 = 16.0;
+const INSTR_H: f32 = 44.0;
+const TOOLBAR_H: f32 = 40.0;
+const TOOL_W: f32 = 32.0;
+const TOOL_GAP: f32 = 6.0;
+const SIZE_W: f32 = 84.0;
+const EDITOR_GAP: f32 = 10.0;
+const EDITOR_H: f32 = 140.0;
+const SUBMIT_GAP: f32 = 14.0;
+const SUBMIT_H: f32 = 36.0;
+const OPTION_H: f32 = 30.0;
+
+struct Level32State {
+    initial_on: [bool; 3],
+    target_on: [bool; 3],
+    target_size: usize,
+    phrase: &'static str,
+    x: f32,
+    y: f32,
+    card_w: f32,
+}
+
+fn card_height() -> f32 {
+    CARD_PAD * 2.0 + INSTR_H + TOOLBAR_H + EDITOR_GAP + EDITOR_H + SUBMIT_GAP + SUBMIT_H
+}
+
+fn random_level32(d: &Difficulty) -> Level32State {
+    let mut rng = fresh_rng();
+    let initial_on = [rng.random_bool(0.3), rng.random_bool(0.3), rng.random_bool(0.3)];
+    // At least one toggle has to change
+    let mut target_on = initial_on;
+    while target_on == initial_on {
+        target_on = [rng.random_bool(0.5), rng.random_bool(0.5), rng.random_bool(0.5)];
+    }
+    let mut target_size = DEFAULT_SIZE;
+    while target_size == DEFAULT_SIZE {
+        target_size = rng.random_range(0..SIZES.len());
+    }
+    let phrase = PHRASES[rng.random_range(0..PHRASES.len())];
+    let card_w = rng.random_range(440.0..=520.0f32);
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, card_w, card_height(), 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level32State { initial_on, target_on, target_size, phrase, x, y, card_w }
+}
+
+#[component]
+pub fn Level32() -> Element {
+    let mut state = use_signal(|| random_level32(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let initial_on = state.read().initial_on;
+    let mut active = use_signal(move || initial_on);
+    let mut size = use_signal(|| DEFAULT_SIZE);
+    let mut size_open = use_signal(|| false);
+    let mut text = use_signal(String::new);
+    let mut wrong = use_signal(|| false);
+
+    let st = state.read();
+    let target_on = st.target_on;
+    let target_size = st.target_size;
+    let phrase = st.phrase;
+    let card_x = st.x;
+    let card_y = st.y;
+    let card_w = st.card_w;
+    drop(st);
+
+    let cur_on = active();
+    let cur_size = size();
+    let is_open = size_open();
+    let cur_text = text.read().clone();
+    let is_wrong = wrong();
+
+    let wanted: Vec<&str> = (0..3).filter(|&i| target_on[i]).map(|i| STYLES[i]).collect();
+    let unwanted: Vec<&str> = (0..3).filter(|&i| !target_on[i]).map(|i| STYLES[i]).collect();
+    let style_part = match (wanted.is_empty(), unwanted.is_empty()) {
+        (true, _) => "plain text".to_string(),
+        (false, true) => wanted.join(" + "),
+        (false, false) => format!("{} (no {})", wanted.join(" + "), unwanted.join("/")),
+    };
+    let instruction = tr("instr.format_text", &[&style_part, &SIZES[target_size], &phrase, &SUBMIT_LABEL]);
+
+    // Geometry (viewport coords) — matches the fixed-size CSS below
+    let inner_x = card_x + CARD_PAD;
+    let inner_w = card_w - CARD_PAD * 2.0;
+    let toolbar_y = card_y + CARD_PAD + INSTR_H;
+    let tool_rect = |i: usize| Rect::new(
+        inner_x + 4.0 + i as f32 * (TOOL_W + TOOL_GAP),
+        toolbar_y + (TOOLBAR_H - TOOL_W) / 2.0,
+        TOOL_W,
+        TOOL_W,
+    );
+    let size_left = 4.0 + 3.0 * (TOOL_W + TOOL_GAP) + 12.0;
+    let size_rect = Rect::new(inner_x + size_left, toolbar_y + (TOOLBAR_H - TOOL_W) / 2.0, SIZE_W, TOOL_W);
+    let editor_y = toolbar_y + TOOLBAR_H + EDITOR_GAP;
+    let editor_rect = Rect::new(inner_x, editor_y, inner_w, EDITOR_H);
+    let card_h = card_height();
+
+    // Ground truth — toggles carry their desired state, so satisfied ones emit no click
+    let mut children: Vec<UINode> = (0..3)
+        .map(|i| ui_node::toggle_to(STYLES[i], tool_rect(i), cur_on[i], target_on[i]))
+        .collect();
+    let mut size_node = ui_node::dropdown_with_trigger(
+        SIZE_LABEL,
+        size_rect,
+        SIZES.iter().map(|s| s.to_string()).collect(),
+        SIZES[target_size],
+        SIZE_LABEL,
+    );
+    if let UINode::Dropdown(_, ds) = &mut size_node {
+        ds.selected = Some(SIZES[cur_size].to_string());
+    }
+    children.push(size_node);
+    children.push(UINode::TextInput(
+        Visual::new(EDITOR_LABEL, editor_rect).target(),
+        InputState {
+            placeholder: "Start typing...".into(),
+            current_value: cur_text.clone(),
+            target_value: phrase.to_string(),
+        },
+    ));
+    let tree = ui_node::form(Rect::new(card_x, card_y, card_w, card_h), SUBMIT_LABEL, children);
+    let complete = tree.check().is_complete();
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    let card_style = format!(
+        "position: absolute; left: {}px; top: {}px; width: {}px; padding: {}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); font-family: system-ui, sans-serif; box-sizing: border-box;",
+        card_x, card_y, card_w, CARD_PAD
+    );
+    let editor_weight = if cur_on[0] { "700" } else { "400" };
+    let editor_slant = if cur_on[1] { "italic" } else { "normal" };
+    let editor_deco = if cur_on[2] { "underline" } else { "none" };
+    let editor_size = SIZES[cur_size];
+    let submit_bg = if is_wrong { "#ef4444" } else { "#4f46e5" };
+    let panel_top = TOOLBAR_H - (TOOLBAR_H - TOOL_W) / 2.0 + 2.0;
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 32"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Rich Text Toolbar"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "{card_style}",
+
+                    div {
+                        style: "height: {INSTR_H}px; font-size: 14px; color: #374151; font-weight: 500; line-height: 1.4;",
+                        "{instruction}"
+                    }
+
+                    // Toolbar
+                    div {
+                        style: "position: relative; height: {TOOLBAR_H}px; background: #f3f4f6; border: 1px solid #e5e7eb; border-bottom: none; border-radius: 6px 6px 0 0; box-sizing: border-box;",
+
+                        for i in 0..3usize {
+                            {
+                                let rect = tool_rect(i);
+                                let left = rect.x - inner_x;
+                                let top = rect.y - toolbar_y;
+                                let on = cur_on[i];
+                                let btn_bg = if on { "#dbeafe" } else { "transparent" };
+                                let btn_border = if on { "#93c5fd" } else { "transparent" };
+                                let glyph_css = match i {
+                                    0 => "font-weight: 700;",
+                                    1 => "font-style: italic; font-family: Georgia, serif;",
+                                    _ => "text-decoration: underline;",
+                                };
+                                rsx! {
+                                    button {
+                                        class: "target",
+                                        "data-label": STYLES[i],
+                                        title: STYLES[i],
+                                        "aria-pressed": if on { "true" } else { "false" },
+                                        style: "position: absolute; left: {left}px; top: {top}px; width: {TOOL_W}px; height: {TOOL_W}px; background: {btn_bg}; border: 1px solid {btn_border}; border-radius: 4px; color: #111827; font-size: 15px; cursor: pointer; padding: 0; {glyph_css}",
+                                        tabindex: "-1",
+                                        onclick: move |_| {
+                                            let mut a = active.write();
+                                            a[i] = !a[i];
+                                        },
+                                        "{STYLE_GLYPHS[i]}"
+                                    }
+                                }
+                            }
+                        }
+
+                        div {
+                            style: "position: absolute; left: {size_left - 7.0}px; top: 8px; width: 1px; height: 24px; background: #d1d5db;",
+                        }
+
+                        // Font size dropdown
+                        div {
+                            class: if !is_open { "target" } else { "" },
+                            "data-label": SIZE_LABEL,
+                            title: SIZE_LABEL,
+                            style: "position: absolute; left: {size_left}px; top: {(TOOLBAR_H - TOOL_W) / 2.0}px; width: {SIZE_W}px; height: {TOOL_W}px; background: white; border: 1px solid #d1d5db; border-radius: 4px; box-sizing: border-box; display: flex; align-items: center; justify-content: space-between; padding: 0 8px; font-size: 13px; color: #111827; cursor: pointer; user-select: none;",
+                            onclick: move |_| size_open.set(!is_open),
+                            span { "{editor_size}" }
+                            span { style: "color: #6b7280; font-size: 10px;", "\u{25BC}" }
+                        }
+
+                        if is_open {
+                            div {
+                                style: "position: absolute; left: {size_left}px; top: {panel_top}px; width: {SIZE_W}px; background: white; border: 1px solid #d1d5db; border-radius: 4px; box-shadow: 0 4px 12px rgba(0,0,0,0.15); z-index: 10;",
+                                for (si, label) in SIZES.iter().enumerate() {
+                                    {
+                                        let row_bg = if si == cur_size { "#eef2ff" } else { "white" };
+                                        rsx! {
+                                            div {
+                                                class: if si == target_size { "target" } else { "" },
+                                                "data-label": *label,
+                                                style: "height: {OPTION_H}px; line-height: {OPTION_H}px; padding: 0 8px; font-size: 13px; color: #111827; background: {row_bg}; cursor: pointer;",
+                                                onclick: move |_| {
+                                                    size.set(si);
+                                                    size_open.set(false);
+                                                },
+                                                "{label}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Editor surface
+                    textarea {
+                        class: "target",
+                        "data-label": EDITOR_LABEL,
+                        placeholder: "Start typing...",
+                        value: "{cur_text}",
+                        style: "display: block; width: 100%; height: {EDITOR_H}px; margin-bottom: {SUBMIT_GAP}px; padding: 10px 12px; border: 1px solid #e5e7eb; border-radius: 0 0 6px 6px; box-sizing: border-box; resize: none; outline: none; font-family: system-ui, sans-serif; color: #111827; font-size: {editor_size}; font-weight: {editor_weight}; font-style: {editor_slant}; text-decoration: {editor_deco};",
+                        oninput: move |e: Event<FormData>| text.set(e.value()),
+                    }
+
+                    button {
+                        class: "target",
+                        "data-label": SUBMIT_LABEL,
+                        style: "width: 100%; height: {SUBMIT_H}px; background: {submit_bg}; color: white; border: none; border-radius: 6px; font-size: 14px; font-weight: 600; font-family: system-ui, sans-serif; cursor: pointer; transition: background 0.15s;",
+                        tabindex: "-1",
+                        onclick: move |_| {
+                            if complete {
+                                score.complete();
+                                bg.set(random_canvas_bg());
+                                let new_st = random_level32(Difficulty::current());
+                                active.set(new_st.initial_on);
+                                state.set(new_st);
+                                size.set(DEFAULT_SIZE);
+                                size_open.set(false);
+                                text.set(String::new());
+                                wrong.set(false);
+                            } else {
+                                wrong.set(true);
+                                score.wrong(SUBMIT_LABEL, &text.peek());
+                                spawn(async move {
+                                    gloo_timers::future::TimeoutFuture::new(600).await;
+                                    wrong.set(false);
+                                });
+                            }
+                        },
+                        "{SUBMIT_LABEL}"
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: card_x,
+                target_y: card_y,
+                target_w: card_w,
+                target_h: card_h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, UINode};
+use super::{Difficulty, fresh_rng, random_canvas_bg};
+
+/// (singular, plural, emoji)
+const OBJECTS: &[(&str, &str, &str)] = &[
+    ("car", "cars", "\u{1F697}"),
+    ("bicycle", "bicycles", "\u{1F6B2}"),
+    ("traffic light", "traffic lights", "\u{1F6A6}"),
+    ("tree", "trees", "\u{1F333}"),
+    ("house", "houses", "\u{1F3E0}"),
+    ("bus", "buses", "\u{1F68C}"),
+    ("boat", "boats", "\u{26F5}"),
+    ("cat", "cats", "\u{1F408}"),
+    ("dog", "dogs", "\u{1F415}"),
+];
+
+/// (name, background)
+const TILE_COLORS: &[(&str, &str)] = &[
+    ("red", "#fecaca"),
+    ("blue", "#bfdbfe"),
+    ("green", "#bbf7d0"),
+    ("yellow", "#fef08a"),
+    ("purple", "#e9d5ff"),
+];
+
+const COLS: usize = 3;
+const TILE_GAP: f32 = 4.0;
+const CARD_PAD: f32 = 8.0;
+const HEADER_H: f32 = 72.0;
+const FOOTER_H: f32 = 52.0;
+const SUBMIT_LABEL: &str = "Verify";
+
+struct Tile {
+    object: usize,
+    color: usize,
+}
+
+struct Level33State {
+    tiles: Vec<Tile>,
+    by_color: bool,
+    target: usize, // object index, or color index when by_color
+    show_captions: bool,
+    tile: f32,
+    x: f32,
+    y: f32,
+}
+
+impl Level33State {
+    fn is_match(&self, i: usize) -> bool {
+        let t = &self.tiles[i];
+        if self.by_color { t.color == self.target } else { t.object == self.target }
+    }
+}
+
+/// Random index in `0..len` other than `skip`.
+fn pick_other(rng: &mut impl Rng, len: usize, skip: usize) -> usize {
+    loop {
+        let v = rng.random_range(0..len);
+        if v != skip { return v; }
+    }
+}
+
+fn random_level33(d: &Difficulty) -> Level33State {
+    let mut rng = fresh_rng();
+    let by_color = rng.random_bool(0.3);
+    let target = if by_color {
+        rng.random_range(0..TILE_COLORS.len())
+    } else {
+        rng.random_range(0..OBJECTS.len())
+    };
+
+    // 2-4 matching tiles, placed at random cells
+    let n_match = d.count(&mut rng, 2..=4);
+    let mut cells: Vec<usize> = (0..COLS * COLS).collect();
+    let mut matching = Vec::with_capacity(n_match);
+    for _ in 0..n_match {
+        matching.push(cells.remove(rng.random_range(0..cells.len())));
+    }
+
+    let tiles: Vec<Tile> = (0..COLS * COLS).map(|i| {
+        let hit = matching.contains(&i);
+        if by_color {
+            Tile {
+                object: rng.random_range(0..OBJECTS.len()),
+                color: if hit { target } else { pick_other(&mut rng, TILE_COLORS.len(), target) },
+            }
+        } else {
+            Tile {
+                object: if hit { target } else { pick_other(&mut rng, OBJECTS.len(), target) },
+                color: rng.random_range(0..TILE_COLORS.len()),
+            }
+        }
+    }).collect();
+
+    let show_captions = rng.random_bool(0.5);
+    let tile = rng.random_range(80.0..=100.0f32);
+    let grid = tile * COLS as f32 + TILE_GAP * (COLS - 1) as f32;
+    let card_w = grid + CARD_PAD * 2.0;
+    let card_h = HEADER_H + grid + FOOTER_H + CARD_PAD * 2.0;
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+
+    Level33State { tiles, by_color, target, show_captions, tile, x, y }
+}
+
+#[component]
+pub fn Level33() -> Element {
+    let mut state = use_signal(|| random_level33(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut selected = use_signal(|| vec![false; COLS * COLS]);
+    let mut wrong = use_signal(|| false);
+
+    let st = state.read();
+    let tile = st.tile;
+    let card_x = st.x;
+    let card_y = st.y;
+    let show_captions = st.show_captions;
+    let prompt = if st.by_color {
+        format!("a {} background", TILE_COLORS[st.target].0)
+    } else {
+        OBJECTS[st.target].1.to_string()
+    };
+    let tile_data: Vec<(&'static str, &'static str, &'static str, bool)> = (0..st.tiles.len())
+        .map(|i| {
+            let t = &st.tiles[i];
+            (OBJECTS[t.object].0, OBJECTS[t.object].2, TILE_COLORS[t.color].1, st.is_match(i))
+        })
+        .collect();
+    let contents: Vec<String> = st.tiles.iter()
+        .map(|t| format!("{} {}", TILE_COLORS[t.color].0, OBJECTS[t.object].0))
+        .collect();
+    drop(st);
+
+    let sel = selected.read().clone();
+    let is_wrong = wrong();
+
+    let grid = tile * COLS as f32 + TILE_GAP * (COLS - 1) as f32;
+    let card_w = grid + CARD_PAD * 2.0;
+    let card_h = HEADER_H + grid + FOOTER_H + CARD_PAD * 2.0;
+    let grid_x = card_x + CARD_PAD;
+    let grid_y = card_y + CARD_PAD + HEADER_H;
+    let tile_rect = |i: usize| Rect::new(
+        grid_x + (i % COLS) as f32 * (tile + TILE_GAP),
+        grid_y + (i / COLS) as f32 * (tile + TILE_GAP),
+        tile,
+        tile,
+    );
+
+    // Ground truth
+    let mut grid_node = ui_node::tile_grid(
+        "captcha",
+        Rect::new(grid_x, grid_y, grid, grid),
+        &prompt,
+        COLS,
+        tile_data.iter().enumerate()
+            .map(|(i, d)| (contents[i].clone(), tile_rect(i), d.3))
+            .collect(),
+    );
+    if let UINode::TileGrid(_, gs) = &mut grid_node {
+        for (t, &s) in gs.tiles.iter_mut().zip(sel.iter()) {
+            t.is_selected = s;
+        }
+    }
+    let tree = ui_node::form(Rect::new(card_x, card_y, card_w, card_h), SUBMIT_LABEL, vec![grid_node]);
+    let complete = tree.check().is_complete();
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    let verify_bg = if is_wrong { "#ef4444" } else { "#1a73e8" };
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 33"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Captcha Grid"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {card_w}px; padding: {CARD_PAD}px; background: white; border: 1px solid #d1d5db; border-radius: 4px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); font-family: system-ui, sans-serif; box-sizing: border-box;",
+
+                    // Prompt header
+                    div {
+                        style: "height: {HEADER_H}px; background: #1a73e8; color: white; padding: 12px 16px; box-sizing: border-box; display: flex; flex-direction: column; justify-content: center;",
+                        span { style: "font-size: 13px;", "Select all images with" }
+                        span { style: "font-size: 22px; font-weight: 700;", "{prompt}" }
+                    }
+
+                    // Grid
+                    div {
+                        style: "position: relative; width: {grid}px; height: {grid}px;",
+                        for (i, (name, emoji, color, _)) in tile_data.iter().enumerate() {
+                            {
+                                let r = tile_rect(i);
+                                let left = r.x - grid_x;
+                                let top = r.y - grid_y;
+                                let is_sel = sel.get(i).copied().unwrap_or(false);
+                                let inset = if is_sel { 8.0 } else { 0.0 };
+                                let emoji_size = tile * 0.45;
+                                let label = format!("tile {}", i + 1);
+                                rsx! {
+                                    div {
+                                        class: "target",
+                                        "data-label": "{label}",
+                                        style: "position: absolute; left: {left}px; top: {top}px; width: {tile}px; height: {tile}px; cursor: pointer; user-select: none;",
+                                        onclick: move |_| {
+                                            if let Some(s) = selected.write().get_mut(i) {
+                                                *s = !*s;
+                                            }
+                                        },
+                                        div {
+                                            style: "position: absolute; inset: {inset}px; background: {color}; display: flex; flex-direction: column; align-items: center; justify-content: center; transition: inset 0.1s;",
+                                            span { style: "font-size: {emoji_size}px; line-height: 1;", "{emoji}" }
+                                            if show_captions {
+                                                span { style: "font-size: 11px; color: #374151; margin-top: 4px;", "{name}" }
+                                            }
+                                        }
+                                        if is_sel {
+                                            div {
+                                                style: "position: absolute; left: 2px; top: 2px; width: 20px; height: 20px; border-radius: 50%; background: #1a73e8; color: white; font-size: 12px; display: flex; align-items: center; justify-content: center;",
+                                                "\u{2713}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Footer
+                    div {
+                        style: "height: {FOOTER_H}px; display: flex; align-items: center; justify-content: flex-end; border-top: 1px solid #e5e7eb; margin-top: {CARD_PAD}px;",
+                        button {
+                            class: "target",
+                            "data-label": SUBMIT_LABEL,
+                            style: "padding: 10px 22px; background: {verify_bg}; color: white; border: none; border-radius: 2px; font-size: 14px; font-weight: 600; letter-spacing: 0.5px; text-transform: uppercase; font-family: system-ui, sans-serif; cursor: pointer; transition: background 0.15s;",
+                            tabindex: "-1",
+                            onclick: move |_| {
+                                if complete {
+                                    score.complete();
+                                    bg.set(random_canvas_bg());
+                                    state.set(random_level33(Difficulty::current()));
+                                    selected.set(vec![false; COLS * COLS]);
+                                    wrong.set(false);
+                                } else {
+                                    wrong.set(true);
+                                    let picked = selected.peek().iter().filter(|s| **s).count();
+                                    score.wrong(SUBMIT_LABEL, &format!("{picked} tiles selected"));
+                                    spawn(async move {
+                                        gloo_timers::future::TimeoutFuture::new(600).await;
+                                        wrong.set(false);
+                                    });
+                                }
+                            },
+                            "{SUBMIT_LABEL}"
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: grid_x,
+                target_y: grid_y,
+                target_w: grid,
+                target_h: grid,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
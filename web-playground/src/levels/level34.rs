@@ -0,0 +1,297 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const SENDERS: &[&str] = &[
+    "Alice Chen", "Bob Martin", "Carla Diaz", "Dev Patel", "Emma Stone",
+    "Farid Haddad", "Grace Kim", "Hugo Weber", "Ines Costa", "Jonas Berg",
+];
+
+const SUBJECTS: &[&str] = &[
+    "Invoice #4821 is ready", "Your build failed", "New comment on your post",
+    "Meeting moved to 3pm", "Password changed", "Weekly report available",
+    "You were mentioned in #design", "Pull request approved", "Storage almost full",
+    "Trial ends in 3 days", "New login from Chrome", "Shared a file with you",
+];
+
+const TIMES: &[&str] = &["now", "2m", "15m", "1h", "3h", "yesterday", "2d"];
+
+const AVATAR_COLORS: &[&str] = &[
+    "#4f46e5", "#2563eb", "#0891b2", "#059669", "#d97706",
+    "#dc2626", "#7c3aed", "#db2777",
+];
+
+const MARK_ALL_LABEL: &str = "Mark all read";
+
+const HEADER_H: f32 = 52.0;
+const ROW_H: f32 = 64.0;
+const ICON_BTN: f32 = 28.0;
+
+struct Notification {
+    sender: &'static str,
+    subject: &'static str,
+    time: &'static str,
+    color: &'static str,
+}
+
+struct Level34State {
+    items: Vec<Notification>,
+    initially_read: Vec<bool>,
+    target: usize,
+    mode: u8, // 0=by sender, 1=by subject, 2=mark all
+    x: f32,
+    y: f32,
+    panel_w: f32,
+}
+
+fn mark_label(n: &Notification) -> String {
+    format!("mark read: {} \u{2014} {}", n.sender, n.subject)
+}
+
+fn random_level34(d: &Difficulty) -> Level34State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 4..=6);
+
+    let mut senders: Vec<usize> = (0..SENDERS.len()).collect();
+    let mut subjects: Vec<usize> = (0..SUBJECTS.len()).collect();
+    let items: Vec<Notification> = (0..count).map(|i| Notification {
+        sender: SENDERS[senders.remove(rng.random_range(0..senders.len()))],
+        subject: SUBJECTS[subjects.remove(rng.random_range(0..subjects.len()))],
+        time: TIMES[i.min(TIMES.len() - 1)],
+        color: AVATAR_COLORS[rng.random_range(0..AVATAR_COLORS.len())],
+    }).collect();
+
+    let mode = rng.random_range(0..3u8);
+    let target = rng.random_range(0..count);
+    // A few older items are already read; the target never is
+    let initially_read: Vec<bool> = (0..count)
+        .map(|i| i != target && i >= count / 2 && rng.random_bool(0.4))
+        .collect();
+
+    let panel_w = rng.random_range(360.0..=420.0f32);
+    let panel_h = HEADER_H + super::layout::stack_height(&vec![ROW_H; count], 0.0);
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, panel_w, panel_h, 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+
+    Level34State { items, initially_read, target, mode, x, y, panel_w }
+}
+
+#[component]
+pub fn Level34() -> Element {
+    let mut state = use_signal(|| random_level34(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let initial_read = state.read().initially_read.clone();
+    let mut read = use_signal(move || initial_read);
+    let mut wrong = use_signal(|| None::<usize>);
+
+    let st = state.read();
+    let target = st.target;
+    let mode = st.mode;
+    let panel_x = st.x;
+    let panel_y = st.y;
+    let panel_w = st.panel_w;
+    let instruction = match mode {
+        0 => tr("instr.mark_from", &[&st.items[target].sender]),
+        1 => tr("instr.mark_subject", &[&st.items[target].subject]),
+        _ => tr("instr.mark_all", &[]),
+    };
+    let rows: Vec<(&'static str, &'static str, &'static str, &'static str, String)> = st.items.iter()
+        .map(|n| (n.sender, n.subject, n.time, n.color, mark_label(n)))
+        .collect();
+    drop(st);
+
+    let count = rows.len();
+    let cur_read: Vec<bool> = read.read().clone();
+    let unread = cur_read.iter().filter(|r| !**r).count();
+    let flash = wrong();
+    let panel_h = HEADER_H + super::layout::stack_height(&vec![ROW_H; count], 0.0);
+
+    // Ground truth — one Card per notification with its mark-read icon button
+    let mark_all_rect = Rect::new(panel_x + panel_w - 124.0, panel_y + 12.0, 112.0, 28.0);
+    let mark_all = if mode == 2 {
+        ui_node::target_button(MARK_ALL_LABEL, mark_all_rect)
+    } else {
+        ui_node::button(MARK_ALL_LABEL, mark_all_rect)
+    };
+    let mut children = vec![mark_all];
+    let row_rects = super::layout::stack(Rect::new(panel_x, panel_y + HEADER_H, panel_w, panel_h - HEADER_H), &vec![ROW_H; count], 0.0);
+    for (i, ((sender, _, _, _, label), row_rect)) in rows.iter().zip(row_rects).enumerate() {
+        let item_children = if cur_read[i] {
+            Vec::new()
+        } else {
+            let btn_rect = Rect::new(
+                panel_x + panel_w - 16.0 - ICON_BTN,
+                row_rect.y + (ROW_H - ICON_BTN) / 2.0,
+                ICON_BTN,
+                ICON_BTN,
+            );
+            if mode != 2 && i == target {
+                vec![ui_node::target_button(label.as_str(), btn_rect)]
+            } else {
+                vec![ui_node::button(label.as_str(), btn_rect)]
+            }
+        };
+        children.push(UINode::Card(
+            Visual::new(format!("notification from {}", sender), row_rect),
+            item_children,
+        ));
+    }
+    let tree = ui_node::card(Rect::new(panel_x, panel_y, panel_w, panel_h), children);
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    let mut fail = move |slot: usize| {
+        wrong.set(Some(slot));
+        spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(600).await;
+            wrong.set(None);
+        });
+    };
+    let mut advance = move || {
+        score.complete();
+        bg.set(random_canvas_bg());
+        let new_st = random_level34(Difficulty::current());
+        read.set(new_st.initially_read.clone());
+        state.set(new_st);
+        wrong.set(None);
+    };
+
+    let mark_all_bg = if flash == Some(count) { "#fee2e2" } else { "transparent" };
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 34"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Notification Center"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                // Instruction
+                div {
+                    style: "position: absolute; left: 0; right: 0; top: 16px; text-align: center; z-index: 30;",
+                    div {
+                        style: "display: inline-block; background: rgba(0,0,0,0.7); padding: 8px 16px; border-radius: 8px; color: white; font-size: 14px; font-weight: 500;",
+                        "{instruction}"
+                    }
+                }
+
+                div {
+                    style: "position: absolute; left: {panel_x}px; top: {panel_y}px; width: {panel_w}px; background: white; border-radius: 10px; box-shadow: 0 8px 32px rgba(0,0,0,0.35); overflow: hidden; font-family: system-ui, sans-serif;",
+
+                    // Header
+                    div {
+                        style: "position: relative; height: {HEADER_H}px; display: flex; align-items: center; gap: 8px; padding: 0 16px; border-bottom: 1px solid #e5e7eb; box-sizing: border-box;",
+                        span { style: "font-size: 15px; font-weight: 600; color: #111827;", "Notifications" }
+                        if unread > 0 {
+                            span {
+                                style: "min-width: 20px; height: 20px; padding: 0 6px; border-radius: 10px; background: #ef4444; color: white; font-size: 11px; font-weight: 600; display: inline-flex; align-items: center; justify-content: center; box-sizing: border-box;",
+                                "{unread}"
+                            }
+                        }
+                        button {
+                            class: if mode == 2 { "target" } else { "" },
+                            "data-label": MARK_ALL_LABEL,
+                            style: "position: absolute; right: 12px; top: 12px; width: 112px; height: 28px; border: none; border-radius: 6px; background: {mark_all_bg}; color: #4f46e5; font-size: 13px; font-weight: 500; cursor: pointer; font-family: system-ui, sans-serif;",
+                            tabindex: "-1",
+                            onclick: move |_| {
+                                if mode == 2 {
+                                    advance();
+                                } else {
+                                    score.wrong(MARK_ALL_LABEL, "");
+                                    fail(count);
+                                }
+                            },
+                            "{MARK_ALL_LABEL}"
+                        }
+                    }
+
+                    for (i, (sender, subject, time, color, label)) in rows.iter().enumerate() {
+                        {
+                            let is_read = cur_read[i];
+                            let is_target = mode != 2 && i == target;
+                            let row_bg = if flash == Some(i) { "#fee2e2" } else if is_read { "white" } else { "#f5f7ff" };
+                            let weight = if is_read { "400" } else { "600" };
+                            let initials: String = sender.split(' ').filter_map(|w| w.chars().next()).collect();
+                            let label = label.clone();
+                            let wrong_label = label.to_string();
+                            rsx! {
+                                div {
+                                    style: "position: relative; height: {ROW_H}px; display: flex; align-items: center; gap: 12px; padding: 0 56px 0 16px; border-bottom: 1px solid #f3f4f6; background: {row_bg}; box-sizing: border-box; transition: background 0.15s;",
+                                    div {
+                                        style: "width: 36px; height: 36px; border-radius: 50%; background: {color}; color: white; font-size: 13px; font-weight: 600; display: flex; align-items: center; justify-content: center; flex-shrink: 0;",
+                                        "{initials}"
+                                    }
+                                    div {
+                                        style: "flex: 1; min-width: 0;",
+                                        div {
+                                            style: "display: flex; justify-content: space-between; font-size: 13px; color: #111827; font-weight: {weight};",
+                                            span { "{sender}" }
+                                            span { style: "color: #9ca3af; font-weight: 400; font-size: 12px;", "{time}" }
+                                        }
+                                        div {
+                                            style: "font-size: 13px; color: #6b7280; white-space: nowrap; overflow: hidden; text-overflow: ellipsis;",
+                                            "{subject}"
+                                        }
+                                    }
+                                    if !is_read {
+                                        button {
+                                            class: if is_target { "target" } else { "" },
+                                            "data-label": "{label}",
+                                            title: "Mark as read",
+                                            style: "position: absolute; right: 16px; top: {(ROW_H - ICON_BTN) / 2.0}px; width: {ICON_BTN}px; height: {ICON_BTN}px; border: 1px solid #d1d5db; border-radius: 50%; background: white; color: #4f46e5; font-size: 14px; cursor: pointer; padding: 0; display: flex; align-items: center; justify-content: center;",
+                                            tabindex: "-1",
+                                            onclick: move |_| {
+                                                if is_target {
+                                                    advance();
+                                                } else {
+                                                    score.wrong(&wrong_label, "");
+                                                    fail(i);
+                                                }
+                                            },
+                                            "\u{2713}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: panel_x,
+                target_y: panel_y,
+                target_w: panel_w,
+                target_h: panel_h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, UINode};
+use super::{Difficulty, fresh_rng, random_canvas_bg, use_hotkeys_capturing};
+
+/// (label, candidate values)
+const FIELDS: &[(&str, &[&str])] = &[
+    ("Full name", &["Ada Lovelace", "Alan Turing", "Grace Hopper", "Linus Torvalds"]),
+    ("Email", &["ada@example.com", "alan@test.org", "grace@mail.dev", "linus@kernel.io"]),
+    ("Company", &["Acme", "Globex", "Initech", "Umbrella"]),
+    ("City", &["Berlin", "Lisbon", "Osaka", "Toronto"]),
+    ("Phone", &["555-0134", "555-0199", "555-0121", "555-0178"]),
+    ("Username", &["adal", "turing42", "ghopper", "torvalds"]),
+];
+
+const CARD_PAD: f32 = 20.0;
+const INSTR_H: f32 = 56.0;
+const FIELD_LABEL_H: f32 = 18.0;
+const FIELD_H: f32 = 36.0;
+const FIELD_GAP: f32 = 14.0;
+const FOOTER_H: f32 = 40.0;
+const SUBMIT_KEY: &str = "Enter";
+
+struct Level35State {
+    fields: Vec<(&'static str, &'static str)>,
+    x: f32,
+    y: f32,
+    card_w: f32,
+}
+
+fn card_height(n: usize) -> f32 {
+    CARD_PAD * 2.0 + INSTR_H + n as f32 * (FIELD_LABEL_H + FIELD_H + FIELD_GAP) + FOOTER_H
+}
+
+fn random_level35(d: &Difficulty) -> Level35State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 2..=4);
+    let mut pool: Vec<usize> = (0..FIELDS.len()).collect();
+    let mut picked: Vec<usize> = (0..count)
+        .map(|_| pool.remove(rng.random_range(0..pool.len())))
+        .collect();
+    picked.sort();
+    let fields = picked.into_iter()
+        .map(|i| {
+            let (label, values) = FIELDS[i];
+            (label, values[rng.random_range(0..values.len())])
+        })
+        .collect();
+    let card_w = rng.random_range(320.0..=400.0f32);
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, card_w, card_height(count), 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level35State { fields, x, y, card_w }
+}
+
+/// Move keyboard focus to field `i` (fires the field's onfocus handler).
+fn focus_field(i: usize) {
+    document::eval(&format!("document.querySelector('[data-kb-field=\"{i}\"]')?.focus()"));
+}
+
+#[component]
+pub fn Level35() -> Element {
+    let mut state = use_signal(|| random_level35(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut values = use_signal(|| vec![String::new(); 4]);
+    let mut focused = use_signal(|| None::<usize>);
+    let mut history = use_signal(Vec::<usize>::new);
+    let mut wrong = use_signal(|| false);
+
+    // Tab order is owned by the level so synthetic Tab presses move focus too
+    use_hotkeys_capturing(&["Tab", "Shift+Tab"], move |combo| {
+        let n = state.peek().fields.len();
+        let cur = *focused.peek();
+        match combo.as_str() {
+            "Tab" => focus_field(cur.map(|c| (c + 1) % n).unwrap_or(0)),
+            "Shift+Tab" => focus_field(cur.map(|c| (c + n - 1) % n).unwrap_or(n - 1)),
+            _ => {}
+        }
+    });
+
+    // Ground truth
+    let st = state.read();
+    let fields: Vec<(&'static str, &'static str)> = st.fields.clone();
+    let card_x = st.x;
+    let card_y = st.y;
+    let card_w = st.card_w;
+    drop(st);
+
+    let count = fields.len();
+    let cur_values: Vec<String> = values.read().clone();
+    let cur_focus = focused();
+    let is_wrong = wrong();
+    let card_h = card_height(count);
+    let field_y = |i: usize| card_y + CARD_PAD + INSTR_H + i as f32 * (FIELD_LABEL_H + FIELD_H + FIELD_GAP) + FIELD_LABEL_H;
+    let field_w = card_w - CARD_PAD * 2.0;
+
+    let mut form = ui_node::keyboard_form(
+        "signup",
+        Rect::new(card_x, card_y, card_w, card_h),
+        fields.iter().enumerate()
+            .map(|(i, (label, value))| (label.to_string(), Rect::new(card_x + CARD_PAD, field_y(i), field_w, FIELD_H), value.to_string()))
+            .collect(),
+        SUBMIT_KEY,
+    );
+    if let UINode::KeyboardForm(_, fs) = &mut form {
+        for (f, v) in fs.fields.iter_mut().zip(cur_values.iter()) {
+            f.current_value = v.clone();
+        }
+        fs.focused = cur_focus;
+        fs.focus_history = history.read().clone();
+    }
+    let complete = form.check().is_complete();
+    let tree = ui_node::card(Rect::new(card_x, card_y, card_w, card_h), vec![form]);
+
+    let mut submit = move || {
+        if complete {
+            score.complete();
+            bg.set(random_canvas_bg());
+            state.set(random_level35(Difficulty::current()));
+            values.set(vec![String::new(); 4]);
+            focused.set(None);
+            history.set(Vec::new());
+            wrong.set(false);
+            document::eval("document.activeElement?.blur()");
+        } else {
+            wrong.set(true);
+            score.wrong(SUBMIT_KEY, &values.peek().join(", "));
+            spawn(async move {
+                gloo_timers::future::TimeoutFuture::new(600).await;
+                wrong.set(false);
+            });
+        }
+    };
+
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+    let card_border = if is_wrong { "#ef4444" } else { "transparent" };
+    let hint_color = if is_wrong { "#ef4444" } else { "#6b7280" };
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 35"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Keyboard-only Form"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {card_w}px; height: {card_h}px; padding: {CARD_PAD}px; background: white; border: 2px solid {card_border}; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; cursor: not-allowed; font-family: system-ui, sans-serif;",
+
+                    div {
+                        style: "height: {INSTR_H}px;",
+                        div {
+                            style: "font-size: 14px; color: #374151; font-weight: 500;",
+                            "Fill in every field, then press {SUBMIT_KEY}"
+                        }
+                        div {
+                            style: "font-size: 12px; color: {hint_color}; margin-top: 4px;",
+                            "\u{2328} Mouse is disabled \u{2014} use Tab / Shift+Tab to move between fields"
+                        }
+                    }
+
+                    for (i, (label, value)) in fields.iter().enumerate() {
+                        {
+                            let label: &'static str = label;
+                            let val = cur_values.get(i).cloned().unwrap_or_default();
+                            let is_focused = cur_focus == Some(i);
+                            let ring = if is_focused { "border: 2px solid #4f46e5; box-shadow: 0 0 0 3px rgba(79,70,229,0.2);" } else { "border: 1px solid #d1d5db;" };
+                            rsx! {
+                                div {
+                                    style: "height: {FIELD_LABEL_H}px; font-size: 12px; color: #6b7280; font-weight: 500;",
+                                    "{label} "
+                                    span { style: "color: #111827; font-family: monospace;", "(\"{value}\")" }
+                                }
+                                input {
+                                    class: "target",
+                                    "data-label": label,
+                                    "data-kb-field": "{i}",
+                                    r#type: "text",
+                                    value: "{val}",
+                                    style: "display: block; width: 100%; height: {FIELD_H}px; margin-bottom: {FIELD_GAP}px; padding: 0 10px; {ring} border-radius: 6px; font-size: 14px; font-family: system-ui, sans-serif; color: #111827; outline: none; box-sizing: border-box; pointer-events: none;",
+                                    onfocus: move |_| {
+                                        focused.set(Some(i));
+                                        if !history.read().contains(&i) {
+                                            history.write().push(i);
+                                        }
+                                    },
+                                    onblur: move |_| {
+                                        if *focused.peek() == Some(i) {
+                                            focused.set(None);
+                                        }
+                                    },
+                                    oninput: move |e: Event<FormData>| {
+                                        if let Some(slot) = values.write().get_mut(i) {
+                                            *slot = e.value();
+                                        }
+                                    },
+                                    onkeydown: move |e: Event<KeyboardData>| {
+                                        if e.key() == Key::Enter {
+                                            submit();
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        style: "height: {FOOTER_H}px; display: flex; align-items: center; justify-content: flex-end; font-size: 12px; color: #9ca3af;",
+                        span {
+                            style: "padding: 4px 8px; border: 1px solid #d1d5db; border-radius: 4px; font-family: monospace;",
+                            "{SUBMIT_KEY} \u{21B5} to submit"
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: card_x,
+                target_y: card_y,
+                target_w: card_w,
+                target_h: card_h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, UINode, Visual, InputState};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr, tr_ordinal};
+
+/// Three-sentence passages; every sentence ends with ". ".
+const PASSAGES: &[&str] = &[
+    "The harbor was quiet before sunrise. Fishermen checked their nets while gulls circled overhead. By noon the market would be crowded with buyers.",
+    "Our library extends its hours during exams. Students can reserve study rooms online. Laptops are available at the front desk.",
+    "The recipe calls for fresh basil and ripe tomatoes. Simmer the sauce for twenty minutes. Serve it warm with crusty bread.",
+    "Mountain trails close after the first snowfall. Rangers post updates at the visitor center. Always carry water and a map.",
+    "The orchestra rehearsed the symphony twice. Violins opened with a gentle melody. Thunderous applause followed the final chord.",
+    "Solar panels were installed on the warehouse roof. They now supply most of the building's power. Energy bills dropped sharply last winter.",
+    "The museum opened a new gallery of ancient maps. Visitors can trace forgotten trade routes. Guided tours start every hour.",
+    "Our team shipped the update on Friday. Customers reported faster loading times. The next release will focus on accessibility.",
+];
+
+const CARD_W: f32 = 400.0;
+const CARD_PAD: f32 = 20.0;
+const INSTR_H: f32 = 44.0;
+const PASSAGE_H: f32 = 154.0;
+const GAP: f32 = 12.0;
+const COPY_W: f32 = 88.0;
+const COPY_H: f32 = 34.0;
+const INPUT_H: f32 = 36.0;
+const PASTE_W: f32 = 88.0;
+const SUBMIT_H: f32 = 38.0;
+const CARD_H: f32 = CARD_PAD * 2.0 + INSTR_H + PASSAGE_H + GAP + COPY_H + GAP + INPUT_H + GAP + SUBMIT_H;
+
+struct Level36State {
+    passage: &'static str,
+    target: String,
+    /// 1-based sentence index when the target is a whole sentence
+    sentence: Option<usize>,
+    x: f32,
+    y: f32,
+}
+
+fn sentences(passage: &str) -> Vec<&str> {
+    passage.split_inclusive(". ").map(str::trim_end).collect()
+}
+
+/// Words of `passage` that occur once and are long enough to aim at.
+fn candidate_words(passage: &str) -> Vec<&str> {
+    let words: Vec<&str> = passage.split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+        .collect();
+    words.iter()
+        .filter(|w| w.len() >= 5 && !w.contains('\''))
+        .filter(|w| passage.matches(**w).count() == 1)
+        .copied()
+        .collect()
+}
+
+fn random_level36(d: &Difficulty) -> Level36State {
+    let mut rng = fresh_rng();
+    let passage = PASSAGES[rng.random_range(0..PASSAGES.len())];
+    let words = candidate_words(passage);
+    let (target, sentence) = if rng.random_bool(0.5) && !words.is_empty() {
+        (words[rng.random_range(0..words.len())].to_string(), None)
+    } else {
+        let all = sentences(passage);
+        let i = rng.random_range(0..all.len());
+        (all[i].to_string(), Some(i + 1))
+    };
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, CARD_W, CARD_H, 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level36State { passage, target, sentence, x, y }
+}
+
+/// The page's current text selection, trimmed.
+fn window_selection() -> String {
+    web_sys::window()
+        .and_then(|w| w.get_selection().ok().flatten())
+        .map(|s| String::from(s.to_string()).trim().to_string())
+        .unwrap_or_default()
+}
+
+#[component]
+pub fn Level36() -> Element {
+    let mut state = use_signal(|| random_level36(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut selected = use_signal(String::new);
+    let mut clipboard = use_signal(String::new);
+    let mut pasted = use_signal(String::new);
+    let mut wrong = use_signal(|| false);
+
+    let st = state.read();
+    let passage = st.passage;
+    let target = st.target.clone();
+    let sentence = st.sentence;
+    let card_x = st.x;
+    let card_y = st.y;
+    drop(st);
+
+    let instruction = match sentence {
+        Some(n) => tr("instr.copy_sentence", &[&tr_ordinal(n)]),
+        None => tr("instr.copy_word", &[&target]),
+    };
+    let copied = clipboard();
+    let input_val = pasted();
+    let is_wrong = wrong();
+
+    // Ground truth
+    let inner_x = card_x + CARD_PAD;
+    let inner_w = CARD_W - CARD_PAD * 2.0;
+    let passage_y = card_y + CARD_PAD + INSTR_H;
+    let copy_y = passage_y + PASSAGE_H + GAP;
+    let input_y = copy_y + COPY_H + GAP;
+    let input_w = inner_w - PASTE_W - 8.0;
+    let needs_copy = copied != target;
+
+    let mut passage_node = ui_node::text_selection("Passage", Rect::new(inner_x, passage_y, inner_w, PASSAGE_H), passage, target.as_str());
+    if let UINode::TextSelection(v, s) = &mut passage_node {
+        v.is_target = needs_copy;
+        s.selected = selected();
+    }
+    let copy_visual = Visual::new("Copy", Rect::new(inner_x, copy_y, COPY_W, COPY_H));
+    let paste_visual = Visual::new("Paste", Rect::new(inner_x + input_w + 8.0, input_y, PASTE_W, INPUT_H));
+    let tree = ui_node::form(
+        Rect::new(card_x, card_y, CARD_W, CARD_H),
+        "Submit",
+        vec![
+            passage_node,
+            UINode::Button(if needs_copy { copy_visual.target() } else { copy_visual }),
+            UINode::TextInput(
+                Visual::new("Pasted text", Rect::new(inner_x, input_y, input_w, INPUT_H)),
+                InputState { placeholder: "Paste here".into(), current_value: input_val.clone(), target_value: target.clone() },
+            ),
+            UINode::Button(if input_val != target { paste_visual.target() } else { paste_visual }),
+        ],
+    );
+
+    let expected = target.clone();
+    let submit = move |_| {
+        if *pasted.peek() == expected {
+            score.complete();
+            bg.set(random_canvas_bg());
+            state.set(random_level36(Difficulty::current()));
+            selected.set(String::new());
+            clipboard.set(String::new());
+            pasted.set(String::new());
+            wrong.set(false);
+            if let Some(sel) = web_sys::window().and_then(|w| w.get_selection().ok().flatten()) {
+                let _ = sel.remove_all_ranges();
+            }
+        } else {
+            wrong.set(true);
+            score.wrong("Submit", &pasted.peek());
+            spawn(async move {
+                gloo_timers::future::TimeoutFuture::new(600).await;
+                wrong.set(false);
+            });
+        }
+    };
+
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+    let input_border = if is_wrong { "#ef4444" } else { "#d1d5db" };
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 36"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Copy & Paste"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {CARD_W}px; height: {CARD_H}px; padding: {CARD_PAD}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: system-ui, sans-serif;",
+
+                    div {
+                        style: "height: {INSTR_H}px; font-size: 15px; color: #374151; font-weight: 500;",
+                        "{instruction}"
+                    }
+
+                    div {
+                        class: if needs_copy { "target" } else { "" },
+                        "data-label": "Passage",
+                        style: "height: {PASSAGE_H}px; padding: 10px 12px; background: #f9fafb; border: 1px solid #e5e7eb; border-radius: 8px; font-size: 14px; line-height: 22px; color: #111827; user-select: text; cursor: text; box-sizing: border-box; overflow: hidden;",
+                        onmouseup: move |_| selected.set(window_selection()),
+                        "{passage}"
+                    }
+
+                    div {
+                        style: "display: flex; align-items: center; gap: 10px; margin-top: {GAP}px; height: {COPY_H}px;",
+                        button {
+                            class: if needs_copy { "target" } else { "" },
+                            "data-label": "Copy",
+                            style: "width: {COPY_W}px; height: {COPY_H}px; background: white; color: #374151; border: 1px solid #d1d5db; border-radius: 6px; font-size: 14px; cursor: pointer;",
+                            // Keep the passage selection when the button is pressed
+                            onmousedown: move |e: Event<MouseData>| e.prevent_default(),
+                            onclick: move |_| {
+                                let text = window_selection();
+                                selected.set(text.clone());
+                                clipboard.set(text);
+                            },
+                            "Copy"
+                        }
+                        if !copied.is_empty() {
+                            span {
+                                style: "font-size: 12px; color: #6b7280;",
+                                "Copied \u{201C}{copied}\u{201D}"
+                            }
+                        }
+                    }
+
+                    div {
+                        style: "display: flex; gap: 8px; margin-top: {GAP}px; height: {INPUT_H}px;",
+                        input {
+                            "data-label": "Pasted text",
+                            r#type: "text",
+                            readonly: true,
+                            placeholder: "Paste here",
+                            value: "{input_val}",
+                            style: "width: {input_w}px; height: {INPUT_H}px; padding: 0 10px; border: 1px solid {input_border}; border-radius: 6px; font-size: 14px; color: #111827; background: #f9fafb; box-sizing: border-box; outline: none;",
+                        }
+                        button {
+                            class: if input_val != target { "target" } else { "" },
+                            "data-label": "Paste",
+                            style: "width: {PASTE_W}px; height: {INPUT_H}px; background: white; color: #374151; border: 1px solid #d1d5db; border-radius: 6px; font-size: 14px; cursor: pointer;",
+                            onclick: move |_| pasted.set(clipboard.peek().clone()),
+                            "Paste"
+                        }
+                    }
+
+                    button {
+                        class: "target",
+                        "data-label": "Submit",
+                        style: "display: block; width: 100%; height: {SUBMIT_H}px; margin-top: {GAP}px; background: #4f46e5; color: white; border: none; border-radius: 6px; font-size: 14px; font-weight: 600; cursor: pointer;",
+                        onclick: submit,
+                        "Submit"
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: card_x,
+                target_y: card_y,
+                target_w: CARD_W,
+                target_h: CARD_H,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
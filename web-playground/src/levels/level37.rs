@@ -0,0 +1,227 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, UINode, Visual, InputState, TooltipState};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const BADGES: &[(&str, &str)] = &[
+    ("Staging", "#2563eb"),
+    ("Production", "#dc2626"),
+    ("Backup", "#059669"),
+    ("Archive", "#7c3aed"),
+    ("Sandbox", "#d97706"),
+    ("Mirror", "#0891b2"),
+];
+
+/// No 0/O or 1/I, so a code read off the tooltip is unambiguous
+const CODE_CHARS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const CODE_LEN: usize = 6;
+
+const CARD_W: f32 = 360.0;
+const CARD_PAD: f32 = 20.0;
+const INSTR_H: f32 = 44.0;
+/// Room above the badges for an open tooltip
+const TIP_H: f32 = 40.0;
+const BADGE_W: f32 = 96.0;
+const BADGE_H: f32 = 30.0;
+const BADGE_GAP: f32 = 12.0;
+const GAP: f32 = 18.0;
+const INPUT_H: f32 = 36.0;
+const SUBMIT_H: f32 = 38.0;
+const CARD_H: f32 = CARD_PAD * 2.0 + INSTR_H + TIP_H + BADGE_H + GAP + INPUT_H + GAP + SUBMIT_H;
+const INPUT_LABEL: &str = "Access code";
+
+struct Level37State {
+    /// (label, colour, code) per badge, left to right
+    badges: Vec<(&'static str, &'static str, String)>,
+    target: usize,
+    x: f32,
+    y: f32,
+}
+
+fn random_code(rng: &mut impl Rng) -> String {
+    (0..CODE_LEN)
+        .map(|_| CODE_CHARS[rng.random_range(0..CODE_CHARS.len())] as char)
+        .collect()
+}
+
+fn random_level37(d: &Difficulty) -> Level37State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 2..=3);
+    let mut pool: Vec<usize> = (0..BADGES.len()).collect();
+    let badges = (0..count)
+        .map(|_| {
+            let (label, color) = BADGES[pool.remove(rng.random_range(0..pool.len()))];
+            (label, color, random_code(&mut rng))
+        })
+        .collect();
+    let target = rng.random_range(0..count);
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, CARD_W, CARD_H, 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level37State { badges, target, x, y }
+}
+
+#[component]
+pub fn Level37() -> Element {
+    let mut state = use_signal(|| random_level37(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut hovered = use_signal(|| None::<usize>);
+    let mut value = use_signal(String::new);
+    let mut wrong = use_signal(|| false);
+
+    let st = state.read();
+    let badges = st.badges.clone();
+    let target = st.target;
+    let card_x = st.x;
+    let card_y = st.y;
+    drop(st);
+
+    let code = badges[target].2.clone();
+    let instruction = tr("instr.tooltip_code", &[&badges[target].0]);
+    let open = hovered();
+    let typed = value();
+    let is_wrong = wrong();
+
+    // Ground truth
+    let inner_x = card_x + CARD_PAD;
+    let inner_w = CARD_W - CARD_PAD * 2.0;
+    let badge_y = card_y + CARD_PAD + INSTR_H + TIP_H;
+    let input_y = badge_y + BADGE_H + GAP;
+    let needs_code = typed != code;
+
+    let mut children: Vec<UINode> = badges.iter().enumerate()
+        .map(|(i, (label, _, badge_code))| {
+            let visual = Visual::new(*label, Rect::new(inner_x + i as f32 * (BADGE_W + BADGE_GAP), badge_y, BADGE_W, BADGE_H));
+            UINode::Tooltip(
+                if i == target && needs_code { visual.target() } else { visual },
+                TooltipState { text: badge_code.clone(), open: open == Some(i) },
+            )
+        })
+        .collect();
+    children.push(UINode::TextInput(
+        Visual::new(INPUT_LABEL, Rect::new(inner_x, input_y, inner_w, INPUT_H)).target(),
+        InputState { placeholder: "Enter code".into(), current_value: typed.clone(), target_value: code.clone() },
+    ));
+    let tree = ui_node::form(Rect::new(card_x, card_y, CARD_W, CARD_H), "Submit", children);
+
+    let submit = move |_| {
+        if !needs_code {
+            score.complete();
+            bg.set(random_canvas_bg());
+            state.set(random_level37(Difficulty::current()));
+            hovered.set(None);
+            value.set(String::new());
+            wrong.set(false);
+        } else {
+            wrong.set(true);
+            score.wrong("Submit", &value.peek());
+            spawn(async move {
+                gloo_timers::future::TimeoutFuture::new(600).await;
+                wrong.set(false);
+            });
+        }
+    };
+
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+    let input_border = if is_wrong { "#ef4444" } else { "#d1d5db" };
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 37"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Tooltip Code"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {CARD_W}px; height: {CARD_H}px; padding: {CARD_PAD}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: system-ui, sans-serif;",
+
+                    div {
+                        style: "height: {INSTR_H}px; font-size: 15px; color: #374151; font-weight: 500;",
+                        "{instruction}"
+                    }
+
+                    div {
+                        style: "display: flex; gap: {BADGE_GAP}px; margin-top: {TIP_H}px; height: {BADGE_H}px;",
+                        for (i, (label, color, badge_code)) in badges.iter().enumerate() {
+                            div {
+                                key: "{label}",
+                                style: "position: relative; width: {BADGE_W}px; height: {BADGE_H}px;",
+                                if open == Some(i) {
+                                    div {
+                                        style: "position: absolute; bottom: calc(100% + 8px); left: 50%; transform: translateX(-50%); padding: 6px 10px; background: #111827; color: white; border-radius: 6px; font-size: 13px; font-family: monospace; white-space: nowrap; pointer-events: none; box-shadow: 0 2px 8px rgba(0,0,0,0.25);",
+                                        "Code: {badge_code}"
+                                    }
+                                }
+                                div {
+                                    class: if i == target && needs_code { "target" } else { "" },
+                                    "data-label": *label,
+                                    style: "width: 100%; height: 100%; display: flex; align-items: center; justify-content: center; background: {color}; color: white; border-radius: 15px; font-size: 13px; font-weight: 600; cursor: help; user-select: none;",
+                                    onmouseenter: move |_| hovered.set(Some(i)),
+                                    onmouseleave: move |_| {
+                                        if *hovered.peek() == Some(i) {
+                                            hovered.set(None);
+                                        }
+                                    },
+                                    "{label}"
+                                }
+                            }
+                        }
+                    }
+
+                    input {
+                        class: "target",
+                        "data-label": INPUT_LABEL,
+                        r#type: "text",
+                        placeholder: "Enter code",
+                        value: "{typed}",
+                        style: "display: block; width: 100%; height: {INPUT_H}px; margin-top: {GAP}px; padding: 0 10px; border: 1px solid {input_border}; border-radius: 6px; font-size: 14px; font-family: monospace; color: #111827; box-sizing: border-box; outline: none;",
+                        oninput: move |e: Event<FormData>| value.set(e.value()),
+                    }
+
+                    button {
+                        class: "target",
+                        "data-label": "Submit",
+                        style: "display: block; width: 100%; height: {SUBMIT_H}px; margin-top: {GAP}px; background: #4f46e5; color: white; border: none; border-radius: 6px; font-size: 14px; font-weight: 600; cursor: pointer;",
+                        onclick: submit,
+                        "Submit"
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: card_x,
+                target_y: card_y,
+                target_w: CARD_W,
+                target_h: CARD_H,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const FILES: &[&str] = &[
+    "report-q3.pdf", "holiday.jpg", "budget.xlsx", "notes.txt", "invoice-0142.pdf",
+    "logo-final.png", "contract.docx", "backup.zip", "slides.key", "readme.md",
+];
+
+const DIALOG_TITLE: &str = "Delete file?";
+const CONFIRM: &str = "Confirm";
+const CANCEL: &str = "Cancel";
+
+const CARD_W: f32 = 380.0;
+const CARD_PAD: f32 = 20.0;
+const INSTR_H: f32 = 44.0;
+const ROW_H: f32 = 44.0;
+const ROW_GAP: f32 = 8.0;
+const DELETE_W: f32 = 72.0;
+const DELETE_H: f32 = 30.0;
+const DIALOG_W: f32 = 300.0;
+const DIALOG_H: f32 = 150.0;
+const DIALOG_PAD: f32 = 20.0;
+const DIALOG_BTN_W: f32 = 88.0;
+const DIALOG_BTN_H: f32 = 34.0;
+
+struct Level38State {
+    files: Vec<&'static str>,
+    target: usize,
+    /// Whether the round wants the deletion confirmed or cancelled
+    confirm: bool,
+    x: f32,
+    y: f32,
+}
+
+fn card_height(rows: usize) -> f32 {
+    CARD_PAD * 2.0 + INSTR_H + rows as f32 * ROW_H + (rows - 1) as f32 * ROW_GAP
+}
+
+fn delete_label(file: &str) -> String {
+    format!("Delete {file}")
+}
+
+fn random_level38(d: &Difficulty) -> Level38State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 3..=4);
+    let mut pool: Vec<usize> = (0..FILES.len()).collect();
+    let files: Vec<&'static str> = (0..count)
+        .map(|_| FILES[pool.remove(rng.random_range(0..pool.len()))])
+        .collect();
+    let target = rng.random_range(0..count);
+    let confirm = rng.random_bool(0.5);
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, CARD_W, card_height(count), 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level38State { files, target, confirm, x, y }
+}
+
+#[component]
+pub fn Level38() -> Element {
+    let mut state = use_signal(|| random_level38(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    // Row whose delete dialog is open
+    let mut dialog = use_signal(|| None::<usize>);
+    let mut wrong = use_signal(|| false);
+
+    let st = state.read();
+    let files = st.files.clone();
+    let target = st.target;
+    let confirm = st.confirm;
+    let card_x = st.x;
+    let card_y = st.y;
+    drop(st);
+
+    let target_file = files[target];
+    let instruction = if confirm {
+        tr("instr.delete_confirm", &[&target_file])
+    } else {
+        tr("instr.delete_cancel", &[&target_file])
+    };
+    let open = dialog();
+    let is_wrong = wrong();
+    let card_h = card_height(files.len());
+
+    // Ground truth
+    let inner_x = card_x + CARD_PAD;
+    let inner_w = CARD_W - CARD_PAD * 2.0;
+    let row_y = |i: usize| card_y + CARD_PAD + INSTR_H + i as f32 * (ROW_H + ROW_GAP);
+    let dialog_x = card_x + (CARD_W - DIALOG_W) / 2.0;
+    let dialog_y = card_y + (card_h - DIALOG_H) / 2.0;
+    let btn_y = dialog_y + DIALOG_H - DIALOG_PAD - DIALOG_BTN_H;
+    let confirm_x = dialog_x + DIALOG_W - DIALOG_PAD - DIALOG_BTN_W;
+    let cancel_x = confirm_x - 8.0 - DIALOG_BTN_W;
+
+    let mut children: Vec<UINode> = files.iter().enumerate()
+        .map(|(i, file)| {
+            let visual = Visual::new(delete_label(file), Rect::new(
+                inner_x + inner_w - DELETE_W, row_y(i) + (ROW_H - DELETE_H) / 2.0, DELETE_W, DELETE_H,
+            ));
+            UINode::Button(if i == target && open.is_none() { visual.target() } else { visual })
+        })
+        .collect();
+    let confirm_visual = Visual::new(CONFIRM, Rect::new(confirm_x, btn_y, DIALOG_BTN_W, DIALOG_BTN_H));
+    let cancel_visual = Visual::new(CANCEL, Rect::new(cancel_x, btn_y, DIALOG_BTN_W, DIALOG_BTN_H));
+    children.push(ui_node::modal(
+        Rect::new(dialog_x, dialog_y, DIALOG_W, DIALOG_H),
+        DIALOG_TITLE,
+        open.is_some(),
+        vec![
+            UINode::ModalButton(if confirm { confirm_visual.target() } else { confirm_visual }),
+            UINode::ModalButton(if confirm { cancel_visual } else { cancel_visual.target() }),
+        ],
+    ));
+    let tree = ui_node::card(Rect::new(card_x, card_y, CARD_W, card_h), children);
+
+    let mut fail = move |label: String, value: &str| {
+        dialog.set(None);
+        wrong.set(true);
+        score.wrong(&label, value);
+        spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(600).await;
+            wrong.set(false);
+        });
+    };
+    let mut answer = move |confirmed: bool| {
+        let row = dialog.peek().unwrap_or(0);
+        let label = if confirmed { CONFIRM } else { CANCEL };
+        if row == target && confirmed == confirm {
+            score.complete();
+            bg.set(random_canvas_bg());
+            state.set(random_level38(Difficulty::current()));
+            dialog.set(None);
+            wrong.set(false);
+        } else {
+            fail(label.to_string(), DIALOG_TITLE);
+        }
+    };
+
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+    let card_border = if is_wrong { "#ef4444" } else { "transparent" };
+    let open_file = open.and_then(|i| files.get(i).copied()).unwrap_or_default();
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 38"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Confirm Dialog"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {CARD_W}px; height: {card_h}px; padding: {CARD_PAD}px; background: white; border: 2px solid {card_border}; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: system-ui, sans-serif;",
+
+                    div {
+                        style: "height: {INSTR_H}px; font-size: 15px; color: #374151; font-weight: 500;",
+                        "{instruction}"
+                    }
+
+                    for (i, file) in files.iter().enumerate() {
+                        {
+                            let file: &'static str = file;
+                            let label = delete_label(file);
+                            let wrong_label = label.clone();
+                            let margin = if i + 1 < files.len() { ROW_GAP } else { 0.0 };
+                            rsx! {
+                                div {
+                                    key: "{file}",
+                                    style: "display: flex; align-items: center; justify-content: space-between; height: {ROW_H}px; margin-bottom: {margin}px; padding: 0 0 0 12px; background: #f9fafb; border: 1px solid #e5e7eb; border-radius: 8px; box-sizing: border-box;",
+                                    span {
+                                        style: "font-size: 14px; color: #111827; font-family: monospace;",
+                                        "{file}"
+                                    }
+                                    button {
+                                        class: if i == target && open.is_none() { "target" } else { "" },
+                                        "data-label": "{label}",
+                                        style: "width: {DELETE_W}px; height: {DELETE_H}px; margin-right: 6px; background: white; color: #dc2626; border: 1px solid #fca5a5; border-radius: 6px; font-size: 13px; font-weight: 600; cursor: pointer;",
+                                        onclick: move |_| {
+                                            if i == target {
+                                                dialog.set(Some(i));
+                                            } else {
+                                                fail(wrong_label.clone(), file);
+                                            }
+                                        },
+                                        "Delete"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if open.is_some() {
+                    div {
+                        style: "position: absolute; inset: 0; background: rgba(0,0,0,0.35); z-index: 10;",
+                    }
+                    div {
+                        role: "dialog",
+                        style: "position: absolute; left: {dialog_x}px; top: {dialog_y}px; width: {DIALOG_W}px; height: {DIALOG_H}px; padding: {DIALOG_PAD}px; background: white; border-radius: 12px; box-shadow: 0 20px 60px rgba(0,0,0,0.5); box-sizing: border-box; z-index: 20; font-family: system-ui, sans-serif;",
+                        div {
+                            style: "font-size: 16px; font-weight: 600; color: #111827; margin-bottom: 6px;",
+                            "{DIALOG_TITLE}"
+                        }
+                        div {
+                            style: "font-size: 13px; color: #6b7280; overflow: hidden; text-overflow: ellipsis; white-space: nowrap;",
+                            "\"{open_file}\" will be permanently deleted."
+                        }
+                        div {
+                            style: "position: absolute; right: {DIALOG_PAD}px; bottom: {DIALOG_PAD}px; display: flex; gap: 8px;",
+                            button {
+                                class: if !confirm { "target" } else { "" },
+                                "data-label": CANCEL,
+                                style: "width: {DIALOG_BTN_W}px; height: {DIALOG_BTN_H}px; background: white; color: #374151; border: 1px solid #d1d5db; border-radius: 6px; font-size: 14px; cursor: pointer;",
+                                onclick: move |_| answer(false),
+                                "{CANCEL}"
+                            }
+                            button {
+                                class: if confirm { "target" } else { "" },
+                                "data-label": CONFIRM,
+                                style: "width: {DIALOG_BTN_W}px; height: {DIALOG_BTN_H}px; background: #dc2626; color: white; border: none; border-radius: 6px; font-size: 14px; font-weight: 600; cursor: pointer;",
+                                onclick: move |_| answer(true),
+                                "{CONFIRM}"
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: card_x,
+                target_y: card_y,
+                target_w: CARD_W,
+                target_h: card_h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
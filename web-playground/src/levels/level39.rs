@@ -0,0 +1,252 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const REPORTS: &[&str] = &[
+    "Q1 Sales", "Q2 Sales", "Q3 Sales", "Churn", "Payroll", "Inventory",
+    "Web Traffic", "Support Tickets", "Marketing Spend", "Headcount",
+];
+
+const LOAD_LABEL: &str = "Load data";
+const CONTENT_LABEL: &str = "Reports";
+
+const CARD_W: f32 = 360.0;
+const CARD_PAD: f32 = 20.0;
+const INSTR_H: f32 = 44.0;
+const LOAD_H: f32 = 36.0;
+const GAP: f32 = 14.0;
+const ROW_H: f32 = 38.0;
+const ROW_GAP: f32 = 8.0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Phase {
+    Idle,
+    /// Loading since this `Date::now()`
+    Loading(f64),
+    Loaded,
+}
+
+struct Level39State {
+    reports: Vec<&'static str>,
+    target: usize,
+    /// How long the skeleton shows before the rows appear
+    load_ms: u32,
+    x: f32,
+    y: f32,
+}
+
+fn list_height(rows: usize) -> f32 {
+    rows as f32 * ROW_H + (rows - 1) as f32 * ROW_GAP
+}
+
+fn card_height(rows: usize) -> f32 {
+    CARD_PAD * 2.0 + INSTR_H + LOAD_H + GAP + list_height(rows)
+}
+
+fn random_level39(d: &Difficulty) -> Level39State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 3..=5);
+    let mut pool: Vec<usize> = (0..REPORTS.len()).collect();
+    let reports: Vec<&'static str> = (0..count)
+        .map(|_| REPORTS[pool.remove(rng.random_range(0..pool.len()))])
+        .collect();
+    let target = rng.random_range(0..count);
+    let load_ms = rng.random_range(1000..=3000u32);
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, CARD_W, card_height(count), 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level39State { reports, target, load_ms, x, y }
+}
+
+#[component]
+pub fn Level39() -> Element {
+    let mut state = use_signal(|| random_level39(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut phase = use_signal(|| Phase::Idle);
+    let mut wrong = use_signal(|| None::<usize>);
+
+    let st = state.read();
+    let reports = st.reports.clone();
+    let target = st.target;
+    let load_ms = st.load_ms;
+    let card_x = st.x;
+    let card_y = st.y;
+    drop(st);
+
+    let instruction = tr("instr.load_then_open", &[&reports[target]]);
+    let current = phase();
+    let loaded = current == Phase::Loaded;
+    let remaining_ms = match current {
+        Phase::Idle => load_ms,
+        Phase::Loading(since) => load_ms.saturating_sub((js_sys::Date::now() - since) as u32),
+        Phase::Loaded => 0,
+    };
+    let pressed = wrong();
+    let card_h = card_height(reports.len());
+
+    // Ground truth
+    let inner_x = card_x + CARD_PAD;
+    let inner_w = CARD_W - CARD_PAD * 2.0;
+    let load_y = card_y + CARD_PAD + INSTR_H;
+    let list_y = load_y + LOAD_H + GAP;
+    let rows: Vec<UINode> = reports.iter().enumerate()
+        .map(|(i, name)| {
+            let visual = Visual::new(*name, Rect::new(inner_x, list_y + i as f32 * (ROW_H + ROW_GAP), inner_w, ROW_H));
+            UINode::Button(if i == target { visual.target() } else { visual })
+        })
+        .collect();
+    let load_visual = Visual::new(LOAD_LABEL, Rect::new(inner_x, load_y, inner_w, LOAD_H));
+    let tree = ui_node::card(
+        Rect::new(card_x, card_y, CARD_W, card_h),
+        vec![
+            UINode::Button(if current == Phase::Idle { load_visual.target() } else { load_visual }),
+            ui_node::loader(
+                CONTENT_LABEL,
+                Rect::new(inner_x, list_y, inner_w, list_height(reports.len())),
+                remaining_ms,
+                loaded,
+                rows,
+            ),
+        ],
+    );
+
+    let start_loading = move |_| {
+        if *phase.peek() != Phase::Idle {
+            return;
+        }
+        phase.set(Phase::Loading(js_sys::Date::now()));
+        spawn(async move {
+            gloo_timers::future::TimeoutFuture::new(load_ms).await;
+            if matches!(*phase.peek(), Phase::Loading(_)) {
+                phase.set(Phase::Loaded);
+            }
+        });
+    };
+
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+    let (load_bg, load_cursor) = if current == Phase::Idle { ("#4f46e5", "pointer") } else { ("#a5b4fc", "default") };
+    let load_text = match current {
+        Phase::Idle => LOAD_LABEL,
+        Phase::Loading(_) => "Loading\u{2026}",
+        Phase::Loaded => "Loaded",
+    };
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            style { "@keyframes skeleton-pulse {{ 0%, 100% {{ opacity: 1; }} 50% {{ opacity: 0.45; }} }}" }
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 39"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Loading Gate"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {CARD_W}px; height: {card_h}px; padding: {CARD_PAD}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: system-ui, sans-serif;",
+
+                    div {
+                        style: "height: {INSTR_H}px; font-size: 15px; color: #374151; font-weight: 500;",
+                        "{instruction}"
+                    }
+
+                    button {
+                        class: if current == Phase::Idle { "target" } else { "" },
+                        "data-label": LOAD_LABEL,
+                        disabled: current != Phase::Idle,
+                        style: "display: block; width: 100%; height: {LOAD_H}px; background: {load_bg}; color: white; border: none; border-radius: 6px; font-size: 14px; font-weight: 600; cursor: {load_cursor};",
+                        onclick: start_loading,
+                        "{load_text}"
+                    }
+
+                    div {
+                        style: "display: flex; flex-direction: column; gap: {ROW_GAP}px; margin-top: {GAP}px;",
+                        match current {
+                            Phase::Idle => rsx! {
+                                div {
+                                    style: "height: {ROW_H}px; display: flex; align-items: center; justify-content: center; font-size: 13px; color: #9ca3af; border: 1px dashed #d1d5db; border-radius: 6px;",
+                                    "No data loaded"
+                                }
+                            },
+                            Phase::Loading(_) => rsx! {
+                                for i in 0..reports.len() {
+                                    div {
+                                        key: "{i}",
+                                        style: "height: {ROW_H}px; border-radius: 6px; background: #e5e7eb; animation: skeleton-pulse 1.2s ease-in-out infinite;",
+                                    }
+                                }
+                            },
+                            Phase::Loaded => rsx! {
+                                for (i, name) in reports.iter().enumerate() {
+                                    {
+                                        let name: &'static str = name;
+                                        let border = if pressed == Some(i) { "#ef4444" } else { "#e5e7eb" };
+                                        rsx! {
+                                            button {
+                                                key: "{name}",
+                                                class: if i == target { "target" } else { "" },
+                                                "data-label": name,
+                                                style: "height: {ROW_H}px; padding: 0 12px; text-align: left; background: #f9fafb; color: #111827; border: 1px solid {border}; border-radius: 6px; font-size: 14px; cursor: pointer;",
+                                                onclick: move |_| {
+                                                    if i == target {
+                                                        score.complete();
+                                                        bg.set(random_canvas_bg());
+                                                        state.set(random_level39(Difficulty::current()));
+                                                        phase.set(Phase::Idle);
+                                                        wrong.set(None);
+                                                    } else {
+                                                        wrong.set(Some(i));
+                                                        score.wrong(name, "");
+                                                        spawn(async move {
+                                                            gloo_timers::future::TimeoutFuture::new(400).await;
+                                                            wrong.set(None);
+                                                        });
+                                                    }
+                                                },
+                                                "{name}"
+                                            }
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: card_x,
+                target_y: card_y,
+                target_w: CARD_W,
+                target_h: card_h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
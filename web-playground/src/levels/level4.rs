@@ -2,8 +2,9 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg};
+use crate::primitives::Typography;
+use crate::ui_node::{self, DropdownMode, Rect};
+use super::{Difficulty, fresh_rng, random_canvas_bg};
 
 const DROPDOWN_GROUPS: &[(&str, &[&str])] = &[
     ("Color", &["Red", "Blue", "Green", "Yellow", "Purple", "Orange", "Pink"]),
@@ -18,11 +19,13 @@ struct Level4State {
     label: String,
     options: Vec<String>,
     target: String,
+    mode: DropdownMode,
     x: f32,
     y: f32,
+    typography: Option<Typography>,
 }
 
-fn random_level4() -> Level4State {
+fn random_level4(d: &Difficulty) -> Level4State {
     let mut rng = fresh_rng();
     let group_idx = rng.random_range(0..DROPDOWN_GROUPS.len());
     let (label, all_options) = DROPDOWN_GROUPS[group_idx];
@@ -37,38 +40,44 @@ fn random_level4() -> Level4State {
 
     let target_idx = rng.random_range(0..options.len());
     let target = options[target_idx].clone();
+    let mode = DropdownMode::ALL[rng.random_range(0..DropdownMode::ALL.len())];
 
     let card_w = 300.0;
     let card_h = 130.0;
     let pad = 80.0;
     let (x, y) = super::safe_position(&mut rng, card_w, card_h, pad);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    let typography = super::random_typography(&mut rng, d);
 
-    Level4State { label: label.to_string(), options, target, x, y }
+    Level4State { label: label.to_string(), options, target, mode, x, y, typography }
 }
 
 #[component]
 pub fn Level4() -> Element {
-    let mut state = use_signal(|| random_level4());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level4(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
 
     let st = state.read();
     let label = st.label.clone();
     let options = st.options.clone();
     let target = st.target.clone();
+    let mode = st.mode;
     let card_x = st.x;
     let card_y = st.y;
+    let typography = st.typography;
     drop(st);
 
     // Build UINode tree for ground truth
     let tree = ui_node::card(
         Rect::new(card_x, card_y, 300.0, 130.0),
         vec![
-            ui_node::dropdown(
+            ui_node::dropdown_in_mode(
                 &label,
                 Rect::new(card_x + 20.0, card_y + 60.0, 260.0, 36.0),
                 options.clone(),
                 &target,
+                mode,
             ),
         ],
     );
@@ -76,8 +85,8 @@ pub fn Level4() -> Element {
     let viewport_style = super::viewport_style(&bg(), false);
 
     let card_style = format!(
-        "position: absolute; left: {}px; top: {}px; background: white; border-radius: 12px; padding: 20px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); width: 260px; font-family: system-ui, sans-serif;",
-        card_x, card_y
+        "position: absolute; left: {}px; top: {}px; background: var(--theme-surface, white); border-radius: var(--theme-radius, 12px); padding: 20px; box-shadow: var(--theme-shadow, 0 4px 24px rgba(0,0,0,0.3)); width: 260px; font-family: var(--theme-font, system-ui, sans-serif); {}",
+        card_x, card_y, typography.map(|t| t.to_css()).unwrap_or_default()
     );
 
     rsx! {
@@ -113,30 +122,35 @@ pub fn Level4() -> Element {
                     style: "{card_style}",
 
                     p {
-                        style: "margin: 0 0 16px 0; font-size: 15px; color: #374151; font-weight: 500;",
+                        style: "margin: 0 0 16px 0; font-size: calc(15px * var(--type-scale, 1)); color: var(--theme-text, #374151); font-weight: 500;",
                         "Select "
                         span {
-                            style: "font-weight: 700; color: #111;",
+                            style: "font-weight: 700; color: var(--theme-text, #111);",
                             "\"{target}\""
                         }
+                        if mode == DropdownMode::Keys {
+                            " with the keyboard"
+                        }
                     }
 
                     div {
                         style: "display: flex; flex-direction: column; gap: 6px;",
                         label {
-                            style: "font-size: 13px; color: #6b7280; font-weight: 500;",
+                            style: "font-size: calc(13px * var(--type-scale, 1)); color: var(--theme-muted, #6b7280); font-weight: 500;",
                             "{label}"
                         }
                         super::CustomSelect {
+                            label: label.clone(),
                             options: options.clone(),
                             is_target: true,
                             target_option: target.clone(),
-                            border_color: "#d1d5db".to_string(),
+                            border_color: "var(--theme-border, #d1d5db)".to_string(),
+                            mode: mode,
                             on_select: move |val: String| {
                                 if val == target {
-                                    score.set(score() + 1);
+                                    score.complete();
                                     bg.set(random_canvas_bg());
-                                    state.set(random_level4());
+                                    state.set(random_level4(Difficulty::current()));
                                 }
                             },
                         }
@@ -151,6 +165,7 @@ pub fn Level4() -> Element {
                 target_w: 300.0,
                 target_h: 130.0,
                 tree: Some(tree.clone()),
+                typography: typography,
             }
         }
     }
@@ -0,0 +1,205 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::primitives::{Shape, ShapeSize, Swatch};
+use crate::ui_node::{self, Rect, ShapeState, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const CARD_W: f32 = 440.0;
+const CARD_PAD: f32 = 20.0;
+const INSTR_H: f32 = 44.0;
+/// Shapes sit one per cell, jittered inside it, so they never overlap
+const CELL: f32 = 80.0;
+const COLS: usize = 5;
+const ROWS: usize = 3;
+const CARD_H: f32 = CARD_PAD * 2.0 + INSTR_H + ROWS as f32 * CELL;
+
+struct Level40State {
+    /// (shape, cell-relative x, cell-relative y) in cell order
+    shapes: Vec<(ShapeState, f32, f32)>,
+    target: usize,
+    x: f32,
+    y: f32,
+}
+
+fn random_shape(rng: &mut impl Rng) -> ShapeState {
+    ShapeState {
+        shape: Shape::ALL[rng.random_range(0..Shape::ALL.len())],
+        swatch: Swatch::ALL[rng.random_range(0..Swatch::ALL.len())],
+        size: ShapeSize::ALL[rng.random_range(0..ShapeSize::ALL.len())],
+    }
+}
+
+/// The target with exactly one of outline, colour or size changed.
+fn near_miss(rng: &mut impl Rng, target: ShapeState) -> ShapeState {
+    let other = random_shape(rng);
+    match rng.random_range(0..3) {
+        0 => ShapeState { shape: other.shape, ..target },
+        1 => ShapeState { swatch: other.swatch, ..target },
+        _ => ShapeState { size: other.size, ..target },
+    }
+}
+
+fn random_level40(d: &Difficulty) -> Level40State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 6..=8);
+    let target_shape = random_shape(&mut rng);
+
+    // Mostly near misses, so every attribute in the instruction matters
+    let mut picked = vec![target_shape];
+    while picked.len() < count {
+        let candidate = if rng.random_bool(0.65) {
+            near_miss(&mut rng, target_shape)
+        } else {
+            random_shape(&mut rng)
+        };
+        if !picked.contains(&candidate) {
+            picked.push(candidate);
+        }
+    }
+
+    let mut cells: Vec<usize> = (0..COLS * ROWS).collect();
+    let mut placed: Vec<(usize, ShapeState)> = picked.into_iter()
+        .map(|s| (cells.remove(rng.random_range(0..cells.len())), s))
+        .collect();
+    placed.sort_by_key(|(cell, _)| *cell);
+    let target = placed.iter().position(|(_, s)| *s == target_shape).unwrap_or(0);
+    let shapes = placed.into_iter()
+        .map(|(cell, s)| {
+            let slack = CELL - s.size.px();
+            let x = (cell % COLS) as f32 * CELL + rng.random_range(0.0..=slack);
+            let y = (cell / COLS) as f32 * CELL + rng.random_range(0.0..=slack);
+            (s, x, y)
+        })
+        .collect();
+
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, CARD_W, CARD_H, 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level40State { shapes, target, x, y }
+}
+
+#[component]
+pub fn Level40() -> Element {
+    let mut state = use_signal(|| random_level40(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut wrong = use_signal(|| None::<usize>);
+
+    let st = state.read();
+    let shapes = st.shapes.clone();
+    let target = st.target;
+    let card_x = st.x;
+    let card_y = st.y;
+    drop(st);
+
+    let instruction = tr("instr.click_shape", &[&shapes[target].0.phrase(super::current_lang())]);
+    let pressed = wrong();
+
+    // Ground truth
+    let field_x = card_x + CARD_PAD;
+    let field_y = card_y + CARD_PAD + INSTR_H;
+    let children: Vec<UINode> = shapes.iter().enumerate()
+        .map(|(i, (s, x, y))| {
+            let px = s.size.px();
+            let visual = Visual::new(s.label(), Rect::new(field_x + x, field_y + y, px, px)).color(s.swatch.to_css());
+            UINode::Shape(if i == target { visual.target() } else { visual }, *s)
+        })
+        .collect();
+    let tree = ui_node::card(Rect::new(card_x, card_y, CARD_W, CARD_H), children);
+
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 40"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Shape By Description"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {CARD_W}px; height: {CARD_H}px; padding: {CARD_PAD}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: system-ui, sans-serif;",
+
+                    div {
+                        style: "height: {INSTR_H}px; font-size: 15px; color: #374151; font-weight: 500;",
+                        "{instruction}"
+                    }
+
+                    div {
+                        style: "position: relative; width: {COLS as f32 * CELL}px; height: {ROWS as f32 * CELL}px;",
+                        for (i, (s, x, y)) in shapes.iter().enumerate() {
+                            {
+                                let label = s.label();
+                                let wrong_label = label.clone();
+                                let px = s.size.px();
+                                let fill = s.swatch.to_css();
+                                let outline = s.shape.to_css();
+                                let ring = if pressed == Some(i) { "outline: 2px solid #ef4444; outline-offset: 3px;" } else { "" };
+                                rsx! {
+                                    // The whole box takes the click; only the inner fill is clipped to the outline
+                                    div {
+                                        key: "{label}",
+                                        class: if i == target { "target" } else { "" },
+                                        "data-label": "{label}",
+                                        style: "position: absolute; left: {x}px; top: {y}px; width: {px}px; height: {px}px; cursor: pointer; {ring}",
+                                        onclick: move |_| {
+                                            if i == target {
+                                                score.complete();
+                                                bg.set(random_canvas_bg());
+                                                state.set(random_level40(Difficulty::current()));
+                                                wrong.set(None);
+                                            } else {
+                                                wrong.set(Some(i));
+                                                score.wrong(&wrong_label, "");
+                                                spawn(async move {
+                                                    gloo_timers::future::TimeoutFuture::new(400).await;
+                                                    wrong.set(None);
+                                                });
+                                            }
+                                        },
+                                        div {
+                                            style: "width: 100%; height: 100%; background: {fill}; pointer-events: none; {outline}",
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: card_x,
+                target_y: card_y,
+                target_w: CARD_W,
+                target_h: CARD_H,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::pool::Icon;
+use crate::ui_node::{self, IconState, Rect, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const CARD_W: f32 = 380.0;
+const CARD_PAD: f32 = 20.0;
+const INSTR_H: f32 = 44.0;
+const BAR_PAD: f32 = 6.0;
+const ICON_BTN: f32 = 40.0;
+const ICON_GAP: f32 = 6.0;
+const BAR_H: f32 = ICON_BTN + BAR_PAD * 2.0;
+const GAP: f32 = 14.0;
+/// Placeholder document under the toolbar, so the bar reads as an app's
+const DOC_H: f32 = 120.0;
+const CARD_H: f32 = CARD_PAD * 2.0 + INSTR_H + BAR_H + GAP + DOC_H;
+
+struct Level41State {
+    /// Toolbar icons, left to right
+    icons: Vec<Icon>,
+    target: usize,
+    x: f32,
+    y: f32,
+}
+
+fn random_level41(d: &Difficulty) -> Level41State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 4..=Icon::ALL.len());
+    let mut pool: Vec<Icon> = Icon::ALL.to_vec();
+    let icons: Vec<Icon> = (0..count)
+        .map(|_| pool.remove(rng.random_range(0..pool.len())))
+        .collect();
+    let target = rng.random_range(0..count);
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, CARD_W, CARD_H, 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level41State { icons, target, x, y }
+}
+
+#[component]
+pub fn Level41() -> Element {
+    let mut state = use_signal(|| random_level41(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut wrong = use_signal(|| None::<usize>);
+
+    let st = state.read();
+    let icons = st.icons.clone();
+    let target = st.target;
+    let card_x = st.x;
+    let card_y = st.y;
+    drop(st);
+
+    // Tasks name the function, never the glyph
+    let instruction = tr(&format!("instr.icon.{}", icons[target].function()), &[]);
+    let pressed = wrong();
+
+    // Ground truth
+    let bar_x = card_x + CARD_PAD + BAR_PAD;
+    let bar_y = card_y + CARD_PAD + INSTR_H + BAR_PAD;
+    let children: Vec<UINode> = icons.iter().enumerate()
+        .map(|(i, icon)| {
+            let visual = Visual::new(icon.function(), Rect::new(bar_x + i as f32 * (ICON_BTN + ICON_GAP), bar_y, ICON_BTN, ICON_BTN));
+            UINode::IconButton(
+                if i == target { visual.target() } else { visual },
+                IconState { glyph: icon.glyph().to_string() },
+            )
+        })
+        .collect();
+    let tree = ui_node::card(Rect::new(card_x, card_y, CARD_W, CARD_H), children);
+
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 41"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Icon Toolbar"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {CARD_W}px; height: {CARD_H}px; padding: {CARD_PAD}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: system-ui, sans-serif;",
+
+                    div {
+                        style: "height: {INSTR_H}px; font-size: 15px; color: #374151; font-weight: 500;",
+                        "{instruction}"
+                    }
+
+                    div {
+                        style: "display: flex; gap: {ICON_GAP}px; height: {BAR_H}px; padding: {BAR_PAD}px; background: #f3f4f6; border-radius: 8px; box-sizing: border-box;",
+                        for (i, icon) in icons.iter().enumerate() {
+                            {
+                                let function = icon.function();
+                                let svg = icon.svg("#374151");
+                                let button_bg = if pressed == Some(i) { "#fee2e2" } else { "white" };
+                                rsx! {
+                                    // No title: a hover tooltip would give the function away as text
+                                    button {
+                                        key: "{function}",
+                                        class: if i == target { "target" } else { "" },
+                                        "data-label": function,
+                                        "aria-label": function,
+                                        style: "width: {ICON_BTN}px; height: {ICON_BTN}px; padding: 9px; background: {button_bg}; border: 1px solid #e5e7eb; border-radius: 6px; cursor: pointer; box-sizing: border-box;",
+                                        onclick: move |_| {
+                                            if i == target {
+                                                score.complete();
+                                                bg.set(random_canvas_bg());
+                                                state.set(random_level41(Difficulty::current()));
+                                                wrong.set(None);
+                                            } else {
+                                                wrong.set(Some(i));
+                                                score.wrong(function, "");
+                                                spawn(async move {
+                                                    gloo_timers::future::TimeoutFuture::new(400).await;
+                                                    wrong.set(None);
+                                                });
+                                            }
+                                        },
+                                        div {
+                                            style: "width: 100%; height: 100%; pointer-events: none;",
+                                            dangerous_inner_html: "{svg}",
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        style: "height: {DOC_H}px; margin-top: {GAP}px; padding: 14px; border: 1px solid #e5e7eb; border-radius: 8px; box-sizing: border-box; display: flex; flex-direction: column; gap: 10px;",
+                        for w in [70, 92, 84, 60] {
+                            div {
+                                key: "{w}",
+                                style: "height: 10px; width: {w}%; background: #e5e7eb; border-radius: 5px;",
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: card_x,
+                target_y: card_y,
+                target_w: CARD_W,
+                target_h: CARD_H,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
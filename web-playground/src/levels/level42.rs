@@ -0,0 +1,214 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::ui_node::{self, Rect, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const ACTIONS: &[&str] = &["Save", "Share", "Archive", "Delete", "Export", "Print", "Duplicate"];
+const DOCS: &[&str] = &["Draft", "Budget", "Roadmap", "Minutes", "Invoice", "Checklist", "Report"];
+
+const PANE_W: f32 = 240.0;
+const PANE_GAP: f32 = 16.0;
+const PANE_PAD: f32 = 16.0;
+const HEADER_H: f32 = 40.0;
+const BTN_H: f32 = 36.0;
+const BTN_GAP: f32 = 8.0;
+const WRAP_W: f32 = PANE_W * 2.0 + PANE_GAP;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    fn name(self) -> &'static str {
+        match self {
+            Side::Left => "left panel",
+            Side::Right => "right panel",
+        }
+    }
+
+    fn short(self) -> &'static str {
+        match self {
+            Side::Left => "left",
+            Side::Right => "right",
+        }
+    }
+}
+
+struct Level42State {
+    /// Same actions, same order, in both panes; only the pane tells them apart
+    actions: Vec<&'static str>,
+    /// Document open in each pane (left, right)
+    docs: (&'static str, &'static str),
+    target_side: Side,
+    target: usize,
+    x: f32,
+    y: f32,
+}
+
+/// Labels repeat across panes, so each carries its side: "Save (right)"
+fn action_label(action: &str, side: Side) -> String {
+    format!("{action} ({})", side.short())
+}
+
+fn pane_height(actions: usize) -> f32 {
+    PANE_PAD * 2.0 + HEADER_H + actions as f32 * BTN_H + (actions - 1) as f32 * BTN_GAP
+}
+
+fn random_level42(d: &Difficulty) -> Level42State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 3..=4);
+    let mut pool: Vec<usize> = (0..ACTIONS.len()).collect();
+    let actions: Vec<&'static str> = (0..count)
+        .map(|_| ACTIONS[pool.remove(rng.random_range(0..pool.len()))])
+        .collect();
+    let mut docs: Vec<usize> = (0..DOCS.len()).collect();
+    let left = DOCS[docs.remove(rng.random_range(0..docs.len()))];
+    let right = DOCS[docs.remove(rng.random_range(0..docs.len()))];
+    let target_side = if rng.random_bool(0.5) { Side::Left } else { Side::Right };
+    let target = rng.random_range(0..count);
+    let (vp_w, vp_h) = crate::primitives::viewport_size();
+    let (x, y) = super::safe_position_in(&mut rng, WRAP_W, pane_height(count), 50.0, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level42State { actions, docs: (left, right), target_side, target, x, y }
+}
+
+#[component]
+pub fn Level42() -> Element {
+    let mut state = use_signal(|| random_level42(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut wrong = use_signal(|| None::<String>);
+
+    let st = state.read();
+    let actions = st.actions.clone();
+    let docs = st.docs;
+    let target_side = st.target_side;
+    let target = st.target;
+    let wrap_x = st.x;
+    let wrap_y = st.y;
+    drop(st);
+
+    let instruction = match target_side {
+        Side::Left => tr("instr.pane_left", &[&actions[target]]),
+        Side::Right => tr("instr.pane_right", &[&actions[target]]),
+    };
+    let pressed = wrong();
+    let pane_h = pane_height(actions.len());
+
+    // Ground truth: each pane is its own little viewport, children pane-local
+    let pane_node = |side: Side, x: f32| {
+        let children = actions.iter().enumerate()
+            .map(|(i, action)| {
+                let visual = Visual::new(action_label(action, side), Rect::new(
+                    PANE_PAD, PANE_PAD + HEADER_H + i as f32 * (BTN_H + BTN_GAP), PANE_W - PANE_PAD * 2.0, BTN_H,
+                ));
+                UINode::Button(if side == target_side && i == target { visual.target() } else { visual })
+            })
+            .collect();
+        ui_node::pane(Rect::new(x, wrap_y, PANE_W, pane_h), side.name(), children)
+    };
+    let tree = ui_node::card(
+        Rect::new(wrap_x, wrap_y, WRAP_W, pane_h),
+        vec![pane_node(Side::Left, wrap_x), pane_node(Side::Right, wrap_x + PANE_W + PANE_GAP)],
+    );
+
+    let description = String::new();
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 42"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Split Screen"
+                }
+                span {
+                    style: "color: #9ca3af; font-size: 14px;",
+                    "{instruction}"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                for (side, pane_x, doc) in [(Side::Left, wrap_x, docs.0), (Side::Right, wrap_x + PANE_W + PANE_GAP, docs.1)] {
+                    div {
+                        key: "{side.short()}",
+                        style: "position: absolute; left: {pane_x}px; top: {wrap_y}px; width: {PANE_W}px; height: {pane_h}px; padding: {PANE_PAD}px; background: white; border-radius: 10px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: system-ui, sans-serif;",
+
+                        div {
+                            style: "height: {HEADER_H}px; display: flex; align-items: flex-start; gap: 8px; font-size: 15px; font-weight: 600; color: #111827;",
+                            span { style: "color: #6b7280; font-weight: 500;", "Docs /" }
+                            "{doc}"
+                        }
+
+                        for (i, action) in actions.iter().enumerate() {
+                            {
+                                let action: &'static str = action;
+                                let label = action_label(action, side);
+                                let wrong_label = label.clone();
+                                let is_target = side == target_side && i == target;
+                                let border = if pressed.as_deref() == Some(label.as_str()) { "#ef4444" } else { "#e5e7eb" };
+                                let margin = if i + 1 < actions.len() { BTN_GAP } else { 0.0 };
+                                rsx! {
+                                    button {
+                                        key: "{label}",
+                                        class: if is_target { "target" } else { "" },
+                                        "data-label": "{label}",
+                                        style: "display: block; width: 100%; height: {BTN_H}px; margin-bottom: {margin}px; padding: 0 12px; text-align: left; background: #f9fafb; color: #111827; border: 1px solid {border}; border-radius: 6px; font-size: 14px; cursor: pointer;",
+                                        onclick: move |_| {
+                                            if is_target {
+                                                score.complete();
+                                                bg.set(random_canvas_bg());
+                                                state.set(random_level42(Difficulty::current()));
+                                                wrong.set(None);
+                                            } else {
+                                                wrong.set(Some(wrong_label.clone()));
+                                                score.wrong(&wrong_label, "");
+                                                spawn(async move {
+                                                    gloo_timers::future::TimeoutFuture::new(400).await;
+                                                    wrong.set(None);
+                                                });
+                                            }
+                                        },
+                                        "{action}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: wrap_x,
+                target_y: wrap_y,
+                target_w: WRAP_W,
+                target_h: pane_h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::primitives::viewport_size;
+use crate::ui_node::{self, Rect, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const FIRST: &[&str] = &["Maya", "Omar", "Lena", "Tariq", "Sofia", "Jonas", "Aiko", "Priya", "Diego", "Nora", "Felix", "Hana"];
+const LAST: &[&str] = &["Chen", "Okafor", "Berg", "Silva", "Novak", "Haddad", "Ito", "Moreau", "Kowalski", "Reyes"];
+
+const LIST_LABEL: &str = "Contacts";
+const CARD_W: f32 = 300.0;
+const CARD_PAD: f32 = 16.0;
+const TITLE_H: f32 = 36.0;
+const ROW_H: f32 = 36.0;
+/// Six rows show at once; the target always starts further down
+const LIST_H: f32 = ROW_H * 6.0;
+const CARD_H: f32 = CARD_PAD * 2.0 + TITLE_H + LIST_H;
+
+struct Level43State {
+    /// Contact names, alphabetical, top to bottom
+    rows: Vec<String>,
+    target: usize,
+    x: f32,
+    y: f32,
+}
+
+fn random_level43(d: &Difficulty) -> Level43State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 20..=28);
+    let mut rows: Vec<String> = Vec::with_capacity(count);
+    while rows.len() < count {
+        let name = format!(
+            "{} {}",
+            FIRST[rng.random_range(0..FIRST.len())],
+            LAST[rng.random_range(0..LAST.len())],
+        );
+        if !rows.contains(&name) {
+            rows.push(name);
+        }
+    }
+    rows.sort();
+    let target = rng.random_range(8..count);
+
+    // The card starts below the fold, so the page has to scroll before the list can
+    let (vp_w, vp_h) = viewport_size();
+    let pad = 40.0;
+    let x = rng.random_range(pad..(vp_w - CARD_W - pad).max(pad + 1.0));
+    let min_y = vp_h * 0.95;
+    let max_y = (vp_h * 1.6 - CARD_H - pad).max(min_y + 1.0);
+    let y = rng.random_range(min_y..max_y);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level43State { rows, target, x, y }
+}
+
+#[component]
+pub fn Level43() -> Element {
+    let mut state = use_signal(|| random_level43(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut wrong = use_signal(|| None::<usize>);
+
+    let st = state.read();
+    let rows = st.rows.clone();
+    let target = st.target;
+    let card_x = st.x;
+    let card_y = st.y;
+    drop(st);
+
+    let instruction = tr("instr.nested_scroll", &[&rows[target]]);
+    let pressed = wrong();
+
+    let (vp_w, vp_h) = viewport_size();
+    let canvas_w = vp_w;
+    let canvas_h = vp_h * 1.6;
+
+    // Ground truth: rows at their unscrolled positions; measurement moves
+    // them as the page and the list scroll
+    let list = Rect::new(card_x + CARD_PAD, card_y + CARD_PAD + TITLE_H, CARD_W - CARD_PAD * 2.0, LIST_H);
+    let children: Vec<UINode> = rows.iter().enumerate()
+        .map(|(i, name)| {
+            let visual = Visual::new(name.as_str(), Rect::new(list.x, list.y + i as f32 * ROW_H, list.w, ROW_H));
+            UINode::Button(if i == target { visual.target() } else { visual })
+        })
+        .collect();
+    let tree = ui_node::card(
+        Rect::new(card_x, card_y, CARD_W, CARD_H),
+        vec![ui_node::scroll_area(LIST_LABEL, list, children)],
+    );
+
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 43"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Nested Scroll"
+                }
+                span {
+                    style: "color: #9ca3af; font-size: 14px;",
+                    "{instruction}"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                // Spacer so the viewport scrolls down to the card
+                div {
+                    style: "position: absolute; left: 0; top: 0; width: {canvas_w}px; height: {canvas_h}px; pointer-events: none;",
+                }
+
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {CARD_W}px; height: {CARD_H}px; padding: {CARD_PAD}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: system-ui, sans-serif;",
+
+                    div {
+                        style: "height: {TITLE_H}px; font-size: 15px; font-weight: 600; color: #111827;",
+                        "{LIST_LABEL}"
+                    }
+
+                    div {
+                        "data-label": LIST_LABEL,
+                        style: "height: {LIST_H}px; overflow-y: auto; border: 1px solid #e5e7eb; border-radius: 8px; box-sizing: border-box;",
+                        for (i, name) in rows.iter().enumerate() {
+                            {
+                                let name = name.clone();
+                                let wrong_name = name.clone();
+                                let row_bg = if pressed == Some(i) { "#fee2e2" } else { "white" };
+                                rsx! {
+                                    div {
+                                        key: "{name}",
+                                        class: if i == target { "target" } else { "" },
+                                        "data-label": "{name}",
+                                        style: "height: {ROW_H}px; line-height: {ROW_H}px; padding: 0 12px; background: {row_bg}; border-bottom: 1px solid #f3f4f6; font-size: 14px; color: #111827; cursor: pointer; box-sizing: border-box;",
+                                        onclick: move |_| {
+                                            if i == target {
+                                                score.complete();
+                                                bg.set(random_canvas_bg());
+                                                state.set(random_level43(Difficulty::current()));
+                                                wrong.set(None);
+                                                // Both scrollers start from the top next round
+                                                document::eval("document.getElementById('viewport')?.scrollTo(0, 0); document.querySelector('[data-label=\"Contacts\"]')?.scrollTo(0, 0);");
+                                            } else {
+                                                wrong.set(Some(i));
+                                                score.wrong(&wrong_name, "");
+                                                spawn(async move {
+                                                    gloo_timers::future::TimeoutFuture::new(400).await;
+                                                    wrong.set(None);
+                                                });
+                                            }
+                                        },
+                                        "{name}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: String::new(),
+                target_x: card_x,
+                target_y: card_y,
+                target_w: CARD_W,
+                target_h: CARD_H,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::primitives::viewport_size;
+use crate::ui_node::{self, Rect, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const AUTHORS: &[&str] = &[
+    "Maya Chen", "Omar Haddad", "Lena Berg", "Tariq Silva", "Sofia Novak", "Jonas Ito",
+    "Aiko Moreau", "Priya Reyes", "Diego Okafor", "Nora Kowalski", "Felix Chen", "Hana Silva",
+    "Ines Berg", "Kofi Novak", "Ravi Ito", "Zoe Haddad",
+];
+
+const FAB_LABEL: &str = "New post";
+const HEADER_H: f32 = 64.0;
+const SIDE: f32 = 24.0;
+const GAP: f32 = 12.0;
+const POST_H: f32 = 100.0;
+const POST_PAD: f32 = 12.0;
+const BTN_W: f32 = 84.0;
+const BTN_H: f32 = 32.0;
+const FAB_W: f32 = 140.0;
+const FAB_H: f32 = 52.0;
+const FAB_MARGIN: f32 = 16.0;
+
+/// What sits on top of the target when the round starts
+#[derive(Clone, Copy, PartialEq)]
+enum Cover {
+    Header,
+    Fab,
+}
+
+struct Level44State {
+    authors: Vec<&'static str>,
+    target: usize,
+    /// Initial viewport scroll, chosen so `cover` hides the target's button
+    scroll: f32,
+}
+
+/// Content-space top of post `i`; the sticky header takes the first `HEADER_H`.
+fn post_top(i: usize) -> f32 {
+    HEADER_H + GAP + i as f32 * (POST_H + GAP)
+}
+
+fn follow_label(author: &str) -> String {
+    format!("Follow {author}")
+}
+
+fn random_level44(d: &Difficulty) -> Level44State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 12..=AUTHORS.len());
+    let mut pool: Vec<&'static str> = AUTHORS.to_vec();
+    let authors: Vec<&'static str> = (0..count)
+        .map(|_| pool.remove(rng.random_range(0..pool.len())))
+        .collect();
+
+    let (_, vp_h) = viewport_size();
+    let content_h = post_top(count) + FAB_H + FAB_MARGIN * 2.0;
+    let max_scroll = (content_h - vp_h).max(0.0);
+    // Scroll that puts post i's button centre under the cover's centre
+    let scroll_for = |cover: Cover, i: usize| {
+        let cy = post_top(i) + POST_H / 2.0;
+        match cover {
+            Cover::Header => cy - HEADER_H / 2.0,
+            Cover::Fab => cy - (vp_h - FAB_MARGIN - FAB_H / 2.0),
+        }
+    };
+    let first = if rng.random_bool(0.5) { Cover::Header } else { Cover::Fab };
+    let other = if first == Cover::Header { Cover::Fab } else { Cover::Header };
+    let candidates: Vec<(Cover, usize)> = [first, other].into_iter()
+        .map(|cover| (0..count)
+            .filter(|&i| (0.0..=max_scroll).contains(&scroll_for(cover, i)))
+            .map(|i| (cover, i))
+            .collect::<Vec<_>>())
+        .find(|c| !c.is_empty())
+        .unwrap_or_else(|| vec![(Cover::Header, 0)]);
+    let (cover, target) = candidates[rng.random_range(0..candidates.len())];
+    let scroll = scroll_for(cover, target).clamp(0.0, max_scroll).round();
+    Level44State { authors, target, scroll }
+}
+
+#[component]
+pub fn Level44() -> Element {
+    let mut state = use_signal(|| random_level44(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut wrong = use_signal(|| None::<String>);
+
+    // Each round opens scrolled so the target hides under the header or the button
+    use_effect(move || {
+        let scroll = state.read().scroll;
+        document::eval(&format!("document.getElementById('viewport')?.scrollTo(0, {scroll})"));
+    });
+
+    let st = state.read();
+    let authors = st.authors.clone();
+    let target = st.target;
+    let scroll = st.scroll;
+    drop(st);
+
+    let instruction = tr("instr.follow", &[&authors[target]]);
+    let pressed = wrong();
+
+    // Ground truth: visible boxes at the round's initial scroll
+    let (vp_w, vp_h) = viewport_size();
+    let btn_x = vp_w - SIDE - POST_PAD - BTN_W;
+    let mut children: Vec<UINode> = authors.iter().enumerate()
+        .map(|(i, author)| {
+            let y = post_top(i) - scroll + (POST_H - BTN_H) / 2.0;
+            let visual = Visual::new(follow_label(author), Rect::new(btn_x, y, BTN_W, BTN_H));
+            UINode::Button(if i == target { visual.target() } else { visual })
+        })
+        .collect();
+    children.push(UINode::Button(Visual::new(FAB_LABEL, Rect::new(
+        vp_w - FAB_MARGIN - FAB_W, vp_h - FAB_MARGIN - FAB_H, FAB_W, FAB_H,
+    ))));
+    let tree = ui_node::card(Rect::new(0.0, 0.0, vp_w, vp_h), children);
+
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    let mut press = move |label: String, is_target: bool| {
+        if is_target {
+            score.complete();
+            bg.set(random_canvas_bg());
+            state.set(random_level44(Difficulty::current()));
+            wrong.set(None);
+        } else {
+            score.wrong(&label, "");
+            wrong.set(Some(label));
+            spawn(async move {
+                gloo_timers::future::TimeoutFuture::new(400).await;
+                wrong.set(None);
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 44"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Sticky Header"
+                }
+                span {
+                    style: "color: #9ca3af; font-size: 14px;",
+                    "{instruction}"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    style: "padding-bottom: {FAB_MARGIN}px; font-family: system-ui, sans-serif;",
+
+                    // Stays pinned over the feed, hiding whatever scrolls beneath it
+                    div {
+                        style: "position: sticky; top: 0; z-index: 10; height: {HEADER_H}px; margin-bottom: {GAP}px; padding: 0 {SIDE}px; display: flex; align-items: center; background: #111827; color: white; font-size: 18px; font-weight: 600; box-shadow: 0 2px 12px rgba(0,0,0,0.3);",
+                        "Feed"
+                    }
+
+                    for (i, author) in authors.iter().enumerate() {
+                        {
+                            let author: &'static str = author;
+                            let label = follow_label(author);
+                            let click_label = label.clone();
+                            let is_target = i == target;
+                            let border = if pressed.as_deref() == Some(label.as_str()) { "#ef4444" } else { "#d1d5db" };
+                            rsx! {
+                                div {
+                                    key: "{author}",
+                                    style: "height: {POST_H}px; margin: 0 {SIDE}px {GAP}px; padding: {POST_PAD}px; display: flex; align-items: center; gap: 12px; background: white; border-radius: 10px; box-sizing: border-box;",
+                                    div {
+                                        style: "width: 44px; height: 44px; border-radius: 50%; background: #e5e7eb; flex-shrink: 0;",
+                                    }
+                                    div {
+                                        style: "flex: 1; display: flex; flex-direction: column; gap: 8px;",
+                                        span { style: "font-size: 14px; font-weight: 600; color: #111827;", "{author}" }
+                                        div { style: "height: 8px; width: 80%; background: #f3f4f6; border-radius: 4px;" }
+                                        div { style: "height: 8px; width: 55%; background: #f3f4f6; border-radius: 4px;" }
+                                    }
+                                    button {
+                                        class: if is_target { "target" } else { "" },
+                                        "data-label": "{label}",
+                                        style: "width: {BTN_W}px; height: {BTN_H}px; flex-shrink: 0; background: white; color: #111827; border: 1px solid {border}; border-radius: 16px; font-size: 13px; cursor: pointer;",
+                                        onclick: move |_| press(click_label.clone(), is_target),
+                                        "Follow"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Floats over the bottom of the feed, covering posts as they pass
+                    button {
+                        "data-label": FAB_LABEL,
+                        style: "position: sticky; bottom: {FAB_MARGIN}px; z-index: 10; display: block; width: {FAB_W}px; height: {FAB_H}px; margin: 0 {FAB_MARGIN}px 0 auto; background: #2563eb; color: white; border: none; border-radius: 26px; font-size: 15px; font-weight: 600; box-shadow: 0 4px 16px rgba(0,0,0,0.35); cursor: pointer;",
+                        onclick: move |_| press(FAB_LABEL.to_string(), false),
+                        "+ {FAB_LABEL}"
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: String::new(),
+                target_x: btn_x,
+                target_y: post_top(target) - scroll,
+                target_w: BTN_W,
+                target_h: BTN_H,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
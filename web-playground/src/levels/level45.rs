@@ -0,0 +1,257 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::primitives::viewport_size;
+use crate::ui_node::{self, Rect, TagState, ToggleState, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const ADJECTIVES: &[&str] = &[
+    "Amber", "Azure", "Coral", "Dusk", "Frost", "Ivory", "Jade", "Lunar",
+    "Mossy", "Noble", "Onyx", "Pearl", "Rusty", "Solar", "Tidal", "Vivid",
+];
+const NOUNS: &[&str] = &["Fox", "Owl", "Elk", "Lynx", "Wren", "Crow", "Hare", "Moth", "Seal", "Wolf", "Bee", "Yak"];
+
+const CELL_W: f32 = 100.0;
+const CELL_H: f32 = 28.0;
+const GAP: f32 = 6.0;
+const CARD_PAD: f32 = 16.0;
+const MAX_COLS: usize = 10;
+const MIN_COLS: usize = 4;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Button,
+    Chip,
+    Toggle,
+}
+
+#[derive(Clone, PartialEq)]
+struct Cell {
+    label: String,
+    kind: Kind,
+}
+
+struct Level45State {
+    /// Grid cells, row by row
+    cells: Vec<Cell>,
+    target: usize,
+    cols: usize,
+    x: f32,
+    y: f32,
+}
+
+/// Columns that fit the viewport, within `MIN_COLS..=MAX_COLS`.
+fn grid_cols(vp_w: f32) -> usize {
+    let fit = ((vp_w - 2.0 * (CARD_PAD + 20.0) + GAP) / (CELL_W + GAP)).floor() as usize;
+    fit.clamp(MIN_COLS, MAX_COLS)
+}
+
+fn card_size(count: usize, cols: usize) -> (f32, f32) {
+    let rows = count.div_ceil(cols);
+    (
+        CARD_PAD * 2.0 + cols as f32 * (CELL_W + GAP) - GAP,
+        CARD_PAD * 2.0 + rows as f32 * (CELL_H + GAP) - GAP,
+    )
+}
+
+/// `count` distinct "Adjective Noun" labels, none contained in another, so a
+/// label names exactly one cell however the solver matches text.
+fn unique_labels(rng: &mut impl Rng, count: usize) -> Vec<String> {
+    let mut combos: Vec<String> = ADJECTIVES.iter()
+        .flat_map(|a| NOUNS.iter().map(move |n| format!("{a} {n}")))
+        .collect();
+    let mut labels: Vec<String> = Vec::with_capacity(count);
+    while labels.len() < count && !combos.is_empty() {
+        let label = combos.swap_remove(rng.random_range(0..combos.len()));
+        if !labels.iter().any(|l| l.contains(label.as_str()) || label.contains(l.as_str())) {
+            labels.push(label);
+        }
+    }
+    labels
+}
+
+fn random_level45(d: &Difficulty) -> Level45State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 40..=100);
+    let cells: Vec<Cell> = unique_labels(&mut rng, count).into_iter()
+        .map(|label| {
+            let kind = match rng.random_range(0..3u8) {
+                0 => Kind::Button,
+                1 => Kind::Chip,
+                _ => Kind::Toggle,
+            };
+            Cell { label, kind }
+        })
+        .collect();
+    let target = rng.random_range(0..cells.len());
+    let (vp_w, vp_h) = viewport_size();
+    let cols = grid_cols(vp_w);
+    let (card_w, card_h) = card_size(cells.len(), cols);
+    let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, 20.0, vp_w, vp_h);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    Level45State { cells, target, cols, x, y }
+}
+
+#[component]
+pub fn Level45() -> Element {
+    let mut state = use_signal(|| random_level45(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut wrong = use_signal(|| None::<usize>);
+
+    let st = state.read();
+    let cells = st.cells.clone();
+    let target = st.target;
+    let cols = st.cols;
+    let card_x = st.x;
+    let card_y = st.y;
+    drop(st);
+
+    let target_cell = &cells[target];
+    let instruction = match target_cell.kind {
+        Kind::Button => tr("instr.dense_button", &[&target_cell.label]),
+        Kind::Chip => tr("instr.dense_chip", &[&target_cell.label]),
+        Kind::Toggle => tr("instr.dense_toggle", &[&target_cell.label]),
+    };
+    let pressed = wrong();
+
+    // Ground truth: cell boxes from the grid arithmetic; chips and toggles
+    // all start off, and only the target has to change
+    let (card_w, card_h) = card_size(cells.len(), cols);
+    let children: Vec<UINode> = cells.iter().enumerate()
+        .map(|(i, cell)| {
+            let rect = Rect::new(
+                card_x + CARD_PAD + (i % cols) as f32 * (CELL_W + GAP),
+                card_y + CARD_PAD + (i / cols) as f32 * (CELL_H + GAP),
+                CELL_W,
+                CELL_H,
+            );
+            let is_target = i == target;
+            let visual = Visual::new(cell.label.as_str(), rect);
+            let visual = if is_target { visual.target() } else { visual };
+            match cell.kind {
+                Kind::Button => UINode::Button(visual),
+                Kind::Chip => UINode::Tag(visual, TagState { is_selected: false, target_selected: is_target }),
+                Kind::Toggle => UINode::Toggle(visual, ToggleState { is_on: false, target_on: is_target }),
+            }
+        })
+        .collect();
+    let tree = ui_node::card(Rect::new(card_x, card_y, card_w, card_h), children);
+
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 45"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Dense Grid"
+                }
+                span {
+                    style: "color: #9ca3af; font-size: 14px;",
+                    "{instruction}"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                // One fixed-track grid: cells never measure or reflow each other
+                div {
+                    style: "position: absolute; left: {card_x}px; top: {card_y}px; width: {card_w}px; padding: {CARD_PAD}px; display: grid; grid-template-columns: repeat({cols}, {CELL_W}px); grid-auto-rows: {CELL_H}px; gap: {GAP}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: system-ui, sans-serif; font-size: 12px;",
+
+                    for (i, cell) in cells.iter().enumerate() {
+                        {
+                            let label = cell.label.clone();
+                            let wrong_label = label.clone();
+                            let flash = pressed == Some(i);
+                            let onclick = move |_| {
+                                if i == target {
+                                    score.complete();
+                                    bg.set(random_canvas_bg());
+                                    state.set(random_level45(Difficulty::current()));
+                                    wrong.set(None);
+                                } else {
+                                    wrong.set(Some(i));
+                                    score.wrong(&wrong_label, "");
+                                    spawn(async move {
+                                        gloo_timers::future::TimeoutFuture::new(400).await;
+                                        wrong.set(None);
+                                    });
+                                }
+                            };
+                            let class = if i == target { "target" } else { "" };
+                            let border = if flash { "#ef4444" } else { "#d1d5db" };
+                            match cell.kind {
+                                Kind::Button => rsx! {
+                                    button {
+                                        key: "{label}",
+                                        class: class,
+                                        "data-label": "{label}",
+                                        style: "padding: 0 6px; background: #f9fafb; color: #111827; border: 1px solid {border}; border-radius: 4px; font-size: 12px; white-space: nowrap; overflow: hidden; cursor: pointer;",
+                                        onclick: onclick,
+                                        "{label}"
+                                    }
+                                },
+                                Kind::Chip => rsx! {
+                                    div {
+                                        key: "{label}",
+                                        class: class,
+                                        "data-label": "{label}",
+                                        role: "option",
+                                        style: "display: flex; align-items: center; justify-content: center; background: #eef2ff; color: #3730a3; border: 1px solid {border}; border-radius: 14px; white-space: nowrap; overflow: hidden; cursor: pointer; box-sizing: border-box;",
+                                        onclick: onclick,
+                                        "{label}"
+                                    }
+                                },
+                                Kind::Toggle => rsx! {
+                                    div {
+                                        key: "{label}",
+                                        class: class,
+                                        "data-label": "{label}",
+                                        role: "switch",
+                                        "aria-checked": "false",
+                                        style: "display: flex; align-items: center; gap: 5px; padding: 0 4px; border: 1px solid {border}; border-radius: 4px; color: #374151; white-space: nowrap; overflow: hidden; cursor: pointer; box-sizing: border-box;",
+                                        onclick: onclick,
+                                        div {
+                                            style: "width: 20px; height: 12px; flex-shrink: 0; border-radius: 6px; background: #d1d5db; position: relative;",
+                                            div { style: "position: absolute; left: 1px; top: 1px; width: 10px; height: 10px; border-radius: 50%; background: white;" }
+                                        }
+                                        "{label}"
+                                    }
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: String::new(),
+                target_x: card_x,
+                target_y: card_y,
+                target_w: card_w,
+                target_h: card_h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
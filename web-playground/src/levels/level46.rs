@@ -0,0 +1,266 @@
+use dioxus::prelude::*;
+use rand::Rng;
+
+use crate::Route;
+use crate::primitives::viewport_size;
+use crate::ui_node::{self, Rect, UINode, Visual};
+use super::{Difficulty, fresh_rng, random_canvas_bg, tr};
+
+const HEADINGS: &[&str] = &[
+    "Overview", "Installation", "Configuration", "Authentication", "Pagination", "Rate limits", "Webhooks",
+    "Error codes", "Versioning", "Pricing", "Security", "Changelog", "Glossary", "Support",
+];
+
+const TITLE: &str = "Developer guide";
+const TOP: f32 = 24.0;
+const SIDE: f32 = 24.0;
+const MAX_W: f32 = 640.0;
+const PAD: f32 = 24.0;
+const TITLE_H: f32 = 48.0;
+const TOC_HEAD_H: f32 = 28.0;
+const LINK_H: f32 = 24.0;
+const TOC_GAP: f32 = 24.0;
+const HEAD_H: f32 = 40.0;
+/// Placeholder text line: a 10px bar and the gap under it
+const LINE_H: f32 = 20.0;
+const BTN_W: f32 = 200.0;
+const BTN_H: f32 = 30.0;
+const BTN_GAP: f32 = 12.0;
+const SECTION_GAP: f32 = 32.0;
+
+struct Section {
+    heading: &'static str,
+    lines: usize,
+}
+
+struct Level46State {
+    sections: Vec<Section>,
+    target: usize,
+    /// Whether the instruction asks for the contents link rather than scrolling
+    via_toc: bool,
+}
+
+fn link_label(i: usize, heading: &str) -> String {
+    format!("{}. {heading}", i + 1)
+}
+
+fn cite_label(heading: &str) -> String {
+    format!("Cite {heading}")
+}
+
+fn section_h(lines: usize) -> f32 {
+    HEAD_H + lines as f32 * LINE_H + BTN_GAP + BTN_H
+}
+
+/// Content-space top of each section, then the article's bottom.
+fn section_tops(sections: &[Section]) -> Vec<f32> {
+    let mut y = TOP + PAD + TITLE_H + TOC_HEAD_H + sections.len() as f32 * LINK_H + TOC_GAP;
+    let mut tops = Vec::with_capacity(sections.len() + 1);
+    for s in sections {
+        tops.push(y);
+        y += section_h(s.lines) + SECTION_GAP;
+    }
+    tops.push(y - SECTION_GAP + PAD);
+    tops
+}
+
+fn random_level46(d: &Difficulty) -> Level46State {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, 8..=12);
+    // A random subset, kept in guide order
+    let mut picked: Vec<usize> = (0..HEADINGS.len()).collect();
+    while picked.len() > count {
+        picked.remove(rng.random_range(0..picked.len()));
+    }
+    let sections: Vec<Section> = picked.into_iter()
+        .map(|i| Section { heading: HEADINGS[i], lines: rng.random_range(3..=8) })
+        .collect();
+
+    // Only sections whose button starts below the fold
+    let (_, vp_h) = viewport_size();
+    let tops = section_tops(&sections);
+    let below: Vec<usize> = (0..sections.len())
+        .filter(|&i| tops[i] + section_h(sections[i].lines) - BTN_H > vp_h)
+        .collect();
+    let target = if below.is_empty() { sections.len() - 1 } else { below[rng.random_range(0..below.len())] };
+    let via_toc = rng.random_bool(0.5);
+    Level46State { sections, target, via_toc }
+}
+
+#[component]
+pub fn Level46() -> Element {
+    let mut state = use_signal(|| random_level46(Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut wrong = use_signal(|| None::<usize>);
+
+    let st = state.read();
+    let headings: Vec<&'static str> = st.sections.iter().map(|s| s.heading).collect();
+    let lines: Vec<usize> = st.sections.iter().map(|s| s.lines).collect();
+    let tops = section_tops(&st.sections);
+    let target = st.target;
+    let via_toc = st.via_toc;
+    drop(st);
+
+    let goal = headings[target];
+    let instruction = if via_toc {
+        tr("instr.article_toc", &[&goal, &cite_label(goal)])
+    } else {
+        tr("instr.article_scroll", &[&goal, &cite_label(goal)])
+    };
+    let pressed = wrong();
+
+    // Ground truth: boxes at their unscrolled positions; measurement moves
+    // them as the page scrolls
+    let (vp_w, _) = viewport_size();
+    let article_w = (vp_w - SIDE * 2.0).min(MAX_W);
+    let article_x = ((vp_w - article_w) / 2.0).max(0.0);
+    let inner_x = article_x + PAD;
+    let links_y = TOP + PAD + TITLE_H + TOC_HEAD_H;
+    let mut children: Vec<UINode> = headings.iter().enumerate()
+        .map(|(i, h)| UINode::Button(Visual::new(link_label(i, h), Rect::new(inner_x, links_y + i as f32 * LINK_H, article_w - PAD * 2.0, LINK_H))))
+        .collect();
+    children.extend(headings.iter().enumerate().map(|(i, h)| {
+        let visual = Visual::new(cite_label(h), Rect::new(inner_x, tops[i] + section_h(lines[i]) - BTN_H, BTN_W, BTN_H));
+        UINode::Button(if i == target { visual.target() } else { visual })
+    }));
+    let toc: Vec<(String, Rect)> = headings.iter().enumerate()
+        .map(|(i, h)| (link_label(i, h), Rect::new(article_x, tops[i], article_w, section_h(lines[i]))))
+        .collect();
+    let article_h = tops[headings.len()] - TOP;
+    let tree = ui_node::article(TITLE, Rect::new(article_x, TOP, article_w, article_h), toc, via_toc, children);
+
+    let viewport_style = super::viewport_style(&bg(), true);
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Level 46"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "Long Document"
+                }
+                span {
+                    style: "color: #9ca3af; font-size: 14px;",
+                    "{instruction}"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                div {
+                    "data-label": TITLE,
+                    style: "position: absolute; left: {article_x}px; top: {TOP}px; width: {article_w}px; padding: {PAD}px; background: white; border-radius: 12px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); box-sizing: border-box; font-family: Georgia, serif; color: #111827;",
+
+                    div {
+                        style: "height: {TITLE_H}px; font-size: 26px; font-weight: 700;",
+                        "{TITLE}"
+                    }
+
+                    nav {
+                        style: "margin-bottom: {TOC_GAP}px;",
+                        div {
+                            style: "height: {TOC_HEAD_H}px; font-family: system-ui, sans-serif; font-size: 13px; font-weight: 600; color: #6b7280; text-transform: uppercase; letter-spacing: 0.05em;",
+                            "Contents"
+                        }
+                        for (i, heading) in headings.iter().enumerate() {
+                            {
+                                let label = link_label(i, heading);
+                                // Anchor jumps scroll #viewport itself, clear of the router
+                                let jump = tops[i] - 8.0;
+                                rsx! {
+                                    a {
+                                        key: "{label}",
+                                        href: "#doc-sec-{i}",
+                                        "data-label": "{label}",
+                                        style: "display: block; height: {LINK_H}px; line-height: {LINK_H}px; font-size: 15px; color: #2563eb; text-decoration: none;",
+                                        onclick: move |e| {
+                                            e.prevent_default();
+                                            document::eval(&format!("document.getElementById('viewport')?.scrollTo(0, {jump})"));
+                                        },
+                                        "{label}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    for (i, heading) in headings.iter().enumerate() {
+                        {
+                            let heading: &'static str = heading;
+                            let label = cite_label(heading);
+                            let click_label = label.clone();
+                            let border = if pressed == Some(i) { "#ef4444" } else { "#d1d5db" };
+                            let gap = if i + 1 == headings.len() { 0.0 } else { SECTION_GAP };
+                            rsx! {
+                                section {
+                                    key: "{heading}",
+                                    id: "doc-sec-{i}",
+                                    style: "height: {section_h(lines[i])}px; margin-bottom: {gap}px; display: flex; flex-direction: column;",
+                                    h3 {
+                                        style: "height: {HEAD_H}px; margin: 0; font-size: 20px; line-height: {HEAD_H}px;",
+                                        "{heading}"
+                                    }
+                                    for n in 0..lines[i] {
+                                        div {
+                                            key: "{n}",
+                                            style: "height: 10px; margin-bottom: 10px; width: {60 + (n * 37 + i * 11) % 38}%; background: #e5e7eb; border-radius: 5px;",
+                                        }
+                                    }
+                                    button {
+                                        class: if i == target { "target" } else { "" },
+                                        "data-label": "{label}",
+                                        style: "width: {BTN_W}px; height: {BTN_H}px; margin-top: {BTN_GAP}px; background: white; color: #374151; border: 1px solid {border}; border-radius: 6px; font-family: system-ui, sans-serif; font-size: 13px; cursor: pointer;",
+                                        onclick: move |_| {
+                                            if i == target {
+                                                score.complete();
+                                                bg.set(random_canvas_bg());
+                                                state.set(random_level46(Difficulty::current()));
+                                                wrong.set(None);
+                                                document::eval("document.getElementById('viewport')?.scrollTo(0, 0)");
+                                            } else {
+                                                wrong.set(Some(i));
+                                                score.wrong(&click_label, "");
+                                                spawn(async move {
+                                                    gloo_timers::future::TimeoutFuture::new(400).await;
+                                                    wrong.set(None);
+                                                });
+                                            }
+                                        },
+                                        "{label}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: String::new(),
+                target_x: inner_x,
+                target_y: tops[target] + section_h(lines[target]) - BTN_H,
+                target_w: BTN_W,
+                target_h: BTN_H,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -2,8 +2,9 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg};
+use crate::primitives::{Angle, Typography};
+use crate::ui_node::{self, Rect, Rotation};
+use super::{Difficulty, fresh_rng, roll_time_limit, RoundTimer, random_canvas_bg};
 
 const BUTTON_LABELS: &[&str] = &[
     "Submit", "Cancel", "Confirm", "Delete", "Save", "Edit", "Next",
@@ -29,11 +30,16 @@ struct Level5State {
     colors: Vec<usize>,
     x: f32,
     y: f32,
+    typography: Option<Typography>,
+    tilt: Option<Angle>,
+
+    /// Countdown for this round (`?timer=`), in ms.
+    time_limit: Option<u32>,
 }
 
-fn random_level5() -> Level5State {
+fn random_level5(d: &Difficulty) -> Level5State {
     let mut rng = fresh_rng();
-    let btn_count = rng.random_range(3..=5usize);
+    let btn_count = d.count(&mut rng, 3..=5);
 
     let mut indices: Vec<usize> = (0..BUTTON_LABELS.len()).collect();
     let mut labels = Vec::with_capacity(btn_count);
@@ -54,14 +60,18 @@ fn random_level5() -> Level5State {
     let pad = 80.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, pad, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    let typography = super::random_typography(&mut rng, d);
+    let tilt = super::random_card_tilt(&mut rng);
 
-    Level5State { target, labels, colors, x, y }
+    let time_limit = roll_time_limit(&mut rng);
+    Level5State { target, labels, colors, x, y, typography, tilt, time_limit }
 }
 
 #[component]
 pub fn Level5() -> Element {
-    let mut state = use_signal(|| random_level5());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level5(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut wrong_idx = use_signal(|| None::<usize>);
     let mut bg = use_signal(|| random_canvas_bg());
 
@@ -71,6 +81,9 @@ pub fn Level5() -> Element {
     let colors = st.colors.clone();
     let card_x = st.x;
     let card_y = st.y;
+    let typography = st.typography;
+    let tilt = st.tilt;
+    let time_limit = st.time_limit;
     drop(st);
 
     let pressed = wrong_idx();
@@ -91,8 +104,10 @@ pub fn Level5() -> Element {
     let viewport_style = super::viewport_style(&bg(), true);
 
     let card_style = format!(
-        "position: absolute; left: {}px; top: {}px; background: white; border-radius: 12px; padding: 20px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); width: 280px; font-family: system-ui, sans-serif;",
-        card_x, card_y
+        "position: absolute; left: {}px; top: {}px; background: white; border-radius: 12px; padding: 20px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); width: 280px; font-family: system-ui, sans-serif; {}{}",
+        card_x, card_y,
+        typography.map(|t| t.to_css()).unwrap_or_default(),
+        tilt.map(|a| format!(" transform: {};", a.to_css())).unwrap_or_default(),
     );
 
     rsx! {
@@ -124,11 +139,21 @@ pub fn Level5() -> Element {
                 id: "viewport",
                 style: "{viewport_style}",
 
+                RoundTimer {
+                    round: super::round_index(),
+                    limit_ms: time_limit,
+                    on_expire: move |_| {
+                        bg.set(random_canvas_bg());
+                        state.set(random_level5(Difficulty::current()));
+                        wrong_idx.set(None);
+                    },
+                }
+
                 div {
                     style: "{card_style}",
 
                     p {
-                        style: "margin: 0 0 16px 0; font-size: 15px; color: #374151; font-weight: 500;",
+                        style: "margin: 0 0 16px 0; font-size: calc(15px * var(--type-scale, 1)); color: #374151; font-weight: 500;",
                         "Click the button that says "
                         span {
                             style: "font-weight: 700; color: #111;",
@@ -146,18 +171,20 @@ pub fn Level5() -> Element {
                                 let btn_bg = if is_wrong { btn_pressed } else { btn_color };
                                 let transform = if is_wrong { "scale(0.95)" } else { "scale(1)" };
                                 let label_clone = label.clone();
+                                let wrong_label = label.clone();
                                 rsx! {
                                     button {
                                         class: if is_target { "target" } else { "" },
-                                        style: "padding: 10px 20px; background: {btn_bg}; color: white; border: none; border-radius: 6px; font-size: 14px; cursor: pointer; text-align: left; font-family: system-ui, sans-serif; transition: transform 0.1s, background 0.1s; transform: {transform};",
+                                        style: "padding: 10px 20px; background: {btn_bg}; color: white; border: none; border-radius: 6px; font-size: calc(14px * var(--type-scale, 1)); cursor: pointer; text-align: left; font-family: inherit; font-weight: inherit; transition: transform 0.1s, background 0.1s; transform: {transform};",
                                         onclick: move |_| {
                                             if is_target {
-                                                score.set(score() + 1);
+                                                score.complete();
                                                 wrong_idx.set(None);
                                                 bg.set(random_canvas_bg());
-                                                state.set(random_level5());
+                                                state.set(random_level5(Difficulty::current()));
                                             } else {
                                                 wrong_idx.set(Some(i));
+                                                score.wrong(&wrong_label, "");
                                                 spawn(async move {
                                                     gloo_timers::future::TimeoutFuture::new(200).await;
                                                     wrong_idx.set(None);
@@ -180,6 +207,8 @@ pub fn Level5() -> Element {
                 target_w: 320.0,
                 target_h: card_h,
                 tree: Some(tree.clone()),
+                typography: typography,
+                rotation: tilt.map(|a| Rotation::about_center(&card_rect, a)),
             }
         }
     }
@@ -2,8 +2,9 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, UINode, Visual, Rect, ToggleState};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use crate::primitives::{Angle, Typography};
+use crate::ui_node::{self, UINode, Visual, Rect, Rotation, ToggleState};
+use super::{Difficulty, fresh_rng, roll_time_limit, RoundTimer, random_canvas_bg, ordinal};
 
 const TOGGLE_LABELS: &[&str] = &[
     "Dark mode", "Notifications", "Auto-save", "Sync", "Airplane mode",
@@ -26,11 +27,16 @@ struct Level6State {
     color_indices: Vec<usize>,
     x: f32,
     y: f32,
+    typography: Option<Typography>,
+    tilt: Option<Angle>,
+
+    /// Countdown for this round (`?timer=`), in ms.
+    time_limit: Option<u32>,
 }
 
-fn random_level6() -> Level6State {
+fn random_level6(d: &Difficulty) -> Level6State {
     let mut rng = fresh_rng();
-    let count = rng.random_range(3..=6usize);
+    let count = d.count(&mut rng, 3..=6);
 
     let mut indices: Vec<usize> = (0..TOGGLE_LABELS.len()).collect();
     let mut labels = Vec::with_capacity(count);
@@ -50,14 +56,18 @@ fn random_level6() -> Level6State {
     let pad = 80.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, pad, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    let typography = super::random_typography(&mut rng, d);
+    let tilt = super::random_card_tilt(&mut rng);
 
-    Level6State { target, labels, color_indices, x, y }
+    let time_limit = roll_time_limit(&mut rng);
+    Level6State { target, labels, color_indices, x, y, typography, tilt, time_limit }
 }
 
 #[component]
 pub fn Level6() -> Element {
-    let mut state = use_signal(|| random_level6());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level6(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut wrong_idx = use_signal(|| None::<usize>);
     let mut bg = use_signal(|| random_canvas_bg());
 
@@ -67,6 +77,9 @@ pub fn Level6() -> Element {
     let color_indices = st.color_indices.clone();
     let card_x = st.x;
     let card_y = st.y;
+    let typography = st.typography;
+    let tilt = st.tilt;
+    let time_limit = st.time_limit;
     drop(st);
 
     let pressed = wrong_idx();
@@ -82,7 +95,7 @@ pub fn Level6() -> Element {
             ui_node::toggle(l.as_str(), toggle_rect, false)
         } else {
             // Non-target toggle — manually construct without target flag
-            UINode::Toggle(Visual::new(l.as_str(), toggle_rect), ToggleState { is_on: false })
+            UINode::Toggle(Visual::new(l.as_str(), toggle_rect), ToggleState { is_on: false, target_on: false })
         }
     }).collect();
     let tree = ui_node::card(card_rect, children);
@@ -90,8 +103,10 @@ pub fn Level6() -> Element {
     let viewport_style = super::viewport_style(&bg(), true);
 
     let card_style = format!(
-        "position: absolute; left: {}px; top: {}px; background: white; border-radius: 12px; padding: 20px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); width: 260px; font-family: system-ui, sans-serif;",
-        card_x, card_y
+        "position: absolute; left: {}px; top: {}px; background: white; border-radius: 12px; padding: 20px; box-shadow: 0 4px 24px rgba(0,0,0,0.3); width: 260px; font-family: system-ui, sans-serif; {}{}",
+        card_x, card_y,
+        typography.map(|t| t.to_css()).unwrap_or_default(),
+        tilt.map(|a| format!(" transform: {};", a.to_css())).unwrap_or_default(),
     );
 
     rsx! {
@@ -123,11 +138,21 @@ pub fn Level6() -> Element {
                 id: "viewport",
                 style: "{viewport_style}",
 
+                RoundTimer {
+                    round: super::round_index(),
+                    limit_ms: time_limit,
+                    on_expire: move |_| {
+                        bg.set(random_canvas_bg());
+                        state.set(random_level6(Difficulty::current()));
+                        wrong_idx.set(None);
+                    },
+                }
+
                 div {
                     style: "{card_style}",
 
                     p {
-                        style: "margin: 0 0 16px 0; font-size: 15px; color: #374151; font-weight: 500;",
+                        style: "margin: 0 0 16px 0; font-size: calc(15px * var(--type-scale, 1)); color: #374151; font-weight: 500;",
                         "Click the "
                         span {
                             style: "font-weight: 700; color: #111;",
@@ -147,6 +172,7 @@ pub fn Level6() -> Element {
                                 let knob_left = if is_wrong { "22px" } else { "2px" };
                                 let shake = if is_wrong { "translateX(2px)" } else { "translateX(0)" };
                                 let label_clone = label.clone();
+                                let wrong_label = label.clone();
                                 rsx! {
                                     div {
                                         class: if is_target { "target" } else { "" },
@@ -154,12 +180,13 @@ pub fn Level6() -> Element {
                                         style: "display: flex; align-items: center; justify-content: space-between; cursor: pointer; transition: transform 0.1s; transform: {shake};",
                                         onclick: move |_| {
                                             if is_target {
-                                                score.set(score() + 1);
+                                                score.complete();
                                                 wrong_idx.set(None);
                                                 bg.set(random_canvas_bg());
-                                                state.set(random_level6());
+                                                state.set(random_level6(Difficulty::current()));
                                             } else {
                                                 wrong_idx.set(Some(i));
+                                                score.wrong(&wrong_label, "");
                                                 spawn(async move {
                                                     gloo_timers::future::TimeoutFuture::new(200).await;
                                                     wrong_idx.set(None);
@@ -168,7 +195,7 @@ pub fn Level6() -> Element {
                                         },
 
                                         span {
-                                            style: "font-size: 14px; color: #374151;",
+                                            style: "font-size: calc(14px * var(--type-scale, 1)); color: #374151;",
                                             "{label_clone}"
                                         }
 
@@ -194,6 +221,8 @@ pub fn Level6() -> Element {
                 target_w: 300.0,
                 target_h: card_h,
                 tree: Some(tree.clone()),
+                typography: typography,
+                rotation: tilt.map(|a| Rotation::about_center(&card_rect, a)),
             }
         }
     }
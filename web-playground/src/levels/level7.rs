@@ -3,7 +3,7 @@ use rand::Rng;
 
 use crate::Route;
 use crate::ui_node::{self, Rect, UINode};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use super::{Difficulty, fresh_rng, random_canvas_bg, ordinal};
 
 const WORDS: &[&str] = &[
     "hello", "world", "search", "login", "submit", "click", "enter",
@@ -26,9 +26,9 @@ struct Level7State {
     y: f32,
 }
 
-fn random_level7() -> Level7State {
+fn random_level7(d: &Difficulty) -> Level7State {
     let mut rng = fresh_rng();
-    let count = rng.random_range(3..=5usize);
+    let count = d.count(&mut rng, 3..=5);
 
     let mut indices: Vec<usize> = (0..INPUT_LABELS.len()).collect();
     let mut labels = Vec::with_capacity(count);
@@ -46,14 +46,15 @@ fn random_level7() -> Level7State {
     let pad = 80.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, pad, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level7State { word, target, labels, x, y }
 }
 
 #[component]
 pub fn Level7() -> Element {
-    let mut state = use_signal(|| random_level7());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level7(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut inputs = use_signal(|| vec![String::new(); 5]);
     let mut wrong_idx = use_signal(|| None::<usize>);
@@ -158,6 +159,7 @@ pub fn Level7() -> Element {
                                 let is_wrong = pressed == Some(i);
                                 let border_color = if is_wrong { "#ef4444" } else { "#d1d5db" };
                                 let label_clone = label.clone();
+                                let wrong_label = label.clone();
                                 let input_val = inputs.read()[i].clone();
                                 let is_target = i == target;
                                 let target_word = word.clone();
@@ -180,14 +182,15 @@ pub fn Level7() -> Element {
                                                 inputs.write()[i] = val.clone();
                                                 if val == target_word {
                                                     if is_target {
-                                                        score.set(score() + 1);
+                                                        score.complete();
                                                         wrong_idx.set(None);
                                                         bg.set(random_canvas_bg());
-                                                        state.set(random_level7());
+                                                        state.set(random_level7(Difficulty::current()));
                                                         inputs.set(vec![String::new(); input_count]);
                                                         document::eval("document.activeElement?.blur()");
                                                     } else {
                                                         wrong_idx.set(Some(i));
+                                                        score.wrong(&wrong_label, &val);
                                                         spawn(async move {
                                                             gloo_timers::future::TimeoutFuture::new(400).await;
                                                             wrong_idx.set(None);
@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect, UINode, Visual, DropdownState};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use crate::ui_node::{self, Rect, UINode, Visual, DropdownMode, DropdownState};
+use super::{Difficulty, fresh_rng, random_canvas_bg, ordinal};
 
 const DROPDOWN_GROUPS: &[(&str, &[&str])] = &[
     ("Color", &["Red", "Blue", "Green", "Yellow", "Purple", "Orange", "Pink"]),
@@ -29,9 +29,9 @@ struct Level8State {
     y: f32,
 }
 
-fn random_level8() -> Level8State {
+fn random_level8(d: &Difficulty) -> Level8State {
     let mut rng = fresh_rng();
-    let dropdown_count = rng.random_range(2..=4usize);
+    let dropdown_count = d.count(&mut rng, 2..=4);
     let select_by_word = rng.random_range(0..2u8) == 0;
 
     let mut group_indices: Vec<usize> = (0..DROPDOWN_GROUPS.len()).collect();
@@ -63,14 +63,15 @@ fn random_level8() -> Level8State {
     let pad = 80.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, pad, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level8State { select_by_word, dropdowns, target_dropdown, target_value, target_option_pos, x, y }
 }
 
 #[component]
 pub fn Level8() -> Element {
-    let mut state = use_signal(|| random_level8());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level8(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut wrong_idx = use_signal(|| None::<usize>);
 
@@ -112,6 +113,8 @@ pub fn Level8() -> Element {
                     selected: None,
                     target_option: String::new(),
                     trigger_label: "Choose...".into(),
+                    mode: DropdownMode::Click,
+                    open_options: Vec::new(),
                 },
             )
         }
@@ -199,6 +202,7 @@ pub fn Level8() -> Element {
                                 let is_wrong = pressed == Some(i);
                                 let border_color = if is_wrong { "#ef4444" } else { "#d1d5db" };
                                 let label_clone = label.clone();
+                                let wrong_label = label.clone();
                                 let is_target = i == target_dropdown;
                                 let expected_value = target_value.clone();
                                 rsx! {
@@ -209,18 +213,20 @@ pub fn Level8() -> Element {
                                             "{label_clone}"
                                         }
                                         super::CustomSelect {
+                                            label: label.clone(),
                                             options: options.clone(),
                                             is_target: is_target,
                                             target_option: if is_target { expected_value.clone() } else { String::new() },
                                             border_color: border_color.to_string(),
                                             on_select: move |val: String| {
                                                 if is_target && val == expected_value {
-                                                    score.set(score() + 1);
+                                                    score.complete();
                                                     wrong_idx.set(None);
                                                     bg.set(random_canvas_bg());
-                                                    state.set(random_level8());
+                                                    state.set(random_level8(Difficulty::current()));
                                                 } else {
                                                     wrong_idx.set(Some(i));
+                                                    score.wrong(&wrong_label, &val);
                                                     spawn(async move {
                                                         gloo_timers::future::TimeoutFuture::new(400).await;
                                                         wrong_idx.set(None);
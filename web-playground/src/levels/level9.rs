@@ -2,8 +2,8 @@ use dioxus::prelude::*;
 use rand::Rng;
 
 use crate::Route;
-use crate::ui_node::{self, Rect, UINode, Visual, InputState, DropdownState, ToggleState};
-use super::{fresh_rng, random_canvas_bg, ordinal};
+use crate::ui_node::{self, Rect, UINode, Visual, InputState, DropdownMode, DropdownState, ToggleState};
+use super::{Difficulty, fresh_rng, random_canvas_bg, ordinal};
 
 const INPUT_LABELS: &[&str] = &[
     "Username", "Email", "Password", "First name", "Last name",
@@ -40,9 +40,9 @@ struct Level9State {
     y: f32,
 }
 
-fn random_level9() -> Level9State {
+fn random_level9(d: &Difficulty) -> Level9State {
     let mut rng = fresh_rng();
-    let count = rng.random_range(3..=5usize);
+    let count = d.count(&mut rng, 3..=5);
     let by_name = rng.random_range(0..2u8) == 0;
 
     let mut label_indices: Vec<usize> = (0..INPUT_LABELS.len()).collect();
@@ -97,14 +97,15 @@ fn random_level9() -> Level9State {
     let pad = 80.0;
     let (vp_w, vp_h) = crate::primitives::viewport_size();
     let (x, y) = super::safe_position_in(&mut rng, card_w, card_h, pad, vp_w * 1.3, vp_h * 1.3);
+    let (x, y) = d.jitter(&mut rng, (x, y));
 
     Level9State { by_name, inputs, target_idx, target_word, target_select, x, y }
 }
 
 #[component]
 pub fn Level9() -> Element {
-    let mut state = use_signal(|| random_level9());
-    let mut score = use_signal(|| 0u32);
+    let mut state = use_signal(|| random_level9(Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
     let mut wrong_idx = use_signal(|| None::<usize>);
     let mut inputs_text = use_signal(|| vec![String::new(); 5]);
@@ -152,7 +153,7 @@ pub fn Level9() -> Element {
                 } else {
                     UINode::Dropdown(
                         Visual::new(label.as_str(), rect),
-                        DropdownState { options: opts.clone(), selected: None, target_option: String::new(), trigger_label: "Choose...".into() },
+                        DropdownState { options: opts.clone(), selected: None, target_option: String::new(), trigger_label: "Choose...".into(), mode: DropdownMode::Click, open_options: Vec::new() },
                     )
                 }
             }
@@ -162,7 +163,7 @@ pub fn Level9() -> Element {
                 } else {
                     UINode::Toggle(
                         Visual::new(label.as_str(), rect),
-                        ToggleState { is_on: false },
+                        ToggleState { is_on: false, target_on: false },
                     )
                 }
             }
@@ -271,6 +272,7 @@ pub fn Level9() -> Element {
                                 let is_wrong = pressed == Some(i);
                                 let border_color = if is_wrong { "#ef4444" } else { "#d1d5db" };
                                 let label_clone = label.clone();
+                                let wrong_label = label.clone();
                                 let kind_val = *kind;
                                 let is_target = i == target_idx;
                                 let tw = target_word.clone();
@@ -304,14 +306,15 @@ pub fn Level9() -> Element {
                                                     }
                                                     if !tw.is_empty() && val == tw {
                                                         if is_target {
-                                                            score.set(score() + 1);
+                                                            score.complete();
                                                             wrong_idx.set(None);
                                                             bg.set(random_canvas_bg());
-                                                            state.set(random_level9());
+                                                            state.set(random_level9(Difficulty::current()));
                                                             inputs_text.set(vec![String::new(); 5]);
                                                             document::eval("document.activeElement?.blur()");
                                                         } else {
                                                             wrong_idx.set(Some(i));
+                                                            score.wrong(&wrong_label, &val);
                                                             spawn(async move {
                                                                 gloo_timers::future::TimeoutFuture::new(400).await;
                                                                 wrong_idx.set(None);
@@ -322,19 +325,21 @@ pub fn Level9() -> Element {
                                             }
                                         } else if kind_val == 1 {
                                             super::CustomSelect {
+                                                label: label_clone.clone(),
                                                 options: opts_clone.clone(),
                                                 is_target: is_target,
                                                 target_option: if is_target { ts.clone() } else { String::new() },
                                                 border_color: border_color.to_string(),
                                                 on_select: move |val: String| {
                                                     if is_target && val == ts {
-                                                        score.set(score() + 1);
+                                                        score.complete();
                                                         wrong_idx.set(None);
                                                         bg.set(random_canvas_bg());
-                                                        state.set(random_level9());
+                                                        state.set(random_level9(Difficulty::current()));
                                                         inputs_text.set(vec![String::new(); 5]);
                                                     } else {
                                                         wrong_idx.set(Some(i));
+                                                        score.wrong(&wrong_label, &val);
                                                         spawn(async move {
                                                             gloo_timers::future::TimeoutFuture::new(400).await;
                                                             wrong_idx.set(None);
@@ -349,13 +354,14 @@ pub fn Level9() -> Element {
                                                 style: "display: flex; align-items: center; justify-content: space-between; cursor: pointer;",
                                                 onclick: move |_| {
                                                     if is_target {
-                                                        score.set(score() + 1);
+                                                        score.complete();
                                                         wrong_idx.set(None);
                                                         bg.set(random_canvas_bg());
-                                                        state.set(random_level9());
+                                                        state.set(random_level9(Difficulty::current()));
                                                         inputs_text.set(vec![String::new(); 5]);
                                                     } else {
                                                         wrong_idx.set(Some(i));
+                                                        score.wrong(&wrong_label, "on");
                                                         spawn(async move {
                                                             gloo_timers::future::TimeoutFuture::new(200).await;
                                                             wrong_idx.set(None);
@@ -3,21 +3,22 @@ use rand::Rng;
 
 use crate::Route;
 use crate::pool::{ElementPool, ElementKind};
-use crate::primitives::{Position, viewport_size};
-use crate::transform::{PlacedElement, Sampler};
+use crate::primitives::{Position, Scale, viewport_size};
+use crate::transform::PlacedElement;
 use crate::ui_node::{self, Rect};
-use super::{fresh_rng, random_canvas_bg};
+use super::{Difficulty, fresh_rng, random_canvas_bg};
 
 /// Place a button guaranteed to be at least partially off-screen so the user
 /// must scroll the viewport to find it.
-fn random_offscreen_element(pool: &ElementPool) -> PlacedElement {
+fn random_offscreen_element(pool: &ElementPool, d: &Difficulty) -> PlacedElement {
     let mut rng = fresh_rng();
-    let snippet = Sampler::pick_kind(&mut rng, pool, ElementKind::Button)
+    let snippet = super::pick_tagged(&mut rng, pool, ElementKind::Button)
         .expect("pool has buttons");
 
     let (vp_w, vp_h) = viewport_size();
-    let w = snippet.approx_width;
-    let h = snippet.approx_height;
+    let scale = d.target_scale(&mut rng);
+    let (w, h) = snippet.size();
+    let (w, h) = (w * scale, h * scale);
     let canvas_w = vp_w * 1.5;
     let canvas_h = vp_h * 1.5;
     let pad = 40.0;
@@ -55,15 +56,16 @@ fn random_offscreen_element(pool: &ElementPool) -> PlacedElement {
         }
     };
 
-    PlacedElement::new(snippet, Position::new(x, y))
+    let (x, y) = d.jitter(&mut rng, (x, y));
+    PlacedElement::new(snippet, Position::new(x, y)).with_scale(Scale::new(scale))
 }
 
 #[component]
 pub fn LevelScroll() -> Element {
     let pool = use_hook(|| ElementPool::with_builtins());
 
-    let mut placed = use_signal(|| random_offscreen_element(&pool));
-    let mut score = use_signal(|| 0u32);
+    let mut placed = use_signal(|| random_offscreen_element(&pool, Difficulty::current()));
+    let mut score = super::use_level_score();
     let mut bg = use_signal(|| random_canvas_bg());
 
     let current = placed.read();
@@ -123,8 +125,8 @@ pub fn LevelScroll() -> Element {
                     style: "{style}",
                     cursor: "pointer",
                     onclick: move |_| {
-                        placed.set(random_offscreen_element(&pool_click));
-                        score.set(score() + 1);
+                        placed.set(random_offscreen_element(&pool_click, Difficulty::current()));
+                        score.complete();
                         bg.set(random_canvas_bg());
                         // Reset scroll position for next round
                         document::eval("document.getElementById('viewport')?.scrollTo(0, 0)");
@@ -0,0 +1,269 @@
+//! DOM measurement — reconcile UINode rects with the rendered layout.
+//!
+//! Levels build their trees from layout constants (`LIST_TOP`, `row_h`, ...)
+//! that can drift from what the browser actually lays out. GroundTruth
+//! measures every `data-label` element in `#viewport` on its DOM poll, and
+//! before resolving the tree moves each node whose label matches exactly one
+//! element and that is off by more than `TOLERANCE` onto the measured box,
+//! carrying its part rects (stepper buttons, radio rows, menu items) along.
+//! Scrolled ancestors — the viewport itself or a list inside it — move
+//! elements away from the tree's unscrolled layout; the node still follows
+//! its element, but only a difference the scroll doesn't explain is a drift.
+//! Those drifts are listed in the ground-truth panel (visible in debug mode)
+//! and logged to the console.
+//!
+//! Open menus are measured the same way: a `CustomSelect` panel carries
+//! `data-menu` set to its dropdown node's label, and its option rows' boxes
+//! become that node's `open_options`.
+
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::ui_node::{Rect, UINode, ViewportTransform};
+
+/// Largest edge difference, in viewport pixels, not reported as drift.
+pub const TOLERANCE: f32 = 4.0;
+
+/// A node whose hand-computed rect disagreed with the DOM.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drift {
+    pub label: String,
+    pub model: Rect,
+    pub measured: Rect,
+}
+
+impl Drift {
+    /// "Save: model 10,20 80x30, DOM 10,28 80x30"
+    pub fn describe(&self) -> String {
+        let (m, d) = (&self.model, &self.measured);
+        format!(
+            "{}: model {:.0},{:.0} {:.0}x{:.0}, DOM {:.0},{:.0} {:.0}x{:.0}",
+            self.label, m.x, m.y, m.w, m.h, d.x, d.y, d.w, d.h,
+        )
+    }
+}
+
+/// A `data-label` element: its label, window CSS box, and how far the
+/// elements it sits in, up to and including `#viewport`, are scrolled.
+pub type Measured = (String, [f64; 4], [f64; 2]);
+
+/// Every `data-label` element inside `#viewport` that has a box.
+pub fn measure_labels() -> Vec<Measured> {
+    let mut out = Vec::new();
+    let Some(viewport) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id("viewport"))
+    else {
+        return out;
+    };
+    let all = viewport.get_elements_by_tag_name("*");
+    for i in 0..all.length() {
+        let Some(el) = all.item(i) else { continue };
+        let Some(label) = el.get_attribute("data-label") else { continue };
+        let r = el.get_bounding_client_rect();
+        if r.width() > 0.0 && r.height() > 0.0 {
+            out.push((label, [r.x(), r.y(), r.width(), r.height()], scrolled_by(&el)));
+        }
+    }
+    out
+}
+
+/// Summed scroll offsets of `el`'s ancestors up to and including `#viewport`.
+fn scrolled_by(el: &web_sys::Element) -> [f64; 2] {
+    let mut scroll = [0.0, 0.0];
+    let mut up = el.parent_element();
+    while let Some(a) = up {
+        scroll[0] += a.scroll_left() as f64;
+        scroll[1] += a.scroll_top() as f64;
+        if a.id() == "viewport" {
+            break;
+        }
+        up = a.parent_element();
+    }
+    scroll
+}
+
+/// An open menu: its dropdown's label and each option row's window CSS box.
+pub type OpenMenu = (String, Vec<(String, [f64; 4])>);
+
+/// Every open `data-menu` panel inside `#viewport`.
+pub fn measure_open_menus() -> Vec<OpenMenu> {
+    let mut out = Vec::new();
+    let Some(viewport) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id("viewport"))
+    else {
+        return out;
+    };
+    let Ok(menus) = viewport.query_selector_all("[data-menu]") else { return out };
+    for i in 0..menus.length() {
+        let Some(menu) = menus.item(i).and_then(|n| n.dyn_into::<web_sys::Element>().ok()) else { continue };
+        let label = menu.get_attribute("data-menu").unwrap_or_default();
+        if label.is_empty() {
+            continue;
+        }
+        let rows = menu.children();
+        let options = (0..rows.length())
+            .filter_map(|j| rows.item(j))
+            .filter_map(|row| {
+                let option = row.get_attribute("data-label")?;
+                let r = row.get_bounding_client_rect();
+                Some((option, [r.x(), r.y(), r.width(), r.height()]))
+            })
+            .collect();
+        out.push((label, options));
+    }
+    out
+}
+
+/// Set every dropdown's `open_options` from the measured menus: the options
+/// of its open menu, or none when its menu is closed.
+pub fn attach_open_menus(tree: &mut UINode, menus: &[OpenMenu], vt: &ViewportTransform) {
+    if let UINode::Dropdown(v, s) = tree {
+        s.open_options = menus.iter()
+            .find(|(label, _)| *label == v.label)
+            .map(|(_, options)| options.iter().map(|(o, bbox)| (o.clone(), vt.unapply(bbox))).collect())
+            .unwrap_or_default();
+    }
+    for child in tree.children_mut() {
+        attach_open_menus(child, menus, vt);
+    }
+}
+
+fn drifted(a: &Rect, b: &Rect) -> bool {
+    (a.x - b.x).abs() > TOLERANCE
+        || (a.y - b.y).abs() > TOLERANCE
+        || (a.w - b.w).abs() > TOLERANCE
+        || (a.h - b.h).abs() > TOLERANCE
+}
+
+/// Move `tree`'s nodes onto their measured boxes and return the ones that
+/// had drifted. Rotated trees are left alone: their DOM boxes are hulls.
+pub fn reconcile(tree: &mut UINode, measured: &[Measured], vt: &ViewportTransform) -> Vec<Drift> {
+    let mut drifts = Vec::new();
+    if vt.rotation.is_some() || measured.is_empty() {
+        return drifts;
+    }
+    reconcile_within(tree, (0.0, 0.0), measured, vt, &mut drifts);
+    drifts
+}
+
+/// `reconcile` for a subtree whose rects are relative to `origin`; panes
+/// shift the origin for their children.
+fn reconcile_within(
+    node: &mut UINode,
+    origin: (f32, f32),
+    measured: &[Measured],
+    vt: &ViewportTransform,
+    drifts: &mut Vec<Drift>,
+) {
+    let v = node.visual();
+    let mut moved = None;
+    if !v.label.is_empty() {
+        let mut hits = measured.iter().filter(|(label, ..)| *label == v.label);
+        if let (Some((_, bbox, scroll)), None) = (hits.next(), hits.next()) {
+            let dom = vt.unapply(bbox);
+            let dom = Rect::new(dom.x - origin.0, dom.y - origin.1, dom.w, dom.h);
+            if drifted(&v.rect, &dom) {
+                // Where the scroll alone would have put the node
+                let s = vt.scale as f64;
+                let scrolled = v.rect.offset((-scroll[0] / s) as f32, (-scroll[1] / s) as f32);
+                if drifted(&scrolled, &dom) {
+                    drifts.push(Drift { label: v.label.clone(), model: scrolled, measured: dom });
+                }
+                moved = Some(dom);
+            }
+        }
+    }
+    if let Some(dom) = moved {
+        let v = node.visual_mut();
+        let (dx, dy) = (dom.x - v.rect.x, dom.y - v.rect.y);
+        v.rect = dom;
+        shift_parts(node, dx, dy);
+    }
+    let inner = match node {
+        UINode::Pane(v, ..) => (origin.0 + v.rect.x, origin.1 + v.rect.y),
+        _ => origin,
+    };
+    for child in node.children_mut() {
+        reconcile_within(child, inner, measured, vt, drifts);
+    }
+}
+
+/// Move the rects a node keeps for its parts by (`dx`, `dy`), after its
+/// own rect moved that far. Open menu options are measured, not moved.
+fn shift_parts(node: &mut UINode, dx: f32, dy: f32) {
+    let shift = |r: &mut Rect| *r = r.offset(dx, dy);
+    match node {
+        UINode::Slider(_, s) => {
+            shift(&mut s.thumb_rect);
+            shift(&mut s.target_thumb_rect);
+        }
+        UINode::Stepper(_, s) => {
+            shift(&mut s.minus_rect);
+            shift(&mut s.plus_rect);
+        }
+        UINode::RadioGroup(_, s) => s.option_rects.iter_mut().for_each(shift),
+        UINode::ContextMenu(_, s) => s.item_rects.iter_mut().for_each(shift),
+        UINode::Carousel(_, s) => {
+            if let Some((prev, next)) = &mut s.arrows {
+                shift(prev);
+                shift(next);
+            }
+            s.indicators.iter_mut().for_each(shift);
+        }
+        UINode::SortableList(_, s) => s.slots.iter_mut().for_each(shift),
+        UINode::CommandPalette(_, s) => shift(&mut s.input_rect),
+        UINode::TileGrid(_, s) => s.tiles.iter_mut().for_each(|t| shift(&mut t.rect)),
+        UINode::KeyboardForm(_, s) => s.fields.iter_mut().for_each(|f| shift(&mut f.rect)),
+        UINode::CheckboxGroup(_, boxes, _) => boxes.iter_mut().for_each(|(b, _)| shift(&mut b.rect)),
+        UINode::FileDragTask(_, s) => {
+            s.files.iter_mut().for_each(|f| shift(&mut f.rect));
+            shift(&mut s.zone.rect);
+        }
+        UINode::Article(_, s, _) => s.toc.iter_mut().for_each(|(_, r)| shift(r)),
+        UINode::Accordion(_, s, _) => shift(&mut s.body),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui_node::{radio_group, scroll_area, stepper, target_button};
+
+    fn at(label: &str, x: f64, y: f64, w: f64, h: f64, scroll_y: f64) -> Measured {
+        (label.to_string(), [x, y, w, h], [0.0, scroll_y])
+    }
+
+    #[test]
+    fn stepper_buttons_follow_the_measured_box() {
+        let mut tree = stepper("Qty", Rect::new(100.0, 100.0, 120.0, 30.0), 0, 9, 1, 0, 3);
+        let drifts = reconcile(&mut tree, &[at("Qty", 100.0, 140.0, 120.0, 30.0, 0.0)], &ViewportTransform::identity());
+        assert_eq!(drifts.len(), 1);
+        let UINode::Stepper(v, s) = &tree else { unreachable!() };
+        assert_eq!(v.rect, Rect::new(100.0, 140.0, 120.0, 30.0));
+        assert_eq!(s.minus_rect, Rect::new(100.0, 140.0, 30.0, 30.0));
+        assert_eq!(s.plus_rect, Rect::new(190.0, 140.0, 30.0, 30.0));
+    }
+
+    #[test]
+    fn radio_rows_follow_the_measured_box() {
+        let options = vec!["S".to_string(), "M".to_string()];
+        let mut tree = radio_group("Size", Rect::new(0.0, 0.0, 100.0, 60.0), options, 1);
+        reconcile(&mut tree, &[at("Size", 20.0, 10.0, 100.0, 60.0, 0.0)], &ViewportTransform::identity());
+        let UINode::RadioGroup(_, s) = &tree else { unreachable!() };
+        assert_eq!(s.option_rects, vec![Rect::new(20.0, 10.0, 100.0, 30.0), Rect::new(20.0, 40.0, 100.0, 30.0)]);
+    }
+
+    #[test]
+    fn scrolled_rows_move_without_drifting() {
+        let row = |i: usize| target_button(format!("Row {i}"), Rect::new(0.0, 200.0 + i as f32 * 40.0, 200.0, 40.0));
+        let mut tree = scroll_area("List", Rect::new(0.0, 200.0, 200.0, 120.0), vec![row(0), row(5)]);
+        // The list is scrolled by 160 px, and row 5 also sits 20 px lower than laid out
+        let measured = [at("Row 0", 0.0, 40.0, 200.0, 40.0, 160.0), at("Row 5", 0.0, 260.0, 200.0, 40.0, 160.0)];
+        let drifts = reconcile(&mut tree, &measured, &ViewportTransform::identity());
+        assert_eq!(drifts.iter().map(|d| d.label.as_str()).collect::<Vec<_>>(), vec!["Row 5"]);
+        assert_eq!(tree.children()[0].visual().rect.y, 40.0);
+        assert_eq!(tree.children()[1].visual().rect.y, 260.0);
+    }
+}
@@ -1,65 +1,174 @@
+mod a11y;
+mod background;
+mod binding;
+#[cfg(feature = "levels")]
+mod campaign;
+mod completion;
 mod custom_select;
+mod device;
+mod difficulty;
+mod distractors;
+#[cfg(feature = "levels")]
+mod endless;
 mod ground_truth;
+#[cfg(all(feature = "levels", feature = "exporter"))]
+mod generate;
+#[cfg(feature = "levels")]
+mod impossible;
+#[cfg(feature = "levels")]
+mod layout;
+#[cfg(feature = "levels")]
 mod level1;
+#[cfg(feature = "levels")]
 mod level2;
+#[cfg(feature = "levels")]
 mod level3;
+#[cfg(feature = "levels")]
 mod level4;
+#[cfg(feature = "levels")]
 mod level5;
+#[cfg(feature = "levels")]
 mod level6;
+#[cfg(feature = "levels")]
 mod level7;
+#[cfg(feature = "levels")]
 mod level8;
+#[cfg(feature = "levels")]
 mod level9;
+#[cfg(feature = "levels")]
 mod level10;
+#[cfg(feature = "levels")]
 mod level11;
+#[cfg(feature = "levels")]
 mod level12;
+#[cfg(feature = "levels")]
 mod level13;
+#[cfg(feature = "levels")]
 mod level14;
+#[cfg(feature = "levels")]
 mod level15;
+#[cfg(feature = "levels")]
 mod level16;
+#[cfg(feature = "levels")]
 mod level17;
+#[cfg(feature = "levels")]
 mod level18;
+#[cfg(feature = "levels")]
 mod level19;
+#[cfg(feature = "levels")]
 mod level20;
+#[cfg(feature = "levels")]
 mod level21;
+#[cfg(feature = "levels")]
 mod level22;
+#[cfg(feature = "levels")]
 mod level23;
+#[cfg(feature = "levels")]
 mod level24;
+#[cfg(feature = "levels")]
 mod level25;
+#[cfg(feature = "levels")]
 mod level26;
+#[cfg(feature = "levels")]
 mod level27;
+#[cfg(feature = "levels")]
+mod level28;
+#[cfg(feature = "levels")]
+mod level29;
+#[cfg(feature = "levels")]
+mod level30;
+#[cfg(feature = "levels")]
+mod level31;
+#[cfg(feature = "levels")]
+mod level32;
+#[cfg(feature = "levels")]
+mod level33;
+#[cfg(feature = "levels")]
+mod level34;
+#[cfg(feature = "levels")]
+mod level35;
+#[cfg(feature = "levels")]
+mod level36;
+#[cfg(feature = "levels")]
+mod level37;
+#[cfg(feature = "levels")]
+mod level38;
+#[cfg(feature = "levels")]
+mod level39;
+#[cfg(feature = "levels")]
+mod level40;
+#[cfg(feature = "levels")]
+mod level41;
+#[cfg(feature = "levels")]
+mod level42;
+#[cfg(feature = "levels")]
+mod level43;
+#[cfg(feature = "levels")]
+mod level44;
+#[cfg(feature = "levels")]
+mod level45;
+#[cfg(feature = "levels")]
+mod level46;
+#[cfg(feature = "levels")]
 mod level_scroll;
+mod measure;
+mod os_cursor;
+mod recovery;
+#[cfg(feature = "levels")]
+mod registry;
+mod rounds;
+#[cfg(feature = "levels")]
+mod scene;
+#[cfg(all(feature = "levels", feature = "solver"))]
+mod selftest;
+#[cfg(feature = "levels")]
+mod session;
+mod small_targets;
+#[cfg(feature = "exporter")]
+mod split;
+mod stats;
+mod theme;
+#[cfg(feature = "levels")]
+mod timer;
 
+#[cfg(feature = "levels")]
+pub(crate) use binding::use_live_state;
+pub(crate) use binding::LiveState;
+#[cfg(any(feature = "levels", feature = "test-routes"))]
 pub(crate) use custom_select::CustomSelect;
+#[cfg(any(feature = "levels", feature = "test-routes"))]
 pub(crate) use ground_truth::GroundTruth;
-pub use level1::Level1;
-pub use level2::Level2;
-pub use level3::Level3;
-pub use level4::Level4;
-pub use level5::Level5;
-pub use level6::Level6;
-pub use level7::Level7;
-pub use level8::Level8;
-pub use level9::Level9;
-pub use level10::Level10;
-pub use level11::Level11;
-pub use level12::Level12;
-pub use level13::Level13;
-pub use level14::Level14;
-pub use level15::Level15;
-pub use level16::Level16;
-pub use level17::Level17;
-pub use level18::Level18;
-pub use level19::Level19;
-pub use level20::Level20;
-pub use level21::Level21;
-pub use level22::Level22;
-pub use level23::Level23;
-pub use level24::Level24;
-pub use level25::Level25;
-pub use level26::Level26;
-pub use level27::Level27;
-pub use level_scroll::LevelScroll;
+pub use ground_truth::install_check_js;
+#[cfg(feature = "levels")]
+pub(crate) use impossible::{roll_impossible, with_not_found, ReportNotFound};
+#[cfg(feature = "exporter")]
+pub(crate) use split::current_split;
+#[cfg(feature = "levels")]
+pub(crate) use timer::{roll_time_limit, RoundTimer};
+#[cfg(feature = "levels")]
+pub use registry::{LevelEntry, LevelHost, LevelPage, LevelRegistry};
+#[cfg(feature = "levels")]
+pub use endless::RandomLevel;
+#[cfg(feature = "levels")]
+pub use campaign::Campaign;
+#[cfg(all(feature = "levels", feature = "solver"))]
+pub use selftest::SelfTest;
+#[cfg(all(feature = "levels", feature = "exporter"))]
+pub use generate::Generate;
+#[cfg(feature = "levels")]
+pub use session::{campaign_order, GameSession};
 
+pub use completion::{CompletionBus, LevelCompletion};
+pub use os_cursor::install as install_os_cursor_js;
+pub use small_targets::install as install_small_targets_css;
+pub use stats::install as install_stats_js;
+#[cfg(feature = "levels")]
+pub use stats::SessionStats;
+pub use device::DeviceProfile;
+pub use difficulty::Difficulty;
+pub use theme::{current_theme, ThemeSlot};
+
+use dioxus::prelude::*;
 use rand::SeedableRng;
 use rand::Rng;
 use rand::rngs::SmallRng;
@@ -67,9 +176,11 @@ use std::cell::{Cell, RefCell};
 use js_sys::Reflect;
 use web_sys::wasm_bindgen::JsValue;
 
-use crate::pool::{ElementPool, ElementKind};
-use crate::primitives::{Position, viewport_size};
-use crate::transform::{PlacedElement, Sampler};
+use crate::pool::{DesignSnippet, ElementPool, ElementKind};
+use crate::primitives::{Angle, Position, Scale, Typography, viewport_size};
+use crate::primitives::typography::FontSize;
+use crate::transform::{PlacedElement, Sampler, TagFilter};
+use crate::ui_node::Lang;
 
 const CANVAS_COLORS: &[&str] = &[
     "#1a1a2e", "#2d1b69", "#0f3460", "#1b4332", "#4a1942",
@@ -79,10 +190,18 @@ const CANVAS_COLORS: &[&str] = &[
     "#c0392b", "#16a085", "#2c3e50", "#e74c3c", "#3498db", "#ffffff",
 ];
 
+/// CSS background for a round's canvas: a flat palette colour, or under
+/// `?bg=varied` a gradient, texture or pattern built on that colour.
+#[track_caller]
 pub fn random_canvas_bg() -> String {
     reroll_viewport();
     let mut rng = fresh_rng();
-    CANVAS_COLORS[rng.random_range(0..CANVAS_COLORS.len())].to_string()
+    let base = CANVAS_COLORS[rng.random_range(0..CANVAS_COLORS.len())];
+    if background::varied() {
+        background::sample(&mut rng, base, CANVAS_COLORS)
+    } else {
+        base.to_string()
+    }
 }
 
 /// Re-randomize the viewport scale factor for the next round.
@@ -93,30 +212,86 @@ fn reroll_viewport() {
     }
 }
 
+/// New RNG for one decision. Every call is logged to `SeedAudit` under the
+/// caller's source location.
+#[track_caller]
 pub fn fresh_rng() -> SmallRng {
+    let tag = std::panic::Location::caller();
     if let Some(seed) = current_seed() {
         let counter = SEED_COUNTER.with(|c| {
             let value = c.get();
             c.set(value + 1);
             value
         });
-        SmallRng::from_seed(expand_seed(seed, counter))
+        expose_on_window("__seedCounter", (counter + 1) as f64);
+        let derived = expand_seed(seed, counter);
+        SeedAudit::record(&tag.to_string(), Some(counter), &derived);
+        SmallRng::from_seed(derived)
     } else {
         let mut buf = [0u8; 32];
         getrandom::fill(&mut buf).expect("getrandom");
+        SeedAudit::record(&tag.to_string(), None, &buf);
         SmallRng::from_seed(buf)
     }
 }
 
+/// Replay log of RNG derivations, readable from JS as `window.__seedAudit`.
+///
+/// Each entry is `{ tag, counter, seed, ts }`: the `fresh_rng` call site
+/// ("src/levels/level3.rs:42:19"), the per-run counter (null when unseeded)
+/// and the full 32-byte `SmallRng` seed as hex, which is enough to rebuild the
+/// exact generator. Viewport-scale rerolls are appended by the autoFit JS
+/// with tag "viewport-scale". Oldest entries are dropped past `CAPACITY`;
+/// assigning `window.__seedAudit = []` clears the log.
+pub struct SeedAudit;
+
+impl SeedAudit {
+    pub const CAPACITY: u32 = 2000;
+
+    pub fn record(tag: &str, counter: Option<u64>, seed: &[u8; 32]) {
+        let Some(window) = web_sys::window() else { return };
+        let log = match Reflect::get(&window, &JsValue::from_str("__seedAudit")) {
+            Ok(v) if js_sys::Array::is_array(&v) => js_sys::Array::from(&v),
+            _ => {
+                let fresh = js_sys::Array::new();
+                let _ = Reflect::set(&window, &JsValue::from_str("__seedAudit"), &fresh);
+                fresh
+            }
+        };
+        let hex: String = seed.iter().map(|b| format!("{b:02x}")).collect();
+        let entry = js_sys::Object::new();
+        let _ = Reflect::set(&entry, &"tag".into(), &tag.into());
+        let counter = counter.map(|c| JsValue::from_f64(c as f64)).unwrap_or(JsValue::NULL);
+        let _ = Reflect::set(&entry, &"counter".into(), &counter);
+        let _ = Reflect::set(&entry, &"seed".into(), &hex.into());
+        let _ = Reflect::set(&entry, &"ts".into(), &js_sys::Date::now().into());
+        log.push(&entry);
+        if log.length() > Self::CAPACITY {
+            log.shift();
+        }
+    }
+}
+
 thread_local! {
-    static SEED: RefCell<Option<u64>> = RefCell::new(None);
-    static SEED_COUNTER: Cell<u64> = Cell::new(0);
+    static SEED: RefCell<Option<u64>> = const { RefCell::new(None) };
+    static SEED_COUNTER: Cell<u64> = const { Cell::new(0) };
+    static ROUND: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Rounds completed so far in this page session, across all levels.
+fn round_index() -> u64 {
+    ROUND.with(|r| r.get())
 }
 
 fn current_seed() -> Option<u64> {
     SEED.with(|seed| {
         if seed.borrow().is_none() {
-            let next = seed_from_window();
+            let next = seed_from_window().or_else(seed_from_query);
+            if let Some(value) = next {
+                // Feed the URL seed back so the viewport-scale JS uses it too
+                expose_on_window("__playgroundSeed", value as f64);
+                expose_on_window("__effectiveSeed", value as f64);
+            }
             *seed.borrow_mut() = next;
         }
         *seed.borrow()
@@ -134,6 +309,63 @@ fn seed_from_window() -> Option<u64> {
     }
 }
 
+/// Reseed the run: later `fresh_rng` calls derive from `seed` starting at
+/// counter 0. `None` drops an earlier override and falls back to `?seed=`
+/// (or OS randomness).
+#[cfg(any(feature = "control", all(feature = "levels", any(feature = "solver", feature = "exporter"))))]
+pub fn set_seed(seed: Option<u64>) {
+    if let (None, Some(window)) = (seed, web_sys::window()) {
+        let _ = Reflect::delete_property(&window, &JsValue::from_str("__playgroundSeed"));
+        let _ = Reflect::delete_property(&window, &JsValue::from_str("__effectiveSeed"));
+    }
+    if let Some(value) = seed {
+        expose_on_window("__playgroundSeed", value as f64);
+        expose_on_window("__effectiveSeed", value as f64);
+    }
+    SEED.with(|s| *s.borrow_mut() = seed);
+    SEED_COUNTER.with(|c| c.set(0));
+    expose_on_window("__seedCounter", 0.0);
+}
+
+/// fresh_rng calls made so far under the current seed.
+fn seed_counter() -> u64 {
+    SEED_COUNTER.with(|c| c.get())
+}
+
+/// `?seed=12345` on any route; used when `window.__playgroundSeed` is unset.
+fn seed_from_query() -> Option<u64> {
+    query_param("seed")?.trim().parse().ok()
+}
+
+/// Mirror seeding state onto `window` (`__effectiveSeed`, `__seedCounter`)
+/// so a run can be reproduced from the URL alone.
+fn expose_on_window(key: &str, value: f64) {
+    if let Some(window) = web_sys::window() {
+        let _ = Reflect::set(&window, &JsValue::from_str(key), &JsValue::from_f64(value));
+    }
+}
+
+/// Read a query-string parameter from the current URL.
+pub fn query_param(name: &str) -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    web_sys::UrlSearchParams::new_with_str(&search).ok()?.get(name)
+}
+
+/// Language for ground truth and instructions, from `?lang=en|es|de|ja`.
+pub fn current_lang() -> Lang {
+    query_param("lang").and_then(|s| Lang::parse(&s)).unwrap_or_default()
+}
+
+/// Level instruction text from the shared string tables, in `current_lang()`.
+pub fn tr(key: &str, args: &[&dyn std::fmt::Display]) -> String {
+    crate::ui_node::tr(current_lang(), key, args)
+}
+
+/// `ordinal` for instruction text: "1st" / "1.º" / "1." / "1番目" in `current_lang()`.
+pub fn tr_ordinal(n: usize) -> String {
+    crate::ui_node::ordinal(current_lang(), n)
+}
+
 fn expand_seed(seed: u64, counter: u64) -> [u8; 32] {
     let mut state = seed ^ counter.wrapping_mul(0x9e3779b97f4a7c15);
     let mut out = [0u8; 32];
@@ -173,17 +405,80 @@ pub fn safe_position_in(rng: &mut impl Rng, w: f32, h: f32, pad: f32, canvas_w:
     (x, y)
 }
 
-pub fn random_element(pool: &ElementPool, kind: ElementKind) -> PlacedElement {
+/// Fraction of a box that overlap placement may cover, from `?overlap=`
+/// (0 = off). Capped at 0.9 so every element keeps a visible sliver.
+pub fn overlap_ratio() -> f32 {
+    query_param("overlap")
+        .and_then(|s| s.trim().parse::<f32>().ok())
+        .map_or(0.0, |r| r.clamp(0.0, 0.9))
+}
+
+/// Whether generated instructions name the target only relative to a
+/// neighbour ("the toggle to the right of Email"), from `?refs=relative`.
+pub fn relative_refs() -> bool {
+    query_param("refs").is_some_and(|s| s.trim() == "relative")
+}
+
+/// Card typography for the round when `?typography=1` is set, in a size
+/// `d` allows. Draws from `rng` only in that mode, so seeded runs without it
+/// replay unchanged.
+pub fn random_typography(rng: &mut impl Rng, d: &Difficulty) -> Option<Typography> {
+    let on = query_param("typography").is_some_and(|s| matches!(s.trim(), "1" | "true" | "on"));
+    on.then(|| {
+        let sizes: Vec<FontSize> = Typography::SIZES.iter()
+            .copied()
+            .filter(|s| d.allows_font(s.value()))
+            .collect();
+        Sampler::random_typography(rng, if sizes.is_empty() { Typography::SIZES } else { &sizes })
+    })
+}
+
+/// Small card tilt (±1–5°) for the round when `?rotate=1` is set. Draws from
+/// `rng` only in that mode, so seeded runs without it replay unchanged.
+pub fn random_card_tilt(rng: &mut impl Rng) -> Option<Angle> {
+    let on = query_param("rotate").is_some_and(|s| matches!(s.trim(), "1" | "true" | "on"));
+    on.then(|| {
+        let degrees = rng.random_range(1.0..=5.0f32);
+        Angle::new(if rng.random_bool(0.5) { degrees } else { -degrees })
+    })
+}
+
+/// Whether the round draws checkboxes, radios and sliders as native
+/// `<input>`s rather than styled divs. `?inputs=native` or `?inputs=custom` forces one;
+/// otherwise it's a coin flip. The tree is the same either way.
+pub fn random_native_inputs(rng: &mut impl Rng) -> bool {
+    match query_param("inputs").as_deref().map(str::trim) {
+        Some("native") => true,
+        Some("custom") => false,
+        _ => rng.random_bool(0.5),
+    }
+}
+
+/// Pick a snippet of `kind` under the `?tags=` filter (`TagFilter::parse`),
+/// falling back to any snippet of the kind when none passes.
+pub fn pick_tagged<R: Rng>(rng: &mut R, pool: &ElementPool, kind: ElementKind) -> Option<DesignSnippet> {
+    let filter = query_param("tags").map(|s| TagFilter::parse(&s)).unwrap_or_default();
+    if filter.is_empty() {
+        return Sampler::pick_kind(rng, pool, kind);
+    }
+    Sampler::pick_kind_where(rng, pool, kind, &filter).or_else(|| Sampler::pick_kind(rng, pool, kind))
+}
+
+#[track_caller]
+pub fn random_element(pool: &ElementPool, kind: ElementKind, d: &Difficulty) -> PlacedElement {
     let mut rng = fresh_rng();
-    let snippet = Sampler::pick_kind(&mut rng, pool, kind)
+    let snippet = pick_tagged(&mut rng, pool, kind)
         .expect("pool has this kind");
 
     let (vp_w, vp_h) = viewport_size();
     let pad = 150.0f32.min(vp_w.min(vp_h) / 4.0);
-    let (x, y) = safe_position(&mut rng, snippet.approx_width, snippet.approx_height, pad);
+    let scale = d.target_scale(&mut rng);
+    let (w, h) = snippet.size();
+    let (x, y) = safe_position(&mut rng, w * scale, h * scale, pad);
+    let (x, y) = d.jitter(&mut rng, (x, y));
     let pos = Position::new(x, y);
 
-    PlacedElement::new(snippet, pos)
+    PlacedElement::new(snippet, pos).with_scale(Scale::new(scale))
 }
 
 /// Generate the standard viewport div style with dynamic sizing.
@@ -192,12 +487,149 @@ pub fn random_element(pool: &ElementPool, kind: ElementKind) -> PlacedElement {
 pub fn viewport_style(bg: &str, scrollable: bool) -> String {
     let (vp_w, vp_h) = viewport_size();
     let overflow = if scrollable { "auto" } else { "hidden" };
+    let theme_vars = current_theme().map(|t| t.css_vars()).unwrap_or_default();
     format!(
-        "width: {vp_w}px; height: {vp_h}px; background: {bg}; position: relative; border: 1px solid #2a2a4a; overflow: {overflow}; transition: background 0.4s;",
+        "width: {vp_w}px; height: {vp_h}px; background: {bg}; position: relative; border: 1px solid #2a2a4a; overflow: {overflow}; transition: background 0.4s; {theme_vars}",
     )
 }
 
 
+/// Listen for keydown events anywhere on the page and forward them to
+/// `on_key` as normalized combos ("Ctrl+K", "Ctrl+Shift+P", "Escape").
+/// Ctrl/Meta letter shortcuts have their browser default suppressed.
+pub fn use_hotkeys(on_key: impl FnMut(String) + Copy + 'static) {
+    use_hotkeys_capturing(&[], on_key);
+}
+
+/// Like `use_hotkeys`, but also suppresses the browser default for the given
+/// combos (e.g. "Tab") so the level can handle them itself.
+pub fn use_hotkeys_capturing(captured: &'static [&'static str], mut on_key: impl FnMut(String) + Copy + 'static) {
+    use_future(move || async move {
+        let captured_js = captured.iter()
+            .map(|c| format!("'{}'", c))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut eval = document::eval(&format!(r#"
+            const captured = [{captured_js}];
+            if (window.__hotkeyListener) document.removeEventListener('keydown', window.__hotkeyListener, true);
+            window.__hotkeyListener = (e) => {{
+                if (['Control', 'Shift', 'Alt', 'Meta'].includes(e.key)) return;
+                const mods = [];
+                if (e.ctrlKey) mods.push('Ctrl');
+                if (e.altKey) mods.push('Alt');
+                if (e.shiftKey) mods.push('Shift');
+                if (e.metaKey) mods.push('Meta');
+                const key = e.key.length === 1 ? e.key.toUpperCase() : e.key;
+                const combo = [...mods, key].join('+');
+                if ((e.ctrlKey || e.metaKey) && key.length === 1) e.preventDefault();
+                if (captured.includes(combo)) e.preventDefault();
+                dioxus.send(combo);
+            }};
+            document.addEventListener('keydown', window.__hotkeyListener, true);
+        "#));
+        while let Ok(combo) = eval.recv::<String>().await {
+            on_key(combo);
+        }
+    });
+    use_drop(|| {
+        document::eval(r#"
+            if (window.__hotkeyListener) {
+                document.removeEventListener('keydown', window.__hotkeyListener, true);
+                window.__hotkeyListener = null;
+            }
+        "#);
+    });
+}
+
+/// Session-wide score and completion hook provided by multi-level runs
+/// (e.g. `/random`). Levels pick it up through `use_level_score`.
+#[derive(Clone, Copy)]
+pub struct LevelRun {
+    pub score: Signal<u32>,
+    pub on_complete: Callback<()>,
+}
+
+/// A level's score counter. Standalone it is local to the level; inside a
+/// `LevelRun` it is the run's shared score and `complete` also fires the
+/// run's advance hook.
+#[derive(Clone, Copy)]
+pub struct LevelScore {
+    score: Signal<u32>,
+    run: Option<LevelRun>,
+    theme: Option<ThemeSlot>,
+    bus: Option<CompletionBus>,
+}
+
+impl LevelScore {
+    /// Count a solved round.
+    pub fn complete(&mut self) {
+        self.score += 1;
+        let event = LevelCompletion::now(round_index());
+        stats::round_solved(&event);
+        rounds::round_end(round_index(), rounds::RoundEnd::Complete);
+        ROUND.with(|r| r.set(r.get() + 1));
+        match self.bus.as_mut() {
+            Some(bus) => bus.emit(event),
+            None => completion::notify_window(&event),
+        }
+        completion::start_round_clock();
+        if let Some(theme) = self.theme.as_mut() {
+            theme.next_round();
+        }
+        #[cfg(feature = "exporter")]
+        {
+            crate::recorder::mark_success();
+            crate::dataset::round_complete();
+        }
+        if let Some(run) = self.run {
+            run.on_complete.call(());
+        }
+    }
+
+    /// Log a wrong attempt on this round; see `record_wrong`.
+    pub fn wrong(&self, target: &str, value: &str) {
+        record_wrong(target, value);
+    }
+}
+
+/// Log a wrong attempt in the episode log: `target` is the label of the
+/// element acted on, `value` what it was set to or submitted with ("" for a
+/// plain click). Logged to the console as one JSON line; dataset records
+/// list a round's wrong attempts next to its ground truth, and the recorder
+/// attaches them to the step that caused them, so correct and incorrect
+/// trajectories can be contrasted.
+pub(crate) fn record_wrong(target: &str, value: &str) {
+    let json = format!(
+        r#"{{"event":"wrong_attempt","level":"{}","target":"{}","value":"{}","round":{},"ts":{}}}"#,
+        crate::ui_node::escape_json(&ground_truth::current_level_id()),
+        crate::ui_node::escape_json(target),
+        crate::ui_node::escape_json(value),
+        round_index(),
+        js_sys::Date::now() as u64,
+    );
+    web_sys::console::log_1(&JsValue::from_str(&json));
+    #[cfg(feature = "exporter")]
+    {
+        crate::recorder::mark_wrong(&json);
+        crate::dataset::round_wrong(&json);
+    }
+}
+
+impl std::fmt::Display for LevelScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.score)
+    }
+}
+
+pub fn use_level_score() -> LevelScore {
+    let local = use_signal(|| 0u32);
+    let run = try_use_context::<LevelRun>();
+    let theme = try_use_context::<ThemeSlot>();
+    let bus = try_use_context::<CompletionBus>();
+    use_hook(completion::start_round_clock);
+    LevelScore { score: run.map(|r| r.score).unwrap_or(local), run, theme, bus }
+}
+
 pub fn ordinal(n: usize) -> String {
     let suffix = match (n % 10, n % 100) {
         (1, 11) => "th",
@@ -0,0 +1,52 @@
+//! Synthetic OS cursor — an arrow pointer drawn into the page for captures.
+//!
+//! Browser screenshots never contain the system cursor, while real desktop
+//! captures do. With `?os_cursor=1` an arrow is drawn at the last pointer
+//! position whenever it is over `#viewport`. A capture-phase listener on
+//! `document` feeds it, so real mouse movement and the solver's dispatched
+//! events move it alike. The arrow has `pointer-events: none`, so hit tests
+//! and clicks go through it, and it stays off under touch device profiles.
+//! `window.__osCursor()` returns the drawn position `[x, y]`, or null.
+
+use dioxus::prelude::*;
+
+/// Whether `?os_cursor=1` is on.
+pub fn enabled() -> bool {
+    super::query_param("os_cursor").is_some_and(|s| s.trim() == "1")
+}
+
+const CURSOR_JS: &str = r##"
+    if (!window.__osCursorInstalled) {
+        window.__osCursorInstalled = true;
+        const arrow = document.createElement('div');
+        arrow.id = '__os-cursor';
+        // Hotspot at the tip, so left / top are the pointer position
+        arrow.style.cssText = 'position:fixed;left:0;top:0;width:18px;height:26px;pointer-events:none;z-index:99997;display:none;';
+        arrow.innerHTML = '<svg xmlns="http://www.w3.org/2000/svg" width="18" height="26" viewBox="0 0 18 26">'
+            + '<path d="M1 1v20l5-5 3.5 8 3-1.3-3.5-7.8H16z" fill="#000" stroke="#fff" stroke-width="1.5" stroke-linejoin="round"/></svg>';
+        document.body.appendChild(arrow);
+        const place = (e) => {
+            const vp = document.getElementById('viewport');
+            const touch = window.__deviceProfile && window.__deviceProfile.touch;
+            const r = vp && vp.getBoundingClientRect();
+            const inside = r && e.clientX >= r.left && e.clientX < r.right && e.clientY >= r.top && e.clientY < r.bottom;
+            if (touch || !inside) { arrow.style.display = 'none'; return; }
+            arrow.style.display = 'block';
+            arrow.style.left = e.clientX + 'px';
+            arrow.style.top = e.clientY + 'px';
+        };
+        for (const type of ['mousemove', 'pointermove', 'mousedown', 'pointerdown', 'mouseup', 'click', 'contextmenu']) {
+            document.addEventListener(type, place, true);
+        }
+        window.__osCursor = () => arrow.style.display === 'none'
+            ? null
+            : [parseFloat(arrow.style.left), parseFloat(arrow.style.top)];
+    }
+"##;
+
+/// Install the arrow when `?os_cursor=1` is set.
+pub fn install() {
+    if enabled() {
+        document::eval(CURSOR_JS);
+    }
+}
@@ -0,0 +1,89 @@
+//! Failure injection — rounds whose steps open with a mistake and its fix.
+//!
+//! With `?recovery=1` GroundTruth rewrites the resolved steps: the first
+//! text entry is typed with a typo, erased and retyped; failing that, when
+//! `?distractors=` placed a decoy, the first click lands on the decoy before
+//! the real target. The payload lists the indices of the deliberate mistakes
+//! and the decoy boxes the solver may have to click, and the thinking
+//! explains the correction.
+//!
+//! Mistakes are derived from the step content alone, so the rewrite is stable
+//! across re-renders and never draws from the seeded RNG.
+
+use crate::ui_node::{escape_json, tr, Action, Lang};
+
+/// Whether `?recovery=1` is on.
+pub fn enabled() -> bool {
+    super::query_param("recovery").is_some_and(|s| s.trim() == "1")
+}
+
+/// Steps with a mistake injected, and the reasoning for recovering from it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recovery {
+    pub steps: Vec<Action>,
+    /// Indices into `steps` of the deliberate mistakes.
+    pub mistakes: Vec<usize>,
+    pub thinking: String,
+}
+
+impl Recovery {
+    /// `,"mistakes":[..],"decoys":[..]` fields for the ground truth payload;
+    /// `decoys` are (label, window bbox).
+    pub fn payload_fields(&self, decoys: &[(String, [i32; 4])]) -> String {
+        let mistakes: Vec<String> = self.mistakes.iter().map(|i| i.to_string()).collect();
+        let decoys: Vec<String> = decoys.iter()
+            .map(|(label, b)| format!(
+                r#"{{"label":"{}","bbox":[{},{},{},{}]}}"#,
+                escape_json(label), b[0], b[1], b[2], b[3],
+            ))
+            .collect();
+        format!(r#","mistakes":[{}],"decoys":[{}]"#, mistakes.join(","), decoys.join(","))
+    }
+}
+
+/// A plausible typo of `value`: swap, drop or double a character, picked by
+/// a hash of the text. `None` for values too short to mistype.
+pub fn typo(value: &str) -> Option<String> {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() < 2 {
+        return None;
+    }
+    let hash = chars.iter().fold(0usize, |h, &c| h.wrapping_mul(31).wrapping_add(c as usize));
+    let at = hash % (chars.len() - 1);
+    let mut out = chars.clone();
+    match hash % 3 {
+        0 if chars[at] != chars[at + 1] => out.swap(at, at + 1),
+        1 => {
+            out.remove(at);
+        }
+        _ => out.insert(at, chars[at]),
+    }
+    Some(out.into_iter().collect())
+}
+
+/// Inject one mistake into `steps`. `decoys` are labels of clickable-looking
+/// clutter; the caller drops any that share a label with a real target.
+pub fn inject(steps: &[Action], decoys: &[String], lang: Lang) -> Option<Recovery> {
+    // Prefer a typo: the first text entry
+    let typed = steps.iter().enumerate().find_map(|(i, a)| match a {
+        Action::Type { target, value } => typo(value).map(|t| (i, target.clone(), value.clone(), t)),
+        _ => None,
+    });
+    if let Some((i, target, value, wrong)) = typed {
+        let mut out = steps[..i].to_vec();
+        out.push(Action::type_text(&target, &wrong));
+        out.push(Action::erase(&target));
+        out.extend_from_slice(&steps[i..]);
+        let thinking = tr(lang, "think.recovery_typo", &[&wrong, &target, &value]);
+        return Some(Recovery { steps: out, mistakes: vec![i], thinking });
+    }
+    // Otherwise a misclick on a decoy before the first click
+    let i = steps.iter().position(|a| matches!(a, Action::Click { .. }))?;
+    let Action::Click { target } = &steps[i] else { return None };
+    let decoy = decoys.first()?;
+    let mut out = steps[..i].to_vec();
+    out.push(Action::click(decoy));
+    out.extend_from_slice(&steps[i..]);
+    let thinking = tr(lang, "think.recovery_misclick", &[decoy, target]);
+    Some(Recovery { steps: out, mistakes: vec![i], thinking })
+}
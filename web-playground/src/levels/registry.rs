@@ -0,0 +1,281 @@
+//! LevelRegistry — single source of truth for every level page.
+//!
+//! The router resolves `/:slug` through this table and the level select grid
+//! is generated from it, so adding a level means adding its `mod` line and
+//! one entry here.
+
+use dioxus::prelude::*;
+use dioxus::core::{DynamicNode, VComponent};
+
+use crate::Route;
+use crate::ui_node::{escape_json, Action};
+use super::*;
+
+/// Grouping shown in the level select grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    /// One control, one action.
+    Basics,
+    /// Target starts outside the viewport.
+    Scrolling,
+    /// Pick the right element among look-alikes.
+    Targeting,
+    /// Several controls in one card.
+    Compound,
+    /// Multi-step widgets and app-like scenes.
+    Complex,
+}
+
+impl Category {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Basics => "basics",
+            Category::Scrolling => "scrolling",
+            Category::Targeting => "targeting",
+            Category::Compound => "compound",
+            Category::Complex => "complex",
+        }
+    }
+}
+
+/// Solver action kinds a level needs (mirrors `ui_node::Action`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interaction {
+    Click,
+    Type,
+    Drag,
+    RightClick,
+    Scroll,
+    ScrollBy,
+    /// Only added by `?recovery=1` rounds on top of the resolved steps, so
+    /// levels don't list it.
+    Erase,
+    Key,
+    SetSlider,
+    Select,
+    Hover,
+    Wait,
+    WaitFor,
+}
+
+impl From<&Action> for Interaction {
+    fn from(action: &Action) -> Self {
+        match action {
+            Action::Click { .. } => Interaction::Click,
+            Action::Type { .. } => Interaction::Type,
+            Action::Drag { .. } => Interaction::Drag,
+            Action::RightClick { .. } => Interaction::RightClick,
+            Action::Scroll { .. } => Interaction::Scroll,
+            Action::ScrollBy { .. } => Interaction::ScrollBy,
+            Action::Erase { .. } => Interaction::Erase,
+            Action::KeyPress { .. } => Interaction::Key,
+            Action::SetSlider { .. } => Interaction::SetSlider,
+            Action::SelectText { .. } => Interaction::Select,
+            Action::Hover { .. } => Interaction::Hover,
+            Action::Wait { .. } => Interaction::Wait,
+            Action::WaitFor { .. } => Interaction::WaitFor,
+        }
+    }
+}
+
+impl Interaction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Interaction::Click => "click",
+            Interaction::Type => "type",
+            Interaction::Drag => "drag",
+            Interaction::RightClick => "right_click",
+            Interaction::Scroll => "scroll",
+            Interaction::ScrollBy => "scroll_by",
+            Interaction::Erase => "erase",
+            Interaction::Key => "key",
+            Interaction::SetSlider => "set_slider",
+            Interaction::Select => "select_text",
+            Interaction::Hover => "hover",
+            Interaction::Wait => "wait",
+            Interaction::WaitFor => "wait_for",
+        }
+    }
+}
+
+/// Metadata and component for one level page.
+pub struct LevelEntry {
+    /// Position in the level select grid ("Level N").
+    pub id: u32,
+    /// Route path segment, e.g. "level16" for `/level16`.
+    pub slug: &'static str,
+    pub title: &'static str,
+    pub category: Category,
+    /// 1 (single obvious control) to 5 (multi-step, mixed interactions).
+    pub difficulty: u8,
+    pub interactions: &'static [Interaction],
+    pub component: fn() -> Element,
+}
+
+impl LevelEntry {
+    /// Kinds among `steps` that the entry doesn't list, in first-seen order.
+    pub fn unlisted(&self, steps: &[Action]) -> Vec<Interaction> {
+        let mut out = Vec::new();
+        for kind in steps.iter().map(Interaction::from) {
+            if !self.interactions.contains(&kind) && !out.contains(&kind) {
+                out.push(kind);
+            }
+        }
+        out
+    }
+
+    /// Serialize the metadata (everything but the component) for `window.__levels`.
+    pub fn to_json(&self) -> String {
+        let kinds: Vec<String> = self.interactions.iter()
+            .map(|k| format!(r#""{}""#, k.as_str()))
+            .collect();
+        format!(
+            r#"{{"id":{},"slug":"{}","path":"/{}","title":"{}","category":"{}","difficulty":{},"interactions":[{}]}}"#,
+            self.id,
+            escape_json(self.slug),
+            escape_json(self.slug),
+            escape_json(self.title),
+            self.category.as_str(),
+            self.difficulty,
+            kinds.join(","),
+        )
+    }
+}
+
+use Category::*;
+use Interaction::*;
+
+macro_rules! level {
+    ($id:expr, $slug:expr, $title:expr, $cat:expr, $diff:expr, [$($kind:expr),*], $comp:path) => {
+        LevelEntry {
+            id: $id,
+            slug: $slug,
+            title: $title,
+            category: $cat,
+            difficulty: $diff,
+            interactions: &[$($kind),*],
+            component: $comp,
+        }
+    };
+}
+
+/// All levels in level select order.
+static LEVELS: &[LevelEntry] = &[
+    level!(1,  "level1",       "Click the button",          Basics,    1, [Click],              level1::Level1),
+    level!(2,  "level2",       "Toggle the switch",         Basics,    1, [Click],              level2::Level2),
+    level!(3,  "level3",       "Type the word",             Basics,    1, [Type],               level3::Level3),
+    level!(4,  "level4",       "Select the right option",   Basics,    1, [Click, Type, Key],  level4::Level4),
+    level!(5,  "level17",      "Radio buttons",             Basics,    1, [Click],              level17::Level17),
+    level!(6,  "level16",      "Slider",                    Basics,    2, [Drag, SetSlider],    level16::Level16),
+    level!(7,  "level18",      "Number stepper",            Basics,    2, [Click],              level18::Level18),
+    level!(8,  "level19",      "Star rating",               Basics,    1, [Click],              level19::Level19),
+    level!(9,  "level20",      "Tabs",                      Basics,    2, [Click],              level20::Level20),
+    level!(10, "level-scroll", "Scroll & click",            Scrolling, 2, [Scroll, Click],      level_scroll::LevelScroll),
+    level!(11, "level5",       "Find the right button",     Targeting, 2, [Click],              level5::Level5),
+    level!(12, "level6",       "Click the right toggle",    Targeting, 2, [Click],              level6::Level6),
+    level!(13, "level7",       "Type into the right input", Targeting, 2, [Type],               level7::Level7),
+    level!(14, "level21",      "Accordion",                 Targeting, 2, [Click],              level21::Level21),
+    level!(15, "level8",       "Multi-dropdown",            Compound,  3, [Click],              level8::Level8),
+    level!(16, "level9",       "Mixed inputs",              Compound,  3, [Click, Type],        level9::Level9),
+    level!(17, "level10",      "Form submission",           Compound,  3, [Click, Type],        level10::Level10),
+    level!(18, "level11",      "Carousel reading",          Complex,   4, [Click, Type, WaitFor], level11::Level11),
+    level!(19, "level12",      "Grid form",                 Complex,   3, [Type, Click],        level12::Level12),
+    level!(20, "level13",      "Table input",               Complex,   3, [Type, Click],        level13::Level13),
+    level!(21, "level14",      "License agreement",         Complex,   3, [Scroll, Click],      level14::Level14),
+    level!(22, "level15",      "Drag & drop",               Complex,   3, [Drag],               level15::Level15),
+    level!(23, "level22",      "Modal dialog",              Complex,   2, [Click],              level22::Level22),
+    level!(24, "level23",      "Context menu",              Complex,   3, [RightClick, Click],  level23::Level23),
+    level!(25, "level24",      "Search autocomplete",       Complex,   3, [Type, Click],        level24::Level24),
+    level!(26, "level25",      "Sortable list",             Complex,   4, [Drag, Click],        level25::Level25),
+    level!(27, "level26",      "Multi-select tags",         Complex,   3, [Click],              level26::Level26),
+    level!(28, "level27",      "Toast dismiss",             Complex,   2, [Click],              level27::Level27),
+    level!(29, "level28",      "Toast auto-dismiss",        Complex,   3, [Click],              level28::Level28),
+    level!(30, "level29",      "Accordion FAQ",             Complex,   3, [Click],              level29::Level29),
+    level!(31, "level30",      "Command palette",           Complex,   4, [Key, Type],          level30::Level30),
+    level!(32, "level31",      "Zoom preview",              Complex,   3, [Drag, Click],        level31::Level31),
+    level!(33, "level32",      "Rich text toolbar",         Complex,   4, [Click, Type],        level32::Level32),
+    level!(34, "level33",      "Captcha grid",              Complex,   3, [Click],              level33::Level33),
+    level!(35, "level34",      "Notification center",       Complex,   2, [Click],              level34::Level34),
+    level!(36, "level35",      "Keyboard-only form",        Complex,   4, [Key, Type],          level35::Level35),
+    level!(37, "level36",      "Copy & paste",              Complex,   4, [Select, Click],      level36::Level36),
+    level!(38, "level37",      "Tooltip code",              Complex,   3, [Hover, Type, Click], level37::Level37),
+    level!(39, "level38",      "Confirm dialog",            Complex,   2, [Click],              level38::Level38),
+    level!(40, "level39",      "Loading gate",              Complex,   2, [Click, Wait],        level39::Level39),
+    level!(41, "level40",      "Shape by description",      Targeting, 2, [Click],              level40::Level40),
+    level!(42, "level41",      "Icon toolbar",              Targeting, 2, [Click],              level41::Level41),
+    level!(43, "level42",      "Split screen",              Targeting, 2, [Click],              level42::Level42),
+    level!(44, "level43",      "Nested scroll",             Scrolling, 3, [Scroll, Click],      level43::Level43),
+    level!(45, "level44",      "Sticky header",             Scrolling, 3, [Scroll, Click],      level44::Level44),
+    level!(46, "level45",      "Dense grid",                Targeting, 3, [Click],              level45::Level45),
+    level!(47, "level46",      "Long document",             Scrolling, 3, [Scroll, Click],      level46::Level46),
+    level!(48, "scene",        "Pool scene",                Targeting, 2, [Click],              scene::PoolScene),
+];
+
+/// Lookup over the static level table.
+pub struct LevelRegistry;
+
+impl LevelRegistry {
+    /// Every level, in level select order.
+    pub fn all() -> &'static [LevelEntry] {
+        LEVELS
+    }
+
+    pub fn by_slug(slug: &str) -> Option<&'static LevelEntry> {
+        LEVELS.iter().find(|e| e.slug == slug)
+    }
+
+    /// JSON array of every level's metadata, in level select order.
+    pub fn to_json() -> String {
+        let inner: Vec<String> = LEVELS.iter().map(|e| e.to_json()).collect();
+        format!("[{}]", inner.join(","))
+    }
+
+    /// Publish the level list as `window.__levels` so external harnesses can
+    /// enumerate levels without scraping the level select grid.
+    pub fn install_js() {
+        document::eval(&format!(
+            "window.__levels = Object.freeze({}.map(l => Object.freeze(l)));",
+            Self::to_json(),
+        ));
+    }
+}
+
+thread_local! {
+    static NEXT_HOST: std::cell::Cell<u64> = const { std::cell::Cell::new(0) };
+}
+
+/// Renders a registered level and marks it as the level on screen, so
+/// ground truth, round events and stats name the level rather than the
+/// route hosting it.
+#[component]
+pub fn LevelHost(slug: &'static str) -> Element {
+    let id = use_hook(|| NEXT_HOST.with(|n| n.replace(n.get() + 1)));
+    // Every render, so a host switched to another level re-marks it
+    ground_truth::set_active_level(id, slug);
+    use_drop(move || ground_truth::clear_active_level(id));
+    let Some(entry) = LevelRegistry::by_slug(slug) else { return rsx! {} };
+    let node = DynamicNode::Component(VComponent::new(entry.component, (), entry.slug));
+    rsx! { {node} }
+}
+
+/// Route target for `/:slug` — renders the registered level component.
+#[component]
+pub fn LevelPage(slug: String) -> Element {
+    match LevelRegistry::by_slug(&slug) {
+        Some(entry) => rsx! { LevelHost { slug: entry.slug } },
+        None => rsx! {
+            div {
+                style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; justify-content: center; gap: 16px; font-family: system-ui, sans-serif;",
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "No level at /{slug}"
+                }
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6366f1; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+            }
+        },
+    }
+}
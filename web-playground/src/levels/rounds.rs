@@ -0,0 +1,118 @@
+//! Round lifecycle events for capture harnesses.
+//!
+//! GroundTruth emits `round_start` the first time it renders a new round, and
+//! a round ends with `round_end` when it is solved or its level unmounts.
+//! Each event is logged to the console as one JSON line (like the input
+//! listener log) and appended to `window.__rounds`:
+//!
+//! ```json
+//! {"event":"round_start","id":7,"round":3,"level":"/level5","seed":42,"seed_counter":17,
+//!  "gt_hash":"9f2c…","viewport":[1024,768],"ts":1700000000000}
+//! {"event":"round_end","id":7,"round":3,"level":"/level5","reason":"complete","ts":…}
+//! ```
+//!
+//! `id` is unique per page session; `round` is the session's solved-round
+//! index, so a round re-entered after leaving its level keeps its `round`
+//! but gets a new `id`. `gt_hash` fingerprints the resolved description and
+//! steps, so screenshots between a start and its end can be grouped into one
+//! episode. Oldest entries are dropped past `CAPACITY`.
+
+use std::cell::Cell;
+
+use js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+
+use crate::ui_node::escape_json;
+
+pub const CAPACITY: u32 = 1000;
+
+thread_local! {
+    /// (id, round) of the round started and not yet ended.
+    static OPEN: Cell<Option<(u64, u64)>> = const { Cell::new(None) };
+    static NEXT_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Why a round ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RoundEnd {
+    Complete,
+    /// The level unmounted mid-round.
+    Abandoned,
+    /// The round's countdown ran out (`?timer=`).
+    Timeout,
+}
+
+impl RoundEnd {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Complete => "complete",
+            Self::Abandoned => "abandoned",
+            Self::Timeout => "timeout",
+        }
+    }
+}
+
+/// 64-bit FNV-1a of `text`, as 16 hex digits.
+pub fn gt_hash(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3));
+    format!("{hash:016x}")
+}
+
+fn emit(json: String) {
+    web_sys::console::log_1(&JsValue::from_str(&json));
+    let Some(window) = web_sys::window() else { return };
+    let log = match Reflect::get(&window, &JsValue::from_str("__rounds")) {
+        Ok(v) if js_sys::Array::is_array(&v) => js_sys::Array::from(&v),
+        _ => {
+            let fresh = js_sys::Array::new();
+            let _ = Reflect::set(&window, &JsValue::from_str("__rounds"), &fresh);
+            fresh
+        }
+    };
+    if let Ok(entry) = js_sys::JSON::parse(&json) {
+        log.push(&entry);
+    }
+    if log.length() > CAPACITY {
+        log.shift();
+    }
+}
+
+/// Open `round`, unless it is already open. Returns whether it opened.
+pub fn round_start(round: u64, gt_hash: &str, viewport: (f64, f64)) -> bool {
+    if OPEN.with(|o| o.get()).is_some_and(|(_, r)| r == round) {
+        return false;
+    }
+    let id = NEXT_ID.with(|n| n.replace(n.get() + 1));
+    OPEN.with(|o| o.set(Some((id, round))));
+    let seed = super::current_seed().map_or("null".to_string(), |s| s.to_string());
+    emit(format!(
+        r#"{{"event":"round_start","id":{},"round":{},"level":"{}","seed":{},"seed_counter":{},"gt_hash":"{}","viewport":[{},{}],"ts":{}}}"#,
+        id,
+        round,
+        escape_json(&super::ground_truth::current_level_id()),
+        seed,
+        super::seed_counter(),
+        gt_hash,
+        viewport.0.round(),
+        viewport.1.round(),
+        js_sys::Date::now() as u64,
+    ));
+    true
+}
+
+/// Close `round` if it is the open one.
+pub fn round_end(round: u64, reason: RoundEnd) {
+    let Some((id, open)) = OPEN.with(|o| o.get()) else { return };
+    if open != round {
+        return;
+    }
+    OPEN.with(|o| o.set(None));
+    emit(format!(
+        r#"{{"event":"round_end","id":{},"round":{},"level":"{}","reason":"{}","ts":{}}}"#,
+        id,
+        round,
+        escape_json(&super::ground_truth::current_level_id()),
+        reason.as_str(),
+        js_sys::Date::now() as u64,
+    ));
+}
@@ -0,0 +1,126 @@
+use dioxus::prelude::*;
+
+use crate::Route;
+use crate::pool::ElementPool;
+use crate::primitives::{viewport_size, Animation};
+use crate::transform::scene::{rendered_rect, Scene};
+use crate::ui_node::Rect;
+use super::{Difficulty, fresh_rng, random_canvas_bg};
+
+const MIN_ELEMENTS: usize = 3;
+const MAX_ELEMENTS: usize = 7;
+
+fn random_scene(pool: &ElementPool, d: &Difficulty) -> Scene {
+    let mut rng = fresh_rng();
+    let count = d.count(&mut rng, MIN_ELEMENTS..=MAX_ELEMENTS);
+    let mut scene = Scene::generate(&mut rng, pool, count).expect("pool has click targets");
+    if super::relative_refs() {
+        scene.refer_relative();
+    }
+    scene
+}
+
+#[component]
+pub fn PoolScene() -> Element {
+    let pool = use_hook(ElementPool::with_builtins);
+
+    let mut scene = use_signal(|| random_scene(&pool, Difficulty::current()));
+    let mut score = super::use_level_score();
+    let mut bg = use_signal(random_canvas_bg);
+    let mut wrong_idx = use_signal(|| None::<usize>);
+
+    let st = scene.read();
+    let instruction = st.instruction();
+    let elements = st.elements.clone();
+    let target = st.target;
+    let target_rect = rendered_rect(st.target());
+    let description = st.target().describe();
+    drop(st);
+
+    let (vp_w, vp_h) = viewport_size();
+    let tree = scene.read().tree(Rect::new(0.0, 0.0, vp_w, vp_h));
+    let viewport_style = super::viewport_style(&bg(), false);
+    let keyframes = Animation::keyframes_css();
+    let pressed = wrong_idx();
+
+    rsx! {
+        style { "{keyframes}" }
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 20px; font-family: system-ui, sans-serif;",
+
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 16px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h2 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 20px;",
+                    "Pool scene"
+                }
+                span {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "{instruction}"
+                }
+                span {
+                    style: "color: #22c55e; font-size: 14px; font-family: monospace;",
+                    "score: {score}"
+                }
+            }
+
+            div {
+                id: "viewport",
+                style: "{viewport_style}",
+
+                for (i, placed) in elements.into_iter().enumerate() {
+                    {
+                        let is_target = i == target;
+                        let style = placed.wrapper_style();
+                        let anim_style = placed.animation_style();
+                        let html = if pressed == Some(i) { placed.snippet.html_active.clone() } else { placed.snippet.html.clone() };
+                        let label = placed.snippet.label.clone();
+                        let wrong_label = label.clone();
+                        let pool = pool.clone();
+                        rsx! {
+                            div {
+                                class: if is_target { "target" } else { "" },
+                                style: "{style}",
+                                cursor: "pointer",
+                                "data-label": "{label}",
+                                onclick: move |_| {
+                                    if is_target {
+                                        score.complete();
+                                        wrong_idx.set(None);
+                                        bg.set(random_canvas_bg());
+                                        scene.set(random_scene(&pool, Difficulty::current()));
+                                    } else {
+                                        wrong_idx.set(Some(i));
+                                        score.wrong(&wrong_label, "");
+                                        spawn(async move {
+                                            gloo_timers::future::TimeoutFuture::new(300).await;
+                                            wrong_idx.set(None);
+                                        });
+                                    }
+                                },
+                                div {
+                                    style: "{anim_style}",
+                                    div { dangerous_inner_html: "{html}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            super::GroundTruth {
+                description: description,
+                target_x: target_rect.x,
+                target_y: target_rect.y,
+                target_w: target_rect.w,
+                target_h: target_rect.h,
+                tree: Some(tree.clone()),
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! `/selftest` — run the solver against every registered level and report
+//! which ones its own ground truth doesn't solve.
+//!
+//! Each level is mounted `?rounds=N` times (default 3), round `r` seeded with
+//! `base + r` where `base` is `?seed=` (or 1). After the level settles the
+//! page runs `window.__solver.solve()` and records its verification report.
+//! The finished table is also published as `window.__selftest`:
+//!
+//! ```json
+//! {"done":true,"passed":104,"failed":1,"results":[{"level":"level5","round":0,"seed":1,
+//!  "pass":true,"completion":"complete","steps":3,"diverged":null}, …]}
+//! ```
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+
+use crate::ui_node::escape_json;
+use super::{LevelHost, LevelRegistry, LevelRun};
+
+const DEFAULT_ROUNDS: u32 = 3;
+const MAX_ROUNDS: u32 = 20;
+/// Time for a freshly mounted level to render and publish its ground truth.
+const SETTLE_MS: u32 = 800;
+
+/// One solver run.
+#[derive(Debug, Clone, PartialEq)]
+struct RoundResult {
+    slug: &'static str,
+    title: &'static str,
+    round: u32,
+    seed: u64,
+    pass: bool,
+    completion: String,
+    steps: u32,
+    /// "step 2: click Save", or the reason the solver couldn't run.
+    diverged: Option<String>,
+}
+
+impl RoundResult {
+    fn to_json(&self) -> String {
+        let diverged = self.diverged.as_ref()
+            .map_or("null".to_string(), |d| format!(r#""{}""#, escape_json(d)));
+        format!(
+            r#"{{"level":"{}","round":{},"seed":{},"pass":{},"completion":"{}","steps":{},"diverged":{}}}"#,
+            self.slug, self.round, self.seed, self.pass, escape_json(&self.completion), self.steps, diverged,
+        )
+    }
+}
+
+/// Solve the mounted level; `Err` when the solver is missing or threw.
+pub(super) async fn run_solver() -> Result<JsValue, String> {
+    let eval = document::eval(r#"
+        if (!window.__solver) throw new Error('solver not installed');
+        window.__solver.reset();
+        return JSON.stringify(await window.__solver.solve());
+    "#);
+    let json: String = eval.join().await.map_err(|e| format!("{e:?}"))?;
+    js_sys::JSON::parse(&json).map_err(|_| "unreadable solver report".to_string())
+}
+
+/// `report[key]`, undefined when missing.
+pub(super) fn field(report: &JsValue, key: &str) -> JsValue {
+    Reflect::get(report, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED)
+}
+
+fn publish(results: &[RoundResult], done: bool) {
+    let passed = results.iter().filter(|r| r.pass).count();
+    let rows: Vec<String> = results.iter().map(|r| r.to_json()).collect();
+    let json = format!(
+        r#"{{"done":{},"passed":{},"failed":{},"results":[{}]}}"#,
+        done, passed, results.len() - passed, rows.join(","),
+    );
+    let (Some(window), Ok(value)) = (web_sys::window(), js_sys::JSON::parse(&json)) else { return };
+    let _ = Reflect::set(&window, &JsValue::from_str("__selftest"), &value);
+}
+
+/// `rounds` is the `?rounds=` query value.
+#[component]
+pub fn SelfTest(rounds: String) -> Element {
+    let rounds = rounds.trim().parse().unwrap_or(DEFAULT_ROUNDS).clamp(1, MAX_ROUNDS);
+    let score = use_signal(|| 0u32);
+    // (level index, round) on screen; None between rounds so each one remounts
+    let mut current = use_signal(|| None::<(usize, u32)>);
+    let mut results = use_signal(Vec::<RoundResult>::new);
+    let mut finished = use_signal(|| false);
+
+    // The driver moves on by itself; solved rounds need no advance hook
+    use_context_provider(move || LevelRun { score, on_complete: Callback::new(|_| {}) });
+
+    use_future(move || async move {
+        let base = super::current_seed().unwrap_or(1);
+        for (index, entry) in LevelRegistry::all().iter().enumerate() {
+            for round in 0..rounds {
+                let seed = base + round as u64;
+                current.set(None);
+                TimeoutFuture::new(50).await;
+                super::set_seed(Some(seed));
+                current.set(Some((index, round)));
+                TimeoutFuture::new(SETTLE_MS).await;
+
+                let mut result = RoundResult {
+                    slug: entry.slug,
+                    title: entry.title,
+                    round,
+                    seed,
+                    pass: false,
+                    completion: String::new(),
+                    steps: 0,
+                    diverged: None,
+                };
+                match run_solver().await {
+                    Ok(report) => {
+                        result.pass = field(&report, "pass").as_bool().unwrap_or(false);
+                        result.completion = field(&report, "completion").as_string().unwrap_or_default();
+                        result.steps = field(&report, "steps").as_f64().unwrap_or(0.0) as u32;
+                        if let Some(step) = field(&report, "divergedStep").as_f64() {
+                            let action = js_sys::JSON::stringify(&field(&report, "divergedAction"))
+                                .ok()
+                                .and_then(|s| s.as_string())
+                                .unwrap_or_default();
+                            result.diverged = Some(format!("step {step}: {action}"));
+                        }
+                    }
+                    Err(e) => result.diverged = Some(e),
+                }
+                results.write().push(result);
+                publish(&results.peek(), false);
+            }
+        }
+        current.set(None);
+        super::set_seed(None);
+        finished.set(true);
+        let all = results.peek();
+        publish(&all, true);
+        let failed = all.iter().filter(|r| !r.pass).count();
+        web_sys::console::log_1(&format!("selftest: {} rounds, {} failed", all.len(), failed).into());
+    });
+
+    let levels = LevelRegistry::all();
+    let total = levels.len() * rounds as usize;
+    let rows = results();
+    let passed = rows.iter().filter(|r| r.pass).count();
+    let failed = rows.len() - passed;
+    let status = if finished() { "done" } else { "running" };
+
+    let stage = current().map(|(index, _)| levels[index].slug);
+
+    rsx! {
+        if let Some(slug) = stage {
+            LevelHost { slug }
+        }
+        div {
+            id: "__selftest",
+            style: "position: fixed; left: 12px; bottom: 12px; width: 560px; max-height: 45vh; overflow-y: auto; padding: 10px 12px; background: rgba(15,15,26,0.95); border: 1px solid #2a2a4a; border-radius: 8px; color: #e5e7eb; font-size: 12px; font-family: monospace; z-index: 1000;",
+            div {
+                style: "margin-bottom: 6px; color: #9ca3af;",
+                "selftest {status} \u{00B7} {rows.len()}/{total} \u{00B7} "
+                span { style: "color: #22c55e;", "{passed} pass" }
+                " \u{00B7} "
+                span { style: if failed > 0 { "color: #ef4444;" } else { "color: #6b7280;" }, "{failed} fail" }
+            }
+            table {
+                style: "width: 100%; border-collapse: collapse;",
+                for row in rows.iter() {
+                    tr {
+                        td { style: "padding: 1px 6px 1px 0;", "{row.slug}" }
+                        td { style: "padding: 1px 6px; color: #9ca3af;", "{row.title}" }
+                        td { style: "padding: 1px 6px;", "r{row.round} s{row.seed}" }
+                        td {
+                            style: if row.pass { "padding: 1px 6px; color: #22c55e;" } else { "padding: 1px 6px; color: #ef4444;" },
+                            if row.pass { "PASS" } else { "FAIL" }
+                        }
+                        td { style: "padding: 1px 6px; color: #9ca3af;", "{row.completion} \u{00B7} {row.steps} steps" }
+                        td { style: "padding: 1px 0 1px 6px; color: #f59e0b;", {row.diverged.clone().unwrap_or_default()} }
+                    }
+                }
+            }
+        }
+    }
+}
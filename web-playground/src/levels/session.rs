@@ -0,0 +1,57 @@
+//! GameSession — player state that outlives a single route. Provided by
+//! `App` above the Router so every page sees the same instance.
+
+use dioxus::prelude::*;
+
+use super::{LevelEntry, LevelRegistry};
+
+/// localStorage key holding the slug of the next campaign level
+/// ("complete" once the campaign is finished).
+const CAMPAIGN_KEY: &str = "playgroundCampaign";
+const CAMPAIGN_DONE: &str = "complete";
+
+#[derive(Clone, Copy)]
+pub struct GameSession {
+    /// Score carried across campaign levels.
+    pub score: Signal<u32>,
+    /// Index into `campaign_order()`; equal to its length once finished.
+    pub campaign_step: Signal<usize>,
+}
+
+impl GameSession {
+    /// Restore campaign progress from localStorage.
+    pub fn load() -> Self {
+        let order = campaign_order();
+        let step = match storage().and_then(|s| s.get_item(CAMPAIGN_KEY).ok().flatten()) {
+            Some(slug) if slug == CAMPAIGN_DONE => order.len(),
+            Some(slug) => order.iter().position(|l| l.slug == slug).unwrap_or(0),
+            None => 0,
+        };
+        Self {
+            score: Signal::new(0),
+            campaign_step: Signal::new(step),
+        }
+    }
+
+    /// Move the campaign to `step` and persist it.
+    pub fn set_campaign_step(&mut self, step: usize) {
+        let order = campaign_order();
+        let step = step.min(order.len());
+        self.campaign_step.set(step);
+        let value = order.get(step).map(|l| l.slug).unwrap_or(CAMPAIGN_DONE);
+        if let Some(s) = storage() {
+            let _ = s.set_item(CAMPAIGN_KEY, value);
+        }
+    }
+}
+
+/// Every registered level, easiest first; ties keep level select order.
+pub fn campaign_order() -> Vec<&'static LevelEntry> {
+    let mut order: Vec<&'static LevelEntry> = LevelRegistry::all().iter().collect();
+    order.sort_by_key(|l| l.difficulty);
+    order
+}
+
+fn storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
@@ -0,0 +1,71 @@
+//! Small targets — shrink interactive controls to stress precise clicking.
+//!
+//! With `?targets=small` a stylesheet scoped to `#viewport` draws buttons,
+//! links and `.target` elements at `SCALE` with the CSS `scale` property,
+//! which composes with a snippet's own transform and shrinks around the
+//! centre, so the click points the tree resolves still land. A `::before`
+//! box reaches `HIT_SLOP_PX` past each shrunk control, and that margin is
+//! the only hit slop the mode leaves. Native checkboxes and radios are drawn
+//! at `CHECKBOX_PX` instead. Text fields, selects and sliders keep their
+//! size, since a slider's value depends on where its track lies.
+//!
+//! Ground truth targets are measured from the DOM, so their boxes and the
+//! `area` the payload records are the shrunk ones.
+
+use dioxus::prelude::*;
+
+/// Scale applied to shrunk controls.
+pub const SCALE: f32 = 0.5;
+/// Side of a native checkbox or radio, in CSS px.
+pub const CHECKBOX_PX: f32 = 12.0;
+/// How far the hit area reaches past a shrunk control, in CSS px.
+pub const HIT_SLOP_PX: f32 = 2.0;
+
+/// Controls that shrink. Nested ones are left alone so scales don't stack.
+const SHRUNK: &str = ".target, button, a[href], [role=button], [role=checkbox], [role=radio], [role=switch], [role=tab], [role=option], [role=menuitem]";
+/// Form fields that keep their size even when marked `.target`.
+const KEPT: &str = "input, select, textarea";
+
+/// Whether `?targets=small` is on.
+pub fn enabled() -> bool {
+    super::query_param("targets").is_some_and(|s| s.trim().eq_ignore_ascii_case("small"))
+}
+
+/// The mode's stylesheet. The slop box sits inside the scaled control, so
+/// its inset is divided by `SCALE` to come out at `HIT_SLOP_PX` on screen.
+fn stylesheet() -> String {
+    let control = format!("#viewport :is({SHRUNK}):not({KEPT}, :is({SHRUNK}) *)");
+    let inset = HIT_SLOP_PX / SCALE;
+    format!(
+        "{control} {{ scale: {SCALE}; }} \
+         {control}::before {{ content: ''; position: absolute; inset: -{inset}px; }} \
+         #viewport input:is([type=checkbox], [type=radio]) {{ width: {CHECKBOX_PX}px; height: {CHECKBOX_PX}px; }}"
+    )
+}
+
+/// Install the stylesheet when `?targets=small` is set.
+pub fn install() {
+    if enabled() {
+        document::eval(&format!(
+            "if (!document.getElementById('__small-targets')) {{ \
+                const style = document.createElement('style'); \
+                style.id = '__small-targets'; \
+                style.textContent = {:?}; \
+                document.head.appendChild(style); \
+            }}",
+            stylesheet(),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slop_is_measured_on_screen() {
+        let css = stylesheet();
+        assert!(css.contains(&format!("inset: -{}px", HIT_SLOP_PX / SCALE)));
+        assert!(css.contains(":not(input, select, textarea, :is(.target, button"));
+    }
+}
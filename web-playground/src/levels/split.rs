@@ -0,0 +1,159 @@
+//! Split assignment — deterministic train/val/test bucket per sample.
+//!
+//! The bucket is a pure function of (seed, round, level), so samples
+//! generated in separate sessions with the same seed land in the same split
+//! without any post-hoc partitioning, and the rounds of one run spread
+//! across the splits at the configured ratios.
+
+use js_sys::Reflect;
+use web_sys::wasm_bindgen::JsValue;
+
+/// Dataset partition a sample belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Split {
+    Train,
+    Val,
+    Test,
+}
+
+impl Split {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Train => "train",
+            Self::Val => "val",
+            Self::Test => "test",
+        }
+    }
+}
+
+/// Relative weights of each split. Need not sum to 1 — they are normalized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplitRatios {
+    pub train: f64,
+    pub val: f64,
+    pub test: f64,
+}
+
+impl Default for SplitRatios {
+    fn default() -> Self {
+        Self { train: 0.8, val: 0.1, test: 0.1 }
+    }
+}
+
+impl SplitRatios {
+    /// Parse "80,10,10" (or "0.8,0.1,0.1"). Returns None unless exactly three
+    /// non-negative numbers with a positive sum are given.
+    pub fn parse(s: &str) -> Option<Self> {
+        let parts: Vec<f64> = s.split(',')
+            .map(|p| p.trim().parse::<f64>())
+            .collect::<Result<_, _>>()
+            .ok()?;
+        let [train, val, test] = parts[..] else { return None };
+        if [train, val, test].iter().any(|v| !v.is_finite() || *v < 0.0) || train + val + test <= 0.0 {
+            return None;
+        }
+        Some(Self { train, val, test })
+    }
+
+    /// Map a uniform value in [0, 1) to a split.
+    fn pick(&self, u: f64) -> Split {
+        let total = self.train + self.val + self.test;
+        let u = u * total;
+        if u < self.train {
+            Split::Train
+        } else if u < self.train + self.val {
+            Split::Val
+        } else {
+            Split::Test
+        }
+    }
+}
+
+/// Deterministic split for round `round` of `level` in the run seeded `seed`.
+pub fn assign_split(seed: u64, round: u64, level: &str, ratios: &SplitRatios) -> Split {
+    // FNV-1a over seed and round bytes + level id, finished with a splitmix round
+    let mut h: u64 = 0xcbf29ce484222325;
+    for b in seed.to_le_bytes().iter().chain(&round.to_le_bytes()).chain(level.as_bytes()) {
+        h ^= *b as u64;
+        h = h.wrapping_mul(0x100000001b3);
+    }
+    let mut state = h;
+    let u = (super::splitmix64(&mut state) >> 11) as f64 / (1u64 << 53) as f64;
+    ratios.pick(u)
+}
+
+/// Split for the current sample, or None when running unseeded.
+///
+/// Ratios come from `?split=80,10,10`, then `window.__splitRatios`, then the
+/// 80/10/10 default.
+pub fn current_split(level: &str) -> Option<Split> {
+    let seed = super::current_seed()?;
+    Some(assign_split(seed, super::round_index(), level, &current_ratios()))
+}
+
+fn current_ratios() -> SplitRatios {
+    if let Some(r) = super::query_param("split").and_then(|s| SplitRatios::parse(&s)) {
+        return r;
+    }
+    web_sys::window()
+        .and_then(|w| Reflect::get(&w, &JsValue::from_str("__splitRatios")).ok())
+        .and_then(|v| v.as_string().or_else(|| {
+            // Also accept a JS array like [0.8, 0.1, 0.1]
+            js_sys::Array::is_array(&v).then(|| {
+                js_sys::Array::from(&v).iter()
+                    .map(|x| x.as_f64().map(|f| f.to_string()).unwrap_or_default())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+        }))
+        .and_then(|s| SplitRatios::parse(&s))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_is_deterministic() {
+        let r = SplitRatios::default();
+        assert_eq!(assign_split(42, 3, "/level3", &r), assign_split(42, 3, "/level3", &r));
+    }
+
+    #[test]
+    fn split_respects_degenerate_ratios() {
+        let only_val = SplitRatios { train: 0.0, val: 1.0, test: 0.0 };
+        for seed in 0..50 {
+            assert_eq!(assign_split(seed, 0, "/level1", &only_val), Split::Val);
+        }
+    }
+
+    #[test]
+    fn split_roughly_matches_ratios() {
+        let r = SplitRatios::default();
+        let train = (0..2000u64)
+            .filter(|s| assign_split(*s, 0, "/level7", &r) == Split::Train)
+            .count();
+        assert!((1450..1750).contains(&train), "train count {train}");
+    }
+
+    #[test]
+    fn rounds_of_one_run_spread_across_splits() {
+        let r = SplitRatios::default();
+        let splits: Vec<Split> = (0..200u64).map(|round| assign_split(42, round, "/level7", &r)).collect();
+        let train = splits.iter().filter(|s| **s == Split::Train).count();
+        assert!((140..180).contains(&train), "train count {train}");
+        assert!(splits.contains(&Split::Val) && splits.contains(&Split::Test));
+    }
+
+    #[test]
+    fn ratios_parse() {
+        assert_eq!(
+            SplitRatios::parse("80, 10,10"),
+            Some(SplitRatios { train: 80.0, val: 10.0, test: 10.0 }),
+        );
+        assert_eq!(SplitRatios::parse("1,2"), None);
+        assert_eq!(SplitRatios::parse("0,0,0"), None);
+        assert_eq!(SplitRatios::parse("a,b,c"), None);
+    }
+}
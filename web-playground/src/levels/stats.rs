@@ -0,0 +1,266 @@
+//! Session statistics — per-level counters for data balance.
+//!
+//! For every level played this page session:
+//!
+//! - `attempts` — rounds started (GroundTruth's `round_start`)
+//! - `solved` — rounds completed, from the `LevelCompletion` events
+//! - `avg_steps` — ground-truth steps per round, i.e. solution length
+//! - `avg_time_ms` — time to solve, over solved rounds
+//! - `wrong_clicks` — clicks inside `#viewport` that missed every target box
+//!
+//! Shown on `/stats` and returned by `window.__stats()`; the page exports the
+//! same JSON. A level solved nearly every attempt in a couple of seconds is
+//! too easy to carry much signal, one rarely solved is a candidate for a
+//! ground-truth or difficulty fix.
+
+use std::cell::RefCell;
+
+#[cfg(feature = "levels")]
+use dioxus::prelude::*;
+use js_sys::Reflect;
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+use super::LevelCompletion;
+use crate::ui_node::escape_json;
+#[cfg(feature = "levels")]
+use crate::Route;
+
+/// Counters for one level.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LevelStats {
+    pub attempts: u32,
+    pub solved: u32,
+    /// Sum of ground-truth step counts over attempts.
+    pub steps: u32,
+    /// Sum of solve times over solved rounds.
+    pub time_ms: f64,
+    pub wrong_clicks: u32,
+}
+
+impl LevelStats {
+    pub fn success_rate(&self) -> f64 {
+        if self.attempts == 0 { 0.0 } else { self.solved as f64 / self.attempts as f64 }
+    }
+
+    pub fn avg_steps(&self) -> f64 {
+        if self.attempts == 0 { 0.0 } else { self.steps as f64 / self.attempts as f64 }
+    }
+
+    pub fn avg_time_ms(&self) -> f64 {
+        if self.solved == 0 { 0.0 } else { self.time_ms / self.solved as f64 }
+    }
+
+    fn to_json(&self, level: &str) -> String {
+        format!(
+            r#"{{"level":"{}","attempts":{},"solved":{},"success_rate":{:.3},"avg_steps":{:.2},"avg_time_ms":{:.0},"wrong_clicks":{}}}"#,
+            escape_json(level),
+            self.attempts,
+            self.solved,
+            self.success_rate(),
+            self.avg_steps(),
+            self.avg_time_ms(),
+            self.wrong_clicks,
+        )
+    }
+}
+
+thread_local! {
+    /// (level id, counters), in first-played order.
+    static STATS: RefCell<Vec<(String, LevelStats)>> = const { RefCell::new(Vec::new()) };
+    /// Target boxes of the round on screen, window CSS pixels [x, y, w, h].
+    static TARGETS: RefCell<Vec<(i32, i32, i32, i32)>> = const { RefCell::new(Vec::new()) };
+}
+
+fn with_level(level: &str, f: impl FnOnce(&mut LevelStats)) {
+    STATS.with(|s| {
+        let mut s = s.borrow_mut();
+        match s.iter_mut().find(|(id, _)| id == level) {
+            Some((_, stats)) => f(stats),
+            None => {
+                let mut stats = LevelStats::default();
+                f(&mut stats);
+                s.push((level.to_string(), stats));
+            }
+        }
+    });
+}
+
+/// A round of `level` with `steps` ground-truth steps started.
+pub(super) fn round_started(level: &str, steps: usize) {
+    with_level(level, |s| {
+        s.attempts += 1;
+        s.steps += steps as u32;
+    });
+}
+
+/// A round was solved.
+pub(super) fn round_solved(event: &LevelCompletion) {
+    with_level(&event.level, |s| {
+        s.solved += 1;
+        s.time_ms += event.elapsed_ms;
+    });
+}
+
+/// Replace the target boxes clicks are checked against.
+pub(super) fn set_targets(boxes: Vec<(i32, i32, i32, i32)>) {
+    TARGETS.with(|t| *t.borrow_mut() = boxes);
+}
+
+/// Every level's counters, in first-played order.
+pub fn snapshot() -> Vec<(String, LevelStats)> {
+    STATS.with(|s| s.borrow().clone())
+}
+
+pub fn clear() {
+    STATS.with(|s| s.borrow_mut().clear());
+}
+
+/// JSON array of every level's counters.
+pub fn to_json() -> String {
+    let rows: Vec<String> = snapshot().iter().map(|(level, s)| s.to_json(level)).collect();
+    format!("[{}]", rows.join(","))
+}
+
+/// Count a click at window (`x`, `y`) on `target` as wrong if it landed in
+/// the level and outside every target box.
+fn on_click(x: f64, y: f64, target: Option<web_sys::Node>) {
+    let Some(viewport) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.get_element_by_id("viewport"))
+    else {
+        return;
+    };
+    if !viewport.contains(target.as_ref()) {
+        return;
+    }
+    let hit = TARGETS.with(|t| {
+        let t = t.borrow();
+        t.is_empty() || t.iter().any(|&(bx, by, bw, bh)| {
+            x >= bx as f64 && x <= (bx + bw) as f64 && y >= by as f64 && y <= (by + bh) as f64
+        })
+    });
+    if !hit {
+        with_level(&super::ground_truth::current_level_id(), |s| s.wrong_clicks += 1);
+    }
+}
+
+/// Install the click counter and `window.__stats()` once per page.
+pub fn install() {
+    let Some(window) = web_sys::window() else { return };
+    if Reflect::has(&window, &"__stats".into()).unwrap_or(false) {
+        return;
+    }
+    let click = Closure::<dyn Fn(web_sys::MouseEvent)>::new(|e: web_sys::MouseEvent| {
+        let target = e.target().and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+        on_click(e.client_x() as f64, e.client_y() as f64, target);
+    });
+    let _ = window.add_event_listener_with_callback_and_bool("click", click.as_ref().unchecked_ref(), true);
+    let stats_fn = Closure::<dyn Fn() -> JsValue>::new(|| js_sys::JSON::parse(&to_json()).unwrap_or(JsValue::NULL));
+    let _ = Reflect::set(&window, &"__stats".into(), stats_fn.as_ref().unchecked_ref());
+    // Both live for the whole page
+    click.forget();
+    stats_fn.forget();
+}
+
+/// `/stats` — the session's counters as a table, refreshed every second.
+#[cfg(feature = "levels")]
+#[component]
+pub fn SessionStats() -> Element {
+    let mut tick = use_signal(|| 0u32);
+    use_future(move || async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(1000).await;
+            *tick.write() += 1;
+        }
+    });
+    tick();
+    let rows = snapshot();
+
+    rsx! {
+        div {
+            style: "min-height: 100vh; background: #0f0f1a; display: flex; flex-direction: column; align-items: center; padding: 40px 20px; font-family: system-ui, sans-serif;",
+            div {
+                style: "display: flex; gap: 16px; align-items: center; margin-bottom: 24px;",
+                Link {
+                    to: Route::LevelSelect {},
+                    style: "color: #6b7280; text-decoration: none; font-size: 14px;",
+                    "\u{2190} Levels"
+                }
+                h1 {
+                    style: "color: #e5e7eb; margin: 0; font-size: 28px; font-weight: 700;",
+                    "Session stats"
+                }
+                button {
+                    style: "padding: 6px 12px; background: #6366f1; color: white; border: none; border-radius: 6px; font-size: 13px; cursor: pointer;",
+                    onclick: move |_| {
+                        document::eval(&format!(
+                            "const url = URL.createObjectURL(new Blob([JSON.stringify({}, null, 2)], {{ type: 'application/json' }})); const a = document.createElement('a'); a.href = url; a.download = 'playground-stats.json'; a.click(); setTimeout(() => URL.revokeObjectURL(url), 1000);",
+                            to_json(),
+                        ));
+                    },
+                    "Export JSON"
+                }
+                button {
+                    style: "padding: 6px 12px; background: #1a1a2e; color: #9ca3af; border: 1px solid #2a2a4a; border-radius: 6px; font-size: 13px; cursor: pointer;",
+                    onclick: move |_| {
+                        clear();
+                        *tick.write() += 1;
+                    },
+                    "Clear"
+                }
+            }
+            if rows.is_empty() {
+                p {
+                    style: "color: #6b7280; font-size: 14px;",
+                    "No rounds played yet this session."
+                }
+            } else {
+                table {
+                    style: "border-collapse: collapse; color: #e5e7eb; font-size: 13px; font-family: monospace;",
+                    tr {
+                        style: "color: #6b7280; text-align: right;",
+                        th { style: "padding: 4px 12px; text-align: left;", "level" }
+                        th { style: "padding: 4px 12px;", "attempts" }
+                        th { style: "padding: 4px 12px;", "solved" }
+                        th { style: "padding: 4px 12px;", "success" }
+                        th { style: "padding: 4px 12px;", "avg steps" }
+                        th { style: "padding: 4px 12px;", "avg time" }
+                        th { style: "padding: 4px 12px;", "wrong clicks" }
+                    }
+                    for (level, s) in rows.iter() {
+                        {
+                            let title = super::LevelRegistry::by_slug(level.trim_start_matches('/')).map_or("", |e| e.title);
+                            let rate = s.success_rate();
+                            // Near-certain and rare solves both skew a dataset
+                            let rate_color = if s.attempts < 3 { "#9ca3af" } else if rate >= 0.95 { "#f59e0b" } else if rate < 0.5 { "#ef4444" } else { "#22c55e" };
+                            let pct = format!("{:.0}%", rate * 100.0);
+                            let steps = format!("{:.1}", s.avg_steps());
+                            let secs = format!("{:.1}s", s.avg_time_ms() / 1000.0);
+                            rsx! {
+                                tr {
+                                    style: "text-align: right; border-top: 1px solid #2a2a4a;",
+                                    td {
+                                        style: "padding: 4px 12px; text-align: left;",
+                                        "{level} "
+                                        span { style: "color: #6b7280;", "{title}" }
+                                    }
+                                    td { style: "padding: 4px 12px;", "{s.attempts}" }
+                                    td { style: "padding: 4px 12px;", "{s.solved}" }
+                                    td { style: "padding: 4px 12px; color: {rate_color};", "{pct}" }
+                                    td { style: "padding: 4px 12px;", "{steps}" }
+                                    td { style: "padding: 4px 12px;", "{secs}" }
+                                    td { style: "padding: 4px 12px;", "{s.wrong_clicks}" }
+                                }
+                            }
+                        }
+                    }
+                }
+                p {
+                    style: "color: #6b7280; font-size: 12px; margin-top: 16px;",
+                    "success rate: amber \u{2265} 95% (too easy?), red < 50% (too hard?), grey under 3 attempts"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Themes — per-round palette, corner radius, shadow and font stack.
+//!
+//! `?theme=random` draws a preset for every round; `?theme=<name>` pins one.
+//! The active theme lives in a `ThemeSlot` context provided by the app root,
+//! and reaches the page as CSS custom properties on `#viewport`
+//! (`--theme-surface`, `--theme-text`, ...). Levels opt in by styling with
+//! `var(--theme-surface, white)` and friends, so without `?theme=` every
+//! level falls back to its own colours and looks exactly as before.
+
+use dioxus::prelude::*;
+use rand::Rng;
+
+/// A named set of styling tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub name: &'static str,
+    /// Card and panel background.
+    pub surface: &'static str,
+    /// Primary text.
+    pub text: &'static str,
+    /// Secondary text (labels, hints).
+    pub muted: &'static str,
+    pub border: &'static str,
+    /// Highlight colour for interactive accents.
+    pub accent: &'static str,
+    /// Card corner radius in px.
+    pub radius: f32,
+    pub shadow: &'static str,
+    pub font: &'static str,
+}
+
+pub const PRESETS: &[Theme] = &[
+    Theme {
+        name: "light",
+        surface: "#ffffff",
+        text: "#111827",
+        muted: "#6b7280",
+        border: "#d1d5db",
+        accent: "#4f46e5",
+        radius: 12.0,
+        shadow: "0 4px 24px rgba(0,0,0,0.3)",
+        font: "system-ui, sans-serif",
+    },
+    Theme {
+        name: "dark",
+        surface: "#1f2937",
+        text: "#f9fafb",
+        muted: "#9ca3af",
+        border: "#4b5563",
+        accent: "#818cf8",
+        radius: 10.0,
+        shadow: "0 4px 24px rgba(0,0,0,0.6)",
+        font: "system-ui, sans-serif",
+    },
+    Theme {
+        name: "material",
+        surface: "#fafafa",
+        text: "#212121",
+        muted: "#757575",
+        border: "#e0e0e0",
+        accent: "#6200ee",
+        radius: 4.0,
+        shadow: "0 2px 4px rgba(0,0,0,0.2), 0 1px 10px rgba(0,0,0,0.12)",
+        font: "Roboto, 'Helvetica Neue', Arial, sans-serif",
+    },
+    Theme {
+        name: "brutalist",
+        surface: "#fffef0",
+        text: "#000000",
+        muted: "#333333",
+        border: "#000000",
+        accent: "#ff3e00",
+        radius: 0.0,
+        shadow: "6px 6px 0 #000000",
+        font: "'Courier New', Courier, monospace",
+    },
+    Theme {
+        name: "pastel",
+        surface: "#fdf2f8",
+        text: "#4c1d95",
+        muted: "#8b5cf6",
+        border: "#f9a8d4",
+        accent: "#ec4899",
+        radius: 18.0,
+        shadow: "0 6px 20px rgba(236,72,153,0.25)",
+        font: "'Nunito', 'Segoe UI', sans-serif",
+    },
+];
+
+impl Theme {
+    pub fn by_name(name: &str) -> Option<&'static Theme> {
+        PRESETS.iter().find(|t| t.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Custom properties for the viewport, e.g. `--theme-surface: #1f2937;`.
+    pub fn css_vars(&self) -> String {
+        format!(
+            "--theme-surface: {}; --theme-text: {}; --theme-muted: {}; --theme-border: {}; \
+             --theme-accent: {}; --theme-radius: {}px; --theme-shadow: {}; --theme-font: {};",
+            self.surface, self.text, self.muted, self.border,
+            self.accent, self.radius, self.shadow, self.font,
+        )
+    }
+}
+
+/// What `?theme=` asks for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ThemeMode {
+    Off,
+    Random,
+    Fixed(&'static Theme),
+}
+
+fn theme_mode() -> ThemeMode {
+    match super::query_param("theme") {
+        None => ThemeMode::Off,
+        Some(s) if s.trim().eq_ignore_ascii_case("random") => ThemeMode::Random,
+        Some(s) => Theme::by_name(&s).map_or(ThemeMode::Off, ThemeMode::Fixed),
+    }
+}
+
+/// Draw the theme for the next round. Only touches the seeded RNG when
+/// sampling, so runs without `?theme=random` replay unchanged.
+fn draw() -> Option<&'static Theme> {
+    match theme_mode() {
+        ThemeMode::Off => None,
+        ThemeMode::Fixed(t) => Some(t),
+        ThemeMode::Random => {
+            let mut rng = super::fresh_rng();
+            Some(&PRESETS[rng.random_range(0..PRESETS.len())])
+        }
+    }
+}
+
+/// Context holding the active theme (`None` when theming is off).
+#[derive(Clone, Copy)]
+pub struct ThemeSlot(Signal<Option<&'static Theme>>);
+
+impl ThemeSlot {
+    /// Provide the slot at the app root; draws the first round's theme.
+    pub fn provide() -> Self {
+        use_context_provider(|| ThemeSlot(Signal::new(draw())))
+    }
+
+    /// Redraw for the next round. No-op unless `?theme=random`.
+    pub fn next_round(&mut self) {
+        if theme_mode() == ThemeMode::Random {
+            self.0.set(draw());
+        }
+    }
+}
+
+/// The active theme, if any. Subscribes the calling component, so levels
+/// re-render when a new round draws a different theme.
+pub fn current_theme() -> Option<&'static Theme> {
+    try_consume_context::<ThemeSlot>().and_then(|slot| (slot.0)())
+}
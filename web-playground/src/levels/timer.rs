@@ -0,0 +1,95 @@
+//! Time-pressure rounds — a countdown opted-in levels render in the viewport.
+//!
+//! With `?timer=1` opted-in levels draw a limit of 5–20 s for every round;
+//! `?timer=<seconds>` fixes it instead (clamped to the same range). The
+//! `RoundTimer` counts down from each new round. When it runs out the round
+//! ends as a failure: the round event closes with `"timeout"`, the dataset
+//! records it with outcome `"timeout"`, and the level re-randomizes through
+//! `on_expire`. Every dataset record of a timed round carries its
+//! `"time_limit_ms"`.
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use rand::Rng;
+
+const MIN_SECS: u32 = 5;
+const MAX_SECS: u32 = 20;
+/// Remaining time below which the countdown turns red.
+const WARN_MS: f64 = 3000.0;
+
+/// `?timer=` as (min, max) seconds, or `None` when off.
+fn limit_range() -> Option<(u32, u32)> {
+    let value: u32 = super::query_param("timer")?.trim().parse().ok()?;
+    match value {
+        0 => None,
+        1 => Some((MIN_SECS, MAX_SECS)),
+        s => {
+            let s = s.clamp(MIN_SECS, MAX_SECS);
+            Some((s, s))
+        }
+    }
+}
+
+/// Roll this round's time limit. Draws from `rng` only when the mode is on,
+/// so seeded runs without `?timer=` replay unchanged.
+pub fn roll_time_limit(rng: &mut impl Rng) -> Option<u32> {
+    let (lo, hi) = limit_range()?;
+    Some(rng.random_range(lo..=hi) * 1000)
+}
+
+/// Record the timeout everywhere a solved round would be recorded.
+fn expire() {
+    super::rounds::round_end(super::round_index(), super::rounds::RoundEnd::Timeout);
+    #[cfg(feature = "exporter")]
+    crate::dataset::round_timeout();
+}
+
+/// Countdown for the current round. `round` is the level's solved-round
+/// count (`round_index()`); a new value, or an expiry, restarts the clock.
+/// Renders nothing when `limit_ms` is `None`.
+#[component]
+pub fn RoundTimer(round: u64, limit_ms: Option<u32>, on_expire: EventHandler<()>) -> Element {
+    let mut expiries = use_signal(|| 0u32);
+    // (round, expiries) the clock was last started for
+    let mut started_for = use_signal(|| None::<(u64, u32)>);
+    // (start, limit) of the running countdown
+    let mut running = use_signal(|| None::<(f64, u32)>);
+    let mut now = use_signal(js_sys::Date::now);
+
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(100).await;
+            let t = js_sys::Date::now();
+            now.set(t);
+            let Some((start, limit)) = *running.peek() else { continue };
+            if t - start >= limit as f64 {
+                running.set(None);
+                expire();
+                *expiries.write() += 1;
+                on_expire.call(());
+            }
+        }
+    });
+
+    let Some(limit) = limit_ms else { return rsx! {} };
+    let key = (round, expiries());
+    if *started_for.peek() != Some(key) {
+        started_for.set(Some(key));
+        let t = js_sys::Date::now();
+        running.set(Some((t, limit)));
+        now.set(t);
+        #[cfg(feature = "exporter")]
+        crate::dataset::set_time_limit(limit);
+    }
+
+    let remaining = running().map_or(0.0, |(start, limit)| (limit as f64 - (now() - start)).max(0.0));
+    let color = if remaining < WARN_MS { "#ef4444" } else { "#e5e7eb" };
+    let secs = format!("{:.1}", remaining / 1000.0);
+
+    rsx! {
+        div {
+            style: "position: absolute; left: 16px; bottom: 16px; padding: 3px 10px; background: rgba(17,24,39,0.85); border-radius: 999px; color: {color}; font-size: 13px; font-family: monospace; pointer-events: none; z-index: 40;",
+            "\u{23F1} {secs}s"
+        }
+    }
+}
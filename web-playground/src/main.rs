@@ -1,100 +1,120 @@
+#[cfg(feature = "canvas-playground")]
 mod canvas;
+#[cfg(feature = "control")]
+mod control;
+#[cfg(feature = "exporter")]
+mod dataset;
+#[cfg(feature = "env")]
+mod env;
 mod landing;
+#[cfg(feature = "levels")]
 mod level_select;
 mod levels;
 mod pool;
 mod primitives;
+#[cfg(feature = "exporter")]
+mod recorder;
+#[cfg(feature = "solver")]
+mod solver;
+#[cfg(feature = "test-routes")]
 mod test_routes;
 mod transform;
 pub mod ui_node;
+#[cfg(feature = "ws")]
+mod ws;
 
 use dioxus::prelude::*;
-use canvas::Playground;
+#[cfg(feature = "canvas-playground")]
+use canvas::{Playground, PoolWorkbench};
 use landing::Landing;
+#[cfg(feature = "levels")]
 use level_select::LevelSelect;
-use levels::{Level1, Level2, Level3, Level4, Level5, Level6, Level7, Level8, Level9, Level10, Level11, Level12, Level13, Level14, Level15, Level16, Level17, Level18, Level19, Level20, Level21, Level22, Level23, Level24, Level25, Level26, Level27, LevelScroll};
+#[cfg(feature = "levels")]
+use levels::{Campaign, LevelPage, RandomLevel, SessionStats};
+#[cfg(all(feature = "levels", feature = "solver"))]
+use levels::SelfTest;
+#[cfg(all(feature = "levels", feature = "exporter"))]
+use levels::Generate;
+#[cfg(feature = "test-routes")]
 use test_routes::{TestButton, TestTextInput, TestToggle, TestDropdown, TestDrag, TestReorder};
 
 #[derive(Routable, Clone, PartialEq)]
 enum Route {
     #[route("/")]
     Landing {},
+    #[cfg(feature = "levels")]
     #[route("/levels")]
     LevelSelect {},
-    #[route("/level1")]
-    Level1 {},
-    #[route("/level2")]
-    Level2 {},
-    #[route("/level3")]
-    Level3 {},
-    #[route("/level4")]
-    Level4 {},
-    #[route("/level5")]
-    Level5 {},
-    #[route("/level6")]
-    Level6 {},
-    #[route("/level7")]
-    Level7 {},
-    #[route("/level8")]
-    Level8 {},
-    #[route("/level9")]
-    Level9 {},
-    #[route("/level10")]
-    Level10 {},
-    #[route("/level11")]
-    Level11 {},
-    #[route("/level12")]
-    Level12 {},
-    #[route("/level13")]
-    Level13 {},
-    #[route("/level14")]
-    Level14 {},
-    #[route("/level15")]
-    Level15 {},
-    #[route("/level16")]
-    Level16 {},
-    #[route("/level17")]
-    Level17 {},
-    #[route("/level18")]
-    Level18 {},
-    #[route("/level19")]
-    Level19 {},
-    #[route("/level20")]
-    Level20 {},
-    #[route("/level21")]
-    Level21 {},
-    #[route("/level22")]
-    Level22 {},
-    #[route("/level23")]
-    Level23 {},
-    #[route("/level24")]
-    Level24 {},
-    #[route("/level25")]
-    Level25 {},
-    #[route("/level26")]
-    Level26 {},
-    #[route("/level27")]
-    Level27 {},
-    #[route("/level-scroll")]
-    LevelScroll {},
+    // Endless mode; `?weight=difficulty` favours harder levels
+    #[cfg(feature = "levels")]
+    #[route("/random?:weight")]
+    RandomLevel { weight: String },
+    #[cfg(feature = "levels")]
+    #[route("/campaign")]
+    Campaign {},
+    // Per-level attempts, success rate, steps, time and wrong clicks this session
+    #[cfg(feature = "levels")]
+    #[route("/stats")]
+    SessionStats {},
+    // Solver run over every level; `?rounds=N` seeded rounds each
+    #[cfg(all(feature = "levels", feature = "solver"))]
+    #[route("/selftest?:rounds")]
+    SelfTest { rounds: String },
+    // Headless batch generation: `?levels=all&rounds=50&seed=42[&agent=external]`
+    #[cfg(all(feature = "levels", feature = "exporter"))]
+    #[route("/generate?:levels&:rounds&:seed&:agent")]
+    Generate { levels: String, rounds: String, seed: String, agent: String },
+    #[cfg(feature = "canvas-playground")]
     #[route("/playground")]
     Playground {},
+    // Snippet pool browser and draft-snippet preview
+    #[cfg(feature = "canvas-playground")]
+    #[route("/pool")]
+    PoolWorkbench {},
+    #[cfg(feature = "test-routes")]
     #[route("/test/button")]
     TestButton {},
+    #[cfg(feature = "test-routes")]
     #[route("/test/text-input")]
     TestTextInput {},
+    #[cfg(feature = "test-routes")]
     #[route("/test/toggle")]
     TestToggle {},
+    #[cfg(feature = "test-routes")]
     #[route("/test/dropdown")]
     TestDropdown {},
+    #[cfg(feature = "test-routes")]
     #[route("/test/drag")]
     TestDrag {},
+    #[cfg(feature = "test-routes")]
     #[route("/test/reorder")]
     TestReorder {},
+    // Every level page, resolved through `levels::LevelRegistry`
+    #[cfg(feature = "levels")]
+    #[route("/:slug")]
+    LevelPage { slug: String },
 }
 
 #[allow(non_snake_case)]
 fn App() -> Element {
+    // Cross-route player state (campaign progress, carried score)
+    #[cfg(feature = "levels")]
+    use_context_provider(levels::GameSession::load);
+    // Per-round styling theme (`?theme=`)
+    levels::ThemeSlot::provide();
+    // Success signal for drivers (`window.__onLevelComplete`)
+    levels::CompletionBus::provide();
+    // `?pool=` snippet file; routes wait for it so every level samples from it
+    let mut pool_ready = use_signal(|| pool::custom::requested_url().is_none());
+    use_future(move || async move {
+        let Some(url) = pool::custom::requested_url() else { return };
+        match pool::custom::load(&url).await {
+            Ok(count) => web_sys::console::log_1(&format!("pool: loaded {count} snippets from {url}").into()),
+            Err(e) => web_sys::console::warn_1(&format!("pool: {url} rejected, using built-ins only: {e}").into()),
+        }
+        pool_ready.set(true);
+    });
+
     // Install global event listeners once (capture phase to see everything)
     use_effect(|| {
         document::eval(r#"
@@ -126,197 +146,12 @@ fn App() -> Element {
                 window.__playgroundCleanupListeners = listeners;
             }
 
-            // ── Solver: step-through automation for VLM training data ──
-            if (!window.__solver) {
-                window.__solver = {
-                    _stepIndex: 0,
-                    _lastStepsJson: '',
-
-                    getGroundTruth() {
-                        const panel = document.getElementById('ground-truth');
-                        if (!panel) { console.warn('solver: ground-truth panel not found'); return { targets: [], steps: [] }; }
-                        let targets = [], steps = [];
-                        for (const div of panel.querySelectorAll(':scope > div')) {
-                            const t = div.textContent;
-                            if (t.startsWith('targets: ')) {
-                                try { targets = JSON.parse(t.slice(9)); } catch (e) { console.warn('solver: failed to parse targets', e); }
-                            } else if (t.startsWith('steps: ')) {
-                                try { steps = JSON.parse(t.slice(7)); } catch (e) { console.warn('solver: failed to parse steps', e); }
-                            }
-                        }
-                        return { targets, steps };
-                    },
-
-                    _bbox(label, targets) {
-                        const t = targets.find(t => t.label === label);
-                        if (!t) return null;
-                        const [x, y, w, h] = t.bbox;
-                        return { x, y, w, h, cx: x + w / 2, cy: y + h / 2 };
-                    },
-
-                    _dispatchAt(x, y, type, opts) {
-                        const el = document.elementFromPoint(x, y);
-                        if (!el) { console.warn('solver: nothing at', x, y); return null; }
-                        const ev = new MouseEvent(type, {
-                            clientX: x, clientY: y, screenX: x, screenY: y,
-                            bubbles: true, cancelable: true, view: window, ...opts
-                        });
-                        el.dispatchEvent(ev);
-                        return el;
-                    },
-
-                    async _doClick(label, targets) {
-                        const b = this._bbox(label, targets);
-                        if (!b) { console.warn('solver: target not found:', label, 'available:', targets.map(t=>t.label)); return; }
-                        const cx = b.cx, cy = b.cy;
-                        console.log('solver: click "' + label + '" at (' + cx + ', ' + cy + ') bbox [' + b.x + ',' + b.y + ',' + b.w + ',' + b.h + ']');
-                        // Full mouse event sequence at ground truth coordinates
-                        const el = this._dispatchAt(cx, cy, 'pointerdown');
-                        this._dispatchAt(cx, cy, 'mousedown');
-                        this._dispatchAt(cx, cy, 'pointerup');
-                        this._dispatchAt(cx, cy, 'mouseup');
-                        this._dispatchAt(cx, cy, 'click');
-                        if (el) console.log('solver: hit', el.tagName, el.className, el.getAttribute('data-label') || el.textContent?.slice(0,30));
-                    },
-
-                    async _doType(label, value, targets) {
-                        const b = this._bbox(label, targets);
-                        if (!b) { console.warn('solver: target not found:', label); return; }
-                        const el = document.elementFromPoint(b.cx, b.cy);
-                        if (!el) return;
-                        el.focus();
-                        const setter = Object.getOwnPropertyDescriptor(HTMLInputElement.prototype, 'value')?.set
-                                     || Object.getOwnPropertyDescriptor(HTMLTextAreaElement.prototype, 'value')?.set;
-                        if (setter) setter.call(el, value);
-                        else el.value = value;
-                        el.dispatchEvent(new Event('input', { bubbles: true }));
-                    },
-
-                    async _doDrag(fromLabel, toLabel, targets) {
-                        const from = this._bbox(fromLabel, targets);
-                        const to = this._bbox(toLabel, targets);
-                        if (!from || !to) { console.warn('solver: drag targets not found:', fromLabel, toLabel); return; }
-                        // Dispatch both pointer and mouse events (matching _doClick pattern)
-                        // — Dioxus may listen for pointer events rather than mouse events.
-                        this._dispatchAt(from.cx, from.cy, 'pointerdown');
-                        const el = this._dispatchAt(from.cx, from.cy, 'mousedown');
-                        if (!el) return;
-                        await new Promise(r => setTimeout(r, 30));
-                        const steps = 10;
-                        for (let i = 1; i <= steps; i++) {
-                            const t = i / steps;
-                            const mx = from.cx + (to.cx - from.cx) * t;
-                            const my = from.cy + (to.cy - from.cy) * t;
-                            const moveEl = document.elementFromPoint(mx, my) || el;
-                            moveEl.dispatchEvent(new PointerEvent('pointermove', {
-                                clientX: mx, clientY: my, bubbles: true, cancelable: true, view: window
-                            }));
-                            moveEl.dispatchEvent(new MouseEvent('mousemove', {
-                                clientX: mx, clientY: my, bubbles: true, cancelable: true, view: window
-                            }));
-                            await new Promise(r => setTimeout(r, 15));
-                        }
-                        const endEl = document.elementFromPoint(to.cx, to.cy) || el;
-                        endEl.dispatchEvent(new PointerEvent('pointerup', {
-                            clientX: to.cx, clientY: to.cy, bubbles: true, cancelable: true, view: window
-                        }));
-                        endEl.dispatchEvent(new MouseEvent('mouseup', {
-                            clientX: to.cx, clientY: to.cy, bubbles: true, cancelable: true, view: window
-                        }));
-                    },
-
-                    async _doRightClick(label, targets) {
-                        const b = this._bbox(label, targets);
-                        if (!b) { console.warn('solver: target not found:', label); return; }
-                        this._dispatchAt(b.cx, b.cy, 'contextmenu');
-                    },
-
-                    async _doScroll(label, targets) {
-                        const b = this._bbox(label, targets);
-                        if (!b) { console.warn('solver: scroll target not found:', label); return; }
-                        const vp = document.getElementById('viewport');
-                        if (!vp) return;
-                        const rect = vp.getBoundingClientRect();
-                        // Scroll so the target center is visible in the viewport
-                        const scrollX = b.cx - rect.left - rect.width / 2;
-                        const scrollY = b.cy - rect.top - rect.height / 2;
-                        vp.scrollBy({ left: scrollX, top: scrollY, behavior: 'smooth' });
-                        await new Promise(r => setTimeout(r, 400));
-                    },
-
-                    async step() {
-                        const gt = this.getGroundTruth();
-                        const stepsJson = JSON.stringify(gt.steps);
-                        if (stepsJson !== this._lastStepsJson) {
-                            this._stepIndex = 0;
-                            this._lastStepsJson = stepsJson;
-                        }
-                        if (!gt.steps.length || this._stepIndex >= gt.steps.length) {
-                            console.log('solver: no more steps');
-                            return null;
-                        }
-                        const action = gt.steps[this._stepIndex];
-                        switch (action.action) {
-                            case 'click':       await this._doClick(action.target, gt.targets); break;
-                            case 'type':        await this._doType(action.target, action.value, gt.targets); break;
-                            case 'drag':        await this._doDrag(action.from, action.to, gt.targets); break;
-                            case 'right_click': await this._doRightClick(action.target, gt.targets); break;
-                            case 'scroll':      await this._doScroll(action.target, gt.targets); break;
-                        }
-                        this._stepIndex++;
-                        await new Promise(r => setTimeout(r, 300));
-                        return { step: this._stepIndex, ...action };
-                    },
-
-                    async solve() {
-                        this._stepIndex = 0;
-                        while (this._stepIndex < 50) {
-                            const gt = this.getGroundTruth();
-                            if (!gt.steps.length || this._stepIndex >= gt.steps.length) break;
-                            await this.step();
-                        }
-                    },
-
-                    reset() { this._stepIndex = 0; }
-                };
-                console.log('solver: ready — use __solver.step() / __solver.solve() / __solver.reset()');
-
-                // Inject step toolbar
-                const bar = document.createElement('div');
-                bar.id = '__solver-bar';
-                bar.style.cssText = 'position:fixed;top:8px;right:8px;z-index:99999;display:flex;gap:6px;font-family:system-ui,sans-serif;';
-                const mkBtn = (label, fn) => {
-                    const b = document.createElement('button');
-                    b.textContent = label;
-                    b.style.cssText = 'padding:6px 14px;border:none;border-radius:6px;font-size:13px;font-weight:600;cursor:pointer;color:white;background:#4f46e5;opacity:0.9;transition:opacity 0.1s;';
-                    b.onmouseenter = () => b.style.opacity = '1';
-                    b.onmouseleave = () => b.style.opacity = '0.9';
-                    b.onclick = fn;
-                    return b;
-                };
-                bar.appendChild(mkBtn('Step', async () => {
-                    try {
-                        console.log('solver: Step button clicked');
-                        const gt = window.__solver.getGroundTruth();
-                        console.log('solver: ground truth =', JSON.stringify(gt).slice(0, 200));
-                        const r = await window.__solver.step();
-                        console.log('solver: step result =', r);
-                    } catch (e) { console.error('solver: step error', e); }
-                }));
-                bar.appendChild(mkBtn('Solve', async () => {
-                    try {
-                        console.log('solver: Solve button clicked');
-                        await window.__solver.solve();
-                        console.log('solver: solve done');
-                    } catch (e) { console.error('solver: solve error', e); }
-                }));
-                const resetBtn = mkBtn('Reset', () => {
-                    console.log('solver: Reset button clicked');
-                    window.__solver.reset();
-                });
-                resetBtn.style.background = '#6b7280';
-                bar.appendChild(resetBtn);
-                document.body.appendChild(bar);
+            // ?seed=12345 on any route seeds the run when no harness set one
+            if (window.__playgroundSeed == null) {
+                const urlSeed = new URLSearchParams(location.search).get('seed');
+                if (urlSeed != null && /^\d+$/.test(urlSeed.trim())) {
+                    window.__playgroundSeed = Number(urlSeed.trim());
+                }
             }
 
             // Auto-fit: set actual CSS width/height on #viewport.
@@ -388,19 +223,32 @@ fn App() -> Element {
                     }
                     if (window.__vpScale == null) {
                         const gen = window.__vpScaleGen || 0;
+                        let seedHex = null;
                         if (window.__playgroundSeed != null) {
                             let s = ((window.__playgroundSeed + gen * 0x517cc1b7) ^ 0x9e3779b9) >>> 0;
                             s = Math.imul(s ^ (s >>> 16), 0x45d9f3b) >>> 0;
                             s = Math.imul(s ^ (s >>> 16), 0x45d9f3b) >>> 0;
                             s = (s ^ (s >>> 16)) >>> 0;
+                            seedHex = s.toString(16).padStart(8, '0');
                             window.__vpScale = 0.25 + (s / 0xFFFFFFFF) * 0.75;
                         } else {
                             window.__vpScale = 0.25 + Math.random() * 0.75;
                         }
+                        // Same buffer as SeedAudit in levels/mod.rs
+                        if (!Array.isArray(window.__seedAudit)) window.__seedAudit = [];
+                        window.__seedAudit.push({ tag: 'viewport-scale', counter: gen, seed: seedHex, scale: window.__vpScale, ts: Date.now() });
+                        if (window.__seedAudit.length > 2000) window.__seedAudit.shift();
                     }
                     availW = Math.floor(Math.max(availW * window.__vpScale, 200));
                     availH = Math.floor(Math.max(availH * window.__vpScale, 150));
 
+                    // `?device=` profiles pin the viewport to the device's CSS size
+                    const device = window.__deviceProfile;
+                    if (device) {
+                        availW = device.width;
+                        availH = device.height;
+                    }
+
                     // Set actual dimensions — no transform
                     vp.style.width = availW + 'px';
                     vp.style.height = availH + 'px';
@@ -492,7 +340,7 @@ fn App() -> Element {
                 window.__debugModeInstalled = true;
                 const key = 'playgroundDebug';
                 const style = document.createElement('style');
-                style.textContent = '#ground-truth{display:none;} #__solver-bar{display:none;} body[data-debug="true"] #ground-truth{display:block;} body[data-debug="true"] #__solver-bar{display:flex;}';
+                style.textContent = '#ground-truth{display:none;} #__solver-bar{display:none;} body[data-debug="true"] #ground-truth{display:block;} body[data-debug="true"] #__solver-bar{display:flex;} #__gt-overlay{display:none;} body[data-debug="true"]:not([data-gt-overlay="off"]) #__gt-overlay{display:block;}';
                 document.head.appendChild(style);
 
                 window.__setDebugMode = (enabled) => {
@@ -511,12 +359,39 @@ fn App() -> Element {
                 window.__setDebugMode(enabled);
             }
         "#);
+        #[cfg(feature = "levels")]
+        levels::LevelRegistry::install_js();
+        levels::DeviceProfile::install_js();
+        levels::Difficulty::install_js();
+        levels::install_check_js();
+        levels::install_stats_js();
+        levels::install_os_cursor_js();
+        levels::install_small_targets_css();
+        #[cfg(feature = "exporter")]
+        recorder::install();
+        #[cfg(feature = "exporter")]
+        dataset::install();
+        #[cfg(feature = "solver")]
+        document::eval(solver::SOLVER_JS);
+        #[cfg(feature = "control")]
+        control::install();
+        #[cfg(feature = "env")]
+        document::eval(env::ENV_JS);
+        #[cfg(feature = "ws")]
+        document::eval(ws::WS_JS);
     });
 
     rsx! {
         div {
             id: "main",
-            Router::<Route> {}
+            if pool_ready() {
+                Router::<Route> {}
+            } else {
+                div {
+                    style: "min-height: 100vh; background: #0f0f1a; color: #6b7280; display: flex; align-items: center; justify-content: center; font-family: system-ui, sans-serif; font-size: 14px;",
+                    "Loading snippet pool\u{2026}"
+                }
+            }
         }
     }
 }
@@ -1,9 +1,36 @@
 //! Built-in design snippets - starter pool of element variants
 
+use super::icons::Icon;
 use super::kind::ElementKind;
 use super::snippet::DesignSnippet;
 
+/// A 36px square button holding only `icon`'s glyph
+fn icon_button(icon: Icon, bg: &str, stroke: &str, radius: &str, extra: &str) -> String {
+    format!(
+        r#"<button aria-label="{}" style="
+            display: inline-flex;
+            align-items: center;
+            justify-content: center;
+            width: 36px;
+            height: 36px;
+            padding: 8px;
+            background: {bg};
+            border: none;
+            border-radius: {radius};
+            cursor: pointer;
+            box-sizing: border-box;
+            {extra}
+        ">{}</button>"#,
+        icon.function(),
+        icon.svg(stroke),
+    )
+}
+
 /// Seed the pool with diverse built-in designs
+///
+/// Every snippet is tagged with its style family, the color scheme it
+/// suits (`light`, `dark` or `colorful`) and its complexity (`simple` or
+/// `detailed`), in that order.
 pub fn builtin_snippets() -> Vec<DesignSnippet> {
     let mut pool = Vec::new();
 
@@ -35,7 +62,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             box-shadow: inset 0 2px 4px rgba(0,0,0,0.2);
         ">Submit</button>"#,
         100.0, 40.0,
-    ));
+    ).with_tags(&["flat", "colorful", "simple"]));
 
     pool.push(DesignSnippet::new(
         "btn-outline-light",
@@ -62,7 +89,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             cursor: pointer;
         ">Cancel</button>"#,
         100.0, 40.0,
-    ));
+    ).with_tags(&["outline", "dark", "simple"]));
 
     pool.push(DesignSnippet::new(
         "btn-gradient-purple",
@@ -93,7 +120,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             box-shadow: 0 2px 8px rgba(102, 126, 234, 0.6);
         ">Get Started</button>"#,
         140.0, 48.0,
-    ));
+    ).with_tags(&["gradient", "colorful", "detailed"]));
 
     pool.push(DesignSnippet::new(
         "btn-pill-green",
@@ -121,7 +148,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             box-shadow: inset 0 2px 4px rgba(0,0,0,0.2);
         ">Confirm</button>"#,
         100.0, 36.0,
-    ));
+    ).with_tags(&["pill", "colorful", "simple"]));
 
     pool.push(DesignSnippet::new(
         "btn-danger-red",
@@ -149,7 +176,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             box-shadow: inset 0 2px 4px rgba(0,0,0,0.2);
         ">Delete</button>"#,
         100.0, 40.0,
-    ));
+    ).with_tags(&["flat", "colorful", "simple"]));
 
     // --- Inputs (browser handles focus natively) ---
 
@@ -169,7 +196,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             color: #111;
         " />"#,
         240.0, 40.0,
-    ));
+    ).with_tags(&["bordered", "light", "simple"]));
 
     pool.push(DesignSnippet::static_new(
         "input-underline",
@@ -187,7 +214,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             color: #111;
         " />"#,
         220.0, 40.0,
-    ));
+    ).with_tags(&["minimal", "light", "simple"]));
 
     pool.push(DesignSnippet::static_new(
         "input-search",
@@ -205,7 +232,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             color: #111;
         " />"#,
         260.0, 42.0,
-    ));
+    ).with_tags(&["rounded", "light", "simple"]));
 
     // --- Checkboxes (unchecked → checked) ---
 
@@ -254,7 +281,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             Accept terms
         </label>"#,
         140.0, 24.0,
-    ));
+    ).with_tags(&["bordered", "light", "detailed"]));
 
     // --- Toggles (off → on) ---
 
@@ -323,7 +350,7 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             Dark mode
         </label>"#,
         140.0, 28.0,
-    ));
+    ).with_tags(&["ios", "light", "detailed"]));
 
     // --- Links (normal → visited color) ---
 
@@ -346,7 +373,45 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             cursor: pointer;
         ">Learn more</a>"##,
         90.0, 20.0,
-    ));
+    ).with_tags(&["minimal", "light", "simple"]));
+
+    // --- Icon buttons (glyph only, no text; normal → pressed) ---
+
+    pool.push(DesignSnippet::new(
+        "icon-ghost-gear",
+        ElementKind::Icon,
+        "gear settings icon button",
+        icon_button(Icon::Gear, "transparent", "#374151", "6px", ""),
+        icon_button(Icon::Gear, "#e5e7eb", "#111827", "6px", ""),
+        36.0, 36.0,
+    ).with_tags(&["minimal", "light", "simple"]));
+
+    pool.push(DesignSnippet::new(
+        "icon-round-trash",
+        ElementKind::Icon,
+        "red round trash delete icon button",
+        icon_button(Icon::Trash, "#ef4444", "white", "50%", ""),
+        icon_button(Icon::Trash, "#b91c1c", "white", "50%", "box-shadow: inset 0 2px 4px rgba(0,0,0,0.25);"),
+        36.0, 36.0,
+    ).with_tags(&["round", "colorful", "simple"]));
+
+    pool.push(DesignSnippet::new(
+        "icon-dark-bell",
+        ElementKind::Icon,
+        "dark bell notifications icon button",
+        icon_button(Icon::Bell, "#1f2937", "#e5e7eb", "8px", ""),
+        icon_button(Icon::Bell, "#111827", "#ffffff", "8px", "box-shadow: inset 0 2px 4px rgba(0,0,0,0.4);"),
+        36.0, 36.0,
+    ).with_tags(&["flat", "dark", "simple"]));
+
+    pool.push(DesignSnippet::new(
+        "icon-outline-search",
+        ElementKind::Icon,
+        "outlined magnifier search icon button",
+        icon_button(Icon::Search, "white", "#2563eb", "6px", "border: 1px solid #bfdbfe;"),
+        icon_button(Icon::Search, "#dbeafe", "#1d4ed8", "6px", "border: 1px solid #93c5fd;"),
+        36.0, 36.0,
+    ).with_tags(&["bordered", "light", "simple"]));
 
     // --- Dropdowns (use static_new - native select handles its own state) ---
 
@@ -375,7 +440,138 @@ pub fn builtin_snippets() -> Vec<DesignSnippet> {
             <option>Option C</option>
         </select>"#,
         180.0, 42.0,
-    ));
+    ).with_tags(&["bordered", "light", "simple"]));
+
+    // --- Badges (decorative, used as distractors) ---
+
+    pool.push(DesignSnippet::static_new(
+        "badge-new-green",
+        ElementKind::Badge,
+        "green pill badge",
+        r#"<span style="
+            display: inline-block;
+            padding: 3px 10px;
+            background: #dcfce7;
+            color: #15803d;
+            border-radius: 999px;
+            font-size: 12px;
+            font-weight: 600;
+            font-family: system-ui, sans-serif;
+        ">New</span>"#,
+        48.0, 22.0,
+    ).with_tags(&["pill", "light", "simple"]));
+
+    pool.push(DesignSnippet::static_new(
+        "badge-beta-outline",
+        ElementKind::Badge,
+        "outline beta badge",
+        r#"<span style="
+            display: inline-block;
+            padding: 2px 8px;
+            border: 1px solid #a855f7;
+            color: #7e22ce;
+            border-radius: 4px;
+            font-size: 11px;
+            font-weight: 600;
+            letter-spacing: 0.05em;
+            text-transform: uppercase;
+            font-family: system-ui, sans-serif;
+        ">Beta</span>"#,
+        50.0, 20.0,
+    ).with_tags(&["outline", "light", "simple"]));
+
+    pool.push(DesignSnippet::static_new(
+        "badge-count-red",
+        ElementKind::Badge,
+        "red count badge",
+        r#"<span style="
+            display: inline-flex;
+            align-items: center;
+            justify-content: center;
+            min-width: 22px;
+            height: 22px;
+            padding: 0 6px;
+            background: #ef4444;
+            color: white;
+            border-radius: 11px;
+            font-size: 12px;
+            font-weight: 700;
+            font-family: system-ui, sans-serif;
+        ">12</span>"#,
+        28.0, 22.0,
+    ).with_tags(&["pill", "colorful", "simple"]));
+
+    // --- Avatars (decorative, used as distractors) ---
+
+    pool.push(DesignSnippet::static_new(
+        "avatar-initials-indigo",
+        ElementKind::Avatar,
+        "indigo initials avatar",
+        r#"<div style="
+            width: 40px;
+            height: 40px;
+            border-radius: 50%;
+            background: #6366f1;
+            color: white;
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            font-size: 15px;
+            font-weight: 600;
+            font-family: system-ui, sans-serif;
+        ">JD</div>"#,
+        40.0, 40.0,
+    ).with_tags(&["round", "colorful", "simple"]));
+
+    pool.push(DesignSnippet::static_new(
+        "avatar-square-amber",
+        ElementKind::Avatar,
+        "rounded-square amber avatar",
+        r#"<div style="
+            width: 36px;
+            height: 36px;
+            border-radius: 8px;
+            background: #fbbf24;
+            color: #78350f;
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            font-size: 14px;
+            font-weight: 700;
+            font-family: system-ui, sans-serif;
+        ">AK</div>"#,
+        36.0, 36.0,
+    ).with_tags(&["rounded", "colorful", "simple"]));
+
+    pool.push(DesignSnippet::static_new(
+        "avatar-status-teal",
+        ElementKind::Avatar,
+        "teal avatar with status dot",
+        r#"<div style="
+            position: relative;
+            width: 40px;
+            height: 40px;
+            border-radius: 50%;
+            background: #14b8a6;
+            color: white;
+            display: flex;
+            align-items: center;
+            justify-content: center;
+            font-size: 15px;
+            font-weight: 600;
+            font-family: system-ui, sans-serif;
+        ">MR<span style="
+            position: absolute;
+            right: 0;
+            bottom: 0;
+            width: 10px;
+            height: 10px;
+            border-radius: 50%;
+            background: #22c55e;
+            border: 2px solid white;
+        "></span></div>"#,
+        40.0, 40.0,
+    ).with_tags(&["round", "colorful", "detailed"]));
 
     pool
 }
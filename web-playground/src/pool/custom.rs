@@ -0,0 +1,224 @@
+//! Custom snippets - design snippets loaded from JSON at runtime
+//!
+//! `?pool=https://example.com/snippets.json` fetches a snippet file before the
+//! first route renders; its snippets join the built-ins in every
+//! `ElementPool::with_builtins()`. The file is an array of snippets (or
+//! `{"snippets": [...]}`):
+//!
+//! ```json
+//! [{"id": "acme-primary", "kind": "button", "label": "acme primary button",
+//!   "html": "<button style=\"...\">Save</button>", "html_active": "...",
+//!   "approx_width": 96, "approx_height": 36}]
+//! ```
+//!
+//! Optional `"tags"` (array of strings) and `"weight"` (positive number,
+//! default 1) feed tag-filtered and weighted sampling. Optional `"hotspots"`
+//! (`[{"name": "menu", "selector": ".caret", "role": "toggle"}]`) make it an
+//! interactive snippet (see [`super::interactive`]).
+//! `label` defaults to `id` and `html_active` to `html`. A file with any
+//! invalid snippet is rejected whole and the built-ins are used alone.
+
+use std::cell::RefCell;
+
+use dioxus::prelude::*;
+use js_sys::{Array, Reflect};
+use web_sys::wasm_bindgen::JsValue;
+
+use super::interactive::HotspotRole;
+use super::kind::ElementKind;
+use super::snippet::DesignSnippet;
+use super::ElementPool;
+use crate::ui_node::escape_json;
+
+/// Largest `approx_width` / `approx_height` accepted, in px.
+pub const MAX_DIMENSION: f32 = 1200.0;
+
+thread_local! {
+    /// Pool built from the `?pool=` file, once loaded.
+    static LOADED: RefCell<Option<ElementPool>> = const { RefCell::new(None) };
+}
+
+/// Snippets loaded from `?pool=` so far.
+pub fn loaded() -> Vec<DesignSnippet> {
+    LOADED.with(|l| l.borrow().as_ref().map_or(Vec::new(), |p| p.all().into_iter().cloned().collect()))
+}
+
+/// The `?pool=` URL, if one was given.
+pub fn requested_url() -> Option<String> {
+    crate::levels::query_param("pool").filter(|u| !u.trim().is_empty())
+}
+
+fn string_field(obj: &JsValue, key: &str) -> Option<String> {
+    Reflect::get(obj, &JsValue::from_str(key)).ok()?.as_string()
+}
+
+fn number_field(obj: &JsValue, key: &str) -> Option<f64> {
+    Reflect::get(obj, &JsValue::from_str(key)).ok()?.as_f64()
+}
+
+/// Elements that load or run content of their own.
+const EMBEDDING_TAGS: &[&str] = &["<iframe", "<object", "<embed"];
+
+/// Whether `lower` has an `on…=` event-handler attribute (`\bon[a-z]+\s*=`).
+fn has_event_handler(lower: &str) -> bool {
+    let bytes = lower.as_bytes();
+    lower.match_indices("on").any(|(i, _)| {
+        let boundary = i == 0 || !(bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_');
+        let name_len = bytes[i + 2..].iter().take_while(|b| b.is_ascii_lowercase()).count();
+        let rest = lower[i + 2 + name_len..].trim_start();
+        boundary && name_len > 0 && rest.starts_with('=')
+    })
+}
+
+/// Snippet HTML must be markup and must not run script: it is rendered in
+/// the playground's own origin. Rejects `<script>`, embedding elements
+/// (`<iframe>`, `<object>`, `<embed>`), `on…=` handlers and `javascript:`.
+fn check_html(html: &str) -> Result<(), &'static str> {
+    let lower = html.to_ascii_lowercase();
+    if html.trim().is_empty() {
+        Err("empty")
+    } else if !(lower.contains('<') && lower.contains('>')) {
+        Err("not markup")
+    } else if lower.contains("<script") || lower.contains("javascript:") || has_event_handler(&lower) {
+        Err("contains script")
+    } else if EMBEDDING_TAGS.iter().any(|t| lower.contains(t)) {
+        Err("embeds content")
+    } else {
+        Ok(())
+    }
+}
+
+fn check_dimension(value: Option<f64>) -> Result<f32, &'static str> {
+    match value {
+        None => Err("missing"),
+        Some(v) if !v.is_finite() || v < 1.0 || v > MAX_DIMENSION as f64 => Err("out of range"),
+        Some(v) => Ok(v as f32),
+    }
+}
+
+fn parse_snippet(obj: &JsValue) -> Result<DesignSnippet, String> {
+    let id = string_field(obj, "id")
+        .filter(|s| !s.trim().is_empty())
+        .ok_or("missing \"id\"")?;
+    let kind_name = string_field(obj, "kind").ok_or("missing \"kind\"")?;
+    let kind = ElementKind::parse(&kind_name)
+        .ok_or_else(|| format!("unknown kind \"{kind_name}\""))?;
+    let html = string_field(obj, "html").unwrap_or_default();
+    check_html(&html).map_err(|e| format!("\"html\" {e}"))?;
+    let html_active = match string_field(obj, "html_active") {
+        Some(active) => {
+            check_html(&active).map_err(|e| format!("\"html_active\" {e}"))?;
+            active
+        }
+        None => html.clone(),
+    };
+    let approx_width = check_dimension(number_field(obj, "approx_width"))
+        .map_err(|e| format!("\"approx_width\" {e}"))?;
+    let approx_height = check_dimension(number_field(obj, "approx_height"))
+        .map_err(|e| format!("\"approx_height\" {e}"))?;
+    let label = string_field(obj, "label").unwrap_or_else(|| id.clone());
+    let mut snippet = DesignSnippet::new(id, kind, label, html, html_active, approx_width, approx_height);
+    let tags = Reflect::get(obj, &JsValue::from_str("tags")).unwrap_or(JsValue::UNDEFINED);
+    if !tags.is_undefined() {
+        if !Array::is_array(&tags) {
+            return Err("\"tags\" must be an array of strings".to_string());
+        }
+        for tag in Array::from(&tags).iter() {
+            snippet.tags.push(tag.as_string().ok_or("\"tags\" must be an array of strings")?);
+        }
+    }
+    if let Some(weight) = number_field(obj, "weight") {
+        if !weight.is_finite() || weight <= 0.0 {
+            return Err("\"weight\" must be positive".to_string());
+        }
+        snippet = snippet.with_weight(weight as f32);
+    }
+    let hotspots = Reflect::get(obj, &JsValue::from_str("hotspots")).unwrap_or(JsValue::UNDEFINED);
+    if !hotspots.is_undefined() {
+        if !Array::is_array(&hotspots) {
+            return Err("\"hotspots\" must be an array".to_string());
+        }
+        for (j, spot) in Array::from(&hotspots).iter().enumerate() {
+            let name = string_field(&spot, "name").filter(|s| !s.trim().is_empty())
+                .ok_or_else(|| format!("hotspot {j}: missing \"name\""))?;
+            let selector = string_field(&spot, "selector").filter(|s| !s.trim().is_empty())
+                .ok_or_else(|| format!("hotspot {j}: missing \"selector\""))?;
+            let role_name = string_field(&spot, "role").unwrap_or_default();
+            let role = HotspotRole::parse(&role_name)
+                .ok_or_else(|| format!("hotspot {j}: unknown role \"{role_name}\""))?;
+            snippet = snippet.with_hotspot(&name, &selector, role);
+        }
+    }
+    Ok(snippet)
+}
+
+/// Parse and validate a snippet file.
+pub fn parse_snippets(json: &str) -> Result<Vec<DesignSnippet>, String> {
+    let root = js_sys::JSON::parse(json).map_err(|_| "not valid JSON".to_string())?;
+    let list = if Array::is_array(&root) {
+        root
+    } else {
+        Reflect::get(&root, &JsValue::from_str("snippets")).unwrap_or(JsValue::UNDEFINED)
+    };
+    if !Array::is_array(&list) {
+        return Err("expected an array of snippets".to_string());
+    }
+    let mut snippets: Vec<DesignSnippet> = Vec::new();
+    for (i, obj) in Array::from(&list).iter().enumerate() {
+        let snippet = parse_snippet(&obj).map_err(|e| format!("snippet {i}: {e}"))?;
+        if snippets.iter().any(|s| s.id == snippet.id) {
+            return Err(format!("snippet {i}: duplicate id \"{}\"", snippet.id));
+        }
+        snippets.push(snippet);
+    }
+    Ok(snippets)
+}
+
+/// Fetch `url` and keep its snippets for every later pool.
+/// Returns how many were loaded.
+pub async fn load(url: &str) -> Result<usize, String> {
+    let eval = document::eval(&format!(
+        r#"
+        const res = await fetch("{}");
+        if (!res.ok) throw new Error('HTTP ' + res.status);
+        return await res.text();
+        "#,
+        escape_json(url),
+    ));
+    let text: String = eval.join().await.map_err(|e| format!("fetch failed: {e:?}"))?;
+    let pool = ElementPool::from_json(&text)?;
+    let count = pool.total();
+    LOADED.with(|l| *l.borrow_mut() = Some(pool));
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_markup_passes() {
+        assert_eq!(check_html(r#"<button style="color: red" data-role="save">Save</button>"#), Ok(()));
+        assert_eq!(check_html(r#"<span>Turn on = off</span><a href="/x">Done</a>"#), Ok(()));
+    }
+
+    #[test]
+    fn script_is_rejected() {
+        assert_eq!(check_html("<script>alert(1)</script>"), Err("contains script"));
+        assert_eq!(check_html(r#"<a href="JavaScript:alert(1)">x</a>"#), Err("contains script"));
+    }
+
+    #[test]
+    fn event_handlers_are_rejected() {
+        assert_eq!(check_html("<img src=x onerror=alert(1)>"), Err("contains script"));
+        assert_eq!(check_html(r#"<div ONMOUSEOVER = "go()">x</div>"#), Err("contains script"));
+        assert_eq!(check_html(r#"<svg/onload="go()"></svg>"#), Err("contains script"));
+    }
+
+    #[test]
+    fn embedding_elements_are_rejected() {
+        assert_eq!(check_html(r#"<iframe srcdoc="<b>x</b>"></iframe>"#), Err("embeds content"));
+        assert_eq!(check_html(r#"<object data="x.swf"></object>"#), Err("embeds content"));
+        assert_eq!(check_html(r#"<embed src="x.svg">"#), Err("embeds content"));
+    }
+}
@@ -0,0 +1,74 @@
+//! Icons - stroke glyphs for icon-only buttons
+//!
+//! Each glyph is a 24×24 SVG body drawn with `currentColor`-style strokes,
+//! so one table serves the pool's icon snippets and the icon toolbar level.
+
+/// A toolbar glyph and the function it conventionally stands for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Icon {
+    Gear,
+    Trash,
+    Search,
+    Bell,
+    Home,
+    Pencil,
+    Download,
+}
+
+impl Icon {
+    pub const ALL: &[Self] = &[
+        Self::Gear,
+        Self::Trash,
+        Self::Search,
+        Self::Bell,
+        Self::Home,
+        Self::Pencil,
+        Self::Download,
+    ];
+
+    /// What the glyph depicts ("gear")
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            Self::Gear => "gear",
+            Self::Trash => "trash",
+            Self::Search => "search",
+            Self::Bell => "bell",
+            Self::Home => "home",
+            Self::Pencil => "pencil",
+            Self::Download => "download",
+        }
+    }
+
+    /// What clicking it does ("Settings"); the accessible label
+    pub fn function(&self) -> &'static str {
+        match self {
+            Self::Gear => "Settings",
+            Self::Trash => "Delete",
+            Self::Search => "Search",
+            Self::Bell => "Notifications",
+            Self::Home => "Home",
+            Self::Pencil => "Edit",
+            Self::Download => "Download",
+        }
+    }
+
+    fn body(&self) -> &'static str {
+        match self {
+            Self::Gear => r#"<circle cx="12" cy="12" r="3"/><circle cx="12" cy="12" r="7"/><path d="M12 2v3M12 19v3M2 12h3M19 12h3M4.9 4.9L7 7M17 17l2.1 2.1M4.9 19.1L7 17M17 7l2.1-2.1"/>"#,
+            Self::Trash => r#"<path d="M3 6h18M19 6l-1 14a2 2 0 0 1-2 2H8a2 2 0 0 1-2-2L5 6M10 11v6M14 11v6M9 6V4a1 1 0 0 1 1-1h4a1 1 0 0 1 1 1v2"/>"#,
+            Self::Search => r#"<circle cx="11" cy="11" r="7"/><path d="M21 21l-5-5"/>"#,
+            Self::Bell => r#"<path d="M18 8a6 6 0 0 0-12 0c0 7-3 9-3 9h18s-3-2-3-9M13.7 21a2 2 0 0 1-3.4 0"/>"#,
+            Self::Home => r#"<path d="M3 10l9-7 9 7v10a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2zM9 22V12h6v10"/>"#,
+            Self::Pencil => r#"<path d="M17 3l4 4L8 20H4v-4z"/>"#,
+            Self::Download => r#"<path d="M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4M7 10l5 5 5-5M12 15V3"/>"#,
+        }
+    }
+
+    /// Inline SVG that fills its box, stroked in `stroke`
+    pub fn svg(&self, stroke: &str) -> String {
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="100%" height="100%" viewBox="0 0 24 24" fill="none" stroke="{stroke}" stroke-width="2" stroke-linecap="round" stroke-linejoin="round">{}</svg>"#,
+            self.body(),
+        )
+    }
+}
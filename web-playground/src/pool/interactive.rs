@@ -0,0 +1,123 @@
+//! Interactive snippets - named hotspots inside a snippet's HTML
+//!
+//! A plain snippet is one click target: clicking anywhere on it swaps
+//! `html` / `html_active`. An interactive snippet declares hotspots instead,
+//! each a CSS selector into its HTML with a semantic role, so one snippet can
+//! hold several distinct targets (a split button's action and its caret, a
+//! chip's label and its close mark). Renderers hit-test clicks with
+//! [`hit`] and apply the role; clicks outside every hotspot do nothing.
+
+use super::kind::ElementKind;
+use super::snippet::DesignSnippet;
+
+/// What clicking a hotspot does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HotspotRole {
+    /// Press: switch to the active state
+    Activate,
+    /// Flip between the default and active states
+    Toggle,
+    /// Remove the element
+    Dismiss,
+    /// Take keyboard focus; no state change
+    Focus,
+}
+
+impl HotspotRole {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Self::Activate => "activate",
+            Self::Toggle => "toggle",
+            Self::Dismiss => "dismiss",
+            Self::Focus => "focus",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Some(match s.trim().to_ascii_lowercase().as_str() {
+            "activate" => Self::Activate,
+            "toggle" => Self::Toggle,
+            "dismiss" => Self::Dismiss,
+            "focus" => Self::Focus,
+            _ => return None,
+        })
+    }
+}
+
+/// A named, selector-addressed target inside a snippet
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hotspot {
+    pub name: String,
+    /// CSS selector, matched against the clicked element and its ancestors
+    pub selector: String,
+    pub role: HotspotRole,
+}
+
+impl Hotspot {
+    /// "caret (toggle)"
+    pub fn describe(&self) -> String {
+        format!("{} ({})", self.name, self.role.describe())
+    }
+}
+
+/// The hotspot of `snippet` the click on `target` landed in, if any.
+/// `root` is the element the snippet HTML was injected into; matches
+/// outside it are ignored.
+pub fn hit<'a>(snippet: &'a DesignSnippet, target: &web_sys::Element, root: &web_sys::Element) -> Option<&'a Hotspot> {
+    snippet.hotspots.iter().find(|h| {
+        matches!(target.closest(&h.selector), Ok(Some(el)) if root.contains(Some(&el)))
+    })
+}
+
+/// Interactive built-ins. Kept out of `with_builtins()` so seeded level
+/// rounds draw from the same pool as before; see `ElementPool::with_interactive`.
+pub fn interactive_snippets() -> Vec<DesignSnippet> {
+    vec![
+        DesignSnippet::new(
+            "btn-split-indigo",
+            ElementKind::Button,
+            "indigo split button",
+            r#"<div style="display: inline-flex; font-family: system-ui, sans-serif; font-size: 14px;">
+                <button class="hs-split-main" style="padding: 9px 16px; background: #6366f1; color: white; border: none; border-radius: 6px 0 0 6px; cursor: pointer;">Save</button>
+                <button class="hs-split-caret" style="padding: 9px 10px; background: #4f46e5; color: white; border: none; border-left: 1px solid #818cf8; border-radius: 0 6px 6px 0; cursor: pointer;">&#9662;</button>
+            </div>"#,
+            r#"<div style="display: inline-flex; font-family: system-ui, sans-serif; font-size: 14px;">
+                <button class="hs-split-main" style="padding: 9px 16px; background: #4338ca; color: white; border: none; border-radius: 6px 0 0 6px; cursor: pointer; box-shadow: inset 0 2px 4px rgba(0,0,0,0.2);">Save</button>
+                <button class="hs-split-caret" style="padding: 9px 10px; background: #3730a3; color: white; border: none; border-left: 1px solid #818cf8; border-radius: 0 6px 6px 0; cursor: pointer;">&#9652;</button>
+            </div>"#,
+            110.0, 38.0,
+        )
+        .with_tags(&["flat", "colorful", "detailed"])
+        .with_hotspot("save", ".hs-split-main", HotspotRole::Activate)
+        .with_hotspot("menu", ".hs-split-caret", HotspotRole::Toggle),
+        DesignSnippet::static_new(
+            "badge-chip-dismiss",
+            ElementKind::Badge,
+            "dismissible filter chip",
+            r#"<span style="display: inline-flex; align-items: center; gap: 6px; padding: 4px 6px 4px 12px; background: #e0f2fe; color: #0369a1; border-radius: 9999px; font-size: 13px; font-family: system-ui, sans-serif;">
+                Status: open
+                <span class="hs-chip-close" style="display: inline-flex; align-items: center; justify-content: center; width: 18px; height: 18px; border-radius: 50%; background: #bae6fd; cursor: pointer;">&#215;</span>
+            </span>"#,
+            120.0, 26.0,
+        )
+        .with_tags(&["pill", "light", "detailed"])
+        .with_hotspot("remove", ".hs-chip-close", HotspotRole::Dismiss),
+        DesignSnippet::new(
+            "input-clearable",
+            ElementKind::Input,
+            "input with clear button",
+            r#"<div style="display: inline-flex; align-items: center; border: 1px solid #d1d5db; border-radius: 6px; background: white; padding-right: 6px;">
+                <input class="hs-clear-field" type="text" value="invoice 2024" style="padding: 8px 10px; border: none; outline: none; font-size: 14px; font-family: system-ui, sans-serif; width: 170px; background: transparent; color: #111;" />
+                <span class="hs-clear-x" style="color: #9ca3af; cursor: pointer; font-size: 16px;">&#215;</span>
+            </div>"#,
+            r#"<div style="display: inline-flex; align-items: center; border: 1px solid #d1d5db; border-radius: 6px; background: white; padding-right: 6px;">
+                <input class="hs-clear-field" type="text" placeholder="Search invoices" style="padding: 8px 10px; border: none; outline: none; font-size: 14px; font-family: system-ui, sans-serif; width: 170px; background: transparent; color: #111;" />
+                <span class="hs-clear-x" style="color: #d1d5db; cursor: pointer; font-size: 16px;">&#215;</span>
+            </div>"#,
+            210.0, 38.0,
+        )
+        .with_tags(&["bordered", "light", "detailed"])
+        .with_hotspot("field", ".hs-clear-field", HotspotRole::Focus)
+        .with_hotspot("clear", ".hs-clear-x", HotspotRole::Activate),
+    ]
+}
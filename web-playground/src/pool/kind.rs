@@ -11,9 +11,28 @@ pub enum ElementKind {
     Checkbox,
     Toggle,
     Link,
+    /// Icon-only button; the glyph is its only cue
+    Icon,
+    /// Small status pill; decorative only
+    Badge,
+    /// User picture / initials circle; decorative only
+    Avatar,
 }
 
 impl ElementKind {
+    /// Every kind, in declaration order
+    pub const ALL: &[Self] = &[
+        Self::Button,
+        Self::Input,
+        Self::Dropdown,
+        Self::Checkbox,
+        Self::Toggle,
+        Self::Link,
+        Self::Icon,
+        Self::Badge,
+        Self::Avatar,
+    ];
+
     pub fn describe(&self) -> &'static str {
         match self {
             Self::Button => "button",
@@ -22,8 +41,28 @@ impl ElementKind {
             Self::Checkbox => "checkbox",
             Self::Toggle => "toggle switch",
             Self::Link => "link",
+            Self::Icon => "icon button",
+            Self::Badge => "badge",
+            Self::Avatar => "avatar",
         }
     }
+
+    /// Parse a snippet file's `"kind"`: the variant name in lowercase
+    /// (`"button"`, `"toggle"`, ...).
+    pub fn parse(s: &str) -> Option<Self> {
+        Some(match s.trim().to_ascii_lowercase().as_str() {
+            "button" => Self::Button,
+            "input" => Self::Input,
+            "dropdown" => Self::Dropdown,
+            "checkbox" => Self::Checkbox,
+            "toggle" => Self::Toggle,
+            "link" => Self::Link,
+            "icon" => Self::Icon,
+            "badge" => Self::Badge,
+            "avatar" => Self::Avatar,
+            _ => return None,
+        })
+    }
 }
 
 impl fmt::Display for ElementKind {
@@ -0,0 +1,55 @@
+//! Snippet measurement - the true rendered size of a snippet
+//!
+//! `approx_width` / `approx_height` are hand-written and drift from what the
+//! browser lays out (font metrics, padding edits). The first time a snippet's
+//! size is asked for, its HTML is rendered once off-screen in both states and
+//! the larger bounding box is cached by HTML. Placement (`safe_position`) and
+//! ground-truth bounds use that size; the declared one is the fallback when
+//! nothing can be measured (no DOM, or a zero-sized render).
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use super::snippet::DesignSnippet;
+
+thread_local! {
+    /// (html, html_active) -> measured (width, height), px
+    static SIZES: RefCell<HashMap<(String, String), (f32, f32)>> = RefCell::new(HashMap::new());
+}
+
+/// Rendered (width, height) of `html`, laid out off-screen.
+#[cfg(target_arch = "wasm32")]
+fn render_size(html: &str) -> Option<(f32, f32)> {
+    let document = web_sys::window()?.document()?;
+    let root = document.document_element()?;
+    let probe = document.create_element("div").ok()?;
+    probe.set_attribute(
+        "style",
+        "position: absolute; left: -10000px; top: -10000px; display: inline-block; visibility: hidden; pointer-events: none;",
+    ).ok()?;
+    probe.set_inner_html(html);
+    root.append_child(&probe).ok()?;
+    let r = probe.get_bounding_client_rect();
+    probe.remove();
+    (r.width() > 0.0 && r.height() > 0.0).then(|| (r.width().ceil() as f32, r.height().ceil() as f32))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn render_size(_html: &str) -> Option<(f32, f32)> {
+    None
+}
+
+/// The snippet's measured size, measuring on first use.
+pub fn measured_size(snippet: &DesignSnippet) -> Option<(f32, f32)> {
+    let key = (snippet.html.clone(), snippet.html_active.clone());
+    if let Some(size) = SIZES.with(|s| s.borrow().get(&key).copied()) {
+        return Some(size);
+    }
+    let (w, h) = render_size(&snippet.html)?;
+    let size = match render_size(&snippet.html_active) {
+        Some((aw, ah)) => (w.max(aw), h.max(ah)),
+        None => (w, h),
+    };
+    SIZES.with(|s| s.borrow_mut().insert(key, size));
+    Some(size)
+}
@@ -5,9 +5,15 @@
 //! and applies random transforms for training diversity.
 
 pub mod builtins;
+pub mod custom;
+pub mod icons;
+pub mod interactive;
 pub mod kind;
+pub mod measure;
 pub mod snippet;
 
+#[cfg(feature = "levels")]
+pub use icons::Icon;
 pub use kind::ElementKind;
 pub use snippet::DesignSnippet;
 
@@ -24,15 +30,37 @@ impl ElementPool {
         }
     }
 
-    /// Create a pool seeded with built-in snippets
+    /// Create a pool seeded with built-in snippets, plus any custom ones
+    /// loaded from `?pool=` (see [`custom`])
     pub fn with_builtins() -> Self {
         let mut pool = Self::new();
         for snippet in builtins::builtin_snippets() {
             pool.add(snippet);
         }
+        for snippet in custom::loaded() {
+            pool.add(snippet);
+        }
         pool
     }
 
+    /// Add the interactive built-ins (hotspot snippets) to this pool
+    pub fn with_interactive(mut self) -> Self {
+        for snippet in interactive::interactive_snippets() {
+            self.add(snippet);
+        }
+        self
+    }
+
+    /// Create a pool from a JSON snippet file (format in [`custom`]).
+    /// Fails on the first invalid snippet, naming it.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut pool = Self::new();
+        for snippet in custom::parse_snippets(json)? {
+            pool.add(snippet);
+        }
+        Ok(pool)
+    }
+
     pub fn add(&mut self, snippet: DesignSnippet) {
         self.snippets
             .entry(snippet.kind)
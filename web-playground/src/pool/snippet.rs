@@ -1,5 +1,6 @@
 //! DesignSnippet - a concrete HTML+CSS element from the pool
 
+use super::interactive::{Hotspot, HotspotRole};
 use super::kind::ElementKind;
 
 /// A single design variant from the pool
@@ -24,6 +25,12 @@ pub struct DesignSnippet {
     pub approx_width: f32,
     /// Approximate height in px
     pub approx_height: f32,
+    /// Free-form tags: style family, color scheme, complexity (see builtins)
+    pub tags: Vec<String>,
+    /// Relative sampling weight within its kind (1.0 = even)
+    pub weight: f32,
+    /// Click targets inside the HTML; empty = the whole snippet is one target
+    pub hotspots: Vec<Hotspot>,
 }
 
 impl DesignSnippet {
@@ -44,6 +51,9 @@ impl DesignSnippet {
             html_active: html_active.into(),
             approx_width,
             approx_height,
+            tags: Vec::new(),
+            weight: 1.0,
+            hotspots: Vec::new(),
         }
     }
 
@@ -65,10 +75,53 @@ impl DesignSnippet {
             html_active: html_str,
             approx_width,
             approx_height,
+            tags: Vec::new(),
+            weight: 1.0,
+            hotspots: Vec::new(),
         }
     }
 
+    pub fn with_tags(mut self, tags: &[&str]) -> Self {
+        self.tags = tags.iter().map(|t| t.to_string()).collect();
+        self
+    }
+
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    pub fn with_hotspot(mut self, name: &str, selector: &str, role: HotspotRole) -> Self {
+        self.hotspots.push(Hotspot { name: name.to_string(), selector: selector.to_string(), role });
+        self
+    }
+
+    pub fn is_interactive(&self) -> bool {
+        !self.hotspots.is_empty()
+    }
+
+    /// Layout size: measured from the rendered HTML when possible (see
+    /// [`super::measure`]), else the declared `approx_width` x `approx_height`
+    pub fn size(&self) -> (f32, f32) {
+        super::measure::measured_size(self).unwrap_or((self.approx_width, self.approx_height))
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// "flat blue button (button; flat, colorful, simple)", plus
+    /// " with save (activate), menu (toggle)" for interactive snippets
     pub fn describe(&self) -> String {
-        format!("{} ({})", self.label, self.kind.describe())
+        let mut desc = if self.tags.is_empty() {
+            format!("{} ({})", self.label, self.kind.describe())
+        } else {
+            format!("{} ({}; {})", self.label, self.kind.describe(), self.tags.join(", "))
+        };
+        if self.is_interactive() {
+            let spots: Vec<String> = self.hotspots.iter().map(|h| h.describe()).collect();
+            desc.push_str(&format!(" with {}", spots.join(", ")));
+        }
+        desc
     }
 }
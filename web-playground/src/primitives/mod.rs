@@ -12,9 +12,13 @@ pub mod animation;
 pub mod opacity;
 pub mod position;
 pub mod scale;
+pub mod shape;
+pub mod typography;
 
 pub use angle::Angle;
 pub use animation::Animation;
 pub use opacity::Opacity;
 pub use position::{Position, viewport_size};
 pub use scale::Scale;
+pub use shape::{Shape, ShapeSize, Swatch};
+pub use typography::{FontFamily, Typography};
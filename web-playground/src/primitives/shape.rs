@@ -0,0 +1,138 @@
+//! Shape - unlabeled geometric marks told apart by outline, colour and size
+
+/// Outline of a shape, drawn on a square box
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Circle,
+    Square,
+    Triangle,
+    Diamond,
+    Hexagon,
+}
+
+impl Shape {
+    pub const ALL: &[Self] = &[
+        Self::Circle,
+        Self::Square,
+        Self::Triangle,
+        Self::Diamond,
+        Self::Hexagon,
+    ];
+
+    /// Outline as `border-radius` or `clip-path`
+    pub fn to_css(self) -> &'static str {
+        match self {
+            Self::Circle => "border-radius: 50%;",
+            Self::Square => "border-radius: 4px;",
+            Self::Triangle => "clip-path: polygon(50% 0, 100% 100%, 0 100%);",
+            Self::Diamond => "clip-path: polygon(50% 0, 100% 50%, 50% 100%, 0 50%);",
+            Self::Hexagon => "clip-path: polygon(25% 0, 75% 0, 100% 50%, 75% 100%, 25% 100%, 0 50%);",
+        }
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Self::Circle => "circle",
+            Self::Square => "square",
+            Self::Triangle => "triangle",
+            Self::Diamond => "diamond",
+            Self::Hexagon => "hexagon",
+        }
+    }
+}
+
+/// Fill colour, far enough apart to name at a glance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Swatch {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+}
+
+impl Swatch {
+    pub const ALL: &[Self] = &[
+        Self::Red,
+        Self::Orange,
+        Self::Yellow,
+        Self::Green,
+        Self::Blue,
+        Self::Purple,
+    ];
+
+    pub fn to_css(self) -> &'static str {
+        match self {
+            Self::Red => "#dc2626",
+            Self::Orange => "#ea580c",
+            Self::Yellow => "#eab308",
+            Self::Green => "#059669",
+            Self::Blue => "#2563eb",
+            Self::Purple => "#9333ea",
+        }
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Self::Red => "red",
+            Self::Orange => "orange",
+            Self::Yellow => "yellow",
+            Self::Green => "green",
+            Self::Blue => "blue",
+            Self::Purple => "purple",
+        }
+    }
+}
+
+/// Two sizes, a clear step apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeSize {
+    Small,
+    Large,
+}
+
+impl ShapeSize {
+    pub const ALL: &[Self] = &[Self::Small, Self::Large];
+
+    /// Edge of the shape's box in px
+    pub fn px(&self) -> f32 {
+        match self {
+            Self::Small => 32.0,
+            Self::Large => 64.0,
+        }
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Self::Small => "small",
+            Self::Large => "large",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shape_css() {
+        assert_eq!(Shape::Circle.to_css(), "border-radius: 50%;");
+        assert!(Shape::Triangle.to_css().starts_with("clip-path:"));
+    }
+
+    #[test]
+    fn swatches_are_distinct() {
+        for (i, a) in Swatch::ALL.iter().enumerate() {
+            for b in &Swatch::ALL[i + 1..] {
+                assert_ne!(a.to_css(), b.to_css());
+                assert_ne!(a.describe(), b.describe());
+            }
+        }
+    }
+
+    #[test]
+    fn sizes_are_a_clear_step_apart() {
+        assert!(ShapeSize::Large.px() >= ShapeSize::Small.px() * 1.5);
+    }
+}
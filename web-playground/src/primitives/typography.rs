@@ -0,0 +1,135 @@
+//! Typography - font family, base size and weight for a card's text
+//!
+//! Applied to a card wrapper. Children inherit the family and weight; sizes
+//! written as `calc(Npx * var(--type-scale, 1))` follow the base size, and
+//! fall back to their plain pixel size when no typography is set.
+
+bounded_f32!(FontSize, 12.0, 18.0);
+
+/// Curated web-safe font stacks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontFamily {
+    Helvetica,
+    Verdana,
+    Trebuchet,
+    Georgia,
+    Times,
+    Courier,
+}
+
+impl FontFamily {
+    pub const ALL: &[Self] = &[
+        Self::Helvetica,
+        Self::Verdana,
+        Self::Trebuchet,
+        Self::Georgia,
+        Self::Times,
+        Self::Courier,
+    ];
+
+    pub fn stack(&self) -> &'static str {
+        match self {
+            Self::Helvetica => "Helvetica, Arial, sans-serif",
+            Self::Verdana => "Verdana, Geneva, sans-serif",
+            Self::Trebuchet => "'Trebuchet MS', Tahoma, sans-serif",
+            Self::Georgia => "Georgia, 'Times New Roman', serif",
+            Self::Times => "'Times New Roman', Times, serif",
+            Self::Courier => "'Courier New', Courier, monospace",
+        }
+    }
+
+    /// CSS generic family, used in descriptions
+    pub fn generic(&self) -> &'static str {
+        match self {
+            Self::Helvetica | Self::Verdana | Self::Trebuchet => "sans-serif",
+            Self::Georgia | Self::Times => "serif",
+            Self::Courier => "monospace",
+        }
+    }
+}
+
+/// Font family, base size and weight for a block of text
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Typography {
+    pub family: FontFamily,
+    pub size: FontSize,
+    pub weight: u16,
+}
+
+impl Typography {
+    /// Size the level styles were written against; `--type-scale` is relative to it
+    pub const BASE_SIZE: f32 = 14.0;
+
+    pub const SIZES: &[FontSize] = &[
+        FontSize::new(12.0),
+        FontSize::new(13.0),
+        FontSize::new(14.0),
+        FontSize::new(15.0),
+        FontSize::new(16.0),
+        FontSize::new(18.0),
+    ];
+
+    pub const WEIGHTS: &[u16] = &[300, 400, 400, 600, 700];
+
+    pub fn new(family: FontFamily, size: FontSize, weight: u16) -> Self {
+        Self { family, size, weight }
+    }
+
+    /// Multiplier for pixel sizes written against `BASE_SIZE`
+    pub fn scale(&self) -> f32 {
+        self.size.value() / Self::BASE_SIZE
+    }
+
+    pub fn to_css(self) -> String {
+        format!(
+            "--type-scale: {:.3}; font-family: {}; font-size: {}px; font-weight: {};",
+            self.scale(),
+            self.family.stack(),
+            self.size.value(),
+            self.weight,
+        )
+    }
+
+    pub fn describe(&self) -> String {
+        let size = match self.size.value() {
+            x if x < 14.0 => "small",
+            x if x <= 16.0 => "medium",
+            _ => "large",
+        };
+        let weight = match self.weight {
+            w if w < 400 => "light ",
+            w if w < 600 => "",
+            w if w < 700 => "semibold ",
+            _ => "bold ",
+        };
+        format!("{} {}{} text", size, weight, self.family.generic())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typography_css() {
+        let t = Typography::new(FontFamily::Georgia, FontSize::new(14.0), 400);
+        assert_eq!(
+            t.to_css(),
+            "--type-scale: 1.000; font-family: Georgia, 'Times New Roman', serif; font-size: 14px; font-weight: 400;"
+        );
+    }
+
+    #[test]
+    fn typography_describe() {
+        let t = Typography::new(FontFamily::Times, FontSize::new(12.0), 400);
+        assert_eq!(t.describe(), "small serif text");
+        let t = Typography::new(FontFamily::Courier, FontSize::new(18.0), 700);
+        assert_eq!(t.describe(), "large bold monospace text");
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn font_size_rejects_tiny() {
+        let _ = FontSize::new(8.0);
+    }
+}
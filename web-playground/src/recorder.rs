@@ -0,0 +1,230 @@
+//! Episode recorder — logs every dispatched action as a training record.
+//!
+//! Installs `window.__recorder`:
+//! - `begin(action, screenshot?)` → step id: snapshots `window.__groundTruth`
+//!   and records the pre-action image (or runs the `hooks.before` hook)
+//! - `end(id, dispatched, screenshot?)`: stamps the end time, records the
+//!   post-action image (or runs `hooks.after`) and the post-action ground truth
+//! - `start()` / `stop()` / `clear()` / `export()` (JSONL string)
+//!
+//! The solver passes the references from its capture phase; other drivers can
+//! instead set `hooks.before` / `hooks.after`, called with `(action, phase)`.
+//! Whatever either returns (an image URL, a frame id, …) is stored as
+//! `pre_screenshot` / `post_screenshot`. A round completing marks the last
+//! step a success; a wrong attempt the level logs during a step marks it
+//! `"wrong"` and is listed in its `wrong_attempts`.
+
+use std::cell::RefCell;
+
+use js_sys::{Function, Reflect, JSON};
+use web_sys::wasm_bindgen::closure::Closure;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+struct EpisodeStep {
+    index: usize,
+    t_start: f64,
+    t_end: Option<f64>,
+    /// Solver action as JSON (`{"action":"click","target":"OK"}`).
+    action: String,
+    /// "pending" until ended, then "progress" / "failure"; "success" when the
+    /// step completed the round, "wrong" when the level rejected it.
+    outcome: &'static str,
+    /// Wrong attempts the level logged during this step, as JSON objects.
+    wrong: Vec<String>,
+    pre_screenshot: String,
+    post_screenshot: String,
+    ground_truth: String,
+    post_ground_truth: String,
+}
+
+struct Recorder {
+    recording: bool,
+    episode: u32,
+    steps: Vec<EpisodeStep>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Recorder> = const { RefCell::new(Recorder { recording: true, episode: 1, steps: Vec::new() }) };
+}
+
+fn now() -> f64 {
+    js_sys::Date::now()
+}
+
+/// `JSON.stringify(value)`, or "null" for undefined / unserializable values.
+fn to_json(value: &JsValue) -> String {
+    if value.is_undefined() {
+        return "null".to_string();
+    }
+    JSON::stringify(value)
+        .ok()
+        .and_then(|s| s.as_string())
+        .unwrap_or_else(|| "null".to_string())
+}
+
+fn window_get(key: &str) -> JsValue {
+    web_sys::window()
+        .and_then(|w| Reflect::get(&w, &JsValue::from_str(key)).ok())
+        .unwrap_or(JsValue::UNDEFINED)
+}
+
+fn current_ground_truth() -> String {
+    to_json(&window_get("__groundTruth"))
+}
+
+/// The screenshot reference passed by the caller, else the hook's result.
+fn screenshot(given: &JsValue, phase: &str, action: &JsValue) -> String {
+    if given.is_undefined() {
+        run_hook(phase, action)
+    } else {
+        to_json(given)
+    }
+}
+
+/// Run `window.__recorder.hooks[phase](action, phase)` if registered.
+fn run_hook(phase: &str, action: &JsValue) -> String {
+    let hook = Reflect::get(&window_get("__recorder"), &"hooks".into())
+        .and_then(|hooks| Reflect::get(&hooks, &JsValue::from_str(phase)))
+        .unwrap_or(JsValue::UNDEFINED);
+    match hook.dyn_ref::<Function>() {
+        Some(f) => f
+            .call2(&JsValue::NULL, action, &JsValue::from_str(phase))
+            .map(|v| to_json(&v))
+            .unwrap_or_else(|_| "null".to_string()),
+        None => "null".to_string(),
+    }
+}
+
+fn begin(action: JsValue, shot: JsValue) -> JsValue {
+    if !RECORDER.with(|r| r.borrow().recording) {
+        return JsValue::NULL;
+    }
+    let pre_screenshot = screenshot(&shot, "before", &action);
+    let step_json = to_json(&action);
+    let ground_truth = current_ground_truth();
+    RECORDER.with(|r| {
+        let mut r = r.borrow_mut();
+        let index = r.steps.len();
+        r.steps.push(EpisodeStep {
+            index,
+            t_start: now(),
+            t_end: None,
+            action: step_json,
+            outcome: "pending",
+            pre_screenshot,
+            post_screenshot: "null".to_string(),
+            ground_truth,
+            post_ground_truth: "null".to_string(),
+            wrong: Vec::new(),
+        });
+        JsValue::from_f64(index as f64)
+    })
+}
+
+fn end(id: JsValue, dispatched: JsValue, shot: JsValue) {
+    let Some(index) = id.as_f64().map(|i| i as usize) else { return };
+    let action = RECORDER.with(|r| {
+        r.borrow().steps.get(index).and_then(|s| JSON::parse(&s.action).ok())
+    });
+    let Some(action) = action else { return };
+    let post_screenshot = screenshot(&shot, "after", &action);
+    let post_ground_truth = current_ground_truth();
+    RECORDER.with(|r| {
+        let mut r = r.borrow_mut();
+        if let Some(step) = r.steps.get_mut(index) {
+            step.t_end = Some(now());
+            if step.outcome == "pending" {
+                step.outcome = if dispatched.is_falsy() { "failure" } else { "progress" };
+            }
+            step.post_screenshot = post_screenshot;
+            step.post_ground_truth = post_ground_truth;
+        }
+    });
+}
+
+/// Mark the most recent step as the one that completed the round.
+pub fn mark_success() {
+    RECORDER.with(|r| {
+        if let Some(step) = r.borrow_mut().steps.last_mut() {
+            step.outcome = "success";
+        }
+    });
+}
+
+/// Attach a wrong attempt to the most recent step and mark it "wrong".
+pub fn mark_wrong(attempt: &str) {
+    RECORDER.with(|r| {
+        if let Some(step) = r.borrow_mut().steps.last_mut() {
+            step.wrong.push(attempt.to_string());
+            step.outcome = "wrong";
+        }
+    });
+}
+
+/// The recorded session as JSONL — one line per step.
+pub fn export_jsonl() -> String {
+    RECORDER.with(|r| {
+        let r = r.borrow();
+        r.steps.iter()
+            .map(|s| format!(
+                r#"{{"episode":{},"index":{},"t_start":{},"t_end":{},"action":{},"outcome":"{}","wrong_attempts":[{}],"pre_screenshot":{},"post_screenshot":{},"ground_truth":{},"post_ground_truth":{}}}"#,
+                r.episode,
+                s.index,
+                s.t_start,
+                s.t_end.map_or("null".to_string(), |t| t.to_string()),
+                s.action,
+                s.outcome,
+                s.wrong.join(","),
+                s.pre_screenshot,
+                s.post_screenshot,
+                s.ground_truth,
+                s.post_ground_truth,
+            ))
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+pub(crate) fn set_fn(target: &JsValue, name: &str, f: &Function) {
+    let _ = Reflect::set(target, &JsValue::from_str(name), f);
+}
+
+/// Install `window.__recorder` once per page.
+pub fn install() {
+    let Some(window) = web_sys::window() else { return };
+    if Reflect::has(&window, &"__recorder".into()).unwrap_or(false) {
+        return;
+    }
+    let api = js_sys::Object::new();
+    let hooks = js_sys::Object::new();
+    let _ = Reflect::set(&hooks, &"before".into(), &JsValue::NULL);
+    let _ = Reflect::set(&hooks, &"after".into(), &JsValue::NULL);
+    let _ = Reflect::set(&api, &"hooks".into(), &hooks);
+
+    let begin_fn = Closure::<dyn Fn(JsValue, JsValue) -> JsValue>::new(begin);
+    let end_fn = Closure::<dyn Fn(JsValue, JsValue, JsValue)>::new(end);
+    let start_fn = Closure::<dyn Fn()>::new(|| RECORDER.with(|r| r.borrow_mut().recording = true));
+    let stop_fn = Closure::<dyn Fn()>::new(|| RECORDER.with(|r| r.borrow_mut().recording = false));
+    let clear_fn = Closure::<dyn Fn()>::new(|| RECORDER.with(|r| {
+        let mut r = r.borrow_mut();
+        r.steps.clear();
+        r.episode += 1;
+    }));
+    let export_fn = Closure::<dyn Fn() -> String>::new(export_jsonl);
+
+    set_fn(&api, "begin", begin_fn.as_ref().unchecked_ref());
+    set_fn(&api, "end", end_fn.as_ref().unchecked_ref());
+    set_fn(&api, "start", start_fn.as_ref().unchecked_ref());
+    set_fn(&api, "stop", stop_fn.as_ref().unchecked_ref());
+    set_fn(&api, "clear", clear_fn.as_ref().unchecked_ref());
+    set_fn(&api, "export", export_fn.as_ref().unchecked_ref());
+    // The API lives for the whole page
+    begin_fn.forget();
+    end_fn.forget();
+    start_fn.forget();
+    stop_fn.forget();
+    clear_fn.forget();
+    export_fn.forget();
+
+    let _ = Reflect::set(&window, &"__recorder".into(), &api);
+}
@@ -0,0 +1,1007 @@
+//! Solver — step-through automation that replays ground-truth steps.
+//!
+//! Installs `window.__solver` and a floating toolbar. Steps and target boxes
+//! come from `window.__groundTruth`, with the `#ground-truth` panel as a
+//! fallback; a target missing from both is looked up by `[data-label]`.
+//!
+//! - `step()` plays the next step, `solve()` plays the rest and returns a
+//!   pass / fail report from `window.__checkCompletion()`, `act(action)`
+//!   plays one arbitrary action, `reset()` rewinds.
+//! - `configure(opts)` sets the knobs declared at the top of the object
+//!   (pacing, typing and scroll modes, click jitter, drag paths, timeouts,
+//!   plan choice) and returns their values.
+//! - `setCapture(fn | 'postMessage')` takes a screenshot before and after
+//!   every action; the references land in the episode record.
+//! - `jumpTo(i)` / `runStep(i)` back the toolbar's step debugger.
+//!
+//! Every action is dispatched as the pointer, touch, wheel or key events a
+//! user would produce. After each one the solver bumps
+//! `window.__groundTruthEpoch` and waits for the payload to catch up, so the
+//! next step reads the re-resolved tree. Random choices (click points, drag
+//! paths, the plan among `alternatives`) are drawn from the round's seed and
+//! step index and stored on the recorded action as `point`, `path` and
+//! `plan`, so seeded replays repeat them.
+
+pub const SOLVER_JS: &str = r#"
+    // ── Solver: step-through automation for VLM training data ──
+    if (!window.__solver) {
+        window.__solver = {
+            _stepIndex: 0,
+            // Dispatch points per step index, for the debugger panel
+            _dispatched: [],
+            _lastStepsJson: '',
+            _capture: null,
+            _captureSeq: 0,
+            captureTimeoutMs: 5000,
+            // 'smooth' (programmatic scrollBy) or 'wheel' for `scroll` steps
+            scrollMode: 'smooth',
+            // Pixels per wheel notch, as Chrome reports with deltaMode 0
+            wheelStep: 100,
+            clickJitter: new URLSearchParams(location.search).get('jitter') === '1',
+            // Fraction of the bbox width / height kept clear on each side
+            jitterInset: 0.2,
+            // 'curved' or 'straight' drags, and the shape of a curved one
+            dragPath: 'curved',
+            dragCurvature: 0.3,
+            dragJitterPx: 1.5,
+            dragSteps: [12, 30],
+            // 'linear', 'easeInOut' or 'easeOut'
+            dragEasing: 'easeInOut',
+            // Pause after each step, between drag moves, and between typed characters
+            stepDelayMs: 300,
+            dragStepMs: 15,
+            typeCharDelayMs: 0,
+            // Longest wait for the ground truth of a bumped epoch
+            settleTimeoutMs: 1000,
+            // Longest a `wait_for` step polls for its target
+            waitForTimeoutMs: 10000,
+            // Corrective scrolls a click tries while something covers its target
+            occlusionRetries: 3,
+            // Debug cursor over dispatched pointer events: ?cursor=1 forces it on,
+            // ?cursor=0 off; otherwise it follows debug mode
+            showCursor: { '1': true, '0': false }[new URLSearchParams(location.search).get('cursor')],
+            // 'set' (whole value via the native setter) or 'keys' (per-character key events)
+            typeMode: 'set',
+            // 'seeded' (one of the alternative plans per round) or 'main' (always `steps`)
+            planMode: 'seeded',
+            // Stands in for the seed when drawing plans in unseeded runs
+            _planSalt: String(Math.random()),
+
+            // Set any of the public knobs above, e.g. configure({ typeCharDelayMs: 80 })
+            configure(opts) {
+                const keys = ['stepDelayMs', 'dragStepMs', 'typeCharDelayMs', 'typeMode', 'scrollMode',
+                              'wheelStep', 'clickJitter', 'jitterInset', 'captureTimeoutMs', 'settleTimeoutMs',
+                              'dragPath', 'dragCurvature', 'dragJitterPx', 'dragSteps', 'dragEasing',
+                              'waitForTimeoutMs', 'occlusionRetries', 'showCursor', 'planMode'];
+                for (const k of keys) if (opts && k in opts) this[k] = opts[k];
+                return Object.fromEntries(keys.map(k => [k, this[k]]));
+            },
+
+            setCapture(fn) { this._capture = fn || null; },
+
+            // Resolve to an image reference for `phase` ('before' / 'after'), or null
+            async _runCapture(phase, action, step) {
+                if (!this._capture) return null;
+                if (this._capture === 'postMessage') {
+                    const id = ++this._captureSeq;
+                    const target = window.parent !== window ? window.parent : window;
+                    return await new Promise(resolve => {
+                        const timer = setTimeout(() => { window.removeEventListener('message', onMsg); resolve(null); }, this.captureTimeoutMs);
+                        function onMsg(e) {
+                            const d = e.data;
+                            if (!d || d.type !== 'playground-capture-result' || d.id !== id) return;
+                            clearTimeout(timer);
+                            window.removeEventListener('message', onMsg);
+                            resolve(d.ref ?? null);
+                        }
+                        window.addEventListener('message', onMsg);
+                        target.postMessage({ type: 'playground-capture', id, phase, action, step }, '*');
+                    });
+                }
+                try {
+                    return (await this._capture(phase, action, step)) ?? null;
+                } catch (e) {
+                    console.warn('solver: capture failed', phase, e);
+                    return null;
+                }
+            },
+
+            getGroundTruth() {
+                // Schema v2: one structured payload published by GroundTruth
+                const gt = window.__groundTruth;
+                if (gt && gt.schema >= 2) {
+                    // Decoys (`?recovery=1`) are clickable by label too, after
+                    // the real targets so a shared label never resolves to one
+                    let targets = (gt.targets || []).concat(gt.decoys || []);
+                    // Events are dispatched in CSS pixels; undo `?coords=physical`
+                    if (gt.coordinate_space === 'physical' && gt.dpr) {
+                        targets = targets.map(t => ({ ...t, bbox: t.bbox.map(v => v / gt.dpr) }));
+                    }
+                    const plans = [{ name: gt.plan || 'main', steps: gt.steps || [] }].concat(gt.alternatives || []);
+                    const plan = plans[this._pickPlan(plans, gt)];
+                    return {
+                        targets, steps: plan.steps, plan: plans.length > 1 ? plan.name : null,
+                        outcome: gt.outcome || 'success',
+                        seed: gt.seed ?? null, seedCounter: gt.seed_counter ?? 0,
+                    };
+                }
+                // Legacy: scrape the prefixed lines of the panel
+                const panel = document.getElementById('ground-truth');
+                if (!panel) { console.warn('solver: ground-truth panel not found'); return { targets: [], steps: [] }; }
+                let targets = [], steps = [];
+                for (const div of panel.querySelectorAll(':scope > div')) {
+                    const t = div.textContent;
+                    if (t.startsWith('targets: ')) {
+                        try { targets = JSON.parse(t.slice(9)); } catch (e) { console.warn('solver: failed to parse targets', e); }
+                    } else if (t.startsWith('steps: ')) {
+                        try { steps = JSON.parse(t.slice(7)); } catch (e) { console.warn('solver: failed to parse steps', e); }
+                    }
+                }
+                return { targets, steps };
+            },
+
+            // Index of the plan to play this round: keyed by seed and round
+            // number, so every re-resolve of the round agrees
+            _pickPlan(plans, gt) {
+                if (plans.length < 2 || this.planMode !== 'seeded') return 0;
+                const c = this._check();
+                const rand = this._rng((gt.seed ?? this._planSalt) + ':' + (c ? c.round : 0) + ':plan');
+                return Math.floor(rand() * plans.length);
+            },
+
+            _bbox(label, targets) {
+                const t = targets.find(t => t.label === label);
+                if (!t) return null;
+                const [x, y, w, h] = t.bbox;
+                return { x, y, w, h, cx: x + w / 2, cy: y + h / 2 };
+            },
+
+            // The DOM element carrying `data-label="label"`, if any
+            _labelled(label) {
+                return document.querySelector('[data-label="' + CSS.escape(label) + '"]');
+            },
+
+            // `label`'s ground-truth box or, when the tree doesn't list it, the
+            // live box of its `data-label` element once scrolled into view
+            _locate(label, targets) {
+                const b = this._bbox(label, targets);
+                if (b) return b;
+                const el = this._labelled(label);
+                if (!el) return null;
+                el.scrollIntoView({ block: 'nearest', inline: 'nearest', behavior: 'instant' });
+                const r = el.getBoundingClientRect();
+                if (!r.width || !r.height) return null;
+                console.warn('solver: "' + label + '" is missing from the ground truth targets; using its live DOM box');
+                return { x: r.left, y: r.top, w: r.width, h: r.height, cx: r.left + r.width / 2, cy: r.top + r.height / 2 };
+            },
+
+            // Uniform [0, 1) draws from a 32-bit hash of `key` (mulberry32)
+            _rng(key) {
+                let h = 2166136261;
+                for (let i = 0; i < key.length; i++) { h ^= key.charCodeAt(i); h = Math.imul(h, 16777619); }
+                return () => {
+                    h = (h + 0x6D2B79F5) | 0;
+                    let t = Math.imul(h ^ (h >>> 15), 1 | h);
+                    t = (t + Math.imul(t ^ (t >>> 7), 61 | t)) ^ t;
+                    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+                };
+            },
+
+            // Where to click `label`: its center, or a jittered point inside
+            // the inset bbox when clickJitter is on
+            _clickPoint(label, gt, stepIndex) {
+                const b = this._bbox(label, gt.targets);
+                if (!b) return null;
+                if (!this.clickJitter) return { x: b.cx, y: b.cy };
+                const rand = gt.seed != null
+                    ? this._rng(gt.seed + ':' + gt.seedCounter + ':' + stepIndex)
+                    : Math.random;
+                const ix = b.w * this.jitterInset, iy = b.h * this.jitterInset;
+                return {
+                    x: Math.round(b.x + ix + rand() * (b.w - 2 * ix)),
+                    y: Math.round(b.y + iy + rand() * (b.h - 2 * iy)),
+                };
+            },
+
+            _dispatchAt(x, y, type, opts) {
+                this._trail(x, y, type);
+                const el = document.elementFromPoint(x, y);
+                if (!el) { console.warn('solver: nothing at', x, y); return null; }
+                const ev = new MouseEvent(type, {
+                    clientX: x, clientY: y, screenX: x, screenY: y,
+                    bubbles: true, cancelable: true, view: window, ...opts
+                });
+                el.dispatchEvent(ev);
+                return el;
+            },
+
+            // Move the debug cursor to (x, y): it shrinks while pressed and
+            // leaves a ripple where a click or context click lands
+            _trail(x, y, type) {
+                const show = this.showCursor ?? !!window.__debugMode;
+                if (!show) { if (this._cursor) this._cursor.style.display = 'none'; return; }
+                let c = this._cursor;
+                if (!c) {
+                    c = this._cursor = document.createElement('div');
+                    c.id = '__solver-cursor';
+                    c.style.cssText = 'position:fixed;left:0;top:0;width:14px;height:14px;margin:-9px 0 0 -9px;border-radius:50%;background:rgba(239,68,68,0.85);border:2px solid white;box-shadow:0 0 6px rgba(0,0,0,0.5);pointer-events:none;z-index:99998;transition:left 0.12s linear,top 0.12s linear,transform 0.08s;';
+                    document.body.appendChild(c);
+                }
+                c.style.display = 'block';
+                c.style.left = x + 'px';
+                c.style.top = y + 'px';
+                if (type === 'mousedown' || type === 'pointerdown') c.style.transform = 'scale(0.7)';
+                if (type === 'mouseup' || type === 'pointerup') c.style.transform = '';
+                if (type === 'click' || type === 'contextmenu') {
+                    const r = document.createElement('div');
+                    r.style.cssText = 'position:fixed;left:' + x + 'px;top:' + y + 'px;width:40px;height:40px;margin:-22px 0 0 -22px;border-radius:50%;border:2px solid rgba(239,68,68,0.9);pointer-events:none;z-index:99998;';
+                    document.body.appendChild(r);
+                    r.animate(
+                        [{ transform: 'scale(0.2)', opacity: 1 }, { transform: 'scale(1.2)', opacity: 0 }],
+                        { duration: 450, easing: 'ease-out' },
+                    ).onfinish = () => r.remove();
+                }
+            },
+
+            _isTouch() { return !!(window.__deviceProfile && window.__deviceProfile.touch); },
+
+            _pointerAt(x, y, type, el) {
+                this._trail(x, y, type);
+                el = el || document.elementFromPoint(x, y);
+                if (!el) return null;
+                el.dispatchEvent(new PointerEvent(type, {
+                    clientX: x, clientY: y, screenX: x, screenY: y,
+                    pointerId: 1, isPrimary: true, pointerType: this._isTouch() ? 'touch' : 'mouse',
+                    bubbles: true, cancelable: true, view: window
+                }));
+                return el;
+            },
+
+            _touchAt(x, y, type, el) {
+                el = el || document.elementFromPoint(x, y);
+                if (!el || typeof Touch === 'undefined') return el;
+                const touch = new Touch({ identifier: 1, target: el, clientX: x, clientY: y, screenX: x, screenY: y });
+                const active = type === 'touchend' ? [] : [touch];
+                el.dispatchEvent(new TouchEvent(type, {
+                    touches: active, targetTouches: active, changedTouches: [touch],
+                    bubbles: true, cancelable: true, view: window
+                }));
+                return el;
+            },
+
+            async _doClick(label, targets, point) {
+                const b = this._locate(label, targets);
+                if (!b) { console.warn('solver: target not found:', label, 'available:', targets.map(t=>t.label)); return; }
+                const { x: cx, y: cy } = await this._unocclude(label, point ? point.x : b.cx, point ? point.y : b.cy);
+                console.log('solver: click "' + label + '" at (' + cx + ', ' + cy + ') bbox [' + b.x + ',' + b.y + ',' + b.w + ',' + b.h + ']');
+                // Listen on the labelled element itself: a click that lands
+                // elsewhere (layout moved under a transform) never reaches it
+                const own = this._labelled(label);
+                let received = false;
+                const mark = () => { received = true; };
+                own?.addEventListener('click', mark, true);
+                let el = this._clickAt(cx, cy);
+                if (own && !received && own.isConnected) {
+                    const r = own.getBoundingClientRect();
+                    const rx = r.left + r.width / 2, ry = r.top + r.height / 2;
+                    console.warn('solver: click on "' + label + '" hit', el?.tagName, el?.getAttribute('data-label') || '',
+                        '- retrying at live box (' + rx + ', ' + ry + ')');
+                    this._dispatched[this._stepIndex]?.push([rx, ry]);
+                    el = this._clickAt(rx, ry);
+                    if (!received) console.warn('solver: retry missed "' + label + '" too');
+                }
+                own?.removeEventListener('click', mark, true);
+                if (el) console.log('solver: hit', el.tagName, el.className, el.getAttribute('data-label') || el.textContent?.slice(0,30));
+            },
+
+            // One click or tap at (x, y); returns the element pressed
+            _clickAt(x, y) {
+                let el;
+                if (this._isTouch()) {
+                    // Tap: touch + pointer events, then the mouse compat sequence
+                    el = this._pointerAt(x, y, 'pointerdown');
+                    this._touchAt(x, y, 'touchstart');
+                    this._pointerAt(x, y, 'pointerup');
+                    this._touchAt(x, y, 'touchend');
+                    this._dispatchAt(x, y, 'mousedown');
+                    this._dispatchAt(x, y, 'mouseup');
+                    this._dispatchAt(x, y, 'click');
+                } else {
+                    // Full mouse event sequence at ground truth coordinates
+                    el = this._dispatchAt(x, y, 'pointerdown');
+                    this._dispatchAt(x, y, 'mousedown');
+                    this._dispatchAt(x, y, 'pointerup');
+                    this._dispatchAt(x, y, 'mouseup');
+                    this._dispatchAt(x, y, 'click');
+                }
+                return el;
+            },
+
+            // If a sticky header or floating button sits on top of `label` at
+            // (x, y), scroll the target's scroller just far enough to clear it
+            // and return the point moved along with the target
+            async _unocclude(label, x, y) {
+                const own = this._labelled(label);
+                if (!own) return { x, y };
+                for (let i = 0; i < this.occlusionRetries; i++) {
+                    const hit = document.elementFromPoint(x, y);
+                    if (!hit || own.contains(hit) || hit.contains(own)) return { x, y };
+                    const scroller = this._scrollerOf(own);
+                    if (!scroller) break;
+                    const o = hit.getBoundingClientRect();
+                    const r = own.getBoundingClientRect();
+                    const s = scroller.getBoundingClientRect();
+                    // Cover in the top half (a header): bring the target down below it;
+                    // in the bottom half (a floating button): lift it above it
+                    const dy = (o.top + o.bottom) / 2 < (s.top + s.bottom) / 2
+                        ? -(o.bottom - r.top + 8)
+                        : r.bottom - o.top + 8;
+                    console.log('solver: "' + label + '" covered by', hit.tagName, hit.getAttribute('data-label') || '', '- scrolling by', dy);
+                    const before = scroller.scrollTop;
+                    scroller.scrollBy({ top: dy, behavior: 'instant' });
+                    if (scroller.scrollTop === before) break;
+                    await new Promise(res => requestAnimationFrame(res));
+                    const moved = own.getBoundingClientRect();
+                    x += moved.left - r.left;
+                    y += moved.top - r.top;
+                }
+                if (own.contains(document.elementFromPoint(x, y))) return { x, y };
+                console.warn('solver: "' + label + '" still covered at', x, y);
+                return { x, y };
+            },
+
+            async _doType(label, value, targets) {
+                const b = this._locate(label, targets);
+                if (!b) { console.warn('solver: target not found:', label); return; }
+                let el = document.elementFromPoint(b.cx, b.cy);
+                // Pointer-disabled fields are not hit-testable — type into
+                // the focused field instead of stealing focus from it.
+                if (!el || !('value' in el)) el = document.activeElement;
+                if (!el) return;
+                if (el !== document.activeElement) el.focus();
+                const setter = Object.getOwnPropertyDescriptor(HTMLInputElement.prototype, 'value')?.set
+                             || Object.getOwnPropertyDescriptor(HTMLTextAreaElement.prototype, 'value')?.set;
+                const set = v => { if (setter) setter.call(el, v); else el.value = v; };
+                if (this.typeMode !== 'keys') {
+                    set(value);
+                    el.dispatchEvent(new Event('input', { bubbles: true }));
+                    return;
+                }
+                // Real typing: keydown / keypress / input / keyup per character,
+                // with Shift held around capitals and shifted symbols
+                set('');
+                const base = { bubbles: true, cancelable: true, view: window };
+                const shiftOpts = { ...base, key: 'Shift', code: 'ShiftLeft' };
+                for (const ch of value) {
+                    const k = this._keyFor(ch);
+                    const opts = { ...base, key: ch, code: k.code, shiftKey: k.shift };
+                    if (k.shift) el.dispatchEvent(new KeyboardEvent('keydown', { ...shiftOpts, shiftKey: true }));
+                    if (el.dispatchEvent(new KeyboardEvent('keydown', opts))) {
+                        el.dispatchEvent(new KeyboardEvent('keypress', { ...opts, charCode: ch.charCodeAt(0) }));
+                        set(el.value + ch);
+                        el.dispatchEvent(new InputEvent('input', { bubbles: true, inputType: 'insertText', data: ch }));
+                    }
+                    el.dispatchEvent(new KeyboardEvent('keyup', opts));
+                    if (k.shift) el.dispatchEvent(new KeyboardEvent('keyup', shiftOpts));
+                    if (this.typeCharDelayMs > 0) await new Promise(r => setTimeout(r, this.typeCharDelayMs));
+                }
+            },
+
+            // KeyboardEvent `code` for a US layout, and whether it needs Shift
+            _keyFor(ch) {
+                const shifted = '~!@#$%^&*()_+{}|:"<>?';
+                const plain = '`1234567890-=[]\\;\',./';
+                const codes = ['Backquote', 'Digit1', 'Digit2', 'Digit3', 'Digit4', 'Digit5', 'Digit6',
+                               'Digit7', 'Digit8', 'Digit9', 'Digit0', 'Minus', 'Equal', 'BracketLeft',
+                               'BracketRight', 'Backslash', 'Semicolon', 'Quote', 'Comma', 'Period', 'Slash'];
+                if (/[a-z]/i.test(ch)) return { code: 'Key' + ch.toUpperCase(), shift: ch !== ch.toLowerCase() };
+                if (ch === ' ') return { code: 'Space', shift: false };
+                let i = plain.indexOf(ch);
+                if (i >= 0) return { code: codes[i], shift: false };
+                i = shifted.indexOf(ch);
+                if (i >= 0) return { code: codes[i], shift: true };
+                return { code: '', shift: false };
+            },
+
+            // Pointer positions after the press, ending exactly on the drop
+            // point: a jittered, eased bezier, or the straight line
+            _dragPath(fromLabel, toLabel, gt, stepIndex) {
+                const from = this._locate(fromLabel, gt.targets);
+                const to = this._locate(toLabel, gt.targets);
+                if (!from || !to) return null;
+                if (this.dragPath === 'straight') {
+                    return Array.from({ length: 10 }, (_, i) => {
+                        const t = (i + 1) / 10;
+                        return [from.cx + (to.cx - from.cx) * t, from.cy + (to.cy - from.cy) * t];
+                    });
+                }
+                const rand = gt.seed != null
+                    ? this._rng(gt.seed + ':' + gt.seedCounter + ':' + stepIndex + ':drag')
+                    : Math.random;
+                const dx = to.cx - from.cx, dy = to.cy - from.cy;
+                const len = Math.hypot(dx, dy) || 1;
+                // Unit normal; both control points bow off it by their own amount
+                const nx = -dy / len, ny = dx / len;
+                const bow = () => (rand() * 2 - 1) * this.dragCurvature * len;
+                const b1 = bow(), b2 = bow();
+                const c1 = [from.cx + dx / 3 + nx * b1, from.cy + dy / 3 + ny * b1];
+                const c2 = [from.cx + dx * 2 / 3 + nx * b2, from.cy + dy * 2 / 3 + ny * b2];
+                const ease = {
+                    linear: t => t,
+                    easeOut: t => 1 - (1 - t) * (1 - t),
+                    easeInOut: t => t < 0.5 ? 2 * t * t : 1 - 2 * (1 - t) * (1 - t),
+                }[this.dragEasing] || (t => t);
+                const [lo, hi] = this.dragSteps;
+                const n = lo + Math.floor(rand() * (hi - lo + 1));
+                const path = [];
+                for (let i = 1; i <= n; i++) {
+                    const t = ease(i / n), u = 1 - t;
+                    const at = (p0, p1, p2, p3) => u * u * u * p0 + 3 * u * u * t * p1 + 3 * u * t * t * p2 + t * t * t * p3;
+                    let x = at(from.cx, c1[0], c2[0], to.cx);
+                    let y = at(from.cy, c1[1], c2[1], to.cy);
+                    if (i < n) {
+                        x += (rand() * 2 - 1) * this.dragJitterPx;
+                        y += (rand() * 2 - 1) * this.dragJitterPx;
+                    }
+                    path.push([Math.round(x * 10) / 10, Math.round(y * 10) / 10]);
+                }
+                return path;
+            },
+
+            async _doDrag(fromLabel, toLabel, targets, path) {
+                const from = this._locate(fromLabel, targets);
+                const to = this._locate(toLabel, targets);
+                if (!from || !to || !path) { console.warn('solver: drag targets not found:', fromLabel, toLabel); return; }
+                // Dispatch both pointer and mouse events (matching _doClick pattern)
+                // — Dioxus may listen for pointer events rather than mouse events.
+                const touch = this._isTouch();
+                if (touch) {
+                    this._pointerAt(from.cx, from.cy, 'pointerdown');
+                    this._touchAt(from.cx, from.cy, 'touchstart');
+                } else {
+                    this._dispatchAt(from.cx, from.cy, 'pointerdown');
+                }
+                const el = this._dispatchAt(from.cx, from.cy, 'mousedown');
+                if (!el) return;
+                await new Promise(r => setTimeout(r, 30));
+                for (const [mx, my] of path) {
+                    const moveEl = document.elementFromPoint(mx, my) || el;
+                    this._trail(mx, my, 'mousemove');
+                    if (touch) {
+                        this._pointerAt(mx, my, 'pointermove', moveEl);
+                        // Touch events stay on the element the touch started on
+                        this._touchAt(mx, my, 'touchmove', el);
+                    } else {
+                        moveEl.dispatchEvent(new PointerEvent('pointermove', {
+                            clientX: mx, clientY: my, bubbles: true, cancelable: true, view: window
+                        }));
+                    }
+                    moveEl.dispatchEvent(new MouseEvent('mousemove', {
+                        clientX: mx, clientY: my, bubbles: true, cancelable: true, view: window
+                    }));
+                    await new Promise(r => setTimeout(r, this.dragStepMs));
+                }
+                const endEl = document.elementFromPoint(to.cx, to.cy) || el;
+                if (touch) {
+                    this._pointerAt(to.cx, to.cy, 'pointerup', endEl);
+                    this._touchAt(to.cx, to.cy, 'touchend', el);
+                } else {
+                    endEl.dispatchEvent(new PointerEvent('pointerup', {
+                        clientX: to.cx, clientY: to.cy, bubbles: true, cancelable: true, view: window
+                    }));
+                }
+                endEl.dispatchEvent(new MouseEvent('mouseup', {
+                    clientX: to.cx, clientY: to.cy, bubbles: true, cancelable: true, view: window
+                }));
+            },
+
+            async _doRightClick(label, targets, point) {
+                const b = this._locate(label, targets);
+                if (!b) { console.warn('solver: target not found:', label); return; }
+                this._dispatchAt(point ? point.x : b.cx, point ? point.y : b.cy, 'contextmenu');
+            },
+
+            async _doScroll(label, targets, within) {
+                const b = this._locate(label, targets);
+                if (!b) { console.warn('solver: scroll target not found:', label); return; }
+                if (within) {
+                    // Scroll the named container, not the page, to center the target in it
+                    const c = this._locate(within, targets);
+                    const box = this._labelled(within);
+                    if (!c || !box) { console.warn('solver: scroll container not found:', within); return; }
+                    const dx = b.cx - c.cx, dy = b.cy - c.cy;
+                    if (this.scrollMode === 'wheel') {
+                        await this._wheel(c.cx, c.cy, dx, dy);
+                        return;
+                    }
+                    box.scrollBy({ left: dx, top: dy, behavior: 'smooth' });
+                    await new Promise(r => setTimeout(r, 400));
+                    return;
+                }
+                const vp = document.getElementById('viewport');
+                if (!vp) return;
+                const rect = vp.getBoundingClientRect();
+                // Scroll so the target center is visible in the viewport
+                const scrollX = b.cx - rect.left - rect.width / 2;
+                const scrollY = b.cy - rect.top - rect.height / 2;
+                if (this.scrollMode === 'wheel') {
+                    await this._wheel(rect.left + rect.width / 2, rect.top + rect.height / 2, scrollX, scrollY);
+                    return;
+                }
+                vp.scrollBy({ left: scrollX, top: scrollY, behavior: 'smooth' });
+                await new Promise(r => setTimeout(r, 400));
+            },
+
+            async _doScrollBy(label, dx, dy, targets) {
+                const b = this._locate(label, targets);
+                if (!b) { console.warn('solver: scroll target not found:', label); return; }
+                console.log('solver: wheel over "' + label + '" by (' + dx + ', ' + dy + ')');
+                await this._wheel(b.cx, b.cy, dx, dy);
+            },
+
+            // Clear a text field like a user would: select all, then Backspace
+            async _doErase(label, targets) {
+                const b = this._locate(label, targets);
+                if (!b) { console.warn('solver: target not found:', label); return; }
+                let el = document.elementFromPoint(b.cx, b.cy);
+                if (!el || !('value' in el)) el = document.activeElement;
+                if (!el || !('value' in el)) return;
+                if (el !== document.activeElement) el.focus();
+                el.select?.();
+                const opts = { key: 'Backspace', code: 'Backspace', bubbles: true, cancelable: true, view: window };
+                el.dispatchEvent(new KeyboardEvent('keydown', opts));
+                const setter = Object.getOwnPropertyDescriptor(Object.getPrototypeOf(el), 'value')?.set;
+                if (setter) setter.call(el, '');
+                else el.value = '';
+                el.dispatchEvent(new InputEvent('input', { bubbles: true, inputType: 'deleteContentBackward' }));
+                el.dispatchEvent(new KeyboardEvent('keyup', opts));
+            },
+
+            // Set a native range input to `value`, as arrow keys would leave it
+            async _doSetSlider(label, value, targets) {
+                const b = this._locate(label, targets);
+                if (!b) { console.warn('solver: slider not found:', label); return; }
+                let el = document.elementFromPoint(b.cx, b.cy);
+                if (!el || el.type !== 'range') el = el?.querySelector?.('input[type=range]') || null;
+                if (!el) { console.warn('solver: no range input under', label); return; }
+                console.log('solver: set "' + label + '" to', value);
+                el.focus();
+                const setter = Object.getOwnPropertyDescriptor(HTMLInputElement.prototype, 'value').set;
+                setter.call(el, String(value));
+                el.dispatchEvent(new Event('input', { bubbles: true }));
+                el.dispatchEvent(new Event('change', { bubbles: true }));
+            },
+
+            async _doSelectText(label, text, targets) {
+                const b = this._locate(label, targets);
+                if (!b) { console.warn('solver: passage not found:', label); return; }
+                const hit = document.elementFromPoint(b.cx, b.cy);
+                const root = hit?.closest?.('[data-label]') || hit;
+                if (!root) return;
+                // Find `text` across the passage's text nodes
+                const walker = document.createTreeWalker(root, NodeFilter.SHOW_TEXT);
+                const nodes = [];
+                let full = '';
+                for (let n = walker.nextNode(); n; n = walker.nextNode()) {
+                    nodes.push({ node: n, start: full.length });
+                    full += n.nodeValue;
+                }
+                const at = full.indexOf(text);
+                if (at < 0) { console.warn('solver: "' + text + '" not in', label); return; }
+                const locate = (i) => {
+                    const hitNode = nodes.filter(n => n.start <= i).pop();
+                    return [hitNode.node, i - hitNode.start];
+                };
+                const range = document.createRange();
+                range.setStart(...locate(at));
+                range.setEnd(...locate(at + text.length));
+                const rects = range.getClientRects();
+                if (!rects.length) return;
+                const first = rects[0], last = rects[rects.length - 1];
+                const sx = first.left + 1, sy = first.top + first.height / 2;
+                const ex = last.right - 1, ey = last.top + last.height / 2;
+                console.log('solver: select "' + text + '" in "' + label + '"');
+                this._dispatchAt(sx, sy, 'pointerdown');
+                this._dispatchAt(sx, sy, 'mousedown');
+                const sel = window.getSelection();
+                sel.removeAllRanges();
+                sel.addRange(range);
+                await new Promise(r => setTimeout(r, this.dragStepMs));
+                this._dispatchAt(ex, ey, 'mousemove');
+                this._dispatchAt(ex, ey, 'pointerup');
+                this._dispatchAt(ex, ey, 'mouseup');
+            },
+
+            async _doHover(label, targets) {
+                const b = this._locate(label, targets);
+                if (!b) { console.warn('solver: hover target not found:', label); return; }
+                const el = document.elementFromPoint(b.cx, b.cy);
+                if (!el) return;
+                console.log('solver: hover "' + label + '" at (' + b.cx + ', ' + b.cy + ')');
+                const opts = { clientX: b.cx, clientY: b.cy, bubbles: true, cancelable: true, view: window };
+                this._trail(b.cx, b.cy, 'mousemove');
+                el.dispatchEvent(new PointerEvent('pointerover', opts));
+                el.dispatchEvent(new MouseEvent('mouseover', opts));
+                // enter events don't bubble; the pointer enters every ancestor too
+                for (let n = el; n && n !== document.body; n = n.parentElement) {
+                    n.dispatchEvent(new PointerEvent('pointerenter', { ...opts, bubbles: false }));
+                    n.dispatchEvent(new MouseEvent('mouseenter', { ...opts, bubbles: false }));
+                }
+                el.dispatchEvent(new MouseEvent('mousemove', opts));
+            },
+
+            async _doWait(ms, until) {
+                const deadline = Date.now() + (ms || 0);
+                console.log('solver: wait', ms, 'ms' + (until ? ' for "' + until + '"' : ''));
+                while (Date.now() < deadline) {
+                    if (until && this.getGroundTruth().targets.some(t => t.label === until)) return;
+                    await new Promise(r => setTimeout(r, 50));
+                }
+            },
+
+            // Whether an element labelled `label` is laid out and visible
+            _isShown(label) {
+                for (const el of document.querySelectorAll('[data-label="' + CSS.escape(label) + '"]')) {
+                    const r = el.getBoundingClientRect();
+                    const cs = getComputedStyle(el);
+                    if (r.width > 0 && r.height > 0 && cs.visibility !== 'hidden' && cs.opacity !== '0') return true;
+                }
+                return false;
+            },
+
+            async _doWaitFor(label) {
+                const deadline = Date.now() + this.waitForTimeoutMs;
+                console.log('solver: wait for "' + label + '"');
+                while (Date.now() < deadline) {
+                    if (this._isShown(label)) return;
+                    await new Promise(r => setTimeout(r, 50));
+                }
+                console.warn('solver: "' + label + '" did not appear within', this.waitForTimeoutMs, 'ms');
+            },
+
+            // Nearest ancestor of `el` that can scroll, else the viewport
+            _scrollerOf(el) {
+                for (let n = el; n && n !== document.body; n = n.parentElement) {
+                    const cs = getComputedStyle(n);
+                    const canY = /(auto|scroll)/.test(cs.overflowY) && n.scrollHeight > n.clientHeight;
+                    const canX = /(auto|scroll)/.test(cs.overflowX) && n.scrollWidth > n.clientWidth;
+                    if (canY || canX) return n;
+                }
+                return document.getElementById('viewport');
+            },
+
+            // Scroll by (dx, dy) as a train of wheel events at (x, y). Synthetic
+            // wheel events don't scroll the page, so each notch that isn't
+            // cancelled is applied to the scroller under the pointer.
+            async _wheel(x, y, dx, dy) {
+                const step = this.wheelStep;
+                const notches = Math.max(1, Math.ceil(Math.max(Math.abs(dx), Math.abs(dy)) / step));
+                const el = document.elementFromPoint(x, y) || document.getElementById('viewport');
+                if (!el) return;
+                this._dispatchAt(x, y, 'mousemove');
+                const scroller = this._scrollerOf(el);
+                let doneX = 0, doneY = 0;
+                for (let i = 1; i <= notches; i++) {
+                    const sx = Math.round(dx * i / notches) - doneX;
+                    const sy = Math.round(dy * i / notches) - doneY;
+                    doneX += sx; doneY += sy;
+                    const ev = new WheelEvent('wheel', {
+                        clientX: x, clientY: y, deltaX: sx, deltaY: sy, deltaMode: 0,
+                        bubbles: true, cancelable: true, view: window
+                    });
+                    if (el.dispatchEvent(ev) && scroller) scroller.scrollBy(sx, sy);
+                    await new Promise(r => setTimeout(r, 30));
+                }
+            },
+
+            // Press a key or shortcut like "Ctrl+Shift+P" / "Enter" on the
+            // focused element (events bubble up to document listeners).
+            async _doKey(combo) {
+                const parts = combo.split('+');
+                const name = parts.pop();
+                const mods = parts.map(p => p.toLowerCase());
+                const shift = mods.includes('shift');
+                const key = name.length === 1 ? (shift ? name.toUpperCase() : name.toLowerCase()) : name;
+                const code = name.length === 1 && /[a-z]/i.test(name) ? 'Key' + name.toUpperCase() : name;
+                const opts = {
+                    key, code, bubbles: true, cancelable: true, view: window,
+                    ctrlKey: mods.includes('ctrl'), shiftKey: shift,
+                    altKey: mods.includes('alt'), metaKey: mods.includes('meta'),
+                };
+                const el = document.activeElement || document.body;
+                console.log('solver: key "' + combo + '" on', el.tagName);
+                el.dispatchEvent(new KeyboardEvent('keydown', opts));
+                el.dispatchEvent(new KeyboardEvent('keyup', opts));
+            },
+
+            async _perform(action, targets, point, path) {
+                switch (action.action) {
+                    case 'click':       await this._doClick(action.target, targets, point); break;
+                    case 'type':        await this._doType(action.target, action.value, targets); break;
+                    case 'drag':        await this._doDrag(action.from, action.to, targets, path); break;
+                    case 'right_click': await this._doRightClick(action.target, targets, point); break;
+                    case 'scroll':      await this._doScroll(action.target, targets, action.within); break;
+                    case 'scroll_by':   await this._doScrollBy(action.target, action.dx, action.dy, targets); break;
+                    case 'erase':       await this._doErase(action.target, targets); break;
+                    case 'key':         await this._doKey(action.key); break;
+                    case 'set_slider':  await this._doSetSlider(action.target, action.value, targets); break;
+                    case 'select_text': await this._doSelectText(action.target, action.text, targets); break;
+                    case 'hover':       await this._doHover(action.target, targets); break;
+                    case 'wait':        await this._doWait(action.ms, action.until); break;
+                    case 'wait_for':    await this._doWaitFor(action.target); break;
+                    default: throw new Error('unknown action: ' + action.action);
+                }
+            },
+
+            // Bump the ground truth epoch and wait for GroundTruth to publish
+            // the tree re-resolved at it. Resolves to whether it did in time.
+            async _settle() {
+                const gte = window.__groundTruthEpoch;
+                if (!gte || !gte.bump) return false;
+                const n = gte.bump();
+                const deadline = Date.now() + this.settleTimeoutMs;
+                while (Date.now() < deadline) {
+                    const gt = window.__groundTruth;
+                    if (gt && gt.epoch >= n) return true;
+                    await new Promise(r => setTimeout(r, 20));
+                }
+                console.warn('solver: ground truth did not settle at epoch', n);
+                return false;
+            },
+
+            // Perform one externally chosen action (same shape as a ground
+            // truth step) against the current targets, outside the step list
+            async act(action) {
+                const gt = this.getGroundTruth();
+                const labels = this._requiredLabels(action);
+                const dispatched = labels.every(l => gt.targets.some(t => t.label === l));
+                const clicks = action.action === 'click' || action.action === 'right_click';
+                const point = clicks ? this._clickPoint(action.target, gt, this._stepIndex) : null;
+                const path = action.action === 'drag' ? this._dragPath(action.from, action.to, gt, this._stepIndex) : null;
+                const recorded = point ? { ...action, point: [point.x, point.y] } : path ? { ...action, path } : action;
+                const recId = window.__recorder ? window.__recorder.begin(recorded) : null;
+                await this._perform(action, gt.targets, point, path);
+                await new Promise(r => setTimeout(r, this.stepDelayMs));
+                await this._settle();
+                if (recId != null) window.__recorder.end(recId, dispatched);
+                return { ...recorded, dispatched };
+            },
+
+            // Restart from the first step when the ground truth re-resolved
+            // to a different step list.
+            _syncSteps(gt) {
+                const stepsJson = JSON.stringify(gt.steps);
+                if (stepsJson !== this._lastStepsJson) {
+                    this._stepIndex = 0;
+                    this._lastStepsJson = stepsJson;
+                    this._dispatched = [];
+                }
+            },
+
+            async step() {
+                const gt = this.getGroundTruth();
+                this._syncSteps(gt);
+                if (!gt.steps.length || this._stepIndex >= gt.steps.length) {
+                    console.log('solver: no more steps');
+                    return null;
+                }
+                const action = gt.steps[this._stepIndex];
+                // A step counts as dispatched when every label it names is on screen
+                const labels = this._requiredLabels(action);
+                const dispatched = labels.every(l => gt.targets.some(t => t.label === l));
+                const clicks = action.action === 'click' || action.action === 'right_click';
+                const point = clicks ? this._clickPoint(action.target, gt, this._stepIndex) : null;
+                const path = action.action === 'drag' ? this._dragPath(action.from, action.to, gt, this._stepIndex) : null;
+                const recorded = {
+                    ...action, ...(point && { point: [point.x, point.y] }), ...(path && { path }), ...(gt.plan && { plan: gt.plan }),
+                };
+                const shotBefore = await this._runCapture('before', recorded, this._stepIndex);
+                const recId = window.__recorder ? window.__recorder.begin(recorded, shotBefore) : null;
+                this._dispatched[this._stepIndex] = this._coordsOf(action, gt.targets, point);
+                await this._perform(action, gt.targets, point, path);
+                this._stepIndex++;
+                await new Promise(r => setTimeout(r, this.stepDelayMs));
+                await this._settle();
+                const shotAfter = await this._runCapture('after', recorded, this._stepIndex - 1);
+                if (recId != null) window.__recorder.end(recId, dispatched, shotAfter);
+                return {
+                    step: this._stepIndex, ...recorded, dispatched, at: this._dispatched[this._stepIndex - 1],
+                    screenshots: { before: shotBefore, after: shotAfter },
+                };
+            },
+
+            // Labels that must be on screen for `action` to count as dispatched
+            _requiredLabels(action) {
+                if (action.action === 'wait_for') return [];
+                return [action.target, action.from, action.to].filter(Boolean);
+            },
+
+            // Points an action is dispatched at: [[x, y], ...] (empty for keys)
+            _coordsOf(action, targets, point) {
+                if (point) return [[point.x, point.y]];
+                return [action.target, action.from, action.to]
+                    .filter(Boolean)
+                    .map(l => this._bbox(l, targets))
+                    .filter(Boolean)
+                    .map(b => [Math.round(b.cx), Math.round(b.cy)]);
+            },
+
+            // Make step `i` of the current steps the next one `step()` runs
+            jumpTo(i) {
+                this._lastStepsJson = JSON.stringify(this.getGroundTruth().steps);
+                this._stepIndex = Math.max(0, i);
+            },
+
+            // Re-run step `i` (and leave the cursor after it)
+            async runStep(i) {
+                this.jumpTo(i);
+                return await this.step();
+            },
+
+            _check() { return window.__checkCompletion ? window.__checkCompletion() : null; },
+
+            // Replay the round's steps, then verify the level accepted them.
+            // Resolves to (and keeps as `lastReport`) { pass, completion,
+            // progress, steps, divergedStep, divergedAction }.
+            async solve() {
+                this._stepIndex = 0;
+                const before = this._check();
+                const outcome = this.getGroundTruth().outcome;
+                const done = [];
+                while (done.length < 50) {
+                    const gt = this.getGroundTruth();
+                    this._syncSteps(gt);
+                    if (!gt.steps.length || this._stepIndex >= gt.steps.length) break;
+                    done.push(await this.step());
+                    const now = this._check();
+                    if (before && now && now.round > before.round) break;
+                }
+                const after = this._check();
+                const solved = !!(before && after && after.round > before.round);
+                const pass = outcome === 'impossible'
+                    ? done.length === 0
+                    : solved || (after && after.completion === 'complete');
+                // First step whose targets were missing, else the last one run
+                let diverged = null;
+                if (!pass && done.length) {
+                    const i = done.findIndex(s => !s.dispatched);
+                    diverged = i >= 0 ? i : done.length - 1;
+                }
+                const report = {
+                    pass: !!pass,
+                    completion: solved ? 'complete' : (after ? after.completion : null),
+                    progress: solved ? 1 : (after ? after.progress : null),
+                    steps: done.length,
+                    plan: (done.find(s => s && s.plan) || {}).plan ?? null,
+                    divergedStep: diverged,
+                    divergedAction: diverged != null ? (({ screenshots, step, ...a }) => a)(done[diverged]) : null,
+                };
+                this.lastReport = report;
+                if (report.pass) console.log('solver: verified — level complete after', done.length, 'steps');
+                else console.warn('solver: verification failed', report);
+                return report;
+            },
+
+            reset() {
+                this._stepIndex = 0;
+                this._dispatched = [];
+                if (this._cursor) this._cursor.style.display = 'none';
+            }
+        };
+        console.log('solver: ready — use __solver.step() / __solver.solve() / __solver.reset()');
+
+        // Inject step toolbar
+        const bar = document.createElement('div');
+        bar.id = '__solver-bar';
+        bar.style.cssText = 'position:fixed;top:8px;right:8px;z-index:99999;display:flex;gap:6px;font-family:system-ui,sans-serif;';
+        const mkBtn = (label, fn) => {
+            const b = document.createElement('button');
+            b.textContent = label;
+            b.style.cssText = 'padding:6px 14px;border:none;border-radius:6px;font-size:13px;font-weight:600;cursor:pointer;color:white;background:#4f46e5;opacity:0.9;transition:opacity 0.1s;';
+            b.onmouseenter = () => b.style.opacity = '1';
+            b.onmouseleave = () => b.style.opacity = '0.9';
+            b.onclick = fn;
+            return b;
+        };
+        bar.appendChild(mkBtn('Step', async () => {
+            try {
+                console.log('solver: Step button clicked');
+                const gt = window.__solver.getGroundTruth();
+                console.log('solver: ground truth =', JSON.stringify(gt).slice(0, 200));
+                const r = await window.__solver.step();
+                console.log('solver: step result =', r);
+            } catch (e) { console.error('solver: step error', e); }
+        }));
+        bar.appendChild(mkBtn('Solve', async () => {
+            try {
+                console.log('solver: Solve button clicked');
+                await window.__solver.solve();
+                console.log('solver: solve done');
+            } catch (e) { console.error('solver: solve error', e); }
+        }));
+        const resetBtn = mkBtn('Reset', () => {
+            console.log('solver: Reset button clicked');
+            window.__solver.reset();
+        });
+        resetBtn.style.background = '#6b7280';
+        bar.appendChild(resetBtn);
+        // Show / hide the ground-truth bbox overlay (on by default in debug mode)
+        const boxesBtn = mkBtn('Boxes', () => {
+            const off = document.body.dataset.gtOverlay !== 'off';
+            document.body.dataset.gtOverlay = off ? 'off' : 'on';
+            boxesBtn.style.background = off ? '#6b7280' : '#0d9488';
+        });
+        boxesBtn.style.background = '#0d9488';
+        bar.appendChild(boxesBtn);
+
+        // Step debugger: resolved steps, the cursor, dispatch points, and
+        // jump / re-run controls; the cursor step's targets are outlined
+        const panel = document.createElement('div');
+        panel.id = '__solver-panel';
+        panel.style.cssText = 'position:fixed;top:48px;right:8px;width:340px;max-height:50vh;overflow:auto;background:rgba(17,24,39,0.95);border:1px solid #374151;border-radius:8px;padding:6px;font:12px/1.5 monospace;color:#d1d5db;display:none;';
+        const highlight = document.createElement('div');
+        highlight.id = '__solver-highlight';
+        highlight.style.cssText = 'position:fixed;pointer-events:none;display:none;';
+        const describe = a => {
+            let t = a.action + ' ';
+            if (a.from) t += '"' + a.from + '" → "' + a.to + '"';
+            else if (a.target) t += '"' + a.target + '"';
+            if (a.value !== undefined) t += ' = "' + a.value + '"';
+            if (a.key) t += a.key;
+            if (a.dx !== undefined) t += ' by ' + a.dx + ',' + a.dy;
+            return t;
+        };
+        const renderPanel = () => {
+            const solver = window.__solver;
+            const gt = solver.getGroundTruth();
+            // Same reset rule as step(): new steps restart the cursor
+            const cursor = JSON.stringify(gt.steps) === solver._lastStepsJson ? solver._stepIndex : 0;
+            panel.replaceChildren();
+            if (!gt.steps.length) panel.textContent = 'no steps';
+            gt.steps.forEach((a, i) => {
+                const row = document.createElement('div');
+                row.style.cssText = 'display:flex;gap:6px;align-items:center;padding:2px 4px;border-radius:4px;'
+                    + (i === cursor ? 'background:#312e81;color:white;' : '');
+                const text = document.createElement('span');
+                text.style.cssText = 'flex:1;word-break:break-all;';
+                const at = solver._dispatched[i];
+                text.textContent = i + '. ' + describe(a) + (at && at.length ? '  @ ' + at.map(p => p.join(',')).join(' → ') : '');
+                const mk = (label, fn) => {
+                    const b = document.createElement('button');
+                    b.textContent = label;
+                    b.style.cssText = 'padding:0 6px;border:1px solid #4b5563;border-radius:4px;background:#1f2937;color:#d1d5db;font:11px monospace;cursor:pointer;';
+                    b.onclick = fn;
+                    return b;
+                };
+                row.append(text,
+                    mk('jump', () => { solver.jumpTo(i); renderPanel(); }),
+                    mk('run', async () => { await solver.runStep(i); renderPanel(); }));
+                panel.appendChild(row);
+            });
+            // Outline the cursor step's target(s)
+            const next = gt.steps[cursor];
+            const boxes = next ? [next.target, next.from, next.to].filter(Boolean).map(l => solver._bbox(l, gt.targets)).filter(Boolean) : [];
+            highlight.replaceChildren(...boxes.map(b => {
+                const d = document.createElement('div');
+                d.style.cssText = 'position:fixed;left:' + b.x + 'px;top:' + b.y + 'px;width:' + b.w + 'px;height:' + b.h
+                    + 'px;box-sizing:border-box;border:2px dashed #f59e0b;background:rgba(245,158,11,0.15);';
+                return d;
+            }));
+            highlight.style.display = boxes.length ? 'block' : 'none';
+        };
+        let panelTimer = null;
+        const stepsBtn = mkBtn('Steps', () => {
+            const open = panel.style.display === 'none';
+            panel.style.display = open ? 'block' : 'none';
+            stepsBtn.style.background = open ? '#0d9488' : '#6b7280';
+            clearInterval(panelTimer);
+            if (open) { renderPanel(); panelTimer = setInterval(renderPanel, 500); }
+            else highlight.style.display = 'none';
+        });
+        stepsBtn.style.background = '#6b7280';
+        bar.appendChild(stepsBtn);
+        bar.appendChild(panel);
+        bar.appendChild(highlight);
+        document.body.appendChild(bar);
+    }
+"#;
@@ -35,6 +35,7 @@ pub fn TestDropdown() -> Element {
                     style: "position: absolute; left: 290px; top: 270px; width: 220px;",
 
                     CustomSelect {
+                        label: "Fruit".to_string(),
                         options: options,
                         is_target: true,
                         target_option: target,
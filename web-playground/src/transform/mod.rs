@@ -7,6 +7,7 @@
 
 pub mod placed;
 pub mod sampler;
+pub mod scene;
 
 pub use placed::PlacedElement;
-pub use sampler::Sampler;
+pub use sampler::{Sampler, TagFilter};
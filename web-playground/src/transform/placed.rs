@@ -112,8 +112,8 @@ impl PlacedElement {
 
     /// Bounding box estimate (for collision detection and ground truth)
     pub fn bounds(&self) -> (f32, f32, f32, f32) {
-        let w = self.snippet.approx_width * self.scale.value();
-        let h = self.snippet.approx_height * self.scale.value();
+        let (w, h) = self.snippet.size();
+        let (w, h) = (w * self.scale.value(), h * self.scale.value());
         (self.position.x, self.position.y, w, h)
     }
 }
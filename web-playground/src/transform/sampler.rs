@@ -3,35 +3,115 @@
 use rand::Rng;
 
 use crate::pool::{ElementPool, DesignSnippet, ElementKind};
-use crate::primitives::{Angle, Animation, Opacity, Position, Scale};
+use crate::primitives::{Angle, Animation, FontFamily, Opacity, Position, Scale, Typography};
+use crate::primitives::typography::FontSize;
 use super::placed::PlacedElement;
 
+/// Tag constraints and preferences for picking snippets
+///
+/// Parsed from a comma list such as `"dark,-detailed,gradient*3"`:
+/// a bare tag is required, `-tag` excluded, and `tag*N` multiplies the
+/// weight of snippets carrying it by N.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TagFilter {
+    pub require: Vec<String>,
+    pub exclude: Vec<String>,
+    pub boost: Vec<(String, f32)>,
+}
+
+impl TagFilter {
+    pub fn parse(s: &str) -> Self {
+        let mut filter = Self::default();
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            if let Some(tag) = part.strip_prefix('-') {
+                filter.exclude.push(tag.to_string());
+            } else if let Some((tag, factor)) = part.split_once('*') {
+                if let Ok(factor) = factor.trim().parse::<f32>()
+                    && factor.is_finite()
+                    && factor >= 0.0
+                {
+                    filter.boost.push((tag.trim().to_string(), factor));
+                }
+            } else {
+                filter.require.push(part.to_string());
+            }
+        }
+        filter
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.require.is_empty() && self.exclude.is_empty() && self.boost.is_empty()
+    }
+
+    pub fn accepts(&self, snippet: &DesignSnippet) -> bool {
+        self.require.iter().all(|t| snippet.has_tag(t))
+            && !self.exclude.iter().any(|t| snippet.has_tag(t))
+    }
+
+    /// The snippet's own weight times every matching boost
+    pub fn weight(&self, snippet: &DesignSnippet) -> f32 {
+        self.boost.iter()
+            .filter(|(tag, _)| snippet.has_tag(tag))
+            .fold(snippet.weight.max(0.0), |w, (_, factor)| w * factor)
+    }
+}
+
+/// Weighted index into `weights`. Even weights draw exactly like
+/// `random_range(0..len)`, so unweighted pools replay seeds unchanged.
+fn weighted_index<R: Rng>(rng: &mut R, weights: &[f32]) -> Option<usize> {
+    let first = *weights.first()?;
+    if weights.iter().all(|w| *w == first) {
+        return (first > 0.0).then(|| rng.random_range(0..weights.len()));
+    }
+    let total: f32 = weights.iter().sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut roll = rng.random_range(0.0..total);
+    for (i, w) in weights.iter().enumerate() {
+        if roll < *w {
+            return Some(i);
+        }
+        roll -= w;
+    }
+    weights.iter().rposition(|w| *w > 0.0)
+}
+
+/// Pick from `candidates` by filtered weight.
+fn pick_weighted<R: Rng>(rng: &mut R, candidates: &[&DesignSnippet], filter: &TagFilter) -> Option<DesignSnippet> {
+    let accepted: Vec<&DesignSnippet> = candidates.iter().copied().filter(|s| filter.accepts(s)).collect();
+    let weights: Vec<f32> = accepted.iter().map(|s| filter.weight(s)).collect();
+    weighted_index(rng, &weights).map(|i| accepted[i].clone())
+}
+
 /// Generates random page layouts by sampling from the pool
 pub struct Sampler;
 
 impl Sampler {
-    /// Pick a random snippet from the pool
+    /// Pick a random snippet from the pool, by weight
     pub fn pick_snippet<R: Rng>(rng: &mut R, pool: &ElementPool) -> Option<DesignSnippet> {
-        let all = pool.all();
-        if all.is_empty() {
-            return None;
-        }
-        let idx = rng.random_range(0..all.len());
-        Some(all[idx].clone())
+        pick_weighted(rng, &pool.all(), &TagFilter::default())
     }
 
-    /// Pick a random snippet of a specific kind
+    /// Pick a random snippet of a specific kind, by weight
     pub fn pick_kind<R: Rng>(
         rng: &mut R,
         pool: &ElementPool,
         kind: ElementKind,
     ) -> Option<DesignSnippet> {
-        let snippets = pool.get(kind);
-        if snippets.is_empty() {
-            return None;
-        }
-        let idx = rng.random_range(0..snippets.len());
-        Some(snippets[idx].clone())
+        Self::pick_kind_where(rng, pool, kind, &TagFilter::default())
+    }
+
+    /// Pick a snippet of `kind` that passes `filter`, weighted by the
+    /// snippet's weight and the filter's boosts. `None` when nothing passes.
+    pub fn pick_kind_where<R: Rng>(
+        rng: &mut R,
+        pool: &ElementPool,
+        kind: ElementKind,
+        filter: &TagFilter,
+    ) -> Option<DesignSnippet> {
+        let candidates: Vec<&DesignSnippet> = pool.get(kind).iter().collect();
+        pick_weighted(rng, &candidates, filter)
     }
 
     /// Sample a random position that keeps the element on-canvas
@@ -69,15 +149,20 @@ impl Sampler {
         vocab[rng.random_range(0..vocab.len())]
     }
 
+    /// Sample a random font family and weight, with the base size from `sizes`
+    pub fn random_typography<R: Rng>(rng: &mut R, sizes: &[FontSize]) -> Typography {
+        let family = FontFamily::ALL[rng.random_range(0..FontFamily::ALL.len())];
+        let size = sizes[rng.random_range(0..sizes.len())];
+        let weight = Typography::WEIGHTS[rng.random_range(0..Typography::WEIGHTS.len())];
+        Typography::new(family, size, weight)
+    }
+
     /// Generate a fully randomized placed element
     pub fn random_placed<R: Rng>(rng: &mut R, pool: &ElementPool) -> Option<PlacedElement> {
         let snippet = Self::pick_snippet(rng, pool)?;
         let scale = Self::random_scale(rng);
-        let pos = Self::random_position(
-            rng,
-            snippet.approx_width * scale.value(),
-            snippet.approx_height * scale.value(),
-        );
+        let (w, h) = snippet.size();
+        let pos = Self::random_position(rng, w * scale.value(), h * scale.value());
         let angle = Self::random_angle(rng);
         let opacity = Self::random_opacity(rng);
         let animation = Self::random_animation(rng);
@@ -90,33 +175,69 @@ impl Sampler {
                 .with_animation(animation),
         )
     }
+}
 
-    /// Generate a page with N random elements, avoiding overlaps
-    pub fn random_page<R: Rng>(
-        rng: &mut R,
-        pool: &ElementPool,
-        count: usize,
-    ) -> Vec<PlacedElement> {
-        let mut elements = Vec::with_capacity(count);
-        let mut attempts = 0;
-        let max_attempts = count * 10;
-
-        while elements.len() < count && attempts < max_attempts {
-            attempts += 1;
-            if let Some(placed) = Self::random_placed(rng, pool) {
-                // Simple overlap check
-                let (x, y, w, h) = placed.bounds();
-                let overlaps = elements.iter().any(|existing: &PlacedElement| {
-                    let (ex, ey, ew, eh) = existing.bounds();
-                    x < ex + ew && x + w > ex && y < ey + eh && y + h > ey
-                });
-
-                if !overlaps {
-                    elements.push(placed);
-                }
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::SmallRng;
+
+    fn snippet(id: &str, tags: &[&str]) -> DesignSnippet {
+        DesignSnippet::new(id, ElementKind::Button, id, "<button>x</button>", "<button>x</button>", 100.0, 40.0)
+            .with_tags(tags)
+    }
+
+    fn pool() -> ElementPool {
+        let mut pool = ElementPool::new();
+        pool.add(snippet("flat-light", &["flat", "light", "simple"]));
+        pool.add(snippet("gradient-dark", &["gradient", "dark", "detailed"]));
+        pool.add(snippet("flat-dark", &["flat", "dark", "simple"]));
+        pool
+    }
+
+    #[test]
+    fn parse_filter() {
+        let f = TagFilter::parse("dark, -detailed,flat*3,bad*x");
+        assert_eq!(f.require, vec!["dark"]);
+        assert_eq!(f.exclude, vec!["detailed"]);
+        assert_eq!(f.boost, vec![("flat".to_string(), 3.0)]);
+        assert!(TagFilter::parse(" , ").is_empty());
+    }
+
+    #[test]
+    fn filter_requires_and_excludes() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let f = TagFilter::parse("dark,-detailed");
+        for _ in 0..20 {
+            let s = Sampler::pick_kind_where(&mut rng, &pool(), ElementKind::Button, &f).unwrap();
+            assert_eq!(s.id, "flat-dark");
+        }
+        let none = TagFilter::parse("ios");
+        assert!(Sampler::pick_kind_where(&mut rng, &pool(), ElementKind::Button, &none).is_none());
+    }
+
+    #[test]
+    fn even_weights_draw_like_uniform_range() {
+        let p = pool();
+        let mut a = SmallRng::seed_from_u64(3);
+        let mut b = SmallRng::seed_from_u64(3);
+        for _ in 0..20 {
+            let picked = Sampler::pick_kind(&mut a, &p, ElementKind::Button).unwrap();
+            let expected = &p.get(ElementKind::Button)[b.random_range(0..3)];
+            assert_eq!(picked.id, expected.id);
         }
+    }
 
-        elements
+    #[test]
+    fn weights_skew_picks() {
+        let mut p = ElementPool::new();
+        p.add(snippet("rare", &[]).with_weight(1.0));
+        p.add(snippet("common", &[]).with_weight(99.0));
+        let mut rng = SmallRng::seed_from_u64(11);
+        let common = (0..200)
+            .filter(|_| Sampler::pick_kind(&mut rng, &p, ElementKind::Button).unwrap().id == "common")
+            .count();
+        assert!(common > 180);
     }
 }
@@ -0,0 +1,246 @@
+//! Scene - a multi-element page composed from the pool
+//!
+//! `Scene::generate` places K distinct pool snippets with random transforms,
+//! without overlap, and makes one of them the target: "click the gradient
+//! purple button" among a toggle, a badge and two other buttons. The target
+//! is always a click-completable kind. `Scene::tree` is the matching UINode
+//! ground truth, one node per element in DOM order.
+//!
+//! `Scene::refer_relative` switches the instruction to naming the target only
+//! by a neighbour: "click the toggle switch to the right of the flat blue
+//! button".
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::pool::{DesignSnippet, ElementKind, ElementPool};
+use crate::ui_node::{CheckState, Rect, Relation, ToggleState, UINode, Visual};
+use super::placed::PlacedElement;
+use super::sampler::Sampler;
+
+/// Kinds a single click completes. Inputs and dropdowns need more than a
+/// click; badges and avatars are decorative.
+pub const TARGET_KINDS: &[ElementKind] = &[
+    ElementKind::Button,
+    ElementKind::Link,
+    ElementKind::Icon,
+    ElementKind::Toggle,
+    ElementKind::Checkbox,
+];
+
+/// Placement tries per element before giving up on it.
+const ATTEMPTS: usize = 20;
+
+/// Placed elements plus the index of the one to click
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scene {
+    pub elements: Vec<PlacedElement>,
+    pub target: usize,
+    /// Element the instruction names the target by, for relative references
+    pub anchor: Option<usize>,
+}
+
+/// The box an element actually covers: `wrapper_style` scales about the
+/// center, `PlacedElement::bounds` grows from the top-left corner.
+pub fn rendered_rect(placed: &PlacedElement) -> Rect {
+    let (w, h) = placed.snippet.size();
+    let s = placed.scale.value();
+    Rect::new(
+        placed.position.x + w * (1.0 - s) / 2.0,
+        placed.position.y + h * (1.0 - s) / 2.0,
+        w * s,
+        h * s,
+    )
+}
+
+fn overlaps(a: &Rect, b: &Rect) -> bool {
+    a.x < b.x + b.w && a.x + a.w > b.x && a.y < b.y + b.h && a.y + a.h > b.y
+}
+
+/// A snippet with sampled transforms at a random on-canvas position.
+fn transformed<R: Rng>(rng: &mut R, snippet: DesignSnippet) -> PlacedElement {
+    let scale = Sampler::random_scale(rng);
+    let (w, h) = snippet.size();
+    let pos = Sampler::random_position(rng, w * scale.value(), h * scale.value());
+    PlacedElement::new(snippet, pos)
+        .with_scale(scale)
+        .with_angle(Sampler::random_angle(rng))
+        .with_opacity(Sampler::random_opacity(rng))
+        .with_animation(Sampler::random_animation(rng))
+}
+
+impl Scene {
+    /// Up to `count` distinct, non-overlapping elements. `None` when the
+    /// pool has no click-completable snippet.
+    pub fn generate<R: Rng>(rng: &mut R, pool: &ElementPool, count: usize) -> Option<Self> {
+        // Hotspot snippets only answer on their hotspots; keep them as scenery
+        let target_kinds: Vec<ElementKind> = TARGET_KINDS.iter().copied()
+            .filter(|k| pool.get(*k).iter().any(|s| !s.is_interactive()))
+            .collect();
+        if target_kinds.is_empty() {
+            return None;
+        }
+        let kind = target_kinds[rng.random_range(0..target_kinds.len())];
+        let candidates: Vec<&DesignSnippet> = pool.get(kind).iter().filter(|s| !s.is_interactive()).collect();
+        let target_snippet = candidates[rng.random_range(0..candidates.len())].clone();
+
+        let mut elements = vec![transformed(rng, target_snippet)];
+        let mut taken = vec![rendered_rect(&elements[0])];
+        for _ in 1..count {
+            let placed = (0..ATTEMPTS)
+                .filter_map(|_| Sampler::random_placed(rng, pool))
+                .find(|p| {
+                    !elements.iter().any(|e| e.snippet.id == p.snippet.id)
+                        && !taken.iter().any(|t| overlaps(&rendered_rect(p), t))
+                });
+            if let Some(placed) = placed {
+                taken.push(rendered_rect(&placed));
+                elements.push(placed);
+            }
+        }
+
+        // The target isn't always the first element in the DOM
+        let target_id = elements[0].snippet.id.clone();
+        elements.shuffle(rng);
+        let target = elements.iter().position(|e| e.snippet.id == target_id)?;
+        Some(Self { elements, target, anchor: None })
+    }
+
+    pub fn target(&self) -> &PlacedElement {
+        &self.elements[self.target]
+    }
+
+    /// Name the target by its nearest neighbour that singles it out: no other
+    /// element of the target's kind lies on the same side of that neighbour.
+    /// Returns whether one was found; otherwise the reference stays direct.
+    pub fn refer_relative(&mut self) -> bool {
+        let target_rect = rendered_rect(self.target());
+        let kind = self.target().snippet.kind;
+        self.anchor = self.elements.iter().enumerate()
+            .filter(|(i, _)| *i != self.target)
+            .filter_map(|(i, anchor)| {
+                let anchor_rect = rendered_rect(anchor);
+                let (rel, gap) = target_rect.relation_to(&anchor_rect)?;
+                let ambiguous = self.elements.iter().enumerate().any(|(j, e)| {
+                    j != i && j != self.target && e.snippet.kind == kind
+                        && rendered_rect(e).relation_to(&anchor_rect).is_some_and(|(r, _)| r == rel)
+                });
+                (!ambiguous).then_some((i, gap))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i);
+        self.anchor.is_some()
+    }
+
+    /// Side of the anchor the target lies on, for relative references
+    pub fn relation(&self) -> Option<Relation> {
+        let anchor = &self.elements[self.anchor?];
+        rendered_rect(self.target()).relation_to(&rendered_rect(anchor)).map(|(rel, _)| rel)
+    }
+
+    /// "Click the gradient purple button", or relative to the anchor:
+    /// "Click the toggle switch to the right of the flat blue button"
+    pub fn instruction(&self) -> String {
+        let snippet = &self.target().snippet;
+        let verb = match snippet.kind {
+            ElementKind::Toggle => "Switch on",
+            ElementKind::Checkbox => "Check",
+            _ => "Click",
+        };
+        match (self.anchor, self.relation()) {
+            (Some(anchor), Some(rel)) => format!(
+                "{verb} the {} {} the {}",
+                snippet.kind.describe(),
+                rel.describe(),
+                self.elements[anchor].snippet.label,
+            ),
+            _ => format!("{verb} the {}", snippet.label),
+        }
+    }
+
+    /// Ground truth: every element inside a card spanning `canvas`.
+    pub fn tree(&self, canvas: Rect) -> UINode {
+        let children = self.elements.iter().enumerate()
+            .map(|(i, placed)| {
+                let is_target = i == self.target;
+                let visual = Visual::new(placed.snippet.label.clone(), rendered_rect(placed));
+                let visual = if is_target { visual.target() } else { visual };
+                let visual = match self.anchor {
+                    Some(a) if is_target => {
+                        let anchor = &self.elements[a];
+                        visual.relative_to(anchor.snippet.label.clone(), rendered_rect(anchor))
+                    }
+                    _ => visual,
+                };
+                match placed.snippet.kind {
+                    ElementKind::Toggle => UINode::Toggle(visual, ToggleState { is_on: false, target_on: is_target }),
+                    ElementKind::Checkbox => UINode::Checkbox(visual, CheckState { is_checked: false }),
+                    _ => UINode::Button(visual),
+                }
+            })
+            .collect();
+        UINode::Card(Visual::new("scene", canvas), children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::Position;
+
+    fn placed(id: &str, kind: ElementKind, label: &str, x: f32, y: f32) -> PlacedElement {
+        let snippet = DesignSnippet::new(id, kind, label, "<button>x</button>", "<button>x</button>", 100.0, 40.0);
+        PlacedElement::new(snippet, Position::new(x, y))
+    }
+
+    #[test]
+    fn relative_instruction_names_side_of_anchor() {
+        let mut scene = Scene {
+            elements: vec![
+                placed("email", ElementKind::Input, "Email field", 100.0, 100.0),
+                placed("toggle", ElementKind::Toggle, "green toggle", 260.0, 100.0),
+            ],
+            target: 1,
+            anchor: None,
+        };
+        assert!(scene.refer_relative());
+        assert_eq!(scene.relation(), Some(Relation::RightOf));
+        assert_eq!(scene.instruction(), "Switch on the toggle switch to the right of the Email field");
+        let tree = scene.tree(Rect::new(0.0, 0.0, 800.0, 600.0));
+        let gt = tree.resolve();
+        assert!(gt.description.contains("\"green toggle\" sits 60px to the right of \"Email field\""));
+        let relative = gt.thinking.find("The instruction doesn't name the target").unwrap();
+        assert!(relative < gt.thinking.find("green toggle").unwrap());
+    }
+
+    #[test]
+    fn relative_reference_skips_ambiguous_anchors() {
+        // Every button sits below the link, so the link can't single one out
+        let mut scene = Scene {
+            elements: vec![
+                placed("link", ElementKind::Link, "blue link", 300.0, 100.0),
+                placed("a", ElementKind::Button, "flat button", 300.0, 200.0),
+                placed("b", ElementKind::Button, "outline button", 420.0, 200.0),
+                placed("toggle", ElementKind::Toggle, "green toggle", 140.0, 200.0),
+            ],
+            target: 1,
+            anchor: None,
+        };
+        assert!(scene.refer_relative());
+        assert_ne!(scene.anchor, Some(0));
+
+        // A row of three buttons: each neighbour has another button on the target's side
+        let mut scene = Scene {
+            elements: vec![
+                placed("link", ElementKind::Link, "blue link", 300.0, 0.0),
+                placed("a", ElementKind::Button, "flat button", 180.0, 200.0),
+                placed("b", ElementKind::Button, "outline button", 300.0, 200.0),
+                placed("c", ElementKind::Button, "pill button", 420.0, 200.0),
+            ],
+            target: 2,
+            anchor: None,
+        };
+        assert!(!scene.refer_relative());
+        assert_eq!(scene.instruction(), "Click the outline button");
+    }
+}
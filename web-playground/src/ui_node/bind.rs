@@ -0,0 +1,122 @@
+//! State binding — live element values mirrored into a UINode tree.
+//!
+//! Levels build their trees each render, but not every level threads every
+//! signal into the node it belongs to. A `StateBinding` collects the current
+//! value of each interactive element, keyed by the element's label, and
+//! `UINode::bind` writes those values into the matching nodes' state before
+//! the tree is resolved or checked. Only values move; rects stay as the
+//! level laid them out.
+
+use std::collections::HashMap;
+
+use super::*;
+
+/// Current value of one interactive element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiveValue {
+    /// Toggle, checkbox, tag, tile: on / checked / selected.
+    On(bool),
+    /// Slider, stepper, star rating.
+    Int(i32),
+    /// Text input, command palette query, dropdown selection.
+    Text(String),
+    /// Radio selection, carousel slide (0-based).
+    Index(usize),
+}
+
+/// Label → current value of every element registered so far.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateBinding {
+    values: HashMap<String, LiveValue>,
+}
+
+impl StateBinding {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `label`'s current value, replacing any earlier one.
+    pub fn set(&mut self, label: impl Into<String>, value: LiveValue) {
+        self.values.insert(label.into(), value);
+    }
+
+    pub fn get(&self, label: &str) -> Option<&LiveValue> {
+        self.values.get(label)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl UINode {
+    /// Write every bound value into the node with that label, across the
+    /// whole tree. Values of the wrong kind for a node are ignored. Returns
+    /// how many nodes (or tiles, boxes, fields) were updated.
+    pub fn bind(&mut self, live: &StateBinding) -> usize {
+        if live.is_empty() {
+            return 0;
+        }
+        let mut applied = self.bind_own(live);
+        for child in self.children_mut() {
+            applied += child.bind(live);
+        }
+        applied
+    }
+
+    fn bind_own(&mut self, live: &StateBinding) -> usize {
+        use LiveValue::*;
+        // Composite nodes bind per part, by the part's label
+        match self {
+            UINode::TileGrid(_, s) => {
+                return s.tiles.iter_mut()
+                    .filter_map(|t| match live.get(&t.label) {
+                        Some(On(on)) => { t.is_selected = *on; Some(()) }
+                        _ => None,
+                    })
+                    .count();
+            }
+            UINode::CheckboxGroup(_, boxes, _) => {
+                return boxes.iter_mut()
+                    .filter_map(|(b, s)| match live.get(&b.label) {
+                        Some(On(on)) => { s.is_checked = *on; Some(()) }
+                        _ => None,
+                    })
+                    .count();
+            }
+            UINode::KeyboardForm(_, s) => {
+                return s.fields.iter_mut()
+                    .filter_map(|f| match live.get(&f.label) {
+                        Some(Text(text)) => { f.current_value = text.clone(); Some(()) }
+                        _ => None,
+                    })
+                    .count();
+            }
+            _ => {}
+        }
+        let Some(value) = live.get(&self.visual().label) else { return 0 };
+        match (self, value) {
+            (UINode::Toggle(_, s), On(on)) => s.is_on = *on,
+            (UINode::Checkbox(_, s), On(on)) => s.is_checked = *on,
+            (UINode::Tag(_, s), On(on)) => s.is_selected = *on,
+            (UINode::Star(_, s), Int(n)) => s.current = (*n).max(0) as usize,
+            (UINode::TextInput(_, s), Text(text)) => s.current_value = text.clone(),
+            (UINode::TextSelection(_, s), Text(text)) => s.selected = text.clone(),
+            (UINode::Slider(_, s), Int(n)) => s.current_val = *n,
+            (UINode::Stepper(_, s), Int(n)) => s.current_val = *n,
+            (UINode::Dropdown(_, s), Text(text)) => {
+                s.selected = (!text.is_empty()).then(|| text.clone());
+            }
+            (UINode::RadioGroup(_, s), Index(i)) => s.selected = Some(*i),
+            (UINode::CommandPalette(_, s), Text(text)) => s.query = text.clone(),
+            (UINode::Carousel(_, s), Index(i)) => s.current = *i,
+            (UINode::Tooltip(_, s), On(on)) => s.open = *on,
+            _ => return 0,
+        }
+        1
+    }
+}
@@ -14,9 +14,14 @@ pub fn target_button(label: impl Into<String>, rect: Rect) -> UINode {
     UINode::Button(Visual::new(label, rect).target())
 }
 
-/// Toggle switch.
+/// Toggle switch (target is to flip it).
 pub fn toggle(label: impl Into<String>, rect: Rect, is_on: bool) -> UINode {
-    UINode::Toggle(Visual::new(label, rect).target(), ToggleState { is_on })
+    UINode::Toggle(Visual::new(label, rect).target(), ToggleState { is_on, target_on: !is_on })
+}
+
+/// Toggle that must end up in `target_on` (may already be there).
+pub fn toggle_to(label: impl Into<String>, rect: Rect, is_on: bool, target_on: bool) -> UINode {
+    UINode::Toggle(Visual::new(label, rect).target(), ToggleState { is_on, target_on })
 }
 
 /// Checkbox.
@@ -24,6 +29,44 @@ pub fn checkbox(label: impl Into<String>, rect: Rect, is_checked: bool) -> UINod
     UINode::Checkbox(Visual::new(label, rect).target(), CheckState { is_checked })
 }
 
+/// Checkbox group; `target` picks which of `boxes` must end up checked.
+pub fn checkbox_group(label: impl Into<String>, rect: Rect, boxes: Vec<(Visual, CheckState)>, target: GroupTarget) -> UINode {
+    UINode::CheckboxGroup(Visual::new(label, rect).target(), boxes, target)
+}
+
+/// Carousel whose target slide must be navigated to.
+pub fn carousel(label: impl Into<String>, rect: Rect, state: CarouselState) -> UINode {
+    UINode::Carousel(Visual::new(label, rect).target(), state)
+}
+
+/// List whose `target_item` must be dragged into slot `target_pos`.
+pub fn sortable_list(label: impl Into<String>, rect: Rect, state: SortableState) -> UINode {
+    UINode::SortableList(Visual::new(label, rect).target(), state)
+}
+
+/// Upload task: drag `files[target]` onto `zone`.
+pub fn file_drag_task(label: impl Into<String>, rect: Rect, files: Vec<Visual>, target: usize, zone: Visual) -> UINode {
+    UINode::FileDragTask(Visual::new(label, rect).target(), FileDragState { files, target, zone })
+}
+
+/// Passage in which `target_text` must be selected.
+pub fn text_selection(label: impl Into<String>, rect: Rect, text: impl Into<String>, target_text: impl Into<String>) -> UINode {
+    UINode::TextSelection(
+        Visual::new(label, rect).target(),
+        SelectionState { text: text.into(), target_text: target_text.into(), selected: String::new() },
+    )
+}
+
+/// Unlabeled shape; its label is the English description ("small red circle").
+pub fn shape(rect: Rect, state: ShapeState) -> UINode {
+    UINode::Shape(Visual::new(state.label(), rect).color(state.swatch.to_css()).target(), state)
+}
+
+/// Icon-only button; `label` is its function ("Settings"), `glyph` what it shows ("gear").
+pub fn icon_button(label: impl Into<String>, rect: Rect, glyph: impl Into<String>) -> UINode {
+    UINode::IconButton(Visual::new(label, rect).target(), IconState { glyph: glyph.into() })
+}
+
 /// Tab header.
 pub fn tab(label: impl Into<String>, rect: Rect) -> UINode {
     UINode::Tab(Visual::new(label, rect).target())
@@ -31,12 +74,24 @@ pub fn tab(label: impl Into<String>, rect: Rect) -> UINode {
 
 /// Accordion / collapsible section header.
 pub fn accordion(label: impl Into<String>, rect: Rect) -> UINode {
-    UINode::Accordion(Visual::new(label, rect).target())
+    let body = Rect::new(rect.x, rect.y + rect.h, rect.w, 0.0);
+    UINode::Accordion(Visual::new(label, rect).target(), AccordionState { body }, Vec::new())
+}
+
+/// Collapsed accordion section: header `rect`, and the `body` its children
+/// fill once expanded.
+pub fn accordion_with(label: impl Into<String>, rect: Rect, body: Rect, children: Vec<UINode>) -> UINode {
+    UINode::Accordion(Visual::new(label, rect).target(), AccordionState { body }, children)
 }
 
 /// Selectable tag chip.
 pub fn tag(label: impl Into<String>, rect: Rect, is_selected: bool) -> UINode {
-    UINode::Tag(Visual::new(label, rect).target(), TagState { is_selected })
+    UINode::Tag(Visual::new(label, rect).target(), TagState { is_selected, target_selected: !is_selected })
+}
+
+/// Tag chip that must end up in `target_selected` (may already be there).
+pub fn tag_to(label: impl Into<String>, rect: Rect, is_selected: bool, target_selected: bool) -> UINode {
+    UINode::Tag(Visual::new(label, rect).target(), TagState { is_selected, target_selected })
 }
 
 /// Toast notification.
@@ -94,6 +149,8 @@ pub fn slider(
             target_val: target,
             thumb_rect,
             target_thumb_rect,
+            tolerance: 0,
+            native: false,
         },
     )
 }
@@ -122,6 +179,8 @@ pub fn dropdown(
             selected: None,
             target_option: target_option.into(),
             trigger_label: "Choose...".into(),
+            mode: DropdownMode::Click,
+            open_options: Vec::new(),
         },
     )
 }
@@ -141,10 +200,28 @@ pub fn dropdown_with_trigger(
             selected: None,
             target_option: target_option.into(),
             trigger_label: trigger_label.into(),
+            mode: DropdownMode::Click,
+            open_options: Vec::new(),
         },
     )
 }
 
+/// Dropdown whose task is driven in `mode` (click, filter-and-click, or
+/// arrow keys + Enter).
+pub fn dropdown_in_mode(
+    label: impl Into<String>,
+    rect: Rect,
+    options: Vec<String>,
+    target_option: impl Into<String>,
+    mode: DropdownMode,
+) -> UINode {
+    let mut node = dropdown(label, rect, options, target_option);
+    if let UINode::Dropdown(_, state) = &mut node {
+        state.mode = mode;
+    }
+    node
+}
+
 /// Context menu (right-click trigger).
 pub fn context_menu(
     rect: Rect,
@@ -159,11 +236,28 @@ pub fn context_menu(
             items,
             target_item: target_item.into(),
             trigger_label: tl,
+            item_rects: Vec::new(),
         },
     )
 }
 
-/// Stepper (+/- buttons).
+/// Context menu whose items open at `item_rects` (one per item).
+pub fn context_menu_with_rects(
+    rect: Rect,
+    trigger_label: impl Into<String>,
+    items: Vec<String>,
+    target_item: impl Into<String>,
+    item_rects: Vec<Rect>,
+) -> UINode {
+    let mut node = context_menu(rect, trigger_label, items, target_item);
+    if let UINode::ContextMenu(_, state) = &mut node {
+        state.item_rects = item_rects;
+    }
+    node
+}
+
+/// Stepper (+/- buttons), laid out as `[−] value [+]`: square buttons at
+/// either end of `rect`.
 pub fn stepper(
     label: impl Into<String>,
     rect: Rect,
@@ -182,29 +276,123 @@ pub fn stepper(
             step,
             current_val: current,
             target_val: target,
+            start_val: current,
             minus_label: format!("minus: {}", l),
             plus_label: format!("+: {}", l),
+            minus_rect: Rect::new(rect.x, rect.y, rect.h, rect.h),
+            plus_rect: Rect::new(rect.x + rect.w - rect.h, rect.y, rect.h, rect.h),
         },
     )
 }
 
-/// Radio button group.
+/// Radio button group, options in equal rows filling `rect`.
 pub fn radio_group(
     label: impl Into<String>,
     rect: Rect,
     options: Vec<String>,
     target_option: usize,
+) -> UINode {
+    let row_h = rect.h / options.len().max(1) as f32;
+    let option_rects = layout::stack(rect, &vec![row_h; options.len()], 0.0);
+    radio_group_with_rects(label, rect, options, option_rects, target_option)
+}
+
+/// Radio button group with a known rect per option.
+pub fn radio_group_with_rects(
+    label: impl Into<String>,
+    rect: Rect,
+    options: Vec<String>,
+    option_rects: Vec<Rect>,
+    target_option: usize,
 ) -> UINode {
     UINode::RadioGroup(
         Visual::new(label, rect).target(),
         RadioState {
             options,
+            option_rects,
             selected: None,
             target_option,
         },
     )
 }
 
+/// Command palette opened by a hotkey; the target command is typed then
+/// confirmed with Enter.
+pub fn command_palette(
+    label: impl Into<String>,
+    rect: Rect,
+    hotkey: impl Into<String>,
+    commands: Vec<String>,
+    target_command: impl Into<String>,
+    input_rect: Rect,
+) -> UINode {
+    let l = label.into();
+    UINode::CommandPalette(
+        Visual::new(&l, rect).target(),
+        PaletteState {
+            hotkey: hotkey.into(),
+            commands,
+            target_command: target_command.into(),
+            is_open: false,
+            query: String::new(),
+            input_label: l,
+            input_rect,
+        },
+    )
+}
+
+/// Tile grid where every matching tile must be selected.
+/// `tiles` are (content, rect, is_match) in reading order; labels are "tile N".
+pub fn tile_grid(
+    label: impl Into<String>,
+    rect: Rect,
+    prompt: impl Into<String>,
+    cols: usize,
+    tiles: Vec<(String, Rect, bool)>,
+) -> UINode {
+    UINode::TileGrid(
+        Visual::new(label, rect).target(),
+        TileGridState {
+            prompt: prompt.into(),
+            cols,
+            tiles: tiles.into_iter().enumerate()
+                .map(|(i, (content, rect, is_match))| GridTile {
+                    label: format!("tile {}", i + 1),
+                    content,
+                    rect,
+                    is_selected: false,
+                    is_match,
+                })
+                .collect(),
+        },
+    )
+}
+
+/// Keyboard-only form; `fields` are (label, rect, target value) in tab order.
+pub fn keyboard_form(
+    label: impl Into<String>,
+    rect: Rect,
+    fields: Vec<(String, Rect, String)>,
+    submit_key: impl Into<String>,
+) -> UINode {
+    UINode::KeyboardForm(
+        Visual::new(label, rect).target(),
+        FocusOrderState {
+            fields: fields.into_iter()
+                .map(|(label, rect, target_value)| FocusField {
+                    label,
+                    rect,
+                    current_value: String::new(),
+                    target_value,
+                })
+                .collect(),
+            focused: None,
+            focus_history: Vec::new(),
+            submit_key: submit_key.into(),
+        },
+    )
+}
+
 /// Card container (no submit button).
 pub fn card(rect: Rect, children: Vec<UINode>) -> UINode {
     UINode::Card(Visual::new("card", rect), children)
@@ -217,7 +405,204 @@ pub fn form(rect: Rect, submit_label: impl Into<String>, children: Vec<UINode>)
         FormState {
             submit_label: submit_label.into(),
             cancel_label: None,
+            unordered: false,
         },
         children,
     )
 }
+
+impl UINode {
+    /// Let a form's fields fill in any order. Only for forms whose fields
+    /// are independent (no reveal, expand or select another field relies
+    /// on); other nodes are returned as is.
+    pub fn unordered(mut self) -> Self {
+        if let UINode::Form(_, state, _) = &mut self {
+            state.unordered = true;
+        }
+        self
+    }
+}
+
+/// Dialog container; while `open` is false its children contribute steps
+/// but no boxes.
+pub fn modal(rect: Rect, title: impl Into<String>, open: bool, children: Vec<UINode>) -> UINode {
+    UINode::Modal(Visual::new("dialog", rect), ModalState { title: title.into(), open }, children)
+}
+
+/// Split-screen pane named `name` at `rect`; `children` are laid out
+/// relative to the pane's top-left corner.
+pub fn pane(rect: Rect, name: impl Into<String>, children: Vec<UINode>) -> UINode {
+    UINode::Pane(Visual::new("pane", rect), PaneState { name: name.into() }, children)
+}
+
+/// Scrollable box labelled `label`; `children` keep their unscrolled
+/// viewport rects until measured.
+pub fn scroll_area(label: impl Into<String>, rect: Rect, children: Vec<UINode>) -> UINode {
+    UINode::ScrollArea(Visual::new(label, rect), children)
+}
+
+/// Long document labelled `label` with contents links `toc` (link label,
+/// section rect); `via_toc` picks which way the steps reach the target.
+pub fn article(label: impl Into<String>, rect: Rect, toc: Vec<(String, Rect)>, via_toc: bool, children: Vec<UINode>) -> UINode {
+    UINode::Article(Visual::new(label, rect), ArticleState { toc, via_toc }, children)
+}
+
+/// Content container that shows its children once loaded; until then
+/// resolve waits up to `remaining_ms` for them.
+pub fn loader(label: impl Into<String>, rect: Rect, remaining_ms: u32, loaded: bool, children: Vec<UINode>) -> UINode {
+    UINode::Loader(Visual::new(label, rect), LoaderState { remaining_ms, loaded }, children)
+}
+
+/// Fluent tree construction with automatic layout.
+///
+/// ```ignore
+/// fluent::form("Submit").at(card_rect).padding(16.0).top(60.0)
+///     .stack(4.0).item_height(44.0)
+///     .button("Inbox").target_button("Archive")
+///     .build()
+/// ```
+///
+/// Children are listed without rects; `build` lays them out in the content
+/// box (the container rect inset by `padding`, starting `top` px below its
+/// top edge) with `ui_node::layout`, so the tree can't drift from the CSS.
+pub mod fluent {
+    use super::*;
+    use crate::ui_node::layout;
+
+    /// How children fill the content box
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Arrange {
+        /// One per row, full width, `gap` px apart
+        Stack(f32),
+        /// `cols` equal columns, row-major
+        Grid(usize, f32),
+    }
+
+    type Child = (f32, Box<dyn FnOnce(Rect) -> UINode>);
+
+    /// A form whose children are placed by `build`
+    pub struct TreeBuilder {
+        submit_label: String,
+        rect: Rect,
+        padding: f32,
+        top: Option<f32>,
+        arrange: Arrange,
+        item_h: f32,
+        unordered: bool,
+        children: Vec<Child>,
+    }
+
+    /// Form container (appends submit click after children).
+    pub fn form(submit_label: impl Into<String>) -> TreeBuilder {
+        TreeBuilder {
+            submit_label: submit_label.into(),
+            rect: Rect::new(0.0, 0.0, 0.0, 0.0),
+            padding: 0.0,
+            top: None,
+            arrange: Arrange::Stack(0.0),
+            item_h: 40.0,
+            unordered: false,
+            children: Vec::new(),
+        }
+    }
+
+    impl TreeBuilder {
+        /// Container rect.
+        pub fn at(mut self, rect: Rect) -> Self {
+            self.rect = rect;
+            self
+        }
+
+        /// Inset of the content box on every side.
+        pub fn padding(mut self, px: f32) -> Self {
+            self.padding = px;
+            self
+        }
+
+        /// Offset of the first child from the container's top edge, for
+        /// titles and hints above the children. Defaults to `padding`.
+        pub fn top(mut self, px: f32) -> Self {
+            self.top = Some(px);
+            self
+        }
+
+        /// Children one per row, `gap` px apart (the default, with no gap).
+        pub fn stack(mut self, gap: f32) -> Self {
+            self.arrange = Arrange::Stack(gap);
+            self
+        }
+
+        /// Children in `cols` equal columns, `gap` px apart both ways.
+        pub fn grid(mut self, cols: usize, gap: f32) -> Self {
+            self.arrange = Arrange::Grid(cols, gap);
+            self
+        }
+
+        /// Height of the children added after this call (default 40).
+        pub fn item_height(mut self, px: f32) -> Self {
+            self.item_h = px;
+            self
+        }
+
+        /// Fields fill in any order; see `UINode::unordered`.
+        pub fn unordered(mut self) -> Self {
+            self.unordered = true;
+            self
+        }
+
+        /// Any node, built from the rect the layout gives it.
+        pub fn node(mut self, make: impl FnOnce(Rect) -> UINode + 'static) -> Self {
+            self.children.push((self.item_h, Box::new(make)));
+            self
+        }
+
+        pub fn button(self, label: impl Into<String>) -> Self {
+            let label = label.into();
+            self.node(move |rect| button(label, rect))
+        }
+
+        pub fn target_button(self, label: impl Into<String>) -> Self {
+            let label = label.into();
+            self.node(move |rect| target_button(label, rect))
+        }
+
+        /// Text input the solver should leave alone.
+        pub fn text_input(self, label: impl Into<String>, placeholder: impl Into<String>) -> Self {
+            let (label, placeholder) = (label.into(), placeholder.into());
+            self.node(move |rect| UINode::TextInput(
+                Visual::new(label, rect),
+                InputState { placeholder, current_value: String::new(), target_value: String::new() },
+            ))
+        }
+
+        /// Text input the solver should type `target_value` into.
+        pub fn target_text_input(
+            self,
+            label: impl Into<String>,
+            placeholder: impl Into<String>,
+            target_value: impl Into<String>,
+        ) -> Self {
+            let (label, placeholder, target_value) = (label.into(), placeholder.into(), target_value.into());
+            self.node(move |rect| text_input(label, rect, placeholder, target_value))
+        }
+
+        /// Lay the children out and build the container.
+        pub fn build(self) -> UINode {
+            let top = self.top.unwrap_or(self.padding);
+            let content = Rect::new(
+                self.rect.x + self.padding,
+                self.rect.y + top,
+                (self.rect.w - 2.0 * self.padding).max(0.0),
+                (self.rect.h - top - self.padding).max(0.0),
+            );
+            let heights: Vec<f32> = self.children.iter().map(|(h, _)| *h).collect();
+            let rects = match self.arrange {
+                Arrange::Stack(gap) => layout::stack(content, &heights, gap),
+                Arrange::Grid(cols, gap) => layout::grid(content, cols, &heights, gap),
+            };
+            let children = self.children.into_iter().zip(rects).map(|((_, make), rect)| make(rect)).collect();
+            let form = super::form(self.rect, self.submit_label, children);
+            if self.unordered { form.unordered() } else { form }
+        }
+    }
+}
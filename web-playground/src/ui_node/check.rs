@@ -30,6 +30,16 @@ impl Completion {
         matches!(self, Self::Partial { .. })
     }
 
+    /// Snake-case name, as reported by `window.__checkCompletion`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NotStarted => "not_started",
+            Self::Partial { .. } => "partial",
+            Self::Complete => "complete",
+            Self::Wrong => "wrong",
+        }
+    }
+
     /// Fraction of completion: 0.0 to 1.0.
     pub fn progress(&self) -> f32 {
         match self {
@@ -43,7 +53,137 @@ impl Completion {
     }
 }
 
+/// A whole tree checked against its live state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation<'a> {
+    /// Target nodes whose state misses its target, pre-order.
+    pub unsatisfied: Vec<&'a UINode>,
+}
+
+impl Evaluation<'_> {
+    pub fn is_complete(&self) -> bool {
+        self.unsatisfied.is_empty()
+    }
+
+    /// Label and current value of each unsatisfied node, owned so a submit
+    /// handler can keep them past the tree.
+    pub fn unmet(&self) -> Vec<(String, String)> {
+        self.unsatisfied.iter()
+            .map(|n| (n.visual().label.clone(), n.current_value()))
+            .collect()
+    }
+}
+
 impl UINode {
+    /// Evaluate every target node that carries a target state. Click-only
+    /// nodes and containers are skipped: they have nothing to compare, so
+    /// a tree of them alone evaluates complete.
+    pub fn evaluate(&self) -> Evaluation<'_> {
+        let unsatisfied = self.walk()
+            .filter(|n| n.visual().is_target && n.has_target_state() && !n.check().is_complete())
+            .collect();
+        Evaluation { unsatisfied }
+    }
+
+    /// Whether `check` compares a current state against a target here,
+    /// rather than reporting `NotStarted` for an event-driven node.
+    pub fn has_target_state(&self) -> bool {
+        !matches!(
+            self,
+            UINode::Button(_)
+                | UINode::Tab(_)
+                | UINode::ModalButton(_)
+                | UINode::DragSource(_)
+                | UINode::DropZone(_)
+                | UINode::FileDragTask(..)
+                | UINode::Shape(..)
+                | UINode::IconButton(..)
+                | UINode::Toast(..)
+                | UINode::ContextMenu(..)
+                | UINode::Accordion(..)
+                | UINode::Card(..)
+                | UINode::Form(..)
+                | UINode::Modal(..)
+                | UINode::Loader(..)
+                | UINode::Pane(..)
+                | UINode::ScrollArea(..)
+                | UINode::Article(..)
+        )
+    }
+
+    /// The node's live value as a short string ("42", "on", the typed
+    /// text), for reporting a wrong submission. Empty for stateless nodes.
+    pub fn current_value(&self) -> String {
+        let on_off = |on: bool| if on { "on" } else { "off" }.to_string();
+        match self {
+            UINode::Toggle(_, s) => on_off(s.is_on),
+            UINode::Tag(_, s) => on_off(s.is_selected),
+            UINode::Checkbox(_, s) => on_off(s.is_checked),
+            UINode::Star(_, s) => s.current.to_string(),
+            UINode::TextInput(_, s) => s.current_value.clone(),
+            UINode::TextSelection(_, s) => s.selected.clone(),
+            UINode::Slider(_, s) => s.current_val.to_string(),
+            UINode::Stepper(_, s) => s.current_val.to_string(),
+            UINode::Dropdown(_, s) => s.selected.clone().unwrap_or_default(),
+            UINode::RadioGroup(_, s) => s.selected.and_then(|i| s.options.get(i)).cloned().unwrap_or_default(),
+            UINode::CommandPalette(_, s) => s.query.clone(),
+            UINode::Carousel(_, s) => (s.current + 1).to_string(),
+            UINode::Tooltip(_, s) => on_off(s.open),
+            UINode::SortableList(_, s) => s.current_pos().map_or(String::new(), |p| (p + 1).to_string()),
+            _ => String::new(),
+        }
+    }
+
+    /// The node's live value as JSON: a bool for on/off nodes, a number,
+    /// a string (null while nothing is chosen), the labels that are on for
+    /// grids and groups, the items in order for a sortable list, and field
+    /// values by label for a keyboard form. `None` for stateless nodes.
+    pub fn state_json(&self) -> Option<String> {
+        let quoted = |s: &str| format!(r#""{}""#, escape_json(s));
+        let labels = |ls: Vec<&String>| format!("[{}]", ls.into_iter().map(|l| quoted(l)).collect::<Vec<_>>().join(","));
+        let value = match self {
+            UINode::Toggle(_, s) => s.is_on.to_string(),
+            UINode::Tag(_, s) => s.is_selected.to_string(),
+            UINode::Checkbox(_, s) => s.is_checked.to_string(),
+            UINode::Tooltip(_, s) => s.open.to_string(),
+            UINode::Star(_, s) => s.current.to_string(),
+            UINode::Slider(_, s) => s.current_val.to_string(),
+            UINode::Stepper(_, s) => s.current_val.to_string(),
+            UINode::Carousel(_, s) => (s.current + 1).to_string(),
+            UINode::TextInput(_, s) => quoted(&s.current_value),
+            UINode::TextSelection(_, s) => quoted(&s.selected),
+            UINode::CommandPalette(_, s) => quoted(&s.query),
+            UINode::Dropdown(_, s) => s.selected.as_deref().map_or("null".to_string(), quoted),
+            UINode::RadioGroup(_, s) => s.selected.and_then(|i| s.options.get(i)).map_or("null".to_string(), |o| quoted(o)),
+            UINode::SortableList(_, s) => labels(s.items.iter().collect()),
+            UINode::TileGrid(_, s) => labels(s.tiles.iter().filter(|t| t.is_selected).map(|t| &t.label).collect()),
+            UINode::CheckboxGroup(_, boxes, _) => labels(boxes.iter().filter(|(_, s)| s.is_checked).map(|(b, _)| &b.label).collect()),
+            UINode::KeyboardForm(_, s) => {
+                let fields: Vec<String> = s.fields.iter()
+                    .map(|f| format!("{}:{}", quoted(&f.label), quoted(&f.current_value)))
+                    .collect();
+                format!("{{{}}}", fields.join(","))
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// `[{"label":..,"kind":..,"target":..,"value":..}]` for every node in
+    /// the tree that has a `state_json`, pre-order.
+    pub fn ui_state_json(&self) -> String {
+        let controls: Vec<String> = self.walk()
+            .filter_map(|n| n.state_json().map(|value| format!(
+                r#"{{"label":"{}","kind":"{}","target":{},"value":{}}}"#,
+                escape_json(&n.visual().label),
+                n.kind(),
+                n.visual().is_target,
+                value,
+            )))
+            .collect();
+        format!("[{}]", controls.join(","))
+    }
+
     /// Check how complete this node (or tree) is by comparing current vs target state.
     ///
     /// For leaf nodes with state (slider, input, dropdown, etc.), compares
@@ -59,34 +199,40 @@ impl UINode {
             // ── Click-only: no state to check ───────────────────
             UINode::Button(_)
             | UINode::Tab(_)
-            | UINode::Accordion(_)
             | UINode::ModalButton(_)
             | UINode::DragSource(_)
-            | UINode::DropZone(_) => Completion::NotStarted,
+            | UINode::DropZone(_)
+            | UINode::FileDragTask(..)
+            | UINode::Shape(..)
+            | UINode::IconButton(..) => Completion::NotStarted,
 
             // ── Toggle / Checkbox ───────────────────────────────
             UINode::Toggle(v, state) => {
                 if !v.is_target { return Completion::NotStarted; }
-                // Target is always to flip the toggle
-                if state.is_on {
-                    // If it's on now and we want it off (or vice versa),
-                    // the task is to click it. We can't know if it's been
-                    // clicked yet from state alone — caller handles this.
-                    Completion::NotStarted
+                if state.is_on == state.target_on {
+                    Completion::Complete
                 } else {
                     Completion::NotStarted
                 }
             }
 
-            UINode::Checkbox(v, _state) => {
+            // A target checkbox is done once it is checked
+            UINode::Checkbox(v, state) => {
                 if !v.is_target { return Completion::NotStarted; }
-                // Same as toggle — click-driven
-                Completion::NotStarted
+                if state.is_checked {
+                    Completion::Complete
+                } else {
+                    Completion::NotStarted
+                }
             }
 
-            UINode::Tag(v, _state) => {
+            UINode::Tag(v, state) => {
                 if !v.is_target { return Completion::NotStarted; }
-                Completion::NotStarted
+                if state.is_selected == state.target_selected {
+                    Completion::Complete
+                } else {
+                    Completion::NotStarted
+                }
             }
 
             UINode::Toast(v, _state) => {
@@ -121,10 +267,22 @@ impl UINode {
                 }
             }
 
+            // ── Text selection ──────────────────────────────────
+            UINode::TextSelection(v, state) => {
+                if !v.is_target { return Completion::NotStarted; }
+                if state.selected.trim() == state.target_text {
+                    Completion::Complete
+                } else if state.selected.is_empty() {
+                    Completion::NotStarted
+                } else {
+                    Completion::Wrong
+                }
+            }
+
             // ── Slider ──────────────────────────────────────────
             UINode::Slider(v, state) => {
                 if !v.is_target { return Completion::NotStarted; }
-                if state.current_val == state.target_val {
+                if (state.current_val - state.target_val).abs() <= state.tolerance {
                     Completion::Complete
                 } else {
                     let range = (state.max - state.min).max(1) as f32;
@@ -162,8 +320,8 @@ impl UINode {
                 if state.current_val == state.target_val {
                     Completion::Complete
                 } else {
-                    let total_steps = ((state.target_val - state.current_val).abs() / state.step.max(1)) as usize;
-                    let remaining = ((state.current_val - state.target_val).abs() / state.step.max(1)) as usize;
+                    let total_steps = ((state.target_val - state.start_val).abs() / state.step.max(1)) as usize;
+                    let remaining = ((state.target_val - state.current_val).abs() / state.step.max(1)) as usize;
                     let done = total_steps.saturating_sub(remaining);
                     Completion::Partial { done, total: total_steps }
                 }
@@ -179,8 +337,124 @@ impl UINode {
                 }
             }
 
+            // ── Command palette ─────────────────────────────────
+            UINode::CommandPalette(v, state) => {
+                if !v.is_target { return Completion::NotStarted; }
+                if state.query == state.target_command {
+                    Completion::Complete
+                } else if state.query.is_empty() {
+                    Completion::NotStarted
+                } else if state.target_command.starts_with(&state.query) {
+                    Completion::Partial {
+                        done: state.query.len(),
+                        total: state.target_command.len(),
+                    }
+                } else {
+                    Completion::Wrong
+                }
+            }
+
+            // ── Tile grid ───────────────────────────────────────
+            UINode::TileGrid(v, state) => {
+                if !v.is_target { return Completion::NotStarted; }
+                // A selected non-match is an outright mistake
+                if state.tiles.iter().any(|t| t.is_selected && !t.is_match) {
+                    return Completion::Wrong;
+                }
+                let total = state.tiles.iter().filter(|t| t.is_match).count();
+                let done = state.tiles.iter().filter(|t| t.is_match && t.is_selected).count();
+                if done == total {
+                    Completion::Complete
+                } else if done == 0 {
+                    Completion::NotStarted
+                } else {
+                    Completion::Partial { done, total }
+                }
+            }
+
+            // ── Checkbox group ──────────────────────────────────
+            UINode::CheckboxGroup(v, boxes, target) => {
+                if !v.is_target { return Completion::NotStarted; }
+                // Checking a box outside the target is an outright mistake
+                if boxes.iter().enumerate().any(|(i, (b, s))| s.is_checked && !target.wants(i, &b.label)) {
+                    return Completion::Wrong;
+                }
+                let total = boxes.iter().enumerate().filter(|(i, (b, _))| target.wants(*i, &b.label)).count();
+                let done = boxes.iter().enumerate().filter(|(i, (b, s))| s.is_checked && target.wants(*i, &b.label)).count();
+                if done == total {
+                    Completion::Complete
+                } else if done == 0 {
+                    Completion::NotStarted
+                } else {
+                    Completion::Partial { done, total }
+                }
+            }
+
+            // ── Carousel ────────────────────────────────────────
+            UINode::Carousel(v, state) => {
+                if !v.is_target { return Completion::NotStarted; }
+                if state.current == state.target_slide {
+                    Completion::Complete
+                } else {
+                    Completion::NotStarted
+                }
+            }
+
+            // ── Tooltip ─────────────────────────────────────────
+            UINode::Tooltip(v, state) => {
+                if !v.is_target { return Completion::NotStarted; }
+                if state.open {
+                    Completion::Complete
+                } else {
+                    Completion::NotStarted
+                }
+            }
+
+            // ── Keyboard form ───────────────────────────────────
+            UINode::KeyboardForm(v, state) => {
+                if !v.is_target { return Completion::NotStarted; }
+                // Fields must first receive focus in tab order (no skipping)
+                let in_order = state.focus_history.iter().enumerate().all(|(i, &f)| i == f);
+                let mut done = 0usize;
+                let mut any_wrong = !in_order;
+                for f in &state.fields {
+                    if f.current_value == f.target_value {
+                        done += 1;
+                    } else if !f.current_value.is_empty() && !f.target_value.starts_with(&f.current_value) {
+                        any_wrong = true;
+                    }
+                }
+                let total = state.fields.len();
+                if any_wrong {
+                    Completion::Wrong
+                } else if done == total && state.focus_history.len() == total {
+                    Completion::Complete
+                } else if done > 0 || !state.focus_history.is_empty() {
+                    Completion::Partial { done, total }
+                } else {
+                    Completion::NotStarted
+                }
+            }
+
+            // ── Sortable list ───────────────────────────────────
+            UINode::SortableList(v, state) => {
+                if !v.is_target { return Completion::NotStarted; }
+                if state.current_pos() == Some(state.target_pos) {
+                    Completion::Complete
+                } else {
+                    Completion::NotStarted
+                }
+            }
+
             // ── Containers: aggregate children ──────────────────
-            UINode::Card(_, children) | UINode::Form(_, _, children) => {
+            UINode::Card(_, children)
+            | UINode::Form(_, _, children)
+            | UINode::Modal(_, _, children)
+            | UINode::Loader(_, _, children)
+            | UINode::Pane(_, _, children)
+            | UINode::ScrollArea(_, children)
+            | UINode::Article(_, _, children)
+            | UINode::Accordion(_, _, children) => {
                 let mut done = 0usize;
                 let mut total = 0usize;
                 let mut any_wrong = false;
@@ -212,3 +486,51 @@ impl UINode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seats(current: i32) -> UINode {
+        let mut node = stepper("Seats", Rect::new(0.0, 0.0, 120.0, 32.0), 0, 20, 2, 2, 10);
+        if let UINode::Stepper(_, s) = &mut node {
+            s.current_val = current;
+        }
+        node
+    }
+
+    #[test]
+    fn stepper_progress_counts_from_start() {
+        assert_eq!(seats(2).check(), Completion::Partial { done: 0, total: 4 });
+        assert_eq!(seats(6).check(), Completion::Partial { done: 2, total: 4 });
+        assert_eq!(seats(8).check().progress(), 0.75);
+        assert_eq!(seats(10).check(), Completion::Complete);
+    }
+
+    #[test]
+    fn stepper_moved_away_has_no_progress() {
+        assert_eq!(seats(0).check(), Completion::Partial { done: 0, total: 4 });
+    }
+
+    #[test]
+    fn evaluate_reports_partial_stepper() {
+        let window = toggle_to("Window", Rect::new(0.0, 40.0, 60.0, 24.0), true, true);
+        let tree = form(Rect::new(0.0, 0.0, 200.0, 200.0), "Book", vec![seats(6), window]);
+        let eval = tree.evaluate();
+        assert!(!eval.is_complete());
+        assert_eq!(eval.unmet(), vec![("Seats".to_string(), "6".to_string())]);
+
+        let done = form(Rect::new(0.0, 0.0, 200.0, 200.0), "Book", vec![seats(10)]);
+        assert!(done.evaluate().is_complete());
+    }
+
+    #[test]
+    fn target_checkbox_is_complete_once_checked() {
+        let r = Rect::new(0.0, 0.0, 20.0, 20.0);
+        assert_eq!(checkbox("Terms", r, false).check(), Completion::NotStarted);
+        assert_eq!(checkbox("Terms", r, true).check(), Completion::Complete);
+
+        let tree = form(Rect::new(0.0, 0.0, 200.0, 200.0), "Sign up", vec![checkbox("Terms", r, false)]);
+        assert_eq!(tree.evaluate().unmet(), vec![("Terms".to_string(), "off".to_string())]);
+    }
+}
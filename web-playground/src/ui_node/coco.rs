@@ -0,0 +1,51 @@
+//! COCO export — resolved targets as object-detection annotations.
+//!
+//! One image per resolved tree; every target box becomes an annotation whose
+//! category is the owning node's variant (`UINode::kind()`). Boxes keep the
+//! tree's viewport-pixel coordinates, so the image is the `#viewport` canvas.
+
+use super::*;
+
+/// Every `UINode::kind()`; COCO category ids are index + 1.
+pub const NODE_KINDS: &[&str] = &[
+    "button", "toggle", "checkbox", "tab", "accordion", "tag", "toast", "star",
+    "modal_button", "text_input", "slider", "drag_source", "drop_zone",
+    "dropdown", "context_menu", "stepper", "radio_group", "command_palette",
+    "tile_grid", "checkbox_group", "carousel", "keyboard_form",
+    "sortable_list", "file_drag_task", "text_selection", "tooltip", "shape", "icon_button", "card", "form", "modal", "loader", "pane", "scroll_area", "article",
+];
+
+fn category_id(kind: &str) -> usize {
+    NODE_KINDS.iter().position(|k| *k == kind).map_or(0, |i| i + 1)
+}
+
+impl ResolvedGroundTruth {
+    /// Serialize the targets as a COCO detection document
+    /// (`images`, `annotations`, `categories`) for one `width`×`height` image.
+    pub fn to_coco(&self, image_id: u64, width: u32, height: u32) -> String {
+        let annotations: Vec<String> = self.targets.iter()
+            .enumerate()
+            .map(|(i, (label, rect, kind))| format!(
+                r#"{{"id":{},"image_id":{},"category_id":{},"bbox":[{},{},{},{}],"area":{},"iscrowd":0,"label":"{}"}}"#,
+                i + 1,
+                image_id,
+                category_id(kind),
+                rect.x, rect.y, rect.w, rect.h,
+                rect.w * rect.h,
+                escape_json(label),
+            ))
+            .collect();
+        let categories: Vec<String> = NODE_KINDS.iter()
+            .enumerate()
+            .map(|(i, kind)| format!(r#"{{"id":{},"name":"{}","supercategory":"ui"}}"#, i + 1, kind))
+            .collect();
+        format!(
+            r#"{{"images":[{{"id":{},"width":{},"height":{}}}],"annotations":[{}],"categories":[{}]}}"#,
+            image_id,
+            width,
+            height,
+            annotations.join(","),
+            categories.join(","),
+        )
+    }
+}
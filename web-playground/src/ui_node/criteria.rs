@@ -0,0 +1,232 @@
+//! Success criteria — the completion condition of a tree, as data.
+//!
+//! An evaluator scoring a rollout server-side gets the condition itself
+//! rather than each level's logic. It is a JSON combinator tree:
+//!
+//! - `{"all":[..]}` — every condition holds, in any order.
+//! - `{"order":[..]}` — the conditions are met one after another.
+//! - `{"field":"Volume","equals":40}` — the field's final value, in the
+//!   vocabulary `current_value` reports ("on", the typed text, the chosen
+//!   option, a 1-based slide or slot). `{"between":[lo,hi]}` takes the
+//!   place of `equals` for a slider with a tolerance; `{"selected":[..]}`
+//!   and `{"checked":[..]}` name exactly which tiles or boxes end up on.
+//! - `{"clicked":"Save"}`, `{"dropped":"report.pdf","on":"Upload"}`,
+//!   `{"focused":"Email"}`, `{"hovered":"Help"}` — events, for nodes whose
+//!   completion is the event rather than a state; any past occurrence
+//!   satisfies them.
+//!
+//! Criteria describe the round as first rendered: completed nodes stop
+//! being targets, so compute them from the initial tree.
+
+use super::*;
+
+/// A JSON string literal.
+fn quoted(s: &str) -> String {
+    format!(r#""{}""#, escape_json(s))
+}
+
+fn list(items: impl IntoIterator<Item = String>) -> String {
+    items.into_iter().collect::<Vec<_>>().join(",")
+}
+
+fn field(label: &str, test: &str, value: impl std::fmt::Display) -> String {
+    format!(r#"{{"field":{},"{test}":{value}}}"#, quoted(label))
+}
+
+fn clicked(label: &str) -> String {
+    format!(r#"{{"clicked":{}}}"#, quoted(label))
+}
+
+fn hovered(label: &str) -> String {
+    format!(r#"{{"hovered":{}}}"#, quoted(label))
+}
+
+fn dropped(item: &str, on: &str) -> String {
+    format!(r#"{{"dropped":{},"on":{}}}"#, quoted(item), quoted(on))
+}
+
+impl UINode {
+    /// The tree's success criteria as `{"all":[..]}`; see the module docs.
+    pub fn criteria_json(&self) -> String {
+        let mut out = Vec::new();
+        self.criteria_into(&mut out);
+        format!(r#"{{"all":[{}]}}"#, out.join(","))
+    }
+
+    /// Append this node's conditions to `out`. Non-target leaves add none;
+    /// containers add their children's, plus any drag pair among them.
+    fn criteria_into(&self, out: &mut Vec<String>) {
+        let v = self.visual();
+        let on_off = |on: bool| quoted(if on { "on" } else { "off" });
+        match self {
+            UINode::Card(_, children)
+            | UINode::Modal(_, _, children)
+            | UINode::Loader(_, _, children)
+            | UINode::Pane(_, _, children)
+            | UINode::ScrollArea(_, children)
+            | UINode::Article(_, _, children) => children_criteria(children, out),
+
+            UINode::Accordion(v, _, children) => {
+                if v.is_target {
+                    out.push(clicked(&v.label));
+                }
+                children_criteria(children, out);
+            }
+
+            // Fields first, then the submit click
+            UINode::Form(_, state, children) => {
+                let mut fields = Vec::new();
+                children_criteria(children, &mut fields);
+                out.push(format!(
+                    r#"{{"order":[{{"all":[{}]}},{}]}}"#,
+                    fields.join(","),
+                    clicked(&state.submit_label),
+                ));
+            }
+
+            _ if !v.is_target => {}
+
+            UINode::Button(v)
+            | UINode::Tab(v)
+            | UINode::ModalButton(v)
+            | UINode::Shape(v, _)
+            | UINode::IconButton(v, _) => out.push(clicked(&v.label)),
+
+            UINode::Toast(_, s) => out.push(clicked(&format!("dismiss: {}", s.message))),
+            UINode::ContextMenu(_, s) => out.push(clicked(&s.target_item)),
+
+            // Paired with a drop zone by the parent
+            UINode::DragSource(_) | UINode::DropZone(_) => {}
+
+            UINode::Toggle(v, s) => out.push(field(&v.label, "equals", on_off(s.target_on))),
+            UINode::Checkbox(v, _) => out.push(field(&v.label, "equals", on_off(true))),
+            UINode::Tag(v, s) => out.push(field(&v.label, "equals", on_off(s.target_selected))),
+            // The tooltip closes again once the pointer moves on
+            UINode::Tooltip(v, _) => out.push(hovered(&v.label)),
+            UINode::Star(v, s) => out.push(field(&v.label, "equals", s.target)),
+            UINode::TextInput(v, s) => out.push(field(&v.label, "equals", quoted(&s.target_value))),
+            UINode::TextSelection(v, s) => out.push(field(&v.label, "equals", quoted(&s.target_text))),
+            UINode::Slider(v, s) if s.tolerance > 0 => out.push(field(
+                &v.label,
+                "between",
+                format!("[{},{}]", s.target_val - s.tolerance, s.target_val + s.tolerance),
+            )),
+            UINode::Slider(v, s) => out.push(field(&v.label, "equals", s.target_val)),
+            UINode::Stepper(v, s) => out.push(field(&v.label, "equals", s.target_val)),
+            UINode::Dropdown(v, s) => out.push(field(&v.label, "equals", quoted(&s.target_option))),
+            UINode::RadioGroup(v, s) => {
+                let option = s.options.get(s.target_option).map_or("", String::as_str);
+                out.push(field(&v.label, "equals", quoted(option)));
+            }
+            UINode::CommandPalette(v, s) => out.push(field(&v.label, "equals", quoted(&s.target_command))),
+            UINode::Carousel(v, s) => out.push(field(&v.label, "equals", s.target_slide + 1)),
+            UINode::SortableList(v, s) => out.push(format!(
+                r#"{{"field":{},"item":{},"equals":{}}}"#,
+                quoted(&v.label),
+                quoted(&s.target_item),
+                s.target_pos + 1,
+            )),
+            UINode::TileGrid(v, s) => {
+                let tiles = s.tiles.iter().filter(|t| t.is_match).map(|t| quoted(&t.label));
+                out.push(field(&v.label, "selected", format!("[{}]", list(tiles))));
+            }
+            UINode::CheckboxGroup(v, boxes, target) => {
+                let wanted = boxes.iter().enumerate()
+                    .filter(|(i, (b, _))| target.wants(*i, &b.label))
+                    .map(|(_, (b, _))| quoted(&b.label));
+                out.push(field(&v.label, "checked", format!("[{}]", list(wanted))));
+            }
+            // Every field focused in tab order, each holding its value
+            UINode::KeyboardForm(_, s) => {
+                let focus = s.fields.iter().map(|f| format!(r#"{{"focused":{}}}"#, quoted(&f.label)));
+                let values = s.fields.iter().map(|f| field(&f.label, "equals", quoted(&f.target_value)));
+                out.push(format!(r#"{{"all":[{{"order":[{}]}},{}]}}"#, list(focus), list(values)));
+            }
+            UINode::FileDragTask(_, s) => {
+                if let Some(file) = s.files.get(s.target) {
+                    out.push(dropped(&file.label, &s.zone.label));
+                }
+            }
+        }
+    }
+}
+
+/// Conditions of `children`, then a drop for each target `DragSource` onto
+/// the first `DropZone` among them, as `emit_drag_pairs` resolves it.
+fn children_criteria(children: &[UINode], out: &mut Vec<String>) {
+    for child in children {
+        child.criteria_into(out);
+    }
+    let zone = children.iter().find_map(|c| match c {
+        UINode::DropZone(v) => Some(&v.label),
+        _ => None,
+    });
+    if let Some(zone) = zone {
+        let sources = children.iter().filter_map(|c| match c {
+            UINode::DragSource(v) if v.is_target => Some(&v.label),
+            _ => None,
+        });
+        out.extend(sources.map(|label| dropped(label, zone)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r() -> Rect {
+        Rect::new(0.0, 0.0, 100.0, 30.0)
+    }
+
+    #[test]
+    fn target_checkbox_ends_checked() {
+        let tree = card(r(), vec![checkbox("Remember me", r(), false)]);
+        assert_eq!(tree.criteria_json(), r#"{"all":[{"field":"Remember me","equals":"on"}]}"#);
+    }
+
+    #[test]
+    fn tooltip_is_a_past_hover() {
+        let tree = form(r(), "Done", vec![UINode::Tooltip(
+            Visual::new("Help", r()).target(),
+            TooltipState { text: "Opens the docs".into(), open: false },
+        )]);
+        assert_eq!(
+            tree.criteria_json(),
+            r#"{"all":[{"order":[{"all":[{"hovered":"Help"}]},{"clicked":"Done"}]}]}"#,
+        );
+    }
+
+    #[test]
+    fn non_targets_add_nothing() {
+        let tree = card(r(), vec![button("Cancel", r()), target_button("Save", r())]);
+        assert_eq!(tree.criteria_json(), r#"{"all":[{"clicked":"Save"}]}"#);
+    }
+
+    #[test]
+    fn form_fields_come_before_submit() {
+        let tree = form(r(), "Sign up", vec![
+            text_input("Email", r(), "you@example.com", "a@b.c"),
+            checkbox("Terms", r(), false),
+            stepper("Seats", r(), 1, 9, 1, 1, 3),
+        ]);
+        assert_eq!(
+            tree.criteria_json(),
+            r#"{"all":[{"order":[{"all":[{"field":"Email","equals":"a@b.c"},{"field":"Terms","equals":"on"},{"field":"Seats","equals":3}]},{"clicked":"Sign up"}]}]}"#,
+        );
+    }
+
+    #[test]
+    fn drag_source_drops_on_sibling_zone() {
+        let tree = card(r(), vec![drag_source("report.pdf", r()), drop_zone("Upload", r())]);
+        assert_eq!(tree.criteria_json(), r#"{"all":[{"dropped":"report.pdf","on":"Upload"}]}"#);
+    }
+
+    #[test]
+    fn slider_tolerance_is_a_range() {
+        let mut tree = slider("Volume", r(), 0, 100, 1, 0, 40, r(), r());
+        if let UINode::Slider(_, s) = &mut tree {
+            s.tolerance = 2;
+        }
+        assert_eq!(tree.criteria_json(), r#"{"all":[{"field":"Volume","between":[38,42]}]}"#);
+    }
+}
@@ -0,0 +1,385 @@
+//! i18n — string tables for ground-truth text and level instructions.
+//!
+//! Every user-facing sentence the resolver or a level emits is looked up here
+//! by key and filled with positional `{0}`, `{1}`, … arguments, so word order
+//! can differ per language. Missing translations fall back to English.
+
+use std::fmt::Display;
+
+/// Output language for descriptions, thinking and instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    En,
+    Es,
+    De,
+    Ja,
+}
+
+impl Lang {
+    /// Parse a `?lang=` value ("en", "es", "de", "ja").
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "en" => Some(Self::En),
+            "es" => Some(Self::Es),
+            "de" => Some(Self::De),
+            "ja" => Some(Self::Ja),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Es => "es",
+            Self::De => "de",
+            Self::Ja => "ja",
+        }
+    }
+
+    fn column(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// key → [en, es, de, ja]
+const STRINGS: &[(&str, [&str; 4])] = &[
+    // ── Positions ───────────────────────────────────────────────────────
+    ("pos.near", ["near the {0} ({1})", "cerca de la zona {0} ({1})", "im Bereich {0} ({1})", "{0}付近 ({1})"]),
+    ("pos.near_within", ["near the {0} of the {1} ({2})", "cerca de la zona {0} de {1} ({2})", "im Bereich {0} von {1} ({2})", "{1}の{0}付近 ({2})"]),
+    ("region.top-left", ["top-left", "superior izquierda", "oben links", "左上"]),
+    ("region.top-center", ["top-center", "superior central", "oben mittig", "上部中央"]),
+    ("region.top-right", ["top-right", "superior derecha", "oben rechts", "右上"]),
+    ("region.center-left", ["center-left", "central izquierda", "mittig links", "左中央"]),
+    ("region.center", ["center", "central", "Mitte", "中央"]),
+    ("region.center-right", ["center-right", "central derecha", "mittig rechts", "右中央"]),
+    ("region.bottom-left", ["bottom-left", "inferior izquierda", "unten links", "左下"]),
+    ("region.bottom-center", ["bottom-center", "inferior central", "unten mittig", "下部中央"]),
+    ("region.bottom-right", ["bottom-right", "inferior derecha", "unten rechts", "右下"]),
+    ("parent.card", ["card", "la tarjeta", "der Karte", "カード"]),
+    ("parent.form", ["form", "el formulario", "dem Formular", "フォーム"]),
+    ("parent.section", ["section", "la sección", "dem Abschnitt", "セクション"]),
+    ("parent.dialog", ["dialog", "el diálogo", "dem Dialog", "ダイアログ"]),
+    ("parent.pane", ["pane", "el panel", "dem Bereich", "パネル"]),
+    ("parent.list", ["list", "la lista", "der Liste", "リスト"]),
+    ("parent.article", ["article", "el artículo", "dem Artikel", "記事"]),
+
+    // ── Colours (affix: prefix in en/ja, suffix in es/de) ───────────────
+    ("color.indigo", ["indigo ", " índigo", " (indigo)", "藍色の"]),
+    ("color.blue", ["blue ", " azul", " (blau)", "青い"]),
+    ("color.teal", ["teal ", " verde azulado", " (türkis)", "青緑の"]),
+    ("color.green", ["green ", " verde", " (grün)", "緑の"]),
+    ("color.orange", ["orange ", " naranja", " (orange)", "オレンジ色の"]),
+    ("color.red", ["red ", " rojo", " (rot)", "赤い"]),
+    ("color.pink", ["pink ", " rosa", " (rosa)", "ピンクの"]),
+    ("color.yellow", ["yellow ", " amarillo", " (gelb)", "黄色い"]),
+    ("color.purple", ["purple ", " morado", " (lila)", "紫の"]),
+    // Shape phrase: {0} size, {1} colour affix, {2} outline
+    ("shape.phrase", ["{0} {1}{2}", "{2}{1} {0}", "{2}{1}, {0}", "{0}{1}{2}"]),
+    ("size.small", ["small", "pequeño", "klein", "小さい"]),
+    ("size.large", ["large", "grande", "groß", "大きい"]),
+    ("shape.circle", ["circle", "círculo", "Kreis", "円"]),
+    ("shape.square", ["square", "cuadrado", "Quadrat", "四角形"]),
+    ("shape.triangle", ["triangle", "triángulo", "Dreieck", "三角形"]),
+    ("shape.diamond", ["diamond", "rombo", "Raute", "ひし形"]),
+    ("shape.hexagon", ["hexagon", "hexágono", "Sechseck", "六角形"]),
+    ("relation.above", ["above", "encima de", "über", "上"]),
+    ("relation.below", ["below", "debajo de", "unter", "下"]),
+    ("relation.left_of", ["to the left of", "a la izquierda de", "links von", "左"]),
+    ("relation.right_of", ["to the right of", "a la derecha de", "rechts von", "右"]),
+    ("glyph.gear", ["gear", "engranaje", "Zahnrad", "歯車"]),
+    ("glyph.trash", ["trash can", "papelera", "Mülleimer", "ゴミ箱"]),
+    ("glyph.search", ["magnifying glass", "lupa", "Lupe", "虫眼鏡"]),
+    ("glyph.bell", ["bell", "campana", "Glocke", "ベル"]),
+    ("glyph.home", ["house", "casa", "Haus", "家"]),
+    ("glyph.pencil", ["pencil", "lápiz", "Stift", "鉛筆"]),
+    ("glyph.download", ["down arrow into a tray", "flecha hacia una bandeja", "Pfeil nach unten in eine Ablage", "トレイへの下向き矢印"]),
+
+    // ── State words ─────────────────────────────────────────────────────
+    ("state.on", ["on", "activado", "an", "オン"]),
+    ("state.off", ["off", "desactivado", "aus", "オフ"]),
+    ("state.checked", ["checked", "marcada", "aktiviert", "チェック済み"]),
+    ("state.unchecked", ["unchecked", "sin marcar", "nicht aktiviert", "未チェック"]),
+    ("state.selected", ["selected", "seleccionada", "ausgewählt", "選択済み"]),
+    ("state.unselected", ["unselected", "sin seleccionar", "nicht ausgewählt", "未選択"]),
+    ("state.open", ["open", "abierta", "geöffnet", "開いている"]),
+    ("state.closed", ["closed", "cerrada", "geschlossen", "閉じている"]),
+    ("state.nothing", ["nothing", "nada", "nichts", "なし"]),
+    ("dir.right", ["right", "a la derecha", "nach rechts", "右に"]),
+    ("dir.left", ["left", "a la izquierda", "nach links", "左に"]),
+    ("dir.increment", ["increment", "aumentar", "erhöhen", "増や"]),
+    ("dir.decrement", ["decrement", "disminuir", "verringern", "減ら"]),
+    ("dir.up", ["up", "hacia arriba", "nach oben", "上に"]),
+    ("dir.down", ["down", "hacia abajo", "nach unten", "下に"]),
+    ("list.then", [", then ", ", luego ", ", dann ", "、次に"]),
+    ("group.all", ["all {0} checkboxes", "las {0} casillas", "alle {0} Kästchen", "{0}個すべてのチェックボックス"]),
+    ("group.nth", ["the {0} checkbox (\"{1}\")", "la {0} casilla (\"{1}\")", "das {0} Kästchen (\"{1}\")", "{0}のチェックボックス「{1}」"]),
+    ("group.label", ["the checkbox \"{0}\"", "la casilla \"{0}\"", "das Kästchen \"{0}\"", "チェックボックス「{0}」"]),
+    ("carousel.arrows", ["arrows", "flechas", "Pfeile", "矢印"]),
+    ("carousel.dots", ["dots", "puntos", "Punkte", "ドット"]),
+    ("carousel.arrows_dots", ["arrows and dots", "flechas y puntos", "Pfeile und Punkte", "矢印とドット"]),
+    ("carousel.tabs", ["numbered tabs", "pestañas numeradas", "nummerierte Tabs", "番号タブ"]),
+    ("carousel.auto", ["auto-advancing", "avance automático", "automatisch", "自動切り替え"]),
+
+    // ── Descriptions ────────────────────────────────────────────────────
+    ("desc.button", ["{0}button \"{1}\" at {2}", "botón{0} \"{1}\" {2}", "Schaltfläche{0} \"{1}\" {2}", "{0}ボタン「{1}」 {2}"]),
+    ("desc.toggle", ["toggle \"{0}\" ({1}) at {2}", "interruptor \"{0}\" ({1}) {2}", "Schalter \"{0}\" ({1}) {2}", "トグル「{0}」({1}) {2}"]),
+    ("desc.checkbox", ["checkbox \"{0}\" ({1}) at {2}", "casilla \"{0}\" ({1}) {2}", "Kontrollkästchen \"{0}\" ({1}) {2}", "チェックボックス「{0}」({1}) {2}"]),
+    ("desc.tab", ["tab \"{0}\" at {1}", "pestaña \"{0}\" {1}", "Tab \"{0}\" {1}", "タブ「{0}」 {1}"]),
+    ("desc.accordion", ["accordion \"{0}\" at {1}", "acordeón \"{0}\" {1}", "Akkordeon \"{0}\" {1}", "アコーディオン「{0}」 {1}"]),
+    ("desc.tag", ["tag \"{0}\" ({1}) at {2}", "etiqueta \"{0}\" ({1}) {2}", "Tag \"{0}\" ({1}) {2}", "タグ「{0}」({1}) {2}"]),
+    ("desc.toast", ["toast ({0}) \"{1}\" at {2}", "aviso ({0}) \"{1}\" {2}", "Toast ({0}) \"{1}\" {2}", "トースト({0})「{1}」 {2}"]),
+    ("desc.star", ["star rating \"{0}\" {1}/{2} target={3} at {4}", "valoración \"{0}\" {1}/{2} target={3} {4}", "Sternebewertung \"{0}\" {1}/{2} target={3} {4}", "星評価「{0}」{1}/{2} target={3} {4}"]),
+    ("desc.modal_button", ["{0}modal button \"{1}\" at {2}", "botón{0} de diálogo \"{1}\" {2}", "Dialog-Schaltfläche{0} \"{1}\" {2}", "{0}ダイアログボタン「{1}」 {2}"]),
+    ("desc.text_input", ["text input \"{0}\" placeholder=\"{1}\" at {2}", "campo de texto \"{0}\" placeholder=\"{1}\" {2}", "Textfeld \"{0}\" placeholder=\"{1}\" {2}", "テキスト入力「{0}」placeholder=\"{1}\" {2}"]),
+    ("desc.text_selection", ["passage \"{0}\" target=\"{1}\" at {2}", "pasaje \"{0}\" target=\"{1}\" {2}", "Textabschnitt \"{0}\" target=\"{1}\" {2}", "文章「{0}」target=\"{1}\" {2}"]),
+    ("desc.slider", ["{0}slider \"{1}\" range {2}-{3} step {4} current={5} target={6}{7} at {8}", "control deslizante{0} \"{1}\" rango {2}-{3} paso {4} current={5} target={6}{7} {8}", "Schieberegler{0} \"{1}\" Bereich {2}-{3} Schritt {4} current={5} target={6}{7} {8}", "{0}スライダー「{1}」範囲 {2}-{3} ステップ {4} current={5} target={6}{7} {8}"]),
+    ("desc.slider_band", [" (accepts {0}-{1})", " (acepta {0}-{1})", " (akzeptiert {0}-{1})", " ({0}-{1}で可)"]),
+    ("desc.drag_source", ["draggable \"{0}\" at {1}", "elemento arrastrable \"{0}\" {1}", "ziehbares Element \"{0}\" {1}", "ドラッグ可能な要素「{0}」 {1}"]),
+    ("desc.drop_zone", ["drop zone \"{0}\" at {1}", "zona de destino \"{0}\" {1}", "Ablagebereich \"{0}\" {1}", "ドロップ先「{0}」 {1}"]),
+    ("desc.dropdown", ["dropdown \"{0}\" options=[{1}] target=\"{2}\" at {3}", "desplegable \"{0}\" options=[{1}] target=\"{2}\" {3}", "Dropdown \"{0}\" options=[{1}] target=\"{2}\" {3}", "ドロップダウン「{0}」options=[{1}] target=\"{2}\" {3}"]),
+    ("desc.context_menu", ["context menu trigger=\"{0}\" items=[{1}] target=\"{2}\" at {3}", "menú contextual trigger=\"{0}\" items=[{1}] target=\"{2}\" {3}", "Kontextmenü trigger=\"{0}\" items=[{1}] target=\"{2}\" {3}", "コンテキストメニュー trigger=\"{0}\" items=[{1}] target=\"{2}\" {3}"]),
+    ("desc.stepper", ["stepper \"{0}\" range {1}-{2} step {3} current={4} target={5} at {6}", "selector numérico \"{0}\" rango {1}-{2} paso {3} current={4} target={5} {6}", "Zahlenfeld \"{0}\" Bereich {1}-{2} Schritt {3} current={4} target={5} {6}", "ステッパー「{0}」範囲 {1}-{2} ステップ {3} current={4} target={5} {6}"]),
+    ("desc.radio_group", ["radio group \"{0}\" options=[{1}] at {2}", "grupo de opciones \"{0}\" options=[{1}] {2}", "Optionsgruppe \"{0}\" options=[{1}] {2}", "ラジオグループ「{0}」options=[{1}] {2}"]),
+    ("desc.command_palette", ["command palette ({0}, hotkey {1}) commands=[{2}] target=\"{3}\" at {4}", "paleta de comandos ({0}, atajo {1}) commands=[{2}] target=\"{3}\" {4}", "Befehlspalette ({0}, Tastenkürzel {1}) commands=[{2}] target=\"{3}\" {4}", "コマンドパレット({0}、ショートカット {1}) commands=[{2}] target=\"{3}\" {4}"]),
+    ("desc.tile_grid", ["tile grid \"{0}\" {1} columns prompt=\"{2}\" tiles=[{3}] at {4}", "cuadrícula \"{0}\" {1} columnas prompt=\"{2}\" tiles=[{3}] {4}", "Kachelraster \"{0}\" {1} Spalten prompt=\"{2}\" tiles=[{3}] {4}", "タイルグリッド「{0}」{1}列 prompt=\"{2}\" tiles=[{3}] {4}"]),
+    ("desc.checkbox_group", ["checkbox group \"{0}\" boxes=[{1}] at {2}", "grupo de casillas \"{0}\" boxes=[{1}] {2}", "Kontrollkästchengruppe \"{0}\" boxes=[{1}] {2}", "チェックボックスグループ「{0}」boxes=[{1}] {2}"]),
+    ("desc.carousel", ["carousel \"{0}\" ({1}) showing slide {2}/{3} at {4}", "carrusel \"{0}\" ({1}) en la diapositiva {2}/{3} {4}", "Karussell \"{0}\" ({1}) zeigt Folie {2}/{3} {4}", "カルーセル「{0}」({1}) スライド{2}/{3}を表示中 {4}"]),
+    ("desc.tooltip", ["badge \"{0}\" tooltip {1} at {2}", "insignia \"{0}\" información emergente {1} {2}", "Abzeichen \"{0}\" Tooltip {1} {2}", "バッジ「{0}」ツールチップ{1} {2}"]),
+    ("desc.shape", ["shape: {0} at {1}", "forma: {0} {1}", "Form: {0} {1}", "図形: {0} {1}"]),
+    ("desc.icon_button", ["icon-only button \"{0}\" ({1} glyph, no text) at {2}", "botón solo con icono \"{0}\" (glifo: {1}, sin texto) {2}", "reine Symbolschaltfläche \"{0}\" (Symbol: {1}, kein Text) {2}", "アイコンのみのボタン「{0}」({1}のアイコン、文字なし) {2}"]),
+    ("desc.relative", ["\"{0}\" sits {1}px {2} \"{3}\"", "\"{0}\" está a {1}px {2} \"{3}\"", "\"{0}\" liegt {1}px {2} \"{3}\"", "「{0}」は「{3}」の{2}{1}pxにある"]),
+    ("desc.pane", ["{0} of a split screen at {1}", "{0} de una pantalla dividida {1}", "{0} eines geteilten Bildschirms {1}", "分割画面の{0} {1}"]),
+    ("desc.scroll_area", ["scrollable list \"{0}\" at {1}", "lista desplazable \"{0}\" {1}", "scrollbare Liste \"{0}\" {1}", "スクロール可能なリスト「{0}」 {1}"]),
+    ("desc.article", ["article \"{0}\" with a table of contents at {1}", "artículo \"{0}\" con índice {1}", "Artikel \"{0}\" mit Inhaltsverzeichnis {1}", "目次付きの記事「{0}」 {1}"]),
+    ("desc.keyboard_form", ["keyboard-only form \"{0}\" fields=[{1}] focused={2} at {3}", "formulario solo con teclado \"{0}\" fields=[{1}] focused={2} {3}", "reines Tastaturformular \"{0}\" fields=[{1}] focused={2} {3}", "キーボード専用フォーム「{0}」fields=[{1}] focused={2} {3}"]),
+    ("desc.sortable_list", ["sortable list \"{0}\" items=[{1}] at {2}", "lista ordenable \"{0}\" items=[{1}] {2}", "sortierbare Liste \"{0}\" items=[{1}] {2}", "並べ替えリスト「{0}」items=[{1}] {2}"]),
+    ("desc.file_drag_task", ["file upload files=[{0}] zone=\"{1}\" at {2}", "subida de archivos files=[{0}] zone=\"{1}\" {2}", "Datei-Upload files=[{0}] zone=\"{1}\" {2}", "ファイルアップロード files=[{0}] zone=\"{1}\" {2}"]),
+    ("desc.clutter", ["clutter: {0} \"{1}\" at {2}", "decoración: {0} \"{1}\" {2}", "Dekoration: {0} \"{1}\" {2}", "装飾: {0}「{1}」 {2}"]),
+    ("clutter.badge", ["badge", "insignia", "Badge", "バッジ"]),
+    ("clutter.avatar", ["avatar", "avatar", "Avatar", "アバター"]),
+    ("clutter.button", ["fake button", "botón falso", "Attrappen-Schaltfläche", "ダミーボタン"]),
+    ("desc.background", ["{0} background", "fondo {0}", "Hintergrund: {0}", "{0}の背景"]),
+    ("bg.flat", ["solid", "liso", "einfarbig", "単色"]),
+    ("bg.linear_gradient", ["linear gradient", "con degradado lineal", "linearer Verlauf", "線形グラデーション"]),
+    ("bg.radial_gradient", ["radial gradient", "con degradado radial", "radialer Verlauf", "放射グラデーション"]),
+    ("bg.noise", ["noise-textured", "con textura granulada", "körnige Textur", "ノイズ質感"]),
+    ("bg.stripes", ["striped", "a rayas", "gestreift", "ストライプ"]),
+    ("bg.dots", ["dotted", "de puntos", "gepunktet", "ドット柄"]),
+    ("bg.grid", ["grid", "cuadriculado", "Raster", "グリッド"]),
+    ("bg.checker", ["checkered", "a cuadros", "kariert", "チェック柄"]),
+    ("desc.rotation", ["rotated {0}°", "girado {0}°", "um {0}° gedreht", "{0}°回転"]),
+    ("desc.typography", ["{0}", "tipografía: {0}", "Schrift: {0}", "書体: {0}"]),
+    ("desc.theme", ["{0} theme", "tema {0}", "Design {0}", "{0}テーマ"]),
+    ("theme.light", ["light", "claro", "hell", "ライト"]),
+    ("theme.dark", ["dark", "oscuro", "dunkel", "ダーク"]),
+    ("theme.material", ["material", "material", "Material", "マテリアル"]),
+    ("theme.brutalist", ["brutalist", "brutalista", "brutalistisch", "ブルータリスト"]),
+    ("theme.pastel", ["pastel", "pastel", "Pastell", "パステル"]),
+    ("desc.card", ["card at {0}", "tarjeta {0}", "Karte {0}", "カード {0}"]),
+    ("desc.modal", ["dialog \"{0}\" at {1}", "diálogo \"{0}\" {1}", "Dialog \"{0}\" {1}", "ダイアログ「{0}」 {1}"]),
+    ("desc.loader", ["\"{0}\" loading at {1}", "\"{0}\" cargando {1}", "\"{0}\" lädt {1}", "「{0}」読み込み中 {1}"]),
+    ("desc.loader_done", ["\"{0}\" loaded at {1}", "\"{0}\" cargado {1}", "\"{0}\" geladen {1}", "「{0}」読み込み済み {1}"]),
+    ("desc.form", ["form at {0}", "formulario {0}", "Formular {0}", "フォーム {0}"]),
+
+    // ── Thinking (verbose) ──────────────────────────────────────────────
+    ("think.button", ["I see a {0}button labeled \"{1}\", located at {2}. I should click it.", "Veo un botón{0} con la etiqueta \"{1}\", situado {2}. Debo hacer clic en él.", "Ich sehe eine Schaltfläche{0} mit der Beschriftung \"{1}\", {2}. Ich sollte darauf klicken.", "{2}に{0}「{1}」というボタンがあります。クリックします。"]),
+    ("think.toggle", ["I see a toggle labeled \"{0}\", currently {1}, located {2}. I need to click it to switch it.", "Veo un interruptor con la etiqueta \"{0}\", actualmente {1}, situado {2}. Tengo que hacer clic para cambiarlo.", "Ich sehe einen Schalter \"{0}\", derzeit {1}, {2}. Ich muss darauf klicken, um ihn umzuschalten.", "{2}にトグル「{0}」があり、現在{1}です。クリックして切り替えます。"]),
+    ("think.toggle_keep", ["The toggle labeled \"{0}\" is already {1}, located {2}. I leave it as is.", "El interruptor \"{0}\" ya está {1}, situado {2}. Lo dejo como está.", "Der Schalter \"{0}\" ist bereits {1}, {2}. Ich lasse ihn so.", "{2}のトグル「{0}」はすでに{1}です。そのままにします。"]),
+    ("think.checkbox", ["I see a checkbox labeled \"{0}\", currently {1}, located {2}. I need to click it.", "Veo una casilla con la etiqueta \"{0}\", actualmente {1}, situada {2}. Tengo que hacer clic en ella.", "Ich sehe ein Kontrollkästchen \"{0}\", derzeit {1}, {2}. Ich muss darauf klicken.", "{2}にチェックボックス「{0}」があり、現在{1}です。クリックします。"]),
+    ("think.checkbox_keep", ["The checkbox labeled \"{0}\" is already checked, located {1}. I leave it as is.", "La casilla \"{0}\" ya está marcada, situada {1}. La dejo como está.", "Das Kontrollkästchen \"{0}\" ist bereits angekreuzt, {1}. Ich lasse es so.", "{1}のチェックボックス「{0}」はすでにオンです。そのままにします。"]),
+    ("think.tab", ["I see a tab labeled \"{0}\", located {1}. I need to click it to switch to that tab.", "Veo una pestaña con la etiqueta \"{0}\", situada {1}. Tengo que hacer clic para cambiar a esa pestaña.", "Ich sehe einen Tab \"{0}\", {1}. Ich muss darauf klicken, um zu diesem Tab zu wechseln.", "{1}にタブ「{0}」があります。クリックしてそのタブに切り替えます。"]),
+    ("think.accordion", ["I see a collapsible section labeled \"{0}\", located {1}. I need to click it to expand it.", "Veo una sección plegable con la etiqueta \"{0}\", situada {1}. Tengo que hacer clic para desplegarla.", "Ich sehe einen aufklappbaren Abschnitt \"{0}\", {1}. Ich muss darauf klicken, um ihn aufzuklappen.", "{1}に折りたたみセクション「{0}」があります。クリックして展開します。"]),
+    ("think.tag", ["I see a tag chip labeled \"{0}\", currently {1}, located {2}. I need to click it.", "Veo una etiqueta \"{0}\", actualmente {1}, situada {2}. Tengo que hacer clic en ella.", "Ich sehe einen Tag \"{0}\", derzeit {1}, {2}. Ich muss darauf klicken.", "{2}にタグ「{0}」があり、現在{1}です。クリックします。"]),
+    ("think.toast", ["I see a {0} toast notification saying \"{1}\", located {2}. I need to dismiss it.", "Veo un aviso de tipo {0} que dice \"{1}\", situado {2}. Tengo que cerrarlo.", "Ich sehe eine {0}-Benachrichtigung \"{1}\", {2}. Ich muss sie schließen.", "{2}に「{1}」という{0}通知があります。閉じる必要があります。"]),
+    ("think.star", ["I see a star rating for \"{0}\" currently at {1}/{2}, located {3}. I need to click star {4} to set it to {4}.", "Veo una valoración de \"{0}\" en {1}/{2}, situada {3}. Tengo que hacer clic en la estrella {4} para ponerla en {4}.", "Ich sehe eine Sternebewertung für \"{0}\", derzeit {1}/{2}, {3}. Ich muss auf Stern {4} klicken, um sie auf {4} zu setzen.", "{3}に「{0}」の星評価があり、現在{1}/{2}です。星{4}をクリックして{4}にします。"]),
+    ("think.modal_button", ["I see a {0}button labeled \"{1}\" in the dialog, located {2}. I should click it.", "Veo un botón{0} con la etiqueta \"{1}\" en el diálogo, situado {2}. Debo hacer clic en él.", "Ich sehe im Dialog eine Schaltfläche{0} \"{1}\", {2}. Ich sollte darauf klicken.", "ダイアログの{2}に{0}「{1}」というボタンがあります。クリックします。"]),
+    ("think.text_input", ["I see a text input labeled \"{0}\", located {1}. I need to type \"{2}\" into it.", "Veo un campo de texto con la etiqueta \"{0}\", situado {1}. Tengo que escribir \"{2}\" en él.", "Ich sehe ein Textfeld \"{0}\", {1}. Ich muss \"{2}\" eingeben.", "{1}にテキスト入力「{0}」があります。「{2}」と入力します。"]),
+    ("think.select_text", ["I see a passage labeled \"{0}\", located {1}. I need to select \"{2}\" in it by dragging across those words.", "Veo un pasaje con la etiqueta \"{0}\", situado {1}. Tengo que seleccionar \"{2}\" arrastrando sobre esas palabras.", "Ich sehe einen Textabschnitt \"{0}\", {1}. Ich muss \"{2}\" markieren, indem ich darüber ziehe.", "{1}に文章「{0}」があります。「{2}」の上をドラッグして選択します。"]),
+    ("think.slider_native", ["I see a {0}range input labeled \"{1}\" currently at {2}, located {3}. I need to set it to {4}.", "Veo un control de rango{0} con la etiqueta \"{1}\" en {2}, situado {3}. Tengo que ponerlo en {4}.", "Ich sehe einen Bereichsregler{0} \"{1}\" bei {2}, {3}. Ich muss ihn auf {4} stellen.", "{3}に{0}レンジ入力「{1}」があり、現在{2}です。{4}に設定します。"]),
+    ("think.slider", ["I see a {0}slider labeled \"{1}\" currently at {2}, located {3}. I need to drag it {4} by {5} to reach {6}.", "Veo un control deslizante{0} con la etiqueta \"{1}\" en {2}, situado {3}. Tengo que arrastrarlo {4} {5} unidades hasta {6}.", "Ich sehe einen Schieberegler{0} \"{1}\" bei {2}, {3}. Ich muss ihn um {5} {4} ziehen, um {6} zu erreichen.", "{3}に{0}スライダー「{1}」があり、現在{2}です。{4}{5}ドラッグして{6}にします。"]),
+    ("think.drag_source", ["I see a draggable element labeled \"{0}\", located {1}. I need to drag it to the drop zone.", "Veo un elemento arrastrable con la etiqueta \"{0}\", situado {1}. Tengo que arrastrarlo a la zona de destino.", "Ich sehe ein ziehbares Element \"{0}\", {1}. Ich muss es in den Ablagebereich ziehen.", "{1}にドラッグ可能な要素「{0}」があります。ドロップ先までドラッグします。"]),
+    ("think.dropdown", ["I see a dropdown labeled \"{0}\", located {1}. I need to click \"{2}\" to open it, then select \"{3}\".", "Veo un desplegable con la etiqueta \"{0}\", situado {1}. Tengo que hacer clic en \"{2}\" para abrirlo y luego elegir \"{3}\".", "Ich sehe ein Dropdown \"{0}\", {1}. Ich muss auf \"{2}\" klicken, um es zu öffnen, und dann \"{3}\" wählen.", "{1}にドロップダウン「{0}」があります。「{2}」をクリックして開き、「{3}」を選びます。"]),
+    ("think.dropdown_search", ["I see a searchable dropdown labeled \"{0}\", located {1}. I need to click \"{2}\" to open it, type \"{3}\" to filter the options, then select \"{4}\".", "Veo un desplegable con buscador y la etiqueta \"{0}\", situado {1}. Tengo que hacer clic en \"{2}\" para abrirlo, escribir \"{3}\" para filtrar las opciones y luego elegir \"{4}\".", "Ich sehe ein durchsuchbares Dropdown \"{0}\", {1}. Ich muss auf \"{2}\" klicken, um es zu öffnen, \"{3}\" eingeben, um die Optionen zu filtern, und dann \"{4}\" wählen.", "{1}に検索できるドロップダウン「{0}」があります。「{2}」をクリックして開き、「{3}」と入力して絞り込み、「{4}」を選びます。"]),
+    ("think.dropdown_keys", ["I see a dropdown labeled \"{0}\", located {1}. I need to click \"{2}\" to open it, press {3} {4} time(s) to highlight \"{5}\", then press Enter.", "Veo un desplegable con la etiqueta \"{0}\", situado {1}. Tengo que hacer clic en \"{2}\" para abrirlo, pulsar {3} {4} vez/veces hasta resaltar \"{5}\" y luego pulsar Enter.", "Ich sehe ein Dropdown \"{0}\", {1}. Ich muss auf \"{2}\" klicken, um es zu öffnen, {4}-mal {3} drücken, bis \"{5}\" markiert ist, und dann Enter drücken.", "{1}にドロップダウン「{0}」があります。「{2}」をクリックして開き、{3}を{4}回押して「{5}」を選択状態にし、Enterを押します。"]),
+    ("think.context_menu", ["I see an element I need to right-click, located {0}. I'll right-click \"{1}\", then select \"{2}\" from the menu.", "Veo un elemento en el que debo hacer clic derecho, situado {0}. Haré clic derecho en \"{1}\" y luego elegiré \"{2}\" en el menú.", "Ich sehe ein Element, das ich rechtsklicken muss, {0}. Ich klicke mit rechts auf \"{1}\" und wähle dann \"{2}\" im Menü.", "{0}に右クリックする要素があります。「{1}」を右クリックし、メニューから「{2}」を選びます。"]),
+    ("think.stepper", ["I see a stepper labeled \"{0}\" currently at {1}, located {2}. I need to {3} it {4} times to reach {5}.", "Veo un selector numérico con la etiqueta \"{0}\" en {1}, situado {2}. Tengo que {3}lo {4} veces hasta llegar a {5}.", "Ich sehe ein Zahlenfeld \"{0}\" bei {1}, {2}. Ich muss es {4}-mal {3}, um {5} zu erreichen.", "{2}にステッパー「{0}」があり、現在{1}です。{4}回{3}して{5}にします。"]),
+    ("think.radio_group", ["I see a radio group labeled \"{0}\", located {1}. I need to select the \"{2}\" option.", "Veo un grupo de opciones con la etiqueta \"{0}\", situado {1}. Tengo que elegir la opción \"{2}\".", "Ich sehe eine Optionsgruppe \"{0}\", {1}. Ich muss die Option \"{2}\" wählen.", "{1}にラジオグループ「{0}」があります。「{2}」を選択します。"]),
+    ("think.command_palette", ["I need to run the \"{0}\" command. I'll press {1} to open the command palette, type \"{0}\" into its search box, then press Enter to run it.", "Tengo que ejecutar el comando \"{0}\". Pulsaré {1} para abrir la paleta de comandos, escribiré \"{0}\" en su buscador y pulsaré Enter para ejecutarlo.", "Ich muss den Befehl \"{0}\" ausführen. Ich drücke {1}, um die Befehlspalette zu öffnen, gebe \"{0}\" in das Suchfeld ein und drücke Enter.", "「{0}」コマンドを実行します。{1}でコマンドパレットを開き、検索欄に「{0}」と入力してEnterを押します。"]),
+    ("think.tile_grid", ["I see a grid of tiles asking for {0}, located {1}. The matching tiles are {2}. I need to click {3} tile(s) in reading order.", "Veo una cuadrícula que pide {0}, situada {1}. Las casillas que coinciden son {2}. Tengo que hacer clic en {3} casilla(s) en orden de lectura.", "Ich sehe ein Kachelraster, das nach {0} fragt, {1}. Passende Kacheln sind {2}. Ich muss {3} Kachel(n) in Leserichtung anklicken.", "{1}に{0}を求めるタイルグリッドがあります。該当するタイルは{2}です。読む順に{3}枚クリックします。"]),
+    ("think.checkbox_group", ["I see a group of {0} checkboxes, located {1}. I need {2} checked, so I'll click {3} of them in reading order.", "Veo un grupo de {0} casillas, situado {1}. Tengo que marcar {2}, así que haré clic en {3} en orden de lectura.", "Ich sehe eine Gruppe von {0} Kontrollkästchen, {1}. Ich muss {2} ankreuzen und klicke dazu {3} davon in Leserichtung an.", "{1}に{0}個のチェックボックスのグループがあります。{2}をオンにする必要があるので、読む順に{3}個クリックします。"]),
+    ("think.carousel_step", ["I see a carousel {0} showing slide {1}, but I need slide {2}. I'll click \"{3}\" {4} time(s).", "Veo un carrusel {0} en la diapositiva {1}, pero necesito la {2}. Haré clic en \"{3}\" {4} vez/veces.", "Ich sehe ein Karussell {0} auf Folie {1}, brauche aber Folie {2}. Ich klicke {4}-mal auf \"{3}\".", "{0}にカルーセルがあり、スライド{1}を表示中ですが、スライド{2}が必要です。「{3}」を{4}回クリックします。"]),
+    ("think.carousel_jump", ["I see a carousel {0} showing slide {1}, but I need slide {2}. I'll click its indicator \"{3}\".", "Veo un carrusel {0} en la diapositiva {1}, pero necesito la {2}. Haré clic en su indicador \"{3}\".", "Ich sehe ein Karussell {0} auf Folie {1}, brauche aber Folie {2}. Ich klicke auf die Markierung \"{3}\".", "{0}にカルーセルがあり、スライド{1}を表示中ですが、スライド{2}が必要です。インジケーター「{3}」をクリックします。"]),
+    ("think.carousel_auto", ["I see a carousel {0} that advances on its own, now on slide {1}. I need slide {2}, so I'll wait for it to come round.", "Veo un carrusel {0} que avanza solo, ahora en la diapositiva {1}. Necesito la {2}, así que esperaré a que aparezca.", "Ich sehe ein Karussell {0}, das selbst weiterschaltet, jetzt auf Folie {1}. Ich brauche Folie {2} und warte, bis sie erscheint.", "{0}に自動で切り替わるカルーセルがあり、今はスライド{1}です。スライド{2}が必要なので、表示されるまで待ちます。"]),
+    ("think.carousel_here", ["I see a carousel {0} already showing slide {1}, the one I need.", "Veo un carrusel {0} que ya muestra la diapositiva {1}, la que necesito.", "Ich sehe ein Karussell {0}, das bereits die gesuchte Folie {1} zeigt.", "{0}のカルーセルは既に必要なスライド{1}を表示しています。"]),
+    ("think.tooltip_hover", ["I see a badge \"{0}\", located {1}. The code I need isn't printed on the page; it only shows in this badge's tooltip, so I'll hover over it and read \"{2}\" from the tooltip.", "Veo una insignia \"{0}\", situada {1}. El código que necesito no aparece en la página; solo se muestra en la información emergente de la insignia, así que pasaré el puntero por encima y leeré \"{2}\".", "Ich sehe ein Abzeichen \"{0}\", {1}. Der benötigte Code steht nicht auf der Seite, sondern nur im Tooltip des Abzeichens. Ich fahre mit der Maus darüber und lese \"{2}\" ab.", "{1}にバッジ「{0}」があります。必要なコードはページに書かれておらず、このバッジのツールチップにだけ表示されるので、ホバーしてツールチップから「{2}」を読み取ります。"]),
+    ("think.tooltip_open", ["The tooltip on badge \"{0}\", located {1}, is showing and reads \"{2}\"; that is the code I need.", "La información emergente de la insignia \"{0}\", situada {1}, está visible y dice \"{2}\"; ese es el código que necesito.", "Der Tooltip des Abzeichens \"{0}\", {1}, ist sichtbar und zeigt \"{2}\"; das ist der benötigte Code.", "{1}のバッジ「{0}」のツールチップが表示されており、「{2}」とあります。これが必要なコードです。"]),
+    ("think.shape", ["None of the shapes has any text on it, so I have to go by looks alone. The {0}, located {1}, is the only one whose size, colour and outline all match, so I'll click it.", "Ninguna de las formas tiene texto, así que solo puedo guiarme por su aspecto. El {0}, situado {1}, es el único cuyo tamaño, color y contorno coinciden, así que haré clic en él.", "Keine der Formen trägt Text, ich kann mich also nur am Aussehen orientieren. Nur bei {0}, {1}, passen Größe, Farbe und Umriss, also klicke ich darauf.", "図形には文字がないので、見た目だけで判断します。{1}にある{0}だけが大きさ・色・形のすべてに合うので、それをクリックします。"]),
+    ("think.icon_button", ["The toolbar buttons have no text, only icons. The {1} icon, located {2}, is the usual symbol for \"{0}\", so I'll click it.", "Los botones de la barra no tienen texto, solo iconos. El icono de {1}, situado {2}, es el símbolo habitual de \"{0}\", así que haré clic en él.", "Die Schaltflächen der Leiste haben keinen Text, nur Symbole. Das Symbol {1}, {2}, steht üblicherweise für \"{0}\", also klicke ich darauf.", "ツールバーのボタンには文字がなく、アイコンだけです。{2}にある{1}のアイコンは「{0}」を表す定番の記号なので、それをクリックします。"]),
+    ("think.relative", ["The instruction doesn't name the target; it only says it is {2} \"{0}\". I find \"{0}\" {1}; the element {3}px {2} it is the one I want.", "La instrucción no nombra el objetivo; solo dice que está {2} \"{0}\". Encuentro \"{0}\" {1}; el elemento a {3}px {2} él es el que busco.", "Die Anweisung nennt das Ziel nicht, sondern sagt nur, dass es {2} \"{0}\" liegt. Ich finde \"{0}\" {1}; das Element {3}px {2} ihm ist das gesuchte.", "指示は対象を名指しせず、「{0}」の{2}にあるとだけ言っています。「{0}」は{1}にあり、その{2}{3}pxにある要素が目的のものです。"]),
+    ("think.pane", ["The screen is split into two apps with the same controls. The instruction is about the {0}, located {1}, so I'll only look inside that pane.", "La pantalla está dividida en dos aplicaciones con los mismos controles. La instrucción se refiere al {0}, situado {1}, así que solo miraré dentro de ese panel.", "Der Bildschirm ist in zwei Apps mit denselben Bedienelementen geteilt. Die Anweisung betrifft: {0}, {1}, also schaue ich nur in diesen Bereich.", "画面は同じ操作部品を持つ2つのアプリに分割されています。指示は{1}の{0}についてなので、そのパネルの中だけを見ます。"]),
+    ("think.scroll_page", ["The \"{0}\" list is outside the visible part of the page ({1}), so first I'll scroll the page until it is in view.", "La lista \"{0}\" está fuera de la parte visible de la página ({1}), así que primero desplazaré la página hasta verla.", "Die Liste \"{0}\" liegt außerhalb des sichtbaren Seitenbereichs ({1}), also scrolle ich zuerst die Seite, bis sie zu sehen ist.", "リスト「{0}」はページの見えている範囲の外 ({1}) にあるので、まずページをスクロールして表示させます。"]),
+    ("think.scroll_within", ["\"{0}\" is scrolled out of sight inside \"{1}\". Scrolling the page won't reveal it; I need to scroll the list itself.", "\"{0}\" queda oculto dentro de \"{1}\". Desplazar la página no lo mostrará; tengo que desplazar la propia lista.", "\"{0}\" ist innerhalb von \"{1}\" weggescrollt. Die Seite zu scrollen hilft nicht; ich muss die Liste selbst scrollen.", "「{0}」は「{1}」の中でスクロールされて見えません。ページをスクロールしても出てこないので、リスト自体をスクロールします。"]),
+    ("think.article_scroll", ["\"{0}\" is further down \"{1}\", out of view. I'll scroll the page down until it shows.", "\"{0}\" está más abajo en \"{1}\", fuera de la vista. Desplazaré la página hasta que aparezca.", "\"{0}\" steht weiter unten in \"{1}\", außer Sicht. Ich scrolle die Seite, bis es erscheint.", "「{0}」は「{1}」のさらに下にあり見えません。表示されるまでページをスクロールします。"]),
+    ("think.article_toc", ["\"{0}\" is further down, out of view, but the table of contents links to its section. Clicking \"{1}\" jumps straight there.", "\"{0}\" está más abajo, fuera de la vista, pero el índice enlaza con su sección. Al hacer clic en \"{1}\" salto directamente allí.", "\"{0}\" steht weiter unten, außer Sicht, aber das Inhaltsverzeichnis verlinkt seinen Abschnitt. Ein Klick auf \"{1}\" springt direkt dorthin.", "「{0}」はさらに下にあり見えませんが、目次がそのセクションにリンクしています。「{1}」をクリックすれば直接移動できます。"]),
+    ("think.keyboard_form", ["I see a form {0} where the mouse does not work. I need to press Tab to move between fields and type {1}, then press {2} to submit.", "Veo un formulario {0} donde el ratón no funciona. Tengo que pulsar Tab para moverme entre campos y escribir {1}, y luego pulsar {2} para enviarlo.", "Ich sehe ein Formular {0}, in dem die Maus nicht funktioniert. Ich muss mit Tab zwischen den Feldern wechseln und {1} eingeben, dann {2} zum Absenden drücken.", "{0}にマウスが使えないフォームがあります。Tabでフィールドを移動して{1}と入力し、{2}で送信します。"]),
+    ("think.keyboard_form_field", ["\"{0}\" into {1}", "\"{0}\" en {1}", "\"{0}\" in {1}", "{1}に「{0}」"]),
+    ("think.sortable", ["I see a sortable list {0} with \"{1}\" in position {2}. It belongs in position {3}, so I'll drag it {4} onto that slot.", "Veo una lista ordenable {0} con \"{1}\" en la posición {2}. Debe ir en la posición {3}, así que lo arrastraré {4} hasta ese hueco.", "Ich sehe eine sortierbare Liste {0} mit \"{1}\" an Position {2}. Es gehört an Position {3}, also ziehe ich es {4} auf diesen Platz.", "{0}に並べ替えリストがあり、「{1}」は{2}番目です。{3}番目にあるべきなので、その位置まで{4}ドラッグします。"]),
+    ("think.sortable_here", ["I see a sortable list {0} with \"{1}\" already in position {2}, where it belongs.", "Veo una lista ordenable {0} con \"{1}\" ya en la posición {2}, donde debe estar.", "Ich sehe eine sortierbare Liste {0}, in der \"{1}\" bereits an der richtigen Position {2} steht.", "{0}の並べ替えリストでは、「{1}」は既に正しい{2}番目にあります。"]),
+    ("think.file_drag", ["I see {0} file icons and an upload area \"{1}\" at {2}. I need to drag \"{3}\", at {4}, and drop it onto the upload area.", "Veo {0} iconos de archivo y una zona de subida \"{1}\" en {2}. Tengo que arrastrar \"{3}\", en {4}, y soltarlo en la zona de subida.", "Ich sehe {0} Dateisymbole und einen Upload-Bereich \"{1}\" bei {2}. Ich muss \"{3}\" bei {4} in den Upload-Bereich ziehen.", "{0}個のファイルアイコンと{2}のアップロード領域「{1}」があります。{4}にある「{3}」をアップロード領域までドラッグします。"]),
+    ("think.card", ["I see a card {0}.", "Veo una tarjeta {0}.", "Ich sehe eine Karte {0}.", "{0}にカードがあります。"]),
+    ("think.form", ["I see a form {0}.", "Veo un formulario {0}.", "Ich sehe ein Formular {0}.", "{0}にフォームがあります。"]),
+    ("think.modal_open", ["A \"{0}\" dialog is open {1}.", "Hay un diálogo \"{0}\" abierto {1}.", "Ein Dialog \"{0}\" ist geöffnet, {1}.", "{1}にダイアログ「{0}」が開いています。"]),
+    ("think.modal_closed", ["That will open a \"{0}\" dialog, so its buttons aren't on screen yet. Once it appears I'll click {1}.", "Eso abrirá un diálogo \"{0}\", así que sus botones aún no están en pantalla. Cuando aparezca haré clic en {1}.", "Das öffnet einen Dialog \"{0}\", dessen Schaltflächen noch nicht sichtbar sind. Sobald er erscheint, klicke ich auf {1}.", "これでダイアログ「{0}」が開くため、そのボタンはまだ画面にありません。表示されたら{1}をクリックします。"]),
+    ("think.loader", ["\"{0}\" {1} is still loading, so what I need isn't on screen yet. I'll wait up to {2} ms for it to appear rather than act on the placeholder.", "\"{0}\" {1} todavía se está cargando, así que lo que necesito aún no está en pantalla. Esperaré hasta {2} ms a que aparezca en lugar de actuar sobre el marcador.", "\"{0}\" {1} lädt noch, das Benötigte ist also noch nicht sichtbar. Ich warte bis zu {2} ms, statt auf den Platzhalter zu klicken.", "{1}の「{0}」はまだ読み込み中で、必要な要素はまだ画面にありません。プレースホルダーには触れず、表示されるまで最大{2}ms待ちます。"]),
+    ("think.loader_then", ["Once it has loaded I'll click {0}.", "Cuando termine de cargar haré clic en {0}.", "Sobald es geladen ist, klicke ich auf {0}.", "読み込まれたら{0}をクリックします。"]),
+    ("think.form_submit", ["After completing the form, I click \"{0}\", located near the bottom of the form ({1}).", "Después de completar el formulario, hago clic en \"{0}\", cerca de la parte inferior del formulario ({1}).", "Nach dem Ausfüllen klicke ich auf \"{0}\", unten im Formular ({1}).", "フォームを入力したら、フォーム下部 ({1}) の「{0}」をクリックします。"]),
+    ("think.impossible", ["The instruction asks for \"{0}\", but nothing like that is on screen. I should report it as not found instead of guessing.", "La instrucción pide \"{0}\", pero no hay nada así en pantalla. Debo informar de que no se encuentra en lugar de adivinar.", "Die Anweisung verlangt \"{0}\", aber so etwas ist nicht auf dem Bildschirm. Ich sollte es als nicht gefunden melden, statt zu raten.", "指示は「{0}」を求めていますが、画面上にそのようなものはありません。推測せずに見つからないと報告します。"]),
+    ("think.offscreen", ["I cannot see \"{0}\" — it is off-screen. I need to scroll to find it.", "No veo \"{0}\": está fuera de la pantalla. Tengo que desplazarme para encontrarlo.", "Ich sehe \"{0}\" nicht — es liegt außerhalb des Bildschirms. Ich muss scrollen, um es zu finden.", "「{0}」は画面外にあり見えません。スクロールして探します。"]),
+    ("think.partial", ["\"{0}\" is partially cut off by the viewport edge. I may need to scroll to see it fully.", "\"{0}\" está parcialmente cortado por el borde. Puede que tenga que desplazarme para verlo entero.", "\"{0}\" wird teilweise vom Rand abgeschnitten. Ich muss eventuell scrollen, um es ganz zu sehen.", "「{0}」は画面の端で一部切れています。全体を見るにはスクロールが必要かもしれません。"]),
+    ("think.recovery_typo", ["I typed \"{0}\" into {1} by mistake. I clear the field and type \"{2}\" instead.", "Escribí \"{0}\" en {1} por error. Borro el campo y escribo \"{2}\".", "Ich habe versehentlich \"{0}\" in {1} getippt. Ich leere das Feld und tippe \"{2}\".", "{1}に誤って「{0}」と入力しました。欄を消して「{2}」と入力し直します。"]),
+    ("think.recovery_misclick", ["I clicked \"{0}\" by mistake, but it is only decoration. I click \"{1}\" instead.", "Hice clic en \"{0}\" por error, pero solo es decoración. Hago clic en \"{1}\".", "Ich habe versehentlich auf \"{0}\" geklickt, aber das ist nur Dekoration. Ich klicke stattdessen auf \"{1}\".", "誤って「{0}」をクリックしましたが、飾りにすぎません。代わりに「{1}」をクリックします。"]),
+
+    // ── Thinking (terse / chain-of-thought) ─────────────────────────────
+    ("terse.click", ["Click \"{0}\".", "Clic en \"{0}\".", "Auf \"{0}\" klicken.", "「{0}」をクリック。"]),
+    ("terse.toggle_keep", ["Leave \"{0}\" {1}.", "Dejar \"{0}\" {1}.", "\"{0}\" {1} lassen.", "「{0}」は{1}のまま。"]),
+    ("terse.toggle", ["Turn \"{0}\" {1}.", "Poner \"{0}\" en {1}.", "\"{0}\" auf {1} stellen.", "「{0}」を{1}にする。"]),
+    ("terse.checkbox", ["Click the \"{0}\" checkbox.", "Marcar la casilla \"{0}\".", "Kontrollkästchen \"{0}\" anklicken.", "チェックボックス「{0}」をクリック。"]),
+    ("terse.checkbox_keep", ["Leave \"{0}\" checked.", "Dejar \"{0}\" marcada.", "\"{0}\" angekreuzt lassen.", "「{0}」はオンのまま。"]),
+    ("terse.accordion", ["Expand \"{0}\".", "Desplegar \"{0}\".", "\"{0}\" aufklappen.", "「{0}」を展開。"]),
+    ("terse.modal_closed", ["When \"{0}\" opens, click {1}.", "Cuando se abra \"{0}\", hacer clic en {1}.", "Wenn \"{0}\" erscheint, auf {1} klicken.", "「{0}」が開いたら{1}をクリック。"]),
+    ("terse.loader", ["Wait up to {1} ms for \"{0}\" to load.", "Esperar hasta {1} ms a que cargue \"{0}\".", "Bis zu {1} ms warten, bis \"{0}\" geladen ist.", "「{0}」の読み込みを最大{1}ms待つ。"]),
+    ("terse.tag", ["Click the \"{0}\" tag.", "Clic en la etiqueta \"{0}\".", "Tag \"{0}\" anklicken.", "タグ「{0}」をクリック。"]),
+    ("terse.toast", ["Dismiss the \"{0}\" toast.", "Cerrar el aviso \"{0}\".", "Benachrichtigung \"{0}\" schließen.", "通知「{0}」を閉じる。"]),
+    ("terse.star", ["Click star {0} of \"{1}\".", "Clic en la estrella {0} de \"{1}\".", "Stern {0} von \"{1}\" anklicken.", "「{1}」の星{0}をクリック。"]),
+    ("terse.type", ["Type \"{0}\" into \"{1}\".", "Escribir \"{0}\" en \"{1}\".", "\"{0}\" in \"{1}\" eingeben.", "「{1}」に「{0}」と入力。"]),
+    ("terse.select_text", ["Select \"{0}\" in \"{1}\".", "Seleccionar \"{0}\" en \"{1}\".", "\"{0}\" in \"{1}\" markieren.", "「{1}」の「{0}」を選択。"]),
+    ("terse.slider_native", ["Set \"{0}\" to {1}.", "Poner \"{0}\" en {1}.", "\"{0}\" auf {1} stellen.", "「{0}」を{1}に設定。"]),
+    ("terse.slider", ["Drag \"{0}\" to {1}.", "Arrastrar \"{0}\" hasta {1}.", "\"{0}\" auf {1} ziehen.", "「{0}」を{1}までドラッグ。"]),
+    ("terse.drag", ["Drag \"{0}\" to the drop zone.", "Arrastrar \"{0}\" a la zona de destino.", "\"{0}\" in den Ablagebereich ziehen.", "「{0}」をドロップ先へドラッグ。"]),
+    ("terse.dropdown", ["Open \"{0}\" and pick \"{1}\".", "Abrir \"{0}\" y elegir \"{1}\".", "\"{0}\" öffnen und \"{1}\" wählen.", "「{0}」を開いて「{1}」を選択。"]),
+    ("terse.dropdown_search", ["Open \"{0}\", type \"{1}\", pick \"{2}\".", "Abrir \"{0}\", escribir \"{1}\", elegir \"{2}\".", "\"{0}\" öffnen, \"{1}\" eingeben, \"{2}\" wählen.", "「{0}」を開き、「{1}」と入力して「{2}」を選択。"]),
+    ("terse.dropdown_keys", ["Open \"{0}\", press {1} {2}×, press Enter.", "Abrir \"{0}\", pulsar {1} {2}×, pulsar Enter.", "\"{0}\" öffnen, {2}× {1} drücken, Enter drücken.", "「{0}」を開き、{1}を{2}回押してEnter。"]),
+    ("terse.context_menu", ["Right-click \"{0}\" and pick \"{1}\".", "Clic derecho en \"{0}\" y elegir \"{1}\".", "Rechtsklick auf \"{0}\" und \"{1}\" wählen.", "「{0}」を右クリックして「{1}」を選択。"]),
+    ("terse.stepper", ["Click \"{0}\" {1} times.", "Clic en \"{0}\" {1} veces.", "{1}-mal auf \"{0}\" klicken.", "「{0}」を{1}回クリック。"]),
+    ("terse.keep", ["Leave \"{0}\" as is.", "Dejar \"{0}\" como está.", "\"{0}\" so lassen.", "「{0}」はそのまま。"]),
+    ("terse.radio", ["Select \"{0}\".", "Elegir \"{0}\".", "\"{0}\" wählen.", "「{0}」を選択。"]),
+    ("terse.palette", ["Press {0}, type \"{1}\", press Enter.", "Pulsar {0}, escribir \"{1}\", pulsar Enter.", "{0} drücken, \"{1}\" eingeben, Enter drücken.", "{0}を押し、「{1}」と入力してEnter。"]),
+    ("terse.tiles", ["Click {0}.", "Clic en {0}.", "{0} anklicken.", "{0}をクリック。"]),
+    ("terse.checkbox_group", ["Check {0}.", "Marcar {0}.", "{0} ankreuzen.", "{0}をオン。"]),
+    ("terse.carousel", ["Go to slide {0}.", "Ir a la diapositiva {0}.", "Zu Folie {0} wechseln.", "スライド{0}へ移動。"]),
+    ("terse.carousel_auto", ["Wait for slide {0}.", "Esperar a la diapositiva {0}.", "Auf Folie {0} warten.", "スライド{0}を待つ。"]),
+    ("terse.tooltip_hover", ["Hover \"{0}\"; its tooltip reads \"{1}\".", "Pasar el puntero por \"{0}\"; su información emergente dice \"{1}\".", "Über \"{0}\" fahren; der Tooltip zeigt \"{1}\".", "「{0}」にホバー。ツールチップは「{1}」。"]),
+    ("terse.tooltip_open", ["Tooltip on \"{0}\" reads \"{1}\".", "La información emergente de \"{0}\" dice \"{1}\".", "Tooltip von \"{0}\" zeigt \"{1}\".", "「{0}」のツールチップは「{1}」。"]),
+    ("terse.shape", ["Click the {0}.", "Hacer clic en el {0}.", "{0} anklicken.", "{0}をクリック。"]),
+    ("terse.icon_button", ["Click the {0} icon (\"{1}\").", "Hacer clic en el icono de {0} (\"{1}\").", "Symbol {0} anklicken (\"{1}\").", "{0}のアイコン(「{1}」)をクリック。"]),
+    ("terse.scroll_page", ["Scroll the page to \"{0}\".", "Desplazar la página hasta \"{0}\".", "Seite zu \"{0}\" scrollen.", "ページを「{0}」までスクロール。"]),
+    ("terse.scroll_within", ["Scroll \"{1}\" to \"{0}\".", "Desplazar \"{1}\" hasta \"{0}\".", "\"{1}\" zu \"{0}\" scrollen.", "「{1}」を「{0}」までスクロール。"]),
+    ("terse.keyboard_form", ["Tab through the fields, type each value, press {0}.", "Recorrer los campos con Tab, escribir cada valor, pulsar {0}.", "Mit Tab durch die Felder, Werte eingeben, {0} drücken.", "Tabでフィールドを移動して各値を入力し、{0}を押す。"]),
+    ("terse.sortable", ["Drag \"{0}\" to position {1}.", "Arrastrar \"{0}\" a la posición {1}.", "\"{0}\" an Position {1} ziehen.", "「{0}」を{1}番目へドラッグ。"]),
+    ("terse.file_drag", ["Drag \"{0}\" onto \"{1}\".", "Arrastrar \"{0}\" a \"{1}\".", "\"{0}\" auf \"{1}\" ziehen.", "「{0}」を「{1}」へドラッグ。"]),
+    ("cot.extent", ["The {0} \"{1}\" spans x {2} to {3} and y {4} to {5}, so its centre is at ({6}, {7}).", "El elemento {0} \"{1}\" ocupa x de {2} a {3} e y de {4} a {5}, así que su centro está en ({6}, {7}).", "Das Element {0} \"{1}\" reicht von x {2} bis {3} und y {4} bis {5}, sein Mittelpunkt liegt also bei ({6}, {7}).", "{0}「{1}」はx {2}〜{3}、y {4}〜{5}にあるので、中心は({6}, {7})です。"]),
+
+    // ── Level instructions ──────────────────────────────────────────────
+    ("instr.check_all_accept", ["Check all checkboxes and click Accept", "Marca todas las casillas y haz clic en Aceptar", "Alle Kästchen ankreuzen und auf Akzeptieren klicken", "すべてのチェックボックスをオンにして「同意」をクリック"]),
+    ("instr.check_nth_accept", ["Check the {0} checkbox and click Accept", "Marca la casilla {0} y haz clic en Aceptar", "Das {0} Kästchen ankreuzen und auf Akzeptieren klicken", "{0}のチェックボックスをオンにして「同意」をクリック"]),
+    ("instr.check_label_accept", ["Check \"{0}\" and click Accept", "Marca \"{0}\" y haz clic en Aceptar", "\"{0}\" ankreuzen und auf Akzeptieren klicken", "「{0}」をオンにして「同意」をクリック"]),
+    ("instr.set_nth_slider", ["Set the {0} slider to {1}", "Pon el {0} control deslizante en {1}", "Stelle den {0} Schieberegler auf {1}", "{0}のスライダーを{1}にする"]),
+    ("instr.set_label_to", ["Set \"{0}\" to {1}", "Pon \"{0}\" en {1}", "Stelle \"{0}\" auf {1}", "「{0}」を{1}にする"]),
+    ("instr.set_to", ["Set to {0}", "Pon el valor en {0}", "Stelle auf {0}", "{0}にする"]),
+    ("instr.copy_word", ["Copy the word \"{0}\" and paste it below", "Copia la palabra \"{0}\" y pégala abajo", "Kopiere das Wort \"{0}\" und füge es unten ein", "単語「{0}」をコピーして下に貼り付ける"]),
+    ("instr.copy_sentence", ["Copy the {0} sentence and paste it below", "Copia la {0} oración y pégala abajo", "Kopiere den {0} Satz und füge ihn unten ein", "{0}の文をコピーして下に貼り付ける"]),
+    ("instr.tooltip_code", ["Enter the code from the \"{0}\" badge's tooltip", "Introduce el código de la información emergente de la insignia \"{0}\"", "Gib den Code aus dem Tooltip des Abzeichens \"{0}\" ein", "バッジ「{0}」のツールチップにあるコードを入力"]),
+    ("instr.delete_confirm", ["Delete \"{0}\"", "Elimina \"{0}\"", "Lösche \"{0}\"", "「{0}」を削除"]),
+    ("instr.delete_cancel", ["Click Delete on \"{0}\", then cancel when asked to confirm", "Haz clic en Eliminar en \"{0}\" y cancela cuando pida confirmación", "Klicke bei \"{0}\" auf Löschen und brich die Bestätigung ab", "「{0}」の削除をクリックし、確認ではキャンセルする"]),
+    ("instr.load_then_open", ["Load the data, then open \"{0}\"", "Carga los datos y abre \"{0}\"", "Lade die Daten und öffne dann \"{0}\"", "データを読み込んでから「{0}」を開く"]),
+    ("instr.click_shape", ["Click the {0}", "Haz clic en el {0}", "Klicke auf: {0}", "{0}をクリック"]),
+    ("instr.icon.Settings", ["Open the settings", "Abre la configuración", "Öffne die Einstellungen", "設定を開く"]),
+    ("instr.icon.Delete", ["Delete this item", "Elimina este elemento", "Lösche dieses Element", "この項目を削除"]),
+    ("instr.icon.Search", ["Start a search", "Inicia una búsqueda", "Starte eine Suche", "検索を始める"]),
+    ("instr.icon.Notifications", ["Check your notifications", "Revisa tus notificaciones", "Sieh dir deine Benachrichtigungen an", "通知を確認"]),
+    ("instr.icon.Home", ["Go back to the home page", "Vuelve a la página de inicio", "Geh zurück zur Startseite", "ホームページに戻る"]),
+    ("instr.icon.Edit", ["Edit this item", "Edita este elemento", "Bearbeite dieses Element", "この項目を編集"]),
+    ("instr.icon.Download", ["Download the file", "Descarga el archivo", "Lade die Datei herunter", "ファイルをダウンロード"]),
+    ("instr.nested_scroll", ["Find \"{0}\" in the contact list and click it", "Busca \"{0}\" en la lista de contactos y haz clic", "Suche \"{0}\" in der Kontaktliste und klicke darauf", "連絡先リストで「{0}」を探してクリック"]),
+    ("instr.follow", ["Follow {0}", "Sigue a {0}", "Folge {0}", "{0}をフォロー"]),
+    ("instr.dense_button", ["Click the \"{0}\" button", "Haz clic en el botón \"{0}\"", "Klicke auf die Schaltfläche \"{0}\"", "「{0}」ボタンをクリック"]),
+    ("instr.dense_chip", ["Select the \"{0}\" chip", "Selecciona la etiqueta \"{0}\"", "Wähle den Chip \"{0}\"", "「{0}」チップを選択"]),
+    ("instr.dense_toggle", ["Turn on \"{0}\"", "Activa \"{0}\"", "Schalte \"{0}\" ein", "「{0}」をオンにする"]),
+    ("instr.article_scroll", ["Scroll down to \"{0}\" and click \"{1}\"", "Desplázate hasta \"{0}\" y haz clic en \"{1}\"", "Scrolle zu \"{0}\" und klicke auf \"{1}\"", "「{0}」までスクロールして「{1}」をクリック"]),
+    ("instr.article_toc", ["Jump to \"{0}\" from the contents and click \"{1}\"", "Salta a \"{0}\" desde el índice y haz clic en \"{1}\"", "Springe über das Inhaltsverzeichnis zu \"{0}\" und klicke auf \"{1}\"", "目次から「{0}」へ移動して「{1}」をクリック"]),
+    ("instr.pane_left", ["In the left panel, click \"{0}\"", "En el panel izquierdo, haz clic en \"{0}\"", "Klicke im linken Bereich auf \"{0}\"", "左のパネルで「{0}」をクリック"]),
+    ("instr.pane_right", ["In the right panel, click \"{0}\"", "En el panel derecho, haz clic en \"{0}\"", "Klicke im rechten Bereich auf \"{0}\"", "右のパネルで「{0}」をクリック"]),
+    ("instr.set_nth_stepper", ["Set the {0} stepper to {1}", "Pon el {0} selector numérico en {1}", "Stelle das {0} Zahlenfeld auf {1}", "{0}のステッパーを{1}にする"]),
+    ("instr.group_nth_select", ["In the {0} group, select \"{1}\"", "En el {0} grupo, selecciona \"{1}\"", "Wähle in der {0} Gruppe \"{1}\"", "{0}のグループで「{1}」を選択"]),
+    ("instr.group_select_nth", ["In \"{0}\", select the {1} option", "En \"{0}\", selecciona la {1} opción", "Wähle in \"{0}\" die {1} Option", "「{0}」で{1}の選択肢を選択"]),
+    ("instr.group_select", ["In \"{0}\", select \"{1}\"", "En \"{0}\", selecciona \"{1}\"", "Wähle in \"{0}\" \"{1}\"", "「{0}」で「{1}」を選択"]),
+    ("instr.rate_nth", ["Rate the {0} one {1} out of {2}", "Valora el {0} con {1} de {2}", "Bewerte das {0} mit {1} von {2}", "{0}の評価を{2}点中{1}点にする"]),
+    ("instr.rate", ["Rate {0} out of {1}", "Valora con {0} de {1}", "Bewerte mit {0} von {1}", "{1}点中{0}点で評価"]),
+    ("instr.rate_label", ["Rate \"{0}\" {1} out of {2}", "Valora \"{0}\" con {1} de {2}", "Bewerte \"{0}\" mit {1} von {2}", "「{0}」を{2}点中{1}点で評価"]),
+    ("instr.switch_nth_tab", ["Switch to the {0} tab", "Cambia a la {0} pestaña", "Wechsle zum {0} Tab", "{0}のタブに切り替える"]),
+    ("instr.switch_tab", ["Switch to the \"{0}\" tab", "Cambia a la pestaña \"{0}\"", "Wechsle zum Tab \"{0}\"", "「{0}」タブに切り替える"]),
+    ("instr.expand_nth", ["Expand the {0} section", "Despliega la {0} sección", "Klappe den {0} Abschnitt auf", "{0}のセクションを展開"]),
+    ("instr.expand", ["Expand \"{0}\"", "Despliega \"{0}\"", "Klappe \"{0}\" auf", "「{0}」を展開"]),
+    ("instr.remove_many", ["Remove {0}", "Quita {0}", "Entferne {0}", "{0}を外す"]),
+    ("instr.click", ["Click {0}", "Haz clic en {0}", "Klicke auf {0}", "{0}をクリック"]),
+    ("instr.right_click_then", ["Right-click \"{0}\", then click \"{1}\"", "Haz clic derecho en \"{0}\" y luego en \"{1}\"", "Rechtsklick auf \"{0}\", dann auf \"{1}\" klicken", "「{0}」を右クリックしてから「{1}」をクリック"]),
+    ("instr.select", ["Select \"{0}\"", "Selecciona \"{0}\"", "Wähle \"{0}\"", "「{0}」を選択"]),
+    ("instr.select_many", ["Select {0}", "Selecciona {0}", "Wähle {0}", "{0}を選択"]),
+    ("instr.move_to_position", ["Move \"{0}\" to {1} position", "Mueve \"{0}\" a la posición {1}", "Verschiebe \"{0}\" an die {1} Position", "「{0}」を{1}の位置へ移動"]),
+    ("instr.dismiss", ["Dismiss the \"{0}\" notification", "Cierra la notificación \"{0}\"", "Schließe die Benachrichtigung \"{0}\"", "「{0}」の通知を閉じる"]),
+    ("instr.dismiss_kind", ["Dismiss the {0} notification", "Cierra la notificación de tipo {0}", "Schließe die {0}-Benachrichtigung", "{0}の通知を閉じる"]),
+    ("instr.before_disappears", ["{0} before it disappears", "{0} antes de que desaparezca", "{0}, bevor sie verschwindet", "消える前に{0}"]),
+    ("instr.open_and_click", ["Open \"{0}\" and click \"{1}\"", "Abre \"{0}\" y haz clic en \"{1}\"", "Öffne \"{0}\" und klicke auf \"{1}\"", "「{0}」を開いて「{1}」をクリック"]),
+    ("instr.run_from_palette", ["Run \"{0}\" from the command palette", "Ejecuta \"{0}\" desde la paleta de comandos", "Führe \"{0}\" über die Befehlspalette aus", "コマンドパレットから「{0}」を実行"]),
+    ("instr.press_and_run", ["Press {0} and run \"{1}\"", "Pulsa {0} y ejecuta \"{1}\"", "Drücke {0} und führe \"{1}\" aus", "{0}を押して「{1}」を実行"]),
+    ("instr.zoom_fit", ["Zoom until the whole circle is visible", "Haz zoom hasta que se vea todo el círculo", "Zoome, bis der ganze Kreis sichtbar ist", "円全体が見えるまでズーム"]),
+    ("instr.zoom_cover", ["Zoom until the square fills the entire frame", "Haz zoom hasta que el cuadrado llene todo el marco", "Zoome, bis das Quadrat den ganzen Rahmen füllt", "正方形が枠全体を埋めるまでズーム"]),
+    ("instr.format_text", ["Format as {0}, size {1}, type \"{2}\", then click {3}", "Formato {0}, tamaño {1}, escribe \"{2}\" y haz clic en {3}", "Formatiere {0}, Größe {1}, tippe \"{2}\" und klicke dann auf {3}", "{0}、サイズ{1}にして「{2}」と入力し、{3}をクリック"]),
+    ("instr.mark_from", ["Mark the notification from {0} as read", "Marca como leída la notificación de {0}", "Markiere die Benachrichtigung von {0} als gelesen", "{0}からの通知を既読にする"]),
+    ("instr.mark_subject", ["Mark \"{0}\" as read", "Marca \"{0}\" como leída", "Markiere \"{0}\" als gelesen", "「{0}」を既読にする"]),
+    ("instr.mark_all", ["Mark all notifications as read", "Marca todas las notificaciones como leídas", "Markiere alle Benachrichtigungen als gelesen", "すべての通知を既読にする"]),
+];
+
+/// Raw template for `key` in `lang` (English if untranslated, empty if unknown).
+pub fn text(lang: Lang, key: &str) -> &'static str {
+    STRINGS.iter()
+        .find(|(k, _)| *k == key)
+        .map_or("", |(_, row)| {
+            let s = row[lang.column()];
+            if s.is_empty() { row[0] } else { s }
+        })
+}
+
+/// Look up `key` and substitute `{0}`, `{1}`, … with `args`.
+/// Unknown keys come back verbatim so a typo is visible rather than silent.
+pub fn tr(lang: Lang, key: &str, args: &[&dyn Display]) -> String {
+    let template = text(lang, key);
+    if template.is_empty() {
+        return key.to_string();
+    }
+    let mut out = template.to_string();
+    for (i, arg) in args.iter().enumerate() {
+        out = out.replace(&format!("{{{}}}", i), &arg.to_string());
+    }
+    out
+}
+
+/// Ordinal for "the Nth …": 1st / 1.º / 1. / 1番目.
+pub fn ordinal(lang: Lang, n: usize) -> String {
+    match lang {
+        Lang::En => {
+            let suffix = match (n % 10, n % 100) {
+                (1, 11) | (2, 12) | (3, 13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{n}{suffix}")
+        }
+        Lang::Es => format!("{n}.º"),
+        Lang::De => format!("{n}."),
+        Lang::Ja => format!("{n}番目"),
+    }
+}
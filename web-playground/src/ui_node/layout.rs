@@ -0,0 +1,117 @@
+//! Layout — child rects computed the way the matching CSS lays them out.
+//!
+//! `stack`, `grid` and `flow` mirror a flex column, a `repeat(n, 1fr)` grid
+//! and a wrapping flex row, so UINode rects follow the same arithmetic as the
+//! render instead of each level repeating its own `row_h` / `LIST_TOP` math.
+//! The fluent tree builder (`ui_node::fluent`) lays its children out here.
+
+use super::Rect;
+
+/// Children of `heights` top to bottom at `parent`'s full width, `gap` px
+/// apart: `display: flex; flex-direction: column; gap`.
+pub fn stack(parent: Rect, heights: &[f32], gap: f32) -> Vec<Rect> {
+    let mut y = parent.y;
+    heights.iter().map(|&h| {
+        let r = Rect::new(parent.x, y, parent.w, h);
+        y += h + gap;
+        r
+    }).collect()
+}
+
+/// Height `stack` takes for `heights`.
+pub fn stack_height(heights: &[f32], gap: f32) -> f32 {
+    heights.iter().sum::<f32>() + gap * heights.len().saturating_sub(1) as f32
+}
+
+/// Cells of a `cols`-column grid across `parent`'s width, row-major:
+/// `display: grid; grid-template-columns: repeat(cols, 1fr); gap`. Cells
+/// stretch to the tallest of `heights` in their row.
+pub fn grid(parent: Rect, cols: usize, heights: &[f32], gap: f32) -> Vec<Rect> {
+    let cols = cols.max(1);
+    let cell_w = (parent.w - gap * (cols - 1) as f32) / cols as f32;
+    let mut out = Vec::with_capacity(heights.len());
+    let mut y = parent.y;
+    for row in heights.chunks(cols) {
+        let row_h = row.iter().copied().fold(0.0, f32::max);
+        for col in 0..row.len() {
+            out.push(Rect::new(parent.x + col as f32 * (cell_w + gap), y, cell_w, row_h));
+        }
+        y += row_h + gap;
+    }
+    out
+}
+
+/// Children of `sizes` left to right, wrapping to a new line when the next
+/// one would cross `parent`'s right edge: `display: flex; flex-wrap: wrap;
+/// gap`. Each keeps its own size; lines are as tall as their tallest child.
+pub fn flow(parent: Rect, sizes: &[(f32, f32)], gap: f32) -> Vec<Rect> {
+    let mut out = Vec::with_capacity(sizes.len());
+    let (mut x, mut y, mut line_h) = (parent.x, parent.y, 0.0f32);
+    for &(w, h) in sizes {
+        if x > parent.x && x + w > parent.x + parent.w {
+            x = parent.x;
+            y += line_h + gap;
+            line_h = 0.0;
+        }
+        out.push(Rect::new(x, y, w, h));
+        x += w + gap;
+        line_h = line_h.max(h);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARENT: Rect = Rect { x: 10.0, y: 20.0, w: 300.0, h: 400.0 };
+
+    #[test]
+    fn stack_spaces_children_by_gap() {
+        let rects = stack(PARENT, &[40.0, 30.0, 50.0], 8.0);
+        assert_eq!(rects, vec![
+            Rect::new(10.0, 20.0, 300.0, 40.0),
+            Rect::new(10.0, 68.0, 300.0, 30.0),
+            Rect::new(10.0, 106.0, 300.0, 50.0),
+        ]);
+    }
+
+    #[test]
+    fn stack_height_matches_stack() {
+        let heights = [40.0, 30.0, 50.0];
+        let last = *stack(PARENT, &heights, 8.0).last().unwrap();
+        assert_eq!(last.y + last.h - PARENT.y, stack_height(&heights, 8.0));
+        assert_eq!(stack_height(&[], 8.0), 0.0);
+    }
+
+    #[test]
+    fn grid_wraps_after_cols_and_stretches_rows() {
+        // Cells are (300 - 2 * 15) / 3 = 90 wide
+        let rects = grid(PARENT, 3, &[40.0, 60.0, 40.0, 30.0], 15.0);
+        assert_eq!(rects, vec![
+            Rect::new(10.0, 20.0, 90.0, 60.0),
+            Rect::new(115.0, 20.0, 90.0, 60.0),
+            Rect::new(220.0, 20.0, 90.0, 60.0),
+            Rect::new(10.0, 95.0, 90.0, 30.0),
+        ]);
+    }
+
+    #[test]
+    fn flow_wraps_at_the_parent_width() {
+        // 120 + 8 + 120 fits in 300; the third would end at 376
+        let rects = flow(PARENT, &[(120.0, 30.0), (120.0, 40.0), (120.0, 30.0), (60.0, 30.0)], 8.0);
+        assert_eq!(rects, vec![
+            Rect::new(10.0, 20.0, 120.0, 30.0),
+            Rect::new(138.0, 20.0, 120.0, 40.0),
+            Rect::new(10.0, 68.0, 120.0, 30.0),
+            Rect::new(138.0, 68.0, 60.0, 30.0),
+        ]);
+    }
+
+    #[test]
+    fn flow_keeps_an_oversized_child_on_its_own_line() {
+        let rects = flow(PARENT, &[(400.0, 30.0), (50.0, 30.0)], 8.0);
+        assert_eq!(rects[0], Rect::new(10.0, 20.0, 400.0, 30.0));
+        assert_eq!(rects[1], Rect::new(10.0, 58.0, 50.0, 30.0));
+    }
+}
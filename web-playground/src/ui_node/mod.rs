@@ -4,16 +4,26 @@
 //! Resolving the tree produces description, action steps, and a VLM thinking
 //! chain — replacing hand-written ground truth strings.
 
+mod bind;
 mod builder;
 mod check;
+mod coco;
+mod criteria;
+mod i18n;
+pub mod layout;
 mod prism;
 mod resolve;
+mod thinking;
 
+pub use bind::{LiveValue, StateBinding};
 pub use builder::*;
-pub use check::Completion;
-pub use resolve::ResolvedGroundTruth;
+pub use check::{Completion, Evaluation};
+pub use coco::NODE_KINDS;
+pub use i18n::{ordinal, text, tr, Lang};
+pub use resolve::{Alternative, ResolvedGroundTruth, StepOrder};
+pub use thinking::ThinkingStyle;
 
-use crate::primitives::Position;
+use crate::primitives::{Angle, Position, Shape, ShapeSize, Swatch};
 
 // ── Rect ────────────────────────────────────────────────────────────────
 
@@ -26,6 +36,37 @@ pub struct Rect {
     pub h: f32,
 }
 
+/// Side of a reference box another box lies on (`Rect::relation_to`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Above,
+    Below,
+    LeftOf,
+    RightOf,
+}
+
+impl Relation {
+    /// English phrase: "to the left of"
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Self::Above => "above",
+            Self::Below => "below",
+            Self::LeftOf => "to the left of",
+            Self::RightOf => "to the right of",
+        }
+    }
+
+    /// i18n key suffix ("relation.left_of")
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Above => "above",
+            Self::Below => "below",
+            Self::LeftOf => "left_of",
+            Self::RightOf => "right_of",
+        }
+    }
+}
+
 impl Rect {
     pub const fn new(x: f32, y: f32, w: f32, h: f32) -> Self {
         Self { x, y, w, h }
@@ -85,11 +126,77 @@ impl Rect {
         )
     }
 
+    /// Which side of `anchor` this box lies on, and the gap between their
+    /// facing edges. The side is the axis the centres are further apart on;
+    /// `None` when the boxes overlap.
+    pub fn relation_to(&self, anchor: &Rect) -> Option<(Relation, f32)> {
+        let gap_x = (anchor.x - (self.x + self.w)).max(self.x - (anchor.x + anchor.w));
+        let gap_y = (anchor.y - (self.y + self.h)).max(self.y - (anchor.y + anchor.h));
+        if gap_x < 0.0 && gap_y < 0.0 {
+            return None;
+        }
+        let (cx, cy) = self.center();
+        let (ax, ay) = anchor.center();
+        Some(if (cx - ax).abs() >= (cy - ay).abs() {
+            (if cx < ax { Relation::LeftOf } else { Relation::RightOf }, gap_x.max(0.0))
+        } else {
+            (if cy < ay { Relation::Above } else { Relation::Below }, gap_y.max(0.0))
+        })
+    }
+
+    pub fn is_above(&self, anchor: &Rect) -> bool {
+        matches!(self.relation_to(anchor), Some((Relation::Above, _)))
+    }
+
+    pub fn is_below(&self, anchor: &Rect) -> bool {
+        matches!(self.relation_to(anchor), Some((Relation::Below, _)))
+    }
+
+    pub fn is_left_of(&self, anchor: &Rect) -> bool {
+        matches!(self.relation_to(anchor), Some((Relation::LeftOf, _)))
+    }
+
+    pub fn is_right_of(&self, anchor: &Rect) -> bool {
+        matches!(self.relation_to(anchor), Some((Relation::RightOf, _)))
+    }
+
+    /// Whether `inner` lies entirely inside this box.
+    pub fn contains(&self, inner: &Rect) -> bool {
+        inner.x >= self.x && inner.y >= self.y
+            && inner.x + inner.w <= self.x + self.w
+            && inner.y + inner.h <= self.y + self.h
+    }
+
     /// Apply a viewport transform to get window-space pixel coordinates.
     pub fn to_window(&self, vt: &ViewportTransform) -> (i32, i32, i32, i32) {
         vt.apply(self)
     }
 
+    /// Axis-aligned hull of this rect after rotating it by `angle` about
+    /// `pivot` — the box a rotated element actually covers on screen.
+    pub fn rotated_hull(&self, pivot: (f32, f32), angle: Angle) -> Rect {
+        let (sin, cos) = angle.radians().sin_cos();
+        let (px, py) = pivot;
+        let corners = [
+            (self.x, self.y),
+            (self.x + self.w, self.y),
+            (self.x, self.y + self.h),
+            (self.x + self.w, self.y + self.h),
+        ];
+        let (mut min_x, mut min_y) = (f32::MAX, f32::MAX);
+        let (mut max_x, mut max_y) = (f32::MIN, f32::MIN);
+        for (x, y) in corners {
+            // CSS rotate() is clockwise in screen space (y down)
+            let rx = px + (x - px) * cos - (y - py) * sin;
+            let ry = py + (x - px) * sin + (y - py) * cos;
+            min_x = min_x.min(rx);
+            min_y = min_y.min(ry);
+            max_x = max_x.max(rx);
+            max_y = max_y.max(ry);
+        }
+        Rect::new(min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+
     pub fn offset(&self, parent_x: f32, parent_y: f32) -> Self {
         Self {
             x: self.x + parent_x,
@@ -102,18 +209,104 @@ impl Rect {
 
 // ── ViewportTransform ────────────────────────────────────────────────────
 
+/// A CSS rotation of a whole tree (e.g. a tilted card) about `pivot`, in
+/// viewport-pixel coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotation {
+    pub pivot: (f32, f32),
+    pub angle: Angle,
+}
+
+impl Rotation {
+    /// Rotation about the center of `rect` — CSS's default transform origin.
+    pub fn about_center(rect: &Rect, angle: Angle) -> Self {
+        Self { pivot: rect.center(), angle }
+    }
+}
+
+/// Pixel space that window coordinates are reported in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoordinateSpace {
+    /// CSS pixels, as `getBoundingClientRect` reports them.
+    #[default]
+    Css,
+    /// Device pixels (CSS × DPR), matching DPR-scaled screenshots.
+    Physical,
+}
+
+impl CoordinateSpace {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "css" => Some(Self::Css),
+            "physical" => Some(Self::Physical),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Css => "css",
+            Self::Physical => "physical",
+        }
+    }
+}
+
 /// Maps viewport-local pixel coordinates to window-space pixel coordinates.
 #[derive(Debug, Clone, Copy)]
 pub struct ViewportTransform {
     pub offset_x: f32,
     pub offset_y: f32,
     pub scale: f32,
+    /// Rotation applied to every rect before mapping, if the tree is tilted.
+    pub rotation: Option<Rotation>,
+    /// Device pixel ratio of the capture.
+    pub dpr: f32,
+    /// Space `apply` reports in; `Physical` multiplies by `dpr`.
+    pub space: CoordinateSpace,
 }
 
 impl ViewportTransform {
     /// Identity transform — viewport coords pass through unchanged.
     pub fn identity() -> Self {
-        Self { offset_x: 0.0, offset_y: 0.0, scale: 1.0 }
+        Self { offset_x: 0.0, offset_y: 0.0, scale: 1.0, rotation: None, dpr: 1.0, space: CoordinateSpace::Css }
+    }
+
+    /// The same mapping reported in `space`, for a capture at `dpr`.
+    pub fn in_space(self, space: CoordinateSpace, dpr: f32) -> Self {
+        Self { space, dpr, ..self }
+    }
+
+    /// Factor from window CSS pixels to the reported space.
+    pub fn output_scale(&self) -> f32 {
+        match self.space {
+            CoordinateSpace::Css => 1.0,
+            CoordinateSpace::Physical => self.dpr,
+        }
+    }
+
+    /// The same mapping for a tree rendered rotated by `rotation`.
+    pub fn rotated(self, rotation: Rotation) -> Self {
+        Self { rotation: Some(rotation), ..self }
+    }
+
+    /// Viewport-space box `rect` covers on screen: its rotated hull when the
+    /// tree is rotated, otherwise `rect` itself.
+    pub fn local(&self, rect: &Rect) -> Rect {
+        match self.rotation {
+            Some(r) => rect.rotated_hull(r.pivot, r.angle),
+            None => *rect,
+        }
+    }
+
+    /// Transform for a nested viewport (a split-screen pane) whose own
+    /// origin sits at `origin` in this one. Rects under it are pane-local.
+    pub fn nested(&self, origin: (f32, f32)) -> Self {
+        Self {
+            offset_x: self.offset_x + origin.0 * self.scale,
+            offset_y: self.offset_y + origin.1 * self.scale,
+            rotation: self.rotation.map(|r| Rotation { pivot: (r.pivot.0 - origin.0, r.pivot.1 - origin.1), ..r }),
+            ..*self
+        }
     }
 
     /// Build from the DOM viewport bbox [x, y, width, height].
@@ -125,16 +318,34 @@ impl ViewportTransform {
             offset_x: vp[0] as f32,
             offset_y: vp[1] as f32,
             scale: if vp_w > 0.0 { vp[2] as f32 / vp_w } else { 1.0 },
+            rotation: None,
+            dpr: 1.0,
+            space: CoordinateSpace::Css,
         }
     }
 
-    /// Convert a viewport-space Rect to window-space (x, y, w, h).
+    /// Convert a viewport-space Rect to window-space (x, y, w, h), in the
+    /// transform's coordinate space.
     pub fn apply(&self, rect: &Rect) -> (i32, i32, i32, i32) {
+        let rect = self.local(rect);
+        let k = self.output_scale();
         (
-            (self.offset_x + rect.x * self.scale) as i32,
-            (self.offset_y + rect.y * self.scale) as i32,
-            (rect.w * self.scale) as i32,
-            (rect.h * self.scale) as i32,
+            ((self.offset_x + rect.x * self.scale) * k) as i32,
+            ((self.offset_y + rect.y * self.scale) * k) as i32,
+            (rect.w * self.scale * k) as i32,
+            (rect.h * self.scale * k) as i32,
+        )
+    }
+
+    /// Viewport-local rect under window CSS box [x, y, w, h]; the inverse of
+    /// `apply` for an unrotated tree, before the output space scaling.
+    pub fn unapply(&self, window: &[f64; 4]) -> Rect {
+        let s = self.scale as f64;
+        Rect::new(
+            ((window[0] - self.offset_x as f64) / s) as f32,
+            ((window[1] - self.offset_y as f64) / s) as f32,
+            (window[2] / s) as f32,
+            (window[3] / s) as f32,
         )
     }
 }
@@ -148,7 +359,26 @@ pub enum Action {
     Type { target: String, value: String },
     Drag { from: String, to: String },
     RightClick { target: String },
-    Scroll { target: String },
+    /// Scroll until `target` is in view: the page by default, or the
+    /// scrollable container labelled `within` (a list inside a card).
+    Scroll { target: String, within: Option<String> },
+    /// Wheel-scroll by (dx, dy) CSS px with the pointer over `target`.
+    ScrollBy { target: String, dx: i32, dy: i32 },
+    /// Clear a text field's contents, e.g. to retype after a typo.
+    Erase { target: String },
+    /// Keyboard shortcut or single key, e.g. "Ctrl+K" or "Enter".
+    KeyPress { key: String },
+    /// Set a native range input to `value`, as its keyboard or a11y API would.
+    SetSlider { target: String, value: i32 },
+    /// Select the first occurrence of `text` inside `target`, as a drag
+    /// across it would.
+    SelectText { target: String, text: String },
+    /// Move the pointer over `target` without pressing, e.g. to show a tooltip.
+    Hover { target: String },
+    /// Let `ms` pass, or less once `until` (a target label) is on screen.
+    Wait { ms: u32, until: Option<String> },
+    /// Do nothing until an element labelled `target` is on screen.
+    WaitFor { target: String },
 }
 
 impl Action {
@@ -169,7 +399,43 @@ impl Action {
     }
 
     pub fn scroll(target: impl Into<String>) -> Self {
-        Self::Scroll { target: target.into() }
+        Self::Scroll { target: target.into(), within: None }
+    }
+
+    pub fn scroll_within(target: impl Into<String>, container: impl Into<String>) -> Self {
+        Self::Scroll { target: target.into(), within: Some(container.into()) }
+    }
+
+    pub fn scroll_by(target: impl Into<String>, dx: i32, dy: i32) -> Self {
+        Self::ScrollBy { target: target.into(), dx, dy }
+    }
+
+    pub fn erase(target: impl Into<String>) -> Self {
+        Self::Erase { target: target.into() }
+    }
+
+    pub fn key_press(key: impl Into<String>) -> Self {
+        Self::KeyPress { key: key.into() }
+    }
+
+    pub fn set_slider(target: impl Into<String>, value: i32) -> Self {
+        Self::SetSlider { target: target.into(), value }
+    }
+
+    pub fn select_text(target: impl Into<String>, text: impl Into<String>) -> Self {
+        Self::SelectText { target: target.into(), text: text.into() }
+    }
+
+    pub fn hover(target: impl Into<String>) -> Self {
+        Self::Hover { target: target.into() }
+    }
+
+    pub fn wait(ms: u32, until: Option<String>) -> Self {
+        Self::Wait { ms, until }
+    }
+
+    pub fn wait_for(target: impl Into<String>) -> Self {
+        Self::WaitFor { target: target.into() }
     }
 
     /// Serialize to the JSON format expected by the solver.
@@ -195,9 +461,50 @@ impl Action {
             Self::RightClick { target } => {
                 format!(r#"{{"action":"right_click","target":"{}"}}"#, escape_json(target))
             }
-            Self::Scroll { target } => {
+            Self::Scroll { target, within: None } => {
                 format!(r#"{{"action":"scroll","target":"{}"}}"#, escape_json(target))
             }
+            Self::Scroll { target, within: Some(container) } => {
+                format!(
+                    r#"{{"action":"scroll","target":"{}","within":"{}"}}"#,
+                    escape_json(target),
+                    escape_json(container),
+                )
+            }
+            Self::ScrollBy { target, dx, dy } => {
+                format!(
+                    r#"{{"action":"scroll_by","target":"{}","dx":{},"dy":{}}}"#,
+                    escape_json(target),
+                    dx,
+                    dy,
+                )
+            }
+            Self::Erase { target } => {
+                format!(r#"{{"action":"erase","target":"{}"}}"#, escape_json(target))
+            }
+            Self::KeyPress { key } => {
+                format!(r#"{{"action":"key","key":"{}"}}"#, escape_json(key))
+            }
+            Self::SetSlider { target, value } => {
+                format!(r#"{{"action":"set_slider","target":"{}","value":{}}}"#, escape_json(target), value)
+            }
+            Self::SelectText { target, text } => {
+                format!(
+                    r#"{{"action":"select_text","target":"{}","text":"{}"}}"#,
+                    escape_json(target),
+                    escape_json(text),
+                )
+            }
+            Self::Hover { target } => {
+                format!(r#"{{"action":"hover","target":"{}"}}"#, escape_json(target))
+            }
+            Self::Wait { ms, until: Some(until) } => {
+                format!(r#"{{"action":"wait","ms":{},"until":"{}"}}"#, ms, escape_json(until))
+            }
+            Self::Wait { ms, until: None } => format!(r#"{{"action":"wait","ms":{}}}"#, ms),
+            Self::WaitFor { target } => {
+                format!(r#"{{"action":"wait_for","target":"{}"}}"#, escape_json(target))
+            }
         }
     }
 }
@@ -208,7 +515,7 @@ pub fn actions_to_json(actions: &[Action]) -> String {
     format!("[{}]", inner.join(","))
 }
 
-fn escape_json(s: &str) -> String {
+pub(crate) fn escape_json(s: &str) -> String {
     s.replace('\\', "\\\\")
         .replace('"', "\\\"")
         .replace('\n', "\\n")
@@ -216,6 +523,32 @@ fn escape_json(s: &str) -> String {
         .replace('\t', "\\t")
 }
 
+// ── Outcome ─────────────────────────────────────────────────────────────
+
+/// What a correct agent does with the round.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Outcome {
+    /// The instruction can be carried out; the steps are the solution.
+    #[default]
+    Success,
+    /// The instruction names `missing`, which is not on screen. The correct
+    /// response is to report it as not found rather than act on a lookalike.
+    Impossible { missing: String },
+}
+
+impl Outcome {
+    pub fn impossible(missing: impl Into<String>) -> Self {
+        Self::Impossible { missing: missing.into() }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Impossible { .. } => "impossible",
+        }
+    }
+}
+
 // ── Visual ──────────────────────────────────────────────────────────────
 
 /// Shared visual properties embedded in every UINode variant.
@@ -225,6 +558,12 @@ pub struct Visual {
     pub rect: Rect,
     pub color: Option<String>,
     pub is_target: bool,
+    /// Stacking order among siblings that overlap; higher paints on top.
+    /// 0 is the default layer and isn't mentioned in descriptions.
+    pub z_index: i32,
+    /// Neighbour (label, box) the instruction names this node by, as in
+    /// "the toggle to the right of Email"; `None` for direct references.
+    pub relative_to: Option<(String, Rect)>,
 }
 
 impl Visual {
@@ -234,6 +573,8 @@ impl Visual {
             rect,
             color: None,
             is_target: false,
+            z_index: 0,
+            relative_to: None,
         }
     }
 
@@ -246,6 +587,16 @@ impl Visual {
         self.color = Some(c.into());
         self
     }
+
+    pub fn z_index(mut self, z: i32) -> Self {
+        self.z_index = z;
+        self
+    }
+
+    pub fn relative_to(mut self, label: impl Into<String>, rect: Rect) -> Self {
+        self.relative_to = Some((label.into(), rect));
+        self
+    }
 }
 
 // ── State structs ───────────────────────────────────────────────────────
@@ -253,6 +604,8 @@ impl Visual {
 #[derive(Debug, Clone, PartialEq)]
 pub struct ToggleState {
     pub is_on: bool,
+    /// Desired state; the toggle is done once `is_on` matches it.
+    pub target_on: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -260,9 +613,32 @@ pub struct CheckState {
     pub is_checked: bool,
 }
 
+/// Which boxes of a `CheckboxGroup` must end up checked.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupTarget {
+    /// Every box ("check all checkboxes").
+    All,
+    /// The box at this 0-based index ("check the 2nd checkbox").
+    Ordinal(usize),
+    /// The box with this label.
+    ByLabel(String),
+}
+
+impl GroupTarget {
+    /// Whether box `i` (labelled `label`) must end up checked.
+    pub fn wants(&self, i: usize, label: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Ordinal(n) => i == *n,
+            Self::ByLabel(l) => l == label,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TagState {
     pub is_selected: bool,
+    pub target_selected: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -296,6 +672,34 @@ pub struct SliderState {
     pub thumb_rect: Rect,
     /// Bounding box of the thumb at target position (drag-to).
     pub target_thumb_rect: Rect,
+    /// Accepted distance from `target_val` (0 = exact value required).
+    pub tolerance: i32,
+    /// Rendered as a native `<input type=range>`: set by value, not dragged.
+    pub native: bool,
+}
+
+/// Label (and placeholder) of a searchable dropdown's filter field.
+pub const DROPDOWN_SEARCH_LABEL: &str = "Search...";
+
+/// How a dropdown task is meant to be driven once the menu is open
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DropdownMode {
+    /// Click the option
+    #[default]
+    Click,
+    /// Type into the menu's filter field, then click the option
+    Search,
+    /// Arrow keys to the option, then Enter
+    Keys,
+}
+
+impl DropdownMode {
+    pub const ALL: [Self; 3] = [Self::Click, Self::Search, Self::Keys];
+
+    /// Whether the open menu shows a filter field
+    pub fn searchable(&self) -> bool {
+        *self == Self::Search
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -304,6 +708,40 @@ pub struct DropdownState {
     pub selected: Option<String>,
     pub target_option: String,
     pub trigger_label: String,
+    pub mode: DropdownMode,
+    /// Each option and its bounding box while the menu is open; empty while
+    /// closed. GroundTruth fills it in from the rendered menu.
+    pub open_options: Vec<(String, Rect)>,
+}
+
+impl DropdownState {
+    /// Shortest prefix of the target option that filters the menu down to
+    /// it alone (case-insensitive substring match), else the whole option.
+    pub fn search_query(&self) -> String {
+        let others: Vec<String> = self.options.iter()
+            .filter(|o| **o != self.target_option)
+            .map(|o| o.to_lowercase())
+            .collect();
+        let ends = self.target_option.char_indices().map(|(i, c)| i + c.len_utf8());
+        for end in ends {
+            let prefix = &self.target_option[..end];
+            let needle = prefix.to_lowercase();
+            if !others.iter().any(|o| o.contains(&needle)) {
+                return prefix.to_string();
+            }
+        }
+        self.target_option.clone()
+    }
+
+    /// Arrow presses from the menu's initial highlight (the selected option,
+    /// else none) to the target: (key, count).
+    pub fn arrow_presses(&self) -> (&'static str, usize) {
+        let pos = |o: &str| self.options.iter().position(|x| x == o);
+        let target = pos(&self.target_option).unwrap_or(0) as i64;
+        let from = self.selected.as_deref().and_then(pos).map_or(-1, |i| i as i64);
+        let n = target - from;
+        if n < 0 { ("ArrowUp", (-n) as usize) } else { ("ArrowDown", n as usize) }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -311,6 +749,9 @@ pub struct ContextMenuState {
     pub items: Vec<String>,
     pub target_item: String,
     pub trigger_label: String,
+    /// Bounding box of each item once the menu is open, in `items` order;
+    /// empty when the level doesn't know where the menu opens.
+    pub item_rects: Vec<Rect>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -320,21 +761,268 @@ pub struct StepperState {
     pub step: i32,
     pub current_val: i32,
     pub target_val: i32,
+    /// Value the stepper started at; progress is counted from here.
+    pub start_val: i32,
     pub minus_label: String,
     pub plus_label: String,
+    /// Bounding box of the decrement button.
+    pub minus_rect: Rect,
+    /// Bounding box of the increment button.
+    pub plus_rect: Rect,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RadioState {
     pub options: Vec<String>,
+    /// Bounding box of each option row, in `options` order.
+    pub option_rects: Vec<Rect>,
     pub selected: Option<usize>,
     pub target_option: usize,
 }
 
+/// How a carousel moves between slides
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CarouselNav {
+    /// Previous / next arrows only
+    Arrows,
+    /// One indicator per slide (dots, rings)
+    Dots,
+    /// Arrows plus dots
+    ArrowsDots,
+    /// Numbered tabs above the slide
+    Tabs,
+    /// Advances on its own; nothing to click
+    Auto,
+}
+
+impl CarouselNav {
+    /// i18n key suffix ("carousel.{name}")
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Arrows => "arrows",
+            Self::Dots => "dots",
+            Self::ArrowsDots => "arrows_dots",
+            Self::Tabs => "tabs",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CarouselState {
+    pub nav: CarouselNav,
+    pub slide_count: usize,
+    /// Slide on screen, 0-based.
+    pub current: usize,
+    pub target_slide: usize,
+    /// Previous / next arrow rects, for the arrow navs.
+    pub arrows: Option<(Rect, Rect)>,
+    /// One dot or tab per slide, labelled "slide N"; empty without them.
+    pub indicators: Vec<Rect>,
+}
+
+impl CarouselState {
+    pub const PREV_LABEL: &'static str = "previous slide";
+    pub const NEXT_LABEL: &'static str = "next slide";
+
+    /// DOM label of the indicator for slide `i` (0-based).
+    pub fn indicator_label(i: usize) -> String {
+        format!("slide {}", i + 1)
+    }
+
+    /// DOM label of the slide area while slide `i` (0-based) is on screen.
+    pub fn showing_label(i: usize) -> String {
+        format!("showing slide {}", i + 1)
+    }
+}
+
+/// Text shown in a tooltip while its anchor is hovered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TooltipState {
+    pub text: String,
+    /// Whether the tooltip is on screen now.
+    pub open: bool,
+}
+
+/// Glyph on an icon-only button; the label is the function it stands for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IconState {
+    /// What the glyph depicts ("gear", "trash").
+    pub glyph: String,
+}
+
+/// Unlabeled mark known only by its outline, fill and size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeState {
+    pub shape: Shape,
+    pub swatch: Swatch,
+    pub size: ShapeSize,
+}
+
+impl ShapeState {
+    /// English description ("small red circle"); used as the node's label.
+    pub fn label(&self) -> String {
+        format!("{} {} {}", self.size.describe(), self.swatch.describe(), self.shape.describe())
+    }
+
+    /// The same description in `lang`, for instructions and thinking.
+    pub fn phrase(&self, lang: Lang) -> String {
+        tr(lang, "shape.phrase", &[
+            &text(lang, &format!("size.{}", self.size.describe())),
+            &resolve::color_affix(self.swatch.describe(), lang),
+            &text(lang, &format!("shape.{}", self.shape.describe())),
+        ])
+    }
+}
+
+/// Reorderable list; items and slots run top to bottom.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortableState {
+    /// Item labels in their current order.
+    pub items: Vec<String>,
+    /// Bounding box of each slot, one per item.
+    pub slots: Vec<Rect>,
+    pub target_item: String,
+    /// Slot the target item must end up in, 0-based.
+    pub target_pos: usize,
+}
+
+impl SortableState {
+    /// Label of the drop target for slot `i` (0-based).
+    pub fn slot_label(i: usize) -> String {
+        format!("slot {}", i + 1)
+    }
+
+    /// Slot the target item is in now.
+    pub fn current_pos(&self) -> Option<usize> {
+        self.items.iter().position(|i| *i == self.target_item)
+    }
+}
+
+/// Files to pick from and the zone they upload into.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDragState {
+    /// File icons, labelled with their full file name.
+    pub files: Vec<Visual>,
+    /// Index into `files` of the one to upload.
+    pub target: usize,
+    pub zone: Visual,
+}
+
+/// A passage and the span of it to select.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectionState {
+    pub text: String,
+    /// Word or sentence to select, verbatim from `text`.
+    pub target_text: String,
+    /// What is selected in the passage now (empty for nothing).
+    pub selected: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteState {
+    /// Shortcut that opens the palette, e.g. "Ctrl+K".
+    pub hotkey: String,
+    pub commands: Vec<String>,
+    pub target_command: String,
+    pub is_open: bool,
+    /// Current text in the palette's search input.
+    pub query: String,
+    /// Label and bounding box of the search input (only rendered when open).
+    pub input_label: String,
+    pub input_rect: Rect,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridTile {
+    pub label: String,
+    /// Visible content (caption or object name) shown on the tile.
+    pub content: String,
+    pub rect: Rect,
+    pub is_selected: bool,
+    /// Whether the tile matches the prompt and should end up selected.
+    pub is_match: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileGridState {
+    /// What to look for, e.g. "traffic lights".
+    pub prompt: String,
+    pub cols: usize,
+    /// Tiles in row-major (reading) order.
+    pub tiles: Vec<GridTile>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusField {
+    pub label: String,
+    pub rect: Rect,
+    pub current_value: String,
+    pub target_value: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusOrderState {
+    /// Fields in tab order.
+    pub fields: Vec<FocusField>,
+    /// Field that currently has keyboard focus.
+    pub focused: Option<usize>,
+    /// Field indices in the order they first received focus.
+    pub focus_history: Vec<usize>,
+    /// Key that submits the form, e.g. "Enter".
+    pub submit_key: String,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FormState {
     pub submit_label: String,
     pub cancel_label: Option<String>,
+    /// Fields don't depend on each other, so they may fill in any order.
+    /// Off by default: fields play in tree order.
+    pub unordered: bool,
+}
+
+/// Content behind a loading state (skeleton rows, a spinner).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoaderState {
+    /// Longest the content can still take to show, in ms.
+    pub remaining_ms: u32,
+    /// Whether the content is on screen. Until it is, its children have no
+    /// real boxes.
+    pub loaded: bool,
+}
+
+/// One side of a split screen: a self-contained mini-app.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaneState {
+    /// How the instruction names the pane ("left panel").
+    pub name: String,
+}
+
+/// Contents of an `Article`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArticleState {
+    /// Contents links in document order: (link label, section rect).
+    pub toc: Vec<(String, Rect)>,
+    /// Whether the preferred plan follows the contents link rather than
+    /// scrolling; the other one is offered as an alternative.
+    pub via_toc: bool,
+}
+
+/// Dialog opened by a click elsewhere (a "Delete" button, say).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalState {
+    pub title: String,
+    /// Whether the dialog is on screen. Its children only have real boxes
+    /// while it is.
+    pub open: bool,
+}
+
+/// Collapsible section; the visual's rect is the header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccordionState {
+    /// Box below the header that the revealed children fill once expanded.
+    pub body: Rect,
 }
 
 // ── UINode ──────────────────────────────────────────────────────────────
@@ -347,7 +1035,8 @@ pub enum UINode {
     Toggle(Visual, ToggleState),
     Checkbox(Visual, CheckState),
     Tab(Visual),
-    Accordion(Visual),
+    /// Collapsible section; children are the content revealed on expand.
+    Accordion(Visual, AccordionState, Vec<UINode>),
     Tag(Visual, TagState),
     Toast(Visual, ToastState),
     Star(Visual, StarState),
@@ -355,6 +1044,8 @@ pub enum UINode {
 
     // Text input
     TextInput(Visual, InputState),
+    /// Passage in which a word or sentence must be selected.
+    TextSelection(Visual, SelectionState),
 
     // Drag
     Slider(Visual, SliderState),
@@ -366,8 +1057,78 @@ pub enum UINode {
     ContextMenu(Visual, ContextMenuState),
     Stepper(Visual, StepperState),
     RadioGroup(Visual, RadioState),
+    CommandPalette(Visual, PaletteState),
+    /// Multi-select tile grid ("select all tiles containing X").
+    TileGrid(Visual, TileGridState),
+    /// Related checkboxes checked as a unit: all of them, the n-th, or one
+    /// by label. Boxes are in reading order.
+    CheckboxGroup(Visual, Vec<(Visual, CheckState)>, GroupTarget),
+    /// Slideshow whose target slide must be brought on screen first.
+    Carousel(Visual, CarouselState),
+    /// Form reachable only by keyboard: Tab between fields, key to submit.
+    KeyboardForm(Visual, FocusOrderState),
+    /// List reordered by dragging one item into another slot.
+    SortableList(Visual, SortableState),
+    /// Desktop-style upload: drag one of several file icons onto a drop zone.
+    FileDragTask(Visual, FileDragState),
+    /// Anchor (badge, icon) whose tooltip holds a value to read.
+    Tooltip(Visual, TooltipState),
+    /// Unlabeled shape, picked out by colour, size and outline alone.
+    Shape(Visual, ShapeState),
+    /// Button showing only a glyph; its label is the function, never on screen.
+    IconButton(Visual, IconState),
 
     // Containers
     Card(Visual, Vec<UINode>),
     Form(Visual, FormState, Vec<UINode>),
+    /// Dialog whose children (usually `ModalButton`s) exist only once it opens.
+    Modal(Visual, ModalState, Vec<UINode>),
+    /// Content that appears only after a loading delay.
+    Loader(Visual, LoaderState, Vec<UINode>),
+    /// Nested viewport; its children's rects are relative to its top-left.
+    Pane(Visual, PaneState, Vec<UINode>),
+    /// Box that scrolls on its own (`overflow: auto`); children outside its
+    /// rect are scrolled out of sight. Rects are viewport-space, as measured.
+    ScrollArea(Visual, Vec<UINode>),
+    /// Long page with a table of contents; the target can be reached by
+    /// scrolling or through its section's contents link.
+    Article(Visual, ArticleState, Vec<UINode>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rect(actual: Rect, expected: Rect) {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-3;
+        assert!(
+            close(actual.x, expected.x) && close(actual.y, expected.y) && close(actual.w, expected.w) && close(actual.h, expected.h),
+            "{actual:?} != {expected:?}",
+        );
+    }
+
+    const BOX: Rect = Rect { x: 10.0, y: 20.0, w: 100.0, h: 40.0 };
+
+    #[test]
+    fn hull_without_rotation_is_the_rect() {
+        assert_rect(BOX.rotated_hull(BOX.center(), Angle::new(0.0)), BOX);
+    }
+
+    #[test]
+    fn quarter_turn_about_center_swaps_sides() {
+        assert_rect(BOX.rotated_hull(BOX.center(), Angle::new(90.0)), Rect::new(40.0, -10.0, 40.0, 100.0));
+    }
+
+    #[test]
+    fn eighth_turn_about_center_grows_both_ways() {
+        // Each half-extent becomes (50 + 20) * cos 45°
+        let half = 70.0 * std::f32::consts::FRAC_1_SQRT_2;
+        assert_rect(BOX.rotated_hull(BOX.center(), Angle::new(45.0)), Rect::new(60.0 - half, 40.0 - half, 2.0 * half, 2.0 * half));
+    }
+
+    #[test]
+    fn quarter_turn_about_corner_swings_clockwise() {
+        // Clockwise about the top-left corner: the box swings to its left
+        assert_rect(BOX.rotated_hull((10.0, 20.0), Angle::new(90.0)), Rect::new(-30.0, 20.0, 40.0, 100.0));
+    }
 }
@@ -13,12 +13,13 @@ impl UINode {
             | UINode::Toggle(v, _)
             | UINode::Checkbox(v, _)
             | UINode::Tab(v)
-            | UINode::Accordion(v)
+            | UINode::Accordion(v, _, _)
             | UINode::Tag(v, _)
             | UINode::Toast(v, _)
             | UINode::Star(v, _)
             | UINode::ModalButton(v)
             | UINode::TextInput(v, _)
+            | UINode::TextSelection(v, _)
             | UINode::Slider(v, _)
             | UINode::DragSource(v)
             | UINode::DropZone(v)
@@ -26,8 +27,23 @@ impl UINode {
             | UINode::ContextMenu(v, _)
             | UINode::Stepper(v, _)
             | UINode::RadioGroup(v, _)
+            | UINode::CommandPalette(v, _)
+            | UINode::TileGrid(v, _)
+            | UINode::CheckboxGroup(v, _, _)
+            | UINode::Carousel(v, _)
+            | UINode::Tooltip(v, _)
+            | UINode::Shape(v, _)
+            | UINode::IconButton(v, _)
+            | UINode::KeyboardForm(v, _)
+            | UINode::SortableList(v, _)
+            | UINode::FileDragTask(v, _)
             | UINode::Card(v, _)
-            | UINode::Form(v, _, _) => v,
+            | UINode::Form(v, _, _)
+            | UINode::Modal(v, _, _)
+            | UINode::Loader(v, _, _)
+            | UINode::Pane(v, _, _)
+            | UINode::ScrollArea(v, _)
+            | UINode::Article(v, _, _) => v,
         }
     }
 
@@ -38,12 +54,13 @@ impl UINode {
             | UINode::Toggle(v, _)
             | UINode::Checkbox(v, _)
             | UINode::Tab(v)
-            | UINode::Accordion(v)
+            | UINode::Accordion(v, _, _)
             | UINode::Tag(v, _)
             | UINode::Toast(v, _)
             | UINode::Star(v, _)
             | UINode::ModalButton(v)
             | UINode::TextInput(v, _)
+            | UINode::TextSelection(v, _)
             | UINode::Slider(v, _)
             | UINode::DragSource(v)
             | UINode::DropZone(v)
@@ -51,19 +68,105 @@ impl UINode {
             | UINode::ContextMenu(v, _)
             | UINode::Stepper(v, _)
             | UINode::RadioGroup(v, _)
+            | UINode::CommandPalette(v, _)
+            | UINode::TileGrid(v, _)
+            | UINode::CheckboxGroup(v, _, _)
+            | UINode::Carousel(v, _)
+            | UINode::Tooltip(v, _)
+            | UINode::Shape(v, _)
+            | UINode::IconButton(v, _)
+            | UINode::KeyboardForm(v, _)
+            | UINode::SortableList(v, _)
+            | UINode::FileDragTask(v, _)
             | UINode::Card(v, _)
-            | UINode::Form(v, _, _) => v,
+            | UINode::Form(v, _, _)
+            | UINode::Modal(v, _, _)
+            | UINode::Loader(v, _, _)
+            | UINode::Pane(v, _, _)
+            | UINode::ScrollArea(v, _)
+            | UINode::Article(v, _, _) => v,
+        }
+    }
+
+    /// Variant name in snake_case ("button", "text_input", …); one of `NODE_KINDS`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            UINode::Button(_) => "button",
+            UINode::Toggle(..) => "toggle",
+            UINode::Checkbox(..) => "checkbox",
+            UINode::Tab(_) => "tab",
+            UINode::Accordion(..) => "accordion",
+            UINode::Tag(..) => "tag",
+            UINode::Toast(..) => "toast",
+            UINode::Star(..) => "star",
+            UINode::ModalButton(_) => "modal_button",
+            UINode::TextInput(..) => "text_input",
+            UINode::TextSelection(..) => "text_selection",
+            UINode::Slider(..) => "slider",
+            UINode::DragSource(_) => "drag_source",
+            UINode::DropZone(_) => "drop_zone",
+            UINode::Dropdown(..) => "dropdown",
+            UINode::ContextMenu(..) => "context_menu",
+            UINode::Stepper(..) => "stepper",
+            UINode::RadioGroup(..) => "radio_group",
+            UINode::CommandPalette(..) => "command_palette",
+            UINode::TileGrid(..) => "tile_grid",
+            UINode::CheckboxGroup(..) => "checkbox_group",
+            UINode::Carousel(..) => "carousel",
+            UINode::Tooltip(..) => "tooltip",
+            UINode::Shape(..) => "shape",
+            UINode::IconButton(..) => "icon_button",
+            UINode::KeyboardForm(..) => "keyboard_form",
+            UINode::SortableList(..) => "sortable_list",
+            UINode::FileDragTask(..) => "file_drag_task",
+            UINode::Card(..) => "card",
+            UINode::Form(..) => "form",
+            UINode::Modal(..) => "modal",
+            UINode::Loader(..) => "loader",
+            UINode::Pane(..) => "pane",
+            UINode::ScrollArea(..) => "scroll_area",
+            UINode::Article(..) => "article",
         }
     }
 
     /// Children of container nodes. Returns empty slice for leaf nodes.
     pub fn children(&self) -> &[UINode] {
         match self {
-            UINode::Card(_, children) | UINode::Form(_, _, children) => children,
+            UINode::Card(_, children)
+            | UINode::Form(_, _, children)
+            | UINode::Modal(_, _, children)
+            | UINode::Loader(_, _, children)
+            | UINode::Pane(_, _, children)
+            | UINode::ScrollArea(_, children)
+            | UINode::Article(_, _, children)
+            | UINode::Accordion(_, _, children) => children,
             _ => &[],
         }
     }
 
+    /// Mutable children of container nodes.
+    pub fn children_mut(&mut self) -> &mut [UINode] {
+        match self {
+            UINode::Card(_, children)
+            | UINode::Form(_, _, children)
+            | UINode::Modal(_, _, children)
+            | UINode::Loader(_, _, children)
+            | UINode::Pane(_, _, children)
+            | UINode::ScrollArea(_, children)
+            | UINode::Article(_, _, children)
+            | UINode::Accordion(_, _, children) => children,
+            _ => &mut [],
+        }
+    }
+
+    /// Visit every node's Visual, pre-order.
+    pub fn for_each_visual_mut(&mut self, f: &mut impl FnMut(&mut Visual)) {
+        f(self.visual_mut());
+        for child in self.children_mut() {
+            child.for_each_visual_mut(f);
+        }
+    }
+
     /// Pre-order depth-first traversal of the entire tree.
     pub fn walk(&self) -> WalkIter<'_> {
         WalkIter { stack: vec![self] }
@@ -87,8 +190,8 @@ impl UINode {
         match self { UINode::Tab(v) => Some(v), _ => None }
     }
 
-    pub fn as_accordion(&self) -> Option<&Visual> {
-        match self { UINode::Accordion(v) => Some(v), _ => None }
+    pub fn as_accordion(&self) -> Option<(&Visual, &AccordionState, &[UINode])> {
+        match self { UINode::Accordion(v, s, c) => Some((v, s, c)), _ => None }
     }
 
     pub fn as_tag(&self) -> Option<(&Visual, &TagState)> {
@@ -139,6 +242,38 @@ impl UINode {
         match self { UINode::RadioGroup(v, s) => Some((v, s)), _ => None }
     }
 
+    pub fn as_command_palette(&self) -> Option<(&Visual, &PaletteState)> {
+        match self { UINode::CommandPalette(v, s) => Some((v, s)), _ => None }
+    }
+
+    pub fn as_tile_grid(&self) -> Option<(&Visual, &TileGridState)> {
+        match self { UINode::TileGrid(v, s) => Some((v, s)), _ => None }
+    }
+
+    pub fn as_keyboard_form(&self) -> Option<(&Visual, &FocusOrderState)> {
+        match self { UINode::KeyboardForm(v, s) => Some((v, s)), _ => None }
+    }
+
+    pub fn as_sortable_list(&self) -> Option<(&Visual, &SortableState)> {
+        match self { UINode::SortableList(v, s) => Some((v, s)), _ => None }
+    }
+
+    pub fn as_text_selection(&self) -> Option<(&Visual, &SelectionState)> {
+        match self { UINode::TextSelection(v, s) => Some((v, s)), _ => None }
+    }
+
+    pub fn as_shape(&self) -> Option<(&Visual, &ShapeState)> {
+        match self { UINode::Shape(v, s) => Some((v, s)), _ => None }
+    }
+
+    pub fn as_icon_button(&self) -> Option<(&Visual, &IconState)> {
+        match self { UINode::IconButton(v, s) => Some((v, s)), _ => None }
+    }
+
+    pub fn as_file_drag_task(&self) -> Option<(&Visual, &FileDragState)> {
+        match self { UINode::FileDragTask(v, s) => Some((v, s)), _ => None }
+    }
+
     pub fn as_card(&self) -> Option<(&Visual, &[UINode])> {
         match self { UINode::Card(v, c) => Some((v, c)), _ => None }
     }
@@ -147,6 +282,26 @@ impl UINode {
         match self { UINode::Form(v, f, c) => Some((v, f, c)), _ => None }
     }
 
+    pub fn as_modal(&self) -> Option<(&Visual, &ModalState, &[UINode])> {
+        match self { UINode::Modal(v, s, c) => Some((v, s, c)), _ => None }
+    }
+
+    pub fn as_loader(&self) -> Option<(&Visual, &LoaderState, &[UINode])> {
+        match self { UINode::Loader(v, s, c) => Some((v, s, c)), _ => None }
+    }
+
+    pub fn as_pane(&self) -> Option<(&Visual, &PaneState, &[UINode])> {
+        match self { UINode::Pane(v, s, c) => Some((v, s, c)), _ => None }
+    }
+
+    pub fn as_scroll_area(&self) -> Option<(&Visual, &[UINode])> {
+        match self { UINode::ScrollArea(v, c) => Some((v, c)), _ => None }
+    }
+
+    pub fn as_article(&self) -> Option<(&Visual, &ArticleState, &[UINode])> {
+        match self { UINode::Article(v, s, c) => Some((v, s, c)), _ => None }
+    }
+
     // ── Query helpers ───────────────────────────────────────────────
 
     /// Find all target nodes in the tree.
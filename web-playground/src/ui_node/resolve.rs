@@ -3,6 +3,9 @@
 //! Each node variant contributes its piece of the description, action steps,
 //! thinking chain, and target bounding boxes. Containers recurse into children.
 
+use std::cell::RefCell;
+use std::ops::Range;
+
 use super::*;
 
 /// Complete ground truth output from resolving a UINode tree.
@@ -14,8 +17,104 @@ pub struct ResolvedGroundTruth {
     pub steps: Vec<Action>,
     /// VLM reasoning chain.
     pub thinking: String,
-    /// All labeled bounding boxes: (label, rect) pairs.
-    pub targets: Vec<(String, Rect)>,
+    /// All labeled bounding boxes: (label, rect, kind) with `kind` the
+    /// `UINode::kind()` of the node that owns the box. Rects are
+    /// viewport-local, and already the rotated hull for a rotated tree.
+    pub targets: Vec<(String, Rect, &'static str)>,
+    /// Other step sequences that complete the same task, e.g. jumping
+    /// through a contents link instead of scrolling.
+    pub alternatives: Vec<Alternative>,
+    /// Name of the plan `steps` follow, when there are alternatives.
+    pub plan: Option<&'static str>,
+    /// Which reorderings of `steps` still complete the task.
+    pub order: StepOrder,
+}
+
+/// A partial order over the main `steps`, of which `steps` is the canonical
+/// linearization. Any order that keeps every `edges` pair completes the task.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StepOrder {
+    /// Interchangeable blocks, e.g. a form's fields: within a group the
+    /// blocks may play in any order, and each block is a run of step
+    /// indices that always plays as written.
+    pub groups: Vec<Vec<Vec<usize>>>,
+    /// `(before, after)` step indices that every valid order keeps.
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// A different way to complete the resolved task.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alternative {
+    /// Which plan this is ("scroll", "toc").
+    pub name: &'static str,
+    /// The whole step sequence under this plan.
+    pub steps: Vec<Action>,
+    /// Reasoning for the steps where it departs from the main plan.
+    pub thinking: String,
+}
+
+/// Where an alternative departs from the main steps: the `replaces` steps
+/// from index `at`, which follow plan `main`, swapped for `steps`.
+pub(super) struct Detour {
+    main: &'static str,
+    name: &'static str,
+    at: usize,
+    replaces: usize,
+    steps: Vec<Action>,
+    thinking: String,
+}
+
+/// Rendering settings threaded through the recursive resolve, and where
+/// nodes record alternative plans and interchangeable steps.
+pub(super) struct ResolveOpts<'a> {
+    pub(super) vt: &'a ViewportTransform,
+    pub(super) style: ThinkingStyle,
+    pub(super) lang: Lang,
+    pub(super) detours: &'a RefCell<Vec<Detour>>,
+    /// Step ranges that may play in any order, one `Vec` per group.
+    pub(super) groups: &'a RefCell<Vec<Vec<Range<usize>>>>,
+}
+
+impl StepOrder {
+    /// Chain `len` steps in order, then free each group's blocks, outermost
+    /// group first: the links between blocks give way to links from each
+    /// step still ahead of the group into every block, and from every block
+    /// to each step still after it.
+    fn new(len: usize, groups: Vec<Vec<Range<usize>>>) -> Self {
+        let mut edges: Vec<(usize, usize)> = (1..len).map(|i| (i - 1, i)).collect();
+        let mut outer_first: Vec<&Vec<Range<usize>>> = groups.iter().collect();
+        outer_first.sort_by_key(|blocks| (blocks[0].start, std::cmp::Reverse(blocks[blocks.len() - 1].end)));
+        for blocks in outer_first {
+            let (start, end) = (blocks[0].start, blocks[blocks.len() - 1].end);
+            let before: Vec<usize> = edges.iter().filter(|e| e.1 == start).map(|e| e.0).collect();
+            let after: Vec<usize> = edges.iter().filter(|e| e.0 == end - 1).map(|e| e.1).collect();
+            edges.retain(|&(_, b)| !blocks[1..].iter().any(|r| r.start == b));
+            for r in blocks {
+                edges.extend(before.iter().map(|&a| (a, r.start)));
+                edges.extend(after.iter().map(|&b| (r.end - 1, b)));
+            }
+        }
+        edges.sort_unstable();
+        edges.dedup();
+        let groups = groups.into_iter()
+            .map(|blocks| blocks.into_iter().map(|r| r.collect()).collect())
+            .collect();
+        StepOrder { groups, edges }
+    }
+
+    /// `{"groups":[[[0],[1,2]]],"edges":[[0,3],..]}`.
+    pub fn to_json(&self) -> String {
+        let groups: Vec<String> = self.groups.iter()
+            .map(|blocks| {
+                let blocks: Vec<String> = blocks.iter()
+                    .map(|b| format!("[{}]", b.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(",")))
+                    .collect();
+                format!("[{}]", blocks.join(","))
+            })
+            .collect();
+        let edges: Vec<String> = self.edges.iter().map(|(a, b)| format!("[{a},{b}]")).collect();
+        format!(r#"{{"groups":[{}],"edges":[{}]}}"#, groups.join(","), edges.join(","))
+    }
 }
 
 impl ResolvedGroundTruth {
@@ -23,6 +122,19 @@ impl ResolvedGroundTruth {
     pub fn steps_json(&self) -> String {
         actions_to_json(&self.steps)
     }
+
+    /// `[{"name":..,"steps":[..],"thinking":".."}]`, one entry per alternative.
+    pub fn alternatives_json(&self) -> String {
+        let plans: Vec<String> = self.alternatives.iter()
+            .map(|a| format!(
+                r#"{{"name":"{}","steps":{},"thinking":"{}"}}"#,
+                a.name,
+                actions_to_json(&a.steps),
+                escape_json(&a.thinking),
+            ))
+            .collect();
+        format!("[{}]", plans.join(","))
+    }
 }
 
 impl UINode {
@@ -34,228 +146,268 @@ impl UINode {
     /// Resolve with a viewport transform — coordinates in thinking/description
     /// will be in window space.
     pub fn resolve_with(&self, vt: &ViewportTransform) -> ResolvedGroundTruth {
+        self.resolve_styled(vt, ThinkingStyle::Verbose)
+    }
+
+    /// Like `resolve_with`, with the thinking chain written in `style`.
+    pub fn resolve_styled(&self, vt: &ViewportTransform, style: ThinkingStyle) -> ResolvedGroundTruth {
+        self.resolve_localized(vt, style, Lang::En)
+    }
+
+    /// Like `resolve_styled`, with all description and thinking text in `lang`.
+    /// Action labels and target labels are never translated.
+    pub fn resolve_localized(&self, vt: &ViewportTransform, style: ThinkingStyle, lang: Lang) -> ResolvedGroundTruth {
         let mut desc_parts = Vec::new();
         let mut steps = Vec::new();
         let mut think_parts = Vec::new();
         let mut targets = Vec::new();
+        let detours = RefCell::new(Vec::new());
+        let groups = RefCell::new(Vec::new());
 
-        self.resolve_inner(&mut desc_parts, &mut steps, &mut think_parts, &mut targets, None, vt);
+        let opts = ResolveOpts { vt, style, lang, detours: &detours, groups: &groups };
+        self.resolve_inner(&mut desc_parts, &mut steps, &mut think_parts, &mut targets, None, &opts);
 
+        let detours = detours.into_inner();
+        let plan = detours.first().map(|d| d.main);
+        let alternatives = detours.into_iter()
+            .map(|d| {
+                let mut plan = steps[..d.at].to_vec();
+                plan.extend(d.steps);
+                plan.extend_from_slice(&steps[d.at + d.replaces..]);
+                Alternative { name: d.name, steps: plan, thinking: d.thinking }
+            })
+            .collect();
+        let order = StepOrder::new(steps.len(), groups.into_inner());
         ResolvedGroundTruth {
             description: desc_parts.join(", "),
             steps,
             thinking: think_parts.join(" "),
-            targets,
+            targets: targets.into_iter().map(|(label, rect, kind)| (label, vt.local(&rect), kind)).collect(),
+            alternatives,
+            plan,
+            order,
         }
     }
 
+    /// Record `detour`, its reasoning restyled like this node's own thinking.
+    fn offer(&self, mut detour: Detour, opts: &ResolveOpts) {
+        let mut styled = vec![std::mem::take(&mut detour.thinking)];
+        self.restyle_thinking(&mut styled, 0, &detour.steps, opts);
+        detour.thinking = styled.join(" ");
+        opts.detours.borrow_mut().push(detour);
+    }
+
     fn resolve_inner(
         &self,
         desc: &mut Vec<String>,
         steps: &mut Vec<Action>,
         think: &mut Vec<String>,
-        targets: &mut Vec<(String, Rect)>,
+        targets: &mut Vec<(String, Rect, &'static str)>,
         parent: Option<(&str, &Rect)>,
-        vt: &ViewportTransform,
+        opts: &ResolveOpts,
     ) {
+        let (vt, lang) = (opts.vt, opts.lang);
+        let t = |key: &str, args: &[&dyn std::fmt::Display]| tr(lang, key, args);
         let v = self.visual();
         // Region is relative to parent (or viewport), coords are window-absolute
         let (wx, wy, ww, wh) = vt.apply(&v.rect);
+        let coords = match v.z_index {
+            0 => format!("{},{} {}x{}", wx, wy, ww, wh),
+            z => format!("{},{} {}x{} z={}", wx, wy, ww, wh, z),
+        };
         let pos = match parent {
-            Some((parent_label, parent_rect)) => format!(
-                "near the {} of the {} ({},{} {}x{})",
-                v.rect.region_within(parent_rect), parent_label,
-                wx, wy, ww, wh,
-            ),
-            None => format!(
-                "near the {} ({},{} {}x{})",
-                v.rect.region(), wx, wy, ww, wh,
-            ),
+            Some((parent_key, parent_rect)) => t("pos.near_within", &[
+                &t(&format!("region.{}", vt.local(&v.rect).region_within(&vt.local(parent_rect))), &[]),
+                &t(&format!("parent.{}", parent_key), &[]),
+                &coords,
+            ]),
+            None => t("pos.near", &[&t(&format!("region.{}", vt.local(&v.rect).region()), &[]), &coords]),
         };
         let color_str = v.color.as_deref().unwrap_or("");
+        let kind = self.kind();
+        let think_start = think.len();
+        let steps_start = steps.len();
 
         match self {
             // ── Simple click targets ────────────────────────────────
 
             UINode::Button(v) => {
-                let color_desc = color_prefix(color_str);
-                desc.push(format!("{}button \"{}\" at {}", color_desc, v.label, pos));
-                targets.push((v.label.clone(), v.rect));
+                let color_desc = color_affix(color_str, lang);
+                desc.push(t("desc.button", &[&color_desc, &v.label, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
                 if v.is_target {
                     steps.push(Action::click(&v.label));
-                    think.push(format!(
-                        "I see a {}button labeled \"{}\", located at {}. I should click it.",
-                        color_desc, v.label, pos,
-                    ));
+                    think.push(t("think.button", &[&color_desc, &v.label, &pos]));
                 }
             }
 
             UINode::Toggle(v, state) => {
-                let state_str = if state.is_on { "on" } else { "off" };
-                desc.push(format!("toggle \"{}\" ({}) at {}", v.label, state_str, pos));
-                targets.push((v.label.clone(), v.rect));
-                if v.is_target {
+                let state_str = t(if state.is_on { "state.on" } else { "state.off" }, &[]);
+                desc.push(t("desc.toggle", &[&v.label, &state_str, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
+                if v.is_target && state.is_on != state.target_on {
                     steps.push(Action::click(&v.label));
-                    think.push(format!(
-                        "I see a toggle labeled \"{}\", currently {}, located {}. I need to click it to switch it.",
-                        v.label, state_str, pos,
-                    ));
+                    think.push(t("think.toggle", &[&v.label, &state_str, &pos]));
+                } else if v.is_target {
+                    think.push(t("think.toggle_keep", &[&v.label, &state_str, &pos]));
                 }
             }
 
             UINode::Checkbox(v, state) => {
-                let state_str = if state.is_checked { "checked" } else { "unchecked" };
-                desc.push(format!("checkbox \"{}\" ({}) at {}", v.label, state_str, pos));
-                targets.push((v.label.clone(), v.rect));
-                if v.is_target {
+                let state_str = t(if state.is_checked { "state.checked" } else { "state.unchecked" }, &[]);
+                desc.push(t("desc.checkbox", &[&v.label, &state_str, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
+                // A target box must end checked; clicking a checked one clears it
+                if v.is_target && !state.is_checked {
                     steps.push(Action::click(&v.label));
-                    think.push(format!(
-                        "I see a checkbox labeled \"{}\", currently {}, located {}. I need to click it.",
-                        v.label, state_str, pos,
-                    ));
+                    think.push(t("think.checkbox", &[&v.label, &state_str, &pos]));
+                } else if v.is_target {
+                    think.push(t("think.checkbox_keep", &[&v.label, &pos]));
                 }
             }
 
             UINode::Tab(v) => {
-                desc.push(format!("tab \"{}\" at {}", v.label, pos));
-                targets.push((v.label.clone(), v.rect));
+                desc.push(t("desc.tab", &[&v.label, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
                 if v.is_target {
                     steps.push(Action::click(&v.label));
-                    think.push(format!(
-                        "I see a tab labeled \"{}\", located {}. I need to click it to switch to that tab.",
-                        v.label, pos,
-                    ));
+                    think.push(t("think.tab", &[&v.label, &pos]));
                 }
             }
 
-            UINode::Accordion(v) => {
-                desc.push(format!("accordion \"{}\" at {}", v.label, pos));
-                targets.push((v.label.clone(), v.rect));
+            UINode::Accordion(v, state, children) => {
+                desc.push(t("desc.accordion", &[&v.label, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
                 if v.is_target {
                     steps.push(Action::click(&v.label));
-                    think.push(format!(
-                        "I see a collapsible section labeled \"{}\", located {}. I need to click it to expand it.",
-                        v.label, pos,
-                    ));
+                    think.push(t("think.accordion", &[&v.label, &pos]));
+                }
+                self.restyle_thinking(think, think_start, &steps[steps_start..], opts);
+                // Revealed content resolves after the expand click, placed within the body
+                let ctx = Some(("section", &state.body));
+                for child in children {
+                    child.resolve_inner(desc, steps, think, targets, ctx, opts);
                 }
             }
 
             UINode::Tag(v, state) => {
-                let state_str = if state.is_selected { "selected" } else { "unselected" };
-                desc.push(format!("tag \"{}\" ({}) at {}", v.label, state_str, pos));
-                targets.push((v.label.clone(), v.rect));
-                if v.is_target {
+                let state_str = t(if state.is_selected { "state.selected" } else { "state.unselected" }, &[]);
+                desc.push(t("desc.tag", &[&v.label, &state_str, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
+                if v.is_target && state.is_selected != state.target_selected {
                     steps.push(Action::click(&v.label));
-                    think.push(format!(
-                        "I see a tag chip labeled \"{}\", currently {}, located {}. I need to click it.",
-                        v.label, state_str, pos,
-                    ));
+                    think.push(t("think.tag", &[&v.label, &state_str, &pos]));
                 }
             }
 
             UINode::Toast(v, state) => {
                 let dismiss_label = format!("dismiss: {}", state.message);
-                desc.push(format!(
-                    "toast ({}) \"{}\" at {}", state.kind, state.message, pos,
-                ));
-                targets.push((dismiss_label.clone(), v.rect));
+                desc.push(t("desc.toast", &[&state.kind, &state.message, &pos]));
+                targets.push((dismiss_label.clone(), v.rect, kind));
                 if v.is_target {
                     steps.push(Action::click(&dismiss_label));
-                    think.push(format!(
-                        "I see a {} toast notification saying \"{}\", located {}. I need to dismiss it.",
-                        state.kind, state.message, pos,
-                    ));
+                    think.push(t("think.toast", &[&state.kind, &state.message, &pos]));
                 }
             }
 
             UINode::Star(v, state) => {
-                desc.push(format!(
-                    "star rating \"{}\" {}/{} target={} at {}",
-                    v.label, state.current, state.max, state.target, pos,
-                ));
+                desc.push(t("desc.star", &[&v.label, &state.current, &state.max, &state.target, &pos]));
                 let star_label = format!("star {} of {}", state.target, v.label);
-                targets.push((star_label.clone(), v.rect));
+                targets.push((star_label.clone(), v.rect, kind));
                 if v.is_target {
                     steps.push(Action::click(&star_label));
-                    think.push(format!(
-                        "I see a star rating for \"{}\" currently at {}/{}, located {}. I need to click star {} to set it to {}.",
-                        v.label, state.current, state.max, pos, state.target, state.target,
-                    ));
+                    think.push(t("think.star", &[&v.label, &state.current, &state.max, &pos, &state.target]));
                 }
             }
 
             UINode::ModalButton(v) => {
-                let color_desc = color_prefix(color_str);
-                desc.push(format!("{}modal button \"{}\" at {}", color_desc, v.label, pos));
-                targets.push((v.label.clone(), v.rect));
+                let color_desc = color_affix(color_str, lang);
+                desc.push(t("desc.modal_button", &[&color_desc, &v.label, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
                 if v.is_target {
                     steps.push(Action::click(&v.label));
-                    think.push(format!(
-                        "I see a {}button labeled \"{}\" in the dialog, located {}. I should click it.",
-                        color_desc, v.label, pos,
-                    ));
+                    think.push(t("think.modal_button", &[&color_desc, &v.label, &pos]));
                 }
             }
 
             // ── Text input ──────────────────────────────────────────
 
             UINode::TextInput(v, state) => {
-                desc.push(format!(
-                    "text input \"{}\" placeholder=\"{}\" at {}",
-                    v.label, state.placeholder, pos,
-                ));
-                targets.push((v.label.clone(), v.rect));
+                desc.push(t("desc.text_input", &[&v.label, &state.placeholder, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
                 if v.is_target {
                     steps.push(Action::type_text(&v.label, &state.target_value));
-                    think.push(format!(
-                        "I see a text input labeled \"{}\", located {}. I need to type \"{}\" into it.",
-                        v.label, pos, state.target_value,
-                    ));
+                    think.push(t("think.text_input", &[&v.label, &pos, &state.target_value]));
+                }
+            }
+
+            // ── Text selection ──────────────────────────────────────
+
+            UINode::TextSelection(v, state) => {
+                desc.push(t("desc.text_selection", &[&v.label, &state.target_text, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
+                if v.is_target && state.selected.trim() != state.target_text {
+                    steps.push(Action::select_text(&v.label, &state.target_text));
+                    think.push(t("think.select_text", &[&v.label, &pos, &state.target_text]));
                 }
             }
 
             // ── Slider (drag) ───────────────────────────────────────
 
             UINode::Slider(v, state) => {
-                let color_desc = color_prefix(color_str);
-                desc.push(format!(
-                    "{}slider \"{}\" range {}-{} step {} current={} target={} at {}",
-                    color_desc, v.label, state.min, state.max, state.step,
-                    state.current_val, state.target_val, pos,
-                ));
-                let from_label = format!("drag-from: {}", v.label);
-                let to_label = format!("drag-to: {}", v.label);
-                targets.push((from_label.clone(), state.thumb_rect));
-                targets.push((to_label.clone(), state.target_thumb_rect));
-                if v.is_target {
-                    steps.push(Action::drag(&from_label, &to_label));
-                    let direction = if state.target_val > state.current_val { "right" } else { "left" };
-                    let delta = (state.target_val - state.current_val).abs();
-                    think.push(format!(
-                        "I see a {}slider labeled \"{}\" currently at {}, located {}. I need to drag it {} by {} to reach {}.",
-                        color_desc, v.label, state.current_val, pos, direction, delta, state.target_val,
-                    ));
+                let color_desc = color_affix(color_str, lang);
+                let band = if state.tolerance > 0 {
+                    t("desc.slider_band", &[
+                        &(state.target_val - state.tolerance),
+                        &(state.target_val + state.tolerance),
+                    ])
+                } else {
+                    String::new()
+                };
+                desc.push(t("desc.slider", &[
+                    &color_desc, &v.label, &state.min, &state.max, &state.step,
+                    &state.current_val, &state.target_val, &band, &pos,
+                ]));
+                if state.native {
+                    // A range input takes its value directly; no thumb to drag
+                    targets.push((v.label.clone(), v.rect, kind));
+                    if v.is_target {
+                        steps.push(Action::set_slider(&v.label, state.target_val));
+                        think.push(t("think.slider_native", &[
+                            &color_desc, &v.label, &state.current_val, &pos, &state.target_val,
+                        ]));
+                    }
+                } else {
+                    let from_label = format!("drag-from: {}", v.label);
+                    let to_label = format!("drag-to: {}", v.label);
+                    targets.push((from_label.clone(), state.thumb_rect, kind));
+                    targets.push((to_label.clone(), state.target_thumb_rect, kind));
+                    if v.is_target {
+                        steps.push(Action::drag(&from_label, &to_label));
+                        let direction = t(if state.target_val > state.current_val { "dir.right" } else { "dir.left" }, &[]);
+                        let delta = (state.target_val - state.current_val).abs();
+                        think.push(t("think.slider", &[
+                            &color_desc, &v.label, &state.current_val, &pos, &direction, &delta, &state.target_val,
+                        ]));
+                    }
                 }
             }
 
             // ── Drag source / drop zone ─────────────────────────────
 
             UINode::DragSource(v) => {
-                desc.push(format!("draggable \"{}\" at {}", v.label, pos));
-                targets.push((v.label.clone(), v.rect));
+                desc.push(t("desc.drag_source", &[&v.label, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
                 if v.is_target {
                     // Drag steps are typically constructed at the parent level
                     // since they need to reference the drop zone label.
-                    think.push(format!(
-                        "I see a draggable element labeled \"{}\", located {}. I need to drag it to the drop zone.",
-                        v.label, pos,
-                    ));
+                    think.push(t("think.drag_source", &[&v.label, &pos]));
                 }
             }
 
             UINode::DropZone(v) => {
-                desc.push(format!("drop zone \"{}\" at {}", v.label, pos));
-                targets.push((v.label.clone(), v.rect));
+                desc.push(t("desc.drop_zone", &[&v.label, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
             }
 
             // ── Composite (multi-step) ──────────────────────────────
@@ -264,18 +416,37 @@ impl UINode {
                 let opts_str = state.options.iter()
                     .map(|o| format!("\"{}\"", o))
                     .collect::<Vec<_>>().join(", ");
-                desc.push(format!(
-                    "dropdown \"{}\" options=[{}] target=\"{}\" at {}",
-                    v.label, opts_str, state.target_option, pos,
-                ));
-                targets.push((state.trigger_label.clone(), v.rect));
-                if v.is_target {
+                desc.push(t("desc.dropdown", &[&v.label, &opts_str, &state.target_option, &pos]));
+                targets.push((state.trigger_label.clone(), v.rect, kind));
+                // Option rows are only on screen while the menu is open
+                for (opt, rect) in &state.open_options {
+                    targets.push((opt.clone(), *rect, kind));
+                }
+                let already = state.selected.as_deref() == Some(state.target_option.as_str());
+                if v.is_target && !already {
                     steps.push(Action::click(&state.trigger_label));
-                    steps.push(Action::click(&state.target_option));
-                    think.push(format!(
-                        "I see a dropdown labeled \"{}\", located {}. I need to click \"{}\" to open it, then select \"{}\".",
-                        v.label, pos, state.trigger_label, state.target_option,
-                    ));
+                    match state.mode {
+                        DropdownMode::Click => {
+                            steps.push(Action::click(&state.target_option));
+                            think.push(t("think.dropdown", &[&v.label, &pos, &state.trigger_label, &state.target_option]));
+                        }
+                        DropdownMode::Search => {
+                            let query = state.search_query();
+                            steps.push(Action::type_text(DROPDOWN_SEARCH_LABEL, &query));
+                            steps.push(Action::click(&state.target_option));
+                            think.push(t("think.dropdown_search", &[
+                                &v.label, &pos, &state.trigger_label, &query, &state.target_option,
+                            ]));
+                        }
+                        DropdownMode::Keys => {
+                            let (key, n) = state.arrow_presses();
+                            steps.extend((0..n).map(|_| Action::key_press(key)));
+                            steps.push(Action::key_press("Enter"));
+                            think.push(t("think.dropdown_keys", &[
+                                &v.label, &pos, &state.trigger_label, &key, &n, &state.target_option,
+                            ]));
+                        }
+                    }
                 }
             }
 
@@ -283,29 +454,26 @@ impl UINode {
                 let items_str = state.items.iter()
                     .map(|i| format!("\"{}\"", i))
                     .collect::<Vec<_>>().join(", ");
-                desc.push(format!(
-                    "context menu trigger=\"{}\" items=[{}] target=\"{}\" at {}",
-                    state.trigger_label, items_str, state.target_item, pos,
-                ));
-                targets.push(("trigger".to_string(), v.rect));
+                desc.push(t("desc.context_menu", &[&state.trigger_label, &items_str, &state.target_item, &pos]));
+                targets.push((state.trigger_label.clone(), v.rect, kind));
+                // Items are only on screen after the right-click, at the menu's open position
+                for (item, rect) in state.items.iter().zip(&state.item_rects) {
+                    targets.push((item.clone(), *rect, kind));
+                }
                 if v.is_target {
                     steps.push(Action::right_click(&state.trigger_label));
                     steps.push(Action::click(&state.target_item));
-                    think.push(format!(
-                        "I see an element I need to right-click, located {}. I'll right-click \"{}\", then select \"{}\" from the menu.",
-                        pos, state.trigger_label, state.target_item,
-                    ));
+                    think.push(t("think.context_menu", &[&pos, &state.trigger_label, &state.target_item]));
                 }
             }
 
             UINode::Stepper(v, state) => {
-                desc.push(format!(
-                    "stepper \"{}\" range {}-{} step {} current={} target={} at {}",
-                    v.label, state.min, state.max, state.step,
-                    state.current_val, state.target_val, pos,
-                ));
-                targets.push((state.minus_label.clone(), v.rect));
-                targets.push((state.plus_label.clone(), v.rect));
+                desc.push(t("desc.stepper", &[
+                    &v.label, &state.min, &state.max, &state.step,
+                    &state.current_val, &state.target_val, &pos,
+                ]));
+                targets.push((state.minus_label.clone(), state.minus_rect, kind));
+                targets.push((state.plus_label.clone(), state.plus_rect, kind));
                 if v.is_target {
                     let diff = state.target_val - state.current_val;
                     let n_clicks = (diff.abs() / state.step.max(1)) as usize;
@@ -317,11 +485,10 @@ impl UINode {
                     for _ in 0..n_clicks {
                         steps.push(Action::click(btn_label));
                     }
-                    let direction = if diff > 0 { "increment" } else { "decrement" };
-                    think.push(format!(
-                        "I see a stepper labeled \"{}\" currently at {}, located {}. I need to {} it {} times to reach {}.",
-                        v.label, state.current_val, pos, direction, n_clicks, state.target_val,
-                    ));
+                    let direction = t(if diff > 0 { "dir.increment" } else { "dir.decrement" }, &[]);
+                    think.push(t("think.stepper", &[
+                        &v.label, &state.current_val, &pos, &direction, &n_clicks, &state.target_val,
+                    ]));
                 }
             }
 
@@ -335,55 +502,480 @@ impl UINode {
                         }
                     })
                     .collect::<Vec<_>>().join(", ");
-                desc.push(format!(
-                    "radio group \"{}\" options=[{}] at {}",
-                    v.label, opts_str, pos,
-                ));
+                desc.push(t("desc.radio_group", &[&v.label, &opts_str, &pos]));
                 if v.is_target {
+                    // Only the target group's options: labels can repeat across groups
+                    for (option, rect) in state.options.iter().zip(&state.option_rects) {
+                        targets.push((option.clone(), *rect, kind));
+                    }
                     let target_name = &state.options[state.target_option];
                     steps.push(Action::click(target_name));
-                    targets.push((target_name.clone(), v.rect));
-                    think.push(format!(
-                        "I see a radio group labeled \"{}\", located {}. I need to select the \"{}\" option.",
-                        v.label, pos, target_name,
-                    ));
+                    think.push(t("think.radio_group", &[&v.label, &pos, target_name]));
+                }
+            }
+
+            UINode::CommandPalette(v, state) => {
+                let cmds_str = state.commands.iter()
+                    .map(|c| format!("\"{}\"", c))
+                    .collect::<Vec<_>>().join(", ");
+                let open_str = t(if state.is_open { "state.open" } else { "state.closed" }, &[]);
+                desc.push(t("desc.command_palette", &[
+                    &open_str, &state.hotkey, &cmds_str, &state.target_command, &pos,
+                ]));
+                targets.push((state.input_label.clone(), state.input_rect, kind));
+                if v.is_target {
+                    steps.push(Action::key_press(&state.hotkey));
+                    steps.push(Action::type_text(&state.input_label, &state.target_command));
+                    steps.push(Action::key_press("Enter"));
+                    think.push(t("think.command_palette", &[&state.target_command, &state.hotkey]));
+                }
+            }
+
+            UINode::TileGrid(v, state) => {
+                let tiles_str = state.tiles.iter()
+                    .map(|t| {
+                        let sel = if t.is_selected { " (selected)" } else { "" };
+                        format!("{}=\"{}\"{}", t.label, t.content, sel)
+                    })
+                    .collect::<Vec<_>>().join(", ");
+                desc.push(t("desc.tile_grid", &[&v.label, &state.cols, &state.prompt, &tiles_str, &pos]));
+                for t in &state.tiles {
+                    targets.push((t.label.clone(), t.rect, kind));
+                }
+                if v.is_target {
+                    // Tiles are stored row-major, so this is reading order
+                    let to_click: Vec<&GridTile> = state.tiles.iter()
+                        .filter(|t| t.is_selected != t.is_match)
+                        .collect();
+                    for t in &to_click {
+                        steps.push(Action::click(&t.label));
+                    }
+                    let names = state.tiles.iter()
+                        .filter(|t| t.is_match)
+                        .map(|t| format!("{} (\"{}\")", t.label, t.content))
+                        .collect::<Vec<_>>().join(", ");
+                    think.push(t("think.tile_grid", &[&state.prompt, &pos, &names, &to_click.len()]));
+                }
+            }
+
+            UINode::CheckboxGroup(v, boxes, target) => {
+                let boxes_str = boxes.iter()
+                    .map(|(b, s)| {
+                        let state_str = t(if s.is_checked { "state.checked" } else { "state.unchecked" }, &[]);
+                        format!("\"{}\" ({})", b.label, state_str)
+                    })
+                    .collect::<Vec<_>>().join(", ");
+                desc.push(t("desc.checkbox_group", &[&v.label, &boxes_str, &pos]));
+                for (b, _) in boxes {
+                    targets.push((b.label.clone(), b.rect, kind));
+                }
+                if v.is_target {
+                    let to_click: Vec<&Visual> = boxes.iter().enumerate()
+                        .filter(|(i, (b, s))| target.wants(*i, &b.label) && !s.is_checked)
+                        .map(|(_, (b, _))| b)
+                        .collect();
+                    for b in &to_click {
+                        steps.push(Action::click(&b.label));
+                    }
+                    let goal = match target {
+                        GroupTarget::All => t("group.all", &[&boxes.len()]),
+                        GroupTarget::Ordinal(n) => {
+                            let label = boxes.get(*n).map_or("", |(b, _)| b.label.as_str());
+                            t("group.nth", &[&ordinal(lang, n + 1), &label])
+                        }
+                        GroupTarget::ByLabel(label) => t("group.label", &[label]),
+                    };
+                    think.push(t("think.checkbox_group", &[&boxes.len(), &pos, &goal, &to_click.len()]));
+                }
+            }
+
+            UINode::Tooltip(v, state) => {
+                desc.push(t("desc.tooltip", &[&v.label, &t(if state.open { "state.open" } else { "state.closed" }, &[]), &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
+                if v.is_target {
+                    if state.open {
+                        think.push(t("think.tooltip_open", &[&v.label, &pos, &state.text]));
+                    } else {
+                        steps.push(Action::hover(&v.label));
+                        think.push(t("think.tooltip_hover", &[&v.label, &pos, &state.text]));
+                    }
+                }
+            }
+
+            UINode::Shape(v, state) => {
+                let phrase = state.phrase(lang);
+                desc.push(t("desc.shape", &[&phrase, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
+                if v.is_target {
+                    steps.push(Action::click(&v.label));
+                    think.push(t("think.shape", &[&phrase, &pos]));
+                }
+            }
+
+            UINode::IconButton(v, state) => {
+                let glyph = t(&format!("glyph.{}", state.glyph), &[]);
+                desc.push(t("desc.icon_button", &[&v.label, &glyph, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
+                if v.is_target {
+                    steps.push(Action::click(&v.label));
+                    think.push(t("think.icon_button", &[&v.label, &glyph, &pos]));
+                }
+            }
+
+            UINode::Carousel(v, state) => {
+                let nav_str = t(&format!("carousel.{}", state.nav.name()), &[]);
+                desc.push(t("desc.carousel", &[&v.label, &nav_str, &(state.current + 1), &state.slide_count, &pos]));
+                if let Some((prev, next)) = state.arrows {
+                    targets.push((CarouselState::PREV_LABEL.to_string(), prev, kind));
+                    targets.push((CarouselState::NEXT_LABEL.to_string(), next, kind));
+                }
+                for (i, rect) in state.indicators.iter().enumerate() {
+                    targets.push((CarouselState::indicator_label(i), *rect, kind));
+                }
+                if v.is_target {
+                    let (cur, goal) = (state.current + 1, state.target_slide + 1);
+                    if state.current == state.target_slide {
+                        think.push(t("think.carousel_here", &[&pos, &cur]));
+                    } else if state.nav == CarouselNav::Auto {
+                        steps.push(Action::wait_for(CarouselState::showing_label(state.target_slide)));
+                        think.push(t("think.carousel_auto", &[&pos, &cur, &goal]));
+                    } else if !state.indicators.is_empty() {
+                        // One indicator click beats any number of arrow clicks,
+                        // which stay an alternative
+                        let label = CarouselState::indicator_label(state.target_slide);
+                        if state.arrows.is_some() {
+                            let arrow = if state.target_slide > state.current { CarouselState::NEXT_LABEL } else { CarouselState::PREV_LABEL };
+                            let clicks = state.target_slide.abs_diff(state.current);
+                            self.offer(Detour {
+                                main: "dots",
+                                name: "arrows",
+                                at: steps.len(),
+                                replaces: 1,
+                                steps: vec![Action::click(arrow); clicks],
+                                thinking: t("think.carousel_step", &[&pos, &cur, &goal, &arrow, &clicks]),
+                            }, opts);
+                        }
+                        steps.push(Action::click(&label));
+                        think.push(t("think.carousel_jump", &[&pos, &cur, &goal, &label]));
+                    } else {
+                        let label = if state.target_slide > state.current { CarouselState::NEXT_LABEL } else { CarouselState::PREV_LABEL };
+                        let clicks = state.target_slide.abs_diff(state.current);
+                        for _ in 0..clicks {
+                            steps.push(Action::click(label));
+                        }
+                        think.push(t("think.carousel_step", &[&pos, &cur, &goal, &label, &clicks]));
+                    }
+                }
+            }
+
+            UINode::KeyboardForm(v, state) => {
+                let fields_str = state.fields.iter()
+                    .map(|f| format!("\"{}\"", f.label))
+                    .collect::<Vec<_>>().join(", ");
+                let focus_str = state.focused
+                    .and_then(|i| state.fields.get(i))
+                    .map(|f| format!("\"{}\"", f.label))
+                    .unwrap_or_else(|| t("state.nothing", &[]));
+                desc.push(t("desc.keyboard_form", &[&v.label, &fields_str, &focus_str, &pos]));
+                for f in &state.fields {
+                    targets.push((f.label.clone(), f.rect, kind));
+                }
+                if v.is_target {
+                    // Remaining steps from the live focus position
+                    let mut cursor = state.focused;
+                    for (i, f) in state.fields.iter().enumerate() {
+                        if f.current_value == f.target_value && state.focus_history.contains(&i) {
+                            continue;
+                        }
+                        match cursor {
+                            Some(c) if c > i => {
+                                for _ in i..c { steps.push(Action::key_press("Shift+Tab")); }
+                            }
+                            Some(c) => {
+                                for _ in c..i { steps.push(Action::key_press("Tab")); }
+                            }
+                            None => {
+                                for _ in 0..=i { steps.push(Action::key_press("Tab")); }
+                            }
+                        }
+                        cursor = Some(i);
+                        if f.current_value != f.target_value {
+                            steps.push(Action::type_text(&f.label, &f.target_value));
+                        }
+                    }
+                    steps.push(Action::key_press(&state.submit_key));
+                    let plan = state.fields.iter()
+                        .map(|f| t("think.keyboard_form_field", &[&f.target_value, &f.label]))
+                        .collect::<Vec<_>>().join(text(lang, "list.then"));
+                    think.push(t("think.keyboard_form", &[&pos, &plan, &state.submit_key]));
+                }
+            }
+
+            UINode::SortableList(v, state) => {
+                let items_str = state.items.iter()
+                    .map(|i| format!("\"{}\"", i))
+                    .collect::<Vec<_>>().join(", ");
+                desc.push(t("desc.sortable_list", &[&v.label, &items_str, &pos]));
+                for (item, slot) in state.items.iter().zip(&state.slots) {
+                    targets.push((item.clone(), *slot, kind));
+                }
+                if let (true, Some(from), Some(slot)) = (v.is_target, state.current_pos(), state.slots.get(state.target_pos)) {
+                    let goal = state.target_pos + 1;
+                    if from == state.target_pos {
+                        think.push(t("think.sortable_here", &[&pos, &state.target_item, &goal]));
+                    } else {
+                        // Items swap once the dragged centre passes a neighbour's
+                        // centre, so aim at the half of the slot beyond it
+                        let down = state.target_pos > from;
+                        let half = slot.h / 2.0;
+                        let drop = Rect::new(slot.x, if down { slot.y + half } else { slot.y }, slot.w, half);
+                        let slot_label = SortableState::slot_label(state.target_pos);
+                        targets.push((slot_label.clone(), drop, kind));
+                        steps.push(Action::drag(&state.target_item, &slot_label));
+                        let direction = t(if down { "dir.down" } else { "dir.up" }, &[]);
+                        think.push(t("think.sortable", &[&pos, &state.target_item, &(from + 1), &goal, &direction]));
+                    }
+                }
+            }
+
+            UINode::FileDragTask(v, state) => {
+                let files_str = state.files.iter()
+                    .map(|f| match f.z_index {
+                        0 => format!("\"{}\"", f.label),
+                        z => format!("\"{}\" z={}", f.label, z),
+                    })
+                    .collect::<Vec<_>>().join(", ");
+                desc.push(t("desc.file_drag_task", &[&files_str, &state.zone.label, &pos]));
+                for f in &state.files {
+                    targets.push((f.label.clone(), f.rect, kind));
+                }
+                targets.push((state.zone.label.clone(), state.zone.rect, kind));
+                if let (true, Some(file)) = (v.is_target, state.files.get(state.target)) {
+                    steps.push(Action::drag(&file.label, &state.zone.label));
+                    let coords = |r: &Rect| {
+                        let (x, y, w, h) = vt.apply(r);
+                        format!("{},{} {}x{}", x, y, w, h)
+                    };
+                    think.push(t("think.file_drag", &[
+                        &state.files.len(), &state.zone.label, &coords(&state.zone.rect), &file.label, &coords(&file.rect),
+                    ]));
                 }
             }
 
             // ── Containers ──────────────────────────────────────────
 
             UINode::Card(_v, children) => {
-                desc.push(format!("card at {}", pos));
-                think.push(format!("I see a card {}.", pos));
+                desc.push(t("desc.card", &[&pos]));
+                think.push(t("think.card", &[&pos]));
+                self.restyle_thinking(think, think_start, &[], opts);
                 let ctx = Some(("card", &_v.rect));
                 for child in children {
-                    child.resolve_inner(desc, steps, think, targets, ctx, vt);
+                    child.resolve_inner(desc, steps, think, targets, ctx, opts);
                 }
                 // Auto-detect DragSource+DropZone pairs and emit drag step
                 emit_drag_pairs(children, steps);
             }
 
             UINode::Form(v, form_state, children) => {
-                desc.push(format!("form at {}", pos));
-                think.push(format!("I see a form {}.", pos));
+                desc.push(t("desc.form", &[&pos]));
+                think.push(t("think.form", &[&pos]));
+                self.restyle_thinking(think, think_start, &[], opts);
                 let ctx = Some(("form", &v.rect));
+                // Independent fields fill in any order, each field's own steps together
+                let mut blocks = Vec::new();
                 for child in children {
-                    child.resolve_inner(desc, steps, think, targets, ctx, vt);
+                    let start = steps.len();
+                    child.resolve_inner(desc, steps, think, targets, ctx, opts);
+                    if steps.len() > start {
+                        blocks.push(start..steps.len());
+                    }
+                }
+                if form_state.unordered && blocks.len() > 1 {
+                    opts.groups.borrow_mut().push(blocks);
                 }
                 emit_drag_pairs(children, steps);
                 // Forms end with the submit click
+                let submit_think = think.len();
+                let submit_step = steps.len();
                 steps.push(Action::click(&form_state.submit_label));
-                targets.push((form_state.submit_label.clone(), v.rect));
+                targets.push((form_state.submit_label.clone(), v.rect, "button"));
                 let (sx, sy, sw, sh) = vt.apply(&v.rect);
-                think.push(format!(
-                    "After completing the form, I click \"{}\", located near the bottom of the form ({},{} {}x{}).",
-                    form_state.submit_label, sx, sy, sw, sh,
-                ));
+                let submit_coords = format!("{},{} {}x{}", sx, sy, sw, sh);
+                think.push(t("think.form_submit", &[&form_state.submit_label, &submit_coords]));
+                self.restyle_thinking(think, submit_think, &steps[submit_step..], opts);
+            }
+
+            UINode::Modal(v, state, children) => {
+                if state.open {
+                    desc.push(t("desc.modal", &[&state.title, &pos]));
+                    think.push(t("think.modal_open", &[&state.title, &pos]));
+                    self.restyle_thinking(think, think_start, &[], opts);
+                    let ctx = Some(("dialog", &v.rect));
+                    for child in children {
+                        child.resolve_inner(desc, steps, think, targets, ctx, opts);
+                    }
+                } else {
+                    // Nothing in a closed dialog has a box yet
+                    resolve_hidden(children, steps, opts);
+                    let clicks = clicked_labels(&steps[steps_start..]);
+                    if !clicks.is_empty() {
+                        think.push(t("think.modal_closed", &[&state.title, &clicks]));
+                    }
+                    self.restyle_thinking(think, think_start, &steps[steps_start..], opts);
+                }
+            }
+
+            UINode::Pane(v, state, children) => {
+                desc.push(t("desc.pane", &[&state.name, &pos]));
+                if self.walk().skip(1).any(|n| n.visual().is_target) {
+                    think.push(t("think.pane", &[&state.name, &pos]));
+                }
+                self.restyle_thinking(think, think_start, &[], opts);
+                // Children are pane-local: resolve them through the nested
+                // transform, then move their boxes back into viewport space
+                let nested = vt.nested((v.rect.x, v.rect.y));
+                let inner = ResolveOpts { vt: &nested, ..*opts };
+                let local = Rect::new(0.0, 0.0, v.rect.w, v.rect.h);
+                let targets_start = targets.len();
+                for child in children {
+                    child.resolve_inner(desc, steps, think, targets, Some(("pane", &local)), &inner);
+                }
+                for (_, rect, _) in &mut targets[targets_start..] {
+                    rect.x += v.rect.x;
+                    rect.y += v.rect.y;
+                }
+                emit_drag_pairs(children, steps);
+            }
+
+            UINode::ScrollArea(v, children) => {
+                desc.push(t("desc.scroll_area", &[&v.label, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
+                // Bring the area onto the page, then the target row into the area
+                let (vp_w, vp_h) = crate::primitives::viewport_size();
+                let goal = children.iter()
+                    .flat_map(|c| c.walk())
+                    .find(|n| n.visual().is_target)
+                    .map(|n| n.visual());
+                if let Some(goal) = goal {
+                    if !Rect::new(0.0, 0.0, vp_w, vp_h).contains(&vt.local(&v.rect)) {
+                        steps.push(Action::scroll(&v.label));
+                        think.push(t("think.scroll_page", &[&v.label, &pos]));
+                    }
+                    if !v.rect.contains(&goal.rect) {
+                        steps.push(Action::scroll_within(&goal.label, &v.label));
+                        think.push(t("think.scroll_within", &[&goal.label, &v.label]));
+                    }
+                }
+                self.restyle_thinking(think, think_start, &steps[steps_start..], opts);
+                let ctx = Some(("list", &v.rect));
+                for child in children {
+                    child.resolve_inner(desc, steps, think, targets, ctx, opts);
+                }
+            }
+
+            UINode::Article(v, state, children) => {
+                desc.push(t("desc.article", &[&v.label, &pos]));
+                targets.push((v.label.clone(), v.rect, kind));
+                // An off-screen target is reached by scrolling to it or by
+                // following its section's contents link; one plan becomes the
+                // steps and the other an alternative
+                let (vp_w, vp_h) = crate::primitives::viewport_size();
+                let goal = children.iter()
+                    .flat_map(|c| c.walk())
+                    .find(|n| n.visual().is_target)
+                    .map(|n| n.visual())
+                    .filter(|g| !Rect::new(0.0, 0.0, vp_w, vp_h).contains(&vt.local(&g.rect)));
+                if let Some(goal) = goal {
+                    let scroll = ("scroll", Action::scroll(&goal.label), t("think.article_scroll", &[&goal.label, &v.label]));
+                    let link = state.toc.iter()
+                        .find(|(_, section)| section.contains(&goal.rect))
+                        .map(|(link, _)| ("toc", Action::click(link), t("think.article_toc", &[&goal.label, link])));
+                    let (main, other) = match link {
+                        Some(link) if state.via_toc => (link, Some(scroll)),
+                        Some(link) => (scroll, Some(link)),
+                        None => (scroll, None),
+                    };
+                    if let Some((name, action, thinking)) = other {
+                        self.offer(Detour { main: main.0, name, at: steps.len(), replaces: 1, steps: vec![action], thinking }, opts);
+                    }
+                    steps.push(main.1);
+                    think.push(main.2);
+                }
+                self.restyle_thinking(think, think_start, &steps[steps_start..], opts);
+                let ctx = Some(("article", &v.rect));
+                for child in children {
+                    child.resolve_inner(desc, steps, think, targets, ctx, opts);
+                }
+            }
+
+            UINode::Loader(v, state, children) => {
+                if state.loaded {
+                    desc.push(t("desc.loader_done", &[&v.label, &pos]));
+                    let ctx = Some(("section", &v.rect));
+                    for child in children {
+                        child.resolve_inner(desc, steps, think, targets, ctx, opts);
+                    }
+                } else {
+                    desc.push(t("desc.loader", &[&v.label, &pos]));
+                    // Wait for the first target to appear, then play the content's steps
+                    let until = children.iter()
+                        .flat_map(|c| c.walk())
+                        .find(|n| n.visual().is_target)
+                        .map(|n| n.visual().label.clone());
+                    steps.push(Action::wait(state.remaining_ms, until));
+                    think.push(t("think.loader", &[&v.label, &pos, &state.remaining_ms]));
+                    resolve_hidden(children, steps, opts);
+                    let clicks = clicked_labels(&steps[steps_start..]);
+                    if !clicks.is_empty() {
+                        think.push(t("think.loader_then", &[&clicks]));
+                    }
+                    self.restyle_thinking(think, think_start, &steps[steps_start..], opts);
+                }
             }
         }
+
+        // Named by a neighbour: say where the target sits against it, before its own thought
+        if let (true, Some((anchor, anchor_rect))) = (v.is_target, &v.relative_to)
+            && let Some((rel, gap)) = v.rect.relation_to(anchor_rect)
+        {
+            let relation = t(&format!("relation.{}", rel.name()), &[]);
+            let (ax, ay, aw, ah) = vt.apply(anchor_rect);
+            let anchor_pos = t("pos.near", &[
+                &t(&format!("region.{}", vt.local(anchor_rect).region()), &[]),
+                &format!("{},{} {}x{}", ax, ay, aw, ah),
+            ]);
+            let gap = gap.round() as i32;
+            desc.push(t("desc.relative", &[&v.label, &gap, &relation, anchor]));
+            think.insert(think_start, t("think.relative", &[anchor, &anchor_pos, &relation, &gap]));
+        }
+
+        // Leaf thoughts are restyled as a unit; containers did their own above
+        if !matches!(self, UINode::Card(..) | UINode::Form(..) | UINode::Modal(..) | UINode::Loader(..) | UINode::Pane(..) | UINode::ScrollArea(..) | UINode::Article(..) | UINode::Accordion(..)) {
+            self.restyle_thinking(think, think_start, &steps[steps_start..], opts);
+        }
+    }
+}
+
+/// Resolve `children` for their steps alone. They aren't on screen yet, so
+/// their descriptions, thoughts and boxes are dropped.
+fn resolve_hidden(children: &[UINode], steps: &mut Vec<Action>, opts: &ResolveOpts) {
+    let (mut desc, mut think, mut targets) = (Vec::new(), Vec::new(), Vec::new());
+    for child in children {
+        child.resolve_inner(&mut desc, steps, &mut think, &mut targets, None, opts);
     }
 }
 
+/// Quoted, comma-separated targets of the click steps in `steps`.
+pub(super) fn clicked_labels(steps: &[Action]) -> String {
+    steps.iter()
+        .filter_map(|a| match a {
+            Action::Click { target } => Some(format!("\"{}\"", target)),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// When a container has target DragSource(s) and DropZone(s), emit drag steps.
 fn emit_drag_pairs(children: &[UINode], steps: &mut Vec<Action>) {
     let mut drop_label = None;
@@ -404,25 +996,99 @@ fn emit_drag_pairs(children: &[UINode], steps: &mut Vec<Action>) {
     }
 }
 
-/// Helper: turns a color string into a prefix like "green " or empty string.
+/// Helper: turns a color string into the language's colour affix — a prefix
+/// like "green " in English, a suffix like " verde" in Spanish — or "".
 /// Accepts either english names ("green") or hex codes ("#4f46e5").
-fn color_prefix(color: &str) -> String {
+pub(super) fn color_affix(color: &str, lang: Lang) -> String {
     if color.is_empty() {
-        String::new()
-    } else if color.starts_with('#') {
+        return String::new();
+    }
+    let name = if color.starts_with('#') {
         // Map common hex codes to english names
-        let name = match color {
-            "#4f46e5" | "#7c3aed" => "indigo ",
-            "#2563eb" => "blue ",
-            "#0891b2" | "#0d9488" => "teal ",
-            "#059669" => "green ",
-            "#d97706" | "#ea580c" => "orange ",
-            "#dc2626" | "#ef4444" => "red ",
-            "#db2777" => "pink ",
-            _ => "",
-        };
-        name.to_string()
+        match color {
+            "#4f46e5" | "#7c3aed" => "indigo",
+            "#2563eb" => "blue",
+            "#0891b2" | "#0d9488" => "teal",
+            "#059669" => "green",
+            "#d97706" | "#ea580c" => "orange",
+            "#dc2626" | "#ef4444" => "red",
+            "#db2777" => "pink",
+            _ => return String::new(),
+        }
     } else {
-        format!("{} ", color)
+        color
+    };
+    match text(lang, &format!("color.{}", name)) {
+        "" => format!("{} ", name),
+        affix => affix.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether playing `steps` in this order keeps every edge.
+    fn allows(order: &StepOrder, steps: &[usize]) -> bool {
+        let pos = |i: usize| steps.iter().position(|&s| s == i).unwrap();
+        order.edges.iter().all(|&(a, b)| pos(a) < pos(b))
+    }
+
+    #[test]
+    fn form_fields_play_in_any_order_before_submit() {
+        // 0 opens the form, fields are [1, 2], [3, 4] and [5], 6 submits
+        let order = StepOrder::new(7, vec![vec![1..3, 3..5, 5..6]]);
+        assert_eq!(order.groups, vec![vec![vec![1, 2], vec![3, 4], vec![5]]]);
+        assert_eq!(order.edges, vec![(0, 1), (0, 3), (0, 5), (1, 2), (2, 6), (3, 4), (4, 6), (5, 6)]);
+        assert!(allows(&order, &[0, 1, 2, 3, 4, 5, 6]));
+        assert!(allows(&order, &[0, 5, 3, 4, 1, 2, 6]));
+        assert!(!allows(&order, &[0, 2, 1, 3, 4, 5, 6]), "a field's own steps stay in order");
+        assert!(!allows(&order, &[0, 1, 2, 3, 4, 6, 5]), "submit comes last");
+        assert!(!allows(&order, &[1, 0, 2, 3, 4, 5, 6]), "the form opens first");
+    }
+
+    #[test]
+    fn nested_form_is_one_block_of_the_outer_form() {
+        // Outer fields [0] and the inner form [1..4]: fields [1], [2], submit 3; 4 submits the outer
+        let order = StepOrder::new(5, vec![vec![1..2, 2..3], vec![0..1, 1..4]]);
+        assert_eq!(order.edges, vec![(0, 4), (1, 3), (2, 3), (3, 4)]);
+        assert!(allows(&order, &[0, 1, 2, 3, 4]));
+        assert!(allows(&order, &[2, 1, 3, 0, 4]));
+        assert!(!allows(&order, &[0, 1, 3, 2, 4]), "inner submit after its fields");
+        assert!(!allows(&order, &[0, 1, 2, 4, 3]), "outer submit last");
+    }
+
+    #[test]
+    fn only_unordered_forms_free_their_fields() {
+        let fields = || vec![
+            text_input("Name", Rect::new(0.0, 0.0, 100.0, 30.0), "", "Ada"),
+            text_input("City", Rect::new(0.0, 40.0, 100.0, 30.0), "", "Oslo"),
+        ];
+        let rect = Rect::new(0.0, 0.0, 200.0, 120.0);
+        assert!(form(rect, "Submit", fields()).resolve().order.groups.is_empty());
+        assert_eq!(form(rect, "Submit", fields()).unordered().resolve().order.groups.len(), 1);
+    }
+
+    #[test]
+    fn checked_target_checkbox_is_left_alone() {
+        let r = Rect::new(0.0, 0.0, 20.0, 20.0);
+        assert_eq!(checkbox("Terms", r, false).resolve().steps, vec![Action::click("Terms")]);
+        assert!(checkbox("Terms", r, true).resolve().steps.is_empty());
+    }
+
+    #[test]
+    fn revealed_content_is_placed_within_the_section_body() {
+        let header = Rect::new(0.0, 0.0, 300.0, 48.0);
+        let body = Rect::new(0.0, 48.0, 300.0, 96.0);
+        let tree = accordion_with("Refunds", header, body, vec![target_button("Request", Rect::new(0.0, 56.0, 150.0, 32.0))]);
+        let gt = tree.resolve();
+        assert!(gt.description.contains("top-left of the section"), "{}", gt.description);
+    }
+
+    #[test]
+    fn sequence_without_groups_is_a_chain() {
+        let order = StepOrder::new(3, Vec::new());
+        assert_eq!(order.edges, vec![(0, 1), (1, 2)]);
+        assert!(!allows(&order, &[1, 0, 2]));
     }
 }
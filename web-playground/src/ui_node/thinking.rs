@@ -0,0 +1,181 @@
+//! Thinking styles — alternative renderings of the resolve thinking chain.
+//!
+//! `resolve` always composes the verbose sentence for a node; the other styles
+//! are derived from it here, per UINode variant, after the node's steps are
+//! known.
+
+use super::*;
+use super::resolve::{clicked_labels, ResolveOpts};
+
+/// How the thinking chain is written. `Verbose` is the original style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThinkingStyle {
+    /// One short imperative per node ("Type \"Ada\" into \"Name\".").
+    Terse,
+    /// Full sentences describing what is seen and why.
+    #[default]
+    Verbose,
+    /// Explicit coordinate reasoning (extent and centre) before each verbose sentence.
+    ChainOfThought,
+    /// No thinking at all.
+    None,
+}
+
+impl ThinkingStyle {
+    /// Parse a `?thinking=` value: "terse", "verbose", "cot", "none".
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "terse" => Some(Self::Terse),
+            "verbose" => Some(Self::Verbose),
+            "cot" | "chain-of-thought" => Some(Self::ChainOfThought),
+            "none" | "off" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Terse => "terse",
+            Self::Verbose => "verbose",
+            Self::ChainOfThought => "cot",
+            Self::None => "none",
+        }
+    }
+}
+
+impl UINode {
+    /// Rewrite the verbose thoughts this node pushed (`think[from..]`) in
+    /// `opts.style`. `steps` are the actions the node emitted alongside them.
+    pub(super) fn restyle_thinking(
+        &self,
+        think: &mut Vec<String>,
+        from: usize,
+        steps: &[Action],
+        opts: &ResolveOpts,
+    ) {
+        if from >= think.len() || opts.style == ThinkingStyle::Verbose {
+            return;
+        }
+        let verbose = think.split_off(from).join(" ");
+        let styled = match opts.style {
+            ThinkingStyle::Verbose => Some(verbose),
+            ThinkingStyle::None => None,
+            ThinkingStyle::Terse => self.terse_thought(steps, opts.lang),
+            ThinkingStyle::ChainOfThought => {
+                Some(format!("{} {}", self.coordinate_thought(opts.vt, opts.lang), verbose))
+            }
+        };
+        think.extend(styled);
+    }
+
+    /// Explicit extent/centre reasoning for this node's box.
+    fn coordinate_thought(&self, vt: &ViewportTransform, lang: Lang) -> String {
+        let v = self.visual();
+        let (x, y, w, h) = vt.apply(&v.rect);
+        tr(lang, "cot.extent", &[
+            &self.kind().replace('_', " "), &v.label, &x, &(x + w), &y, &(y + h), &(x + w / 2), &(y + h / 2),
+        ])
+    }
+
+    /// Per-variant terse template.
+    fn terse_thought(&self, steps: &[Action], lang: Lang) -> Option<String> {
+        let t = |key: &str, args: &[&dyn std::fmt::Display]| tr(lang, key, args);
+        let on_off = |on: bool| t(if on { "state.on" } else { "state.off" }, &[]);
+        let label = &self.visual().label;
+        let thought = match self {
+            UINode::Button(_) | UINode::ModalButton(_) | UINode::Tab(_) => t("terse.click", &[label]),
+            UINode::Toggle(_, s) if steps.is_empty() => t("terse.toggle_keep", &[label, &on_off(s.is_on)]),
+            UINode::Toggle(_, s) => t("terse.toggle", &[label, &on_off(s.target_on)]),
+            UINode::Checkbox(..) if steps.is_empty() => t("terse.checkbox_keep", &[label]),
+            UINode::Checkbox(..) => t("terse.checkbox", &[label]),
+            UINode::Accordion(..) => t("terse.accordion", &[label]),
+            UINode::Tag(..) if steps.is_empty() => return None,
+            UINode::Tag(..) => t("terse.tag", &[label]),
+            UINode::Toast(_, s) => t("terse.toast", &[&s.message]),
+            UINode::Star(_, s) => t("terse.star", &[&s.target, label]),
+            UINode::TextInput(_, s) => t("terse.type", &[&s.target_value, label]),
+            UINode::TextSelection(_, s) => t("terse.select_text", &[&s.target_text, label]),
+            UINode::Slider(_, s) if s.native => t("terse.slider_native", &[label, &s.target_val]),
+            UINode::Slider(_, s) => t("terse.slider", &[label, &s.target_val]),
+            UINode::DragSource(_) => t("terse.drag", &[label]),
+            UINode::DropZone(_) => return None,
+            UINode::Dropdown(_, s) => match s.mode {
+                DropdownMode::Click => t("terse.dropdown", &[label, &s.target_option]),
+                DropdownMode::Search => t("terse.dropdown_search", &[label, &s.search_query(), &s.target_option]),
+                DropdownMode::Keys => {
+                    let (key, n) = s.arrow_presses();
+                    t("terse.dropdown_keys", &[label, &key, &n])
+                }
+            },
+            UINode::ContextMenu(_, s) => t("terse.context_menu", &[&s.trigger_label, &s.target_item]),
+            UINode::Stepper(..) => match steps.first() {
+                Some(Action::Click { target }) => t("terse.stepper", &[target, &steps.len()]),
+                _ => t("terse.keep", &[label]),
+            },
+            UINode::RadioGroup(_, s) => t("terse.radio", &[&s.options[s.target_option]]),
+            UINode::CommandPalette(_, s) => t("terse.palette", &[&s.hotkey, &s.target_command]),
+            UINode::TileGrid(..) => {
+                let tiles: Vec<String> = steps.iter()
+                    .filter_map(|a| match a {
+                        Action::Click { target } => Some(format!("\"{}\"", target)),
+                        _ => None,
+                    })
+                    .collect();
+                t("terse.tiles", &[&tiles.join(", ")])
+            }
+            UINode::CheckboxGroup(..) => {
+                let boxes: Vec<String> = steps.iter()
+                    .filter_map(|a| match a {
+                        Action::Click { target } => Some(format!("\"{}\"", target)),
+                        _ => None,
+                    })
+                    .collect();
+                t("terse.checkbox_group", &[&boxes.join(", ")])
+            }
+            UINode::Carousel(_, s) if s.current == s.target_slide => return None,
+            UINode::Carousel(_, s) if s.nav == CarouselNav::Auto => t("terse.carousel_auto", &[&(s.target_slide + 1)]),
+            UINode::Carousel(_, s) => t("terse.carousel", &[&(s.target_slide + 1)]),
+            UINode::Tooltip(_, s) if steps.is_empty() => t("terse.tooltip_open", &[label, &s.text]),
+            UINode::Tooltip(_, s) => t("terse.tooltip_hover", &[label, &s.text]),
+            UINode::Shape(_, s) => t("terse.shape", &[&s.phrase(lang)]),
+            UINode::IconButton(_, s) => t("terse.icon_button", &[&t(&format!("glyph.{}", s.glyph), &[]), label]),
+            UINode::KeyboardForm(_, s) => t("terse.keyboard_form", &[&s.submit_key]),
+            UINode::SortableList(..) if steps.is_empty() => return None,
+            UINode::SortableList(_, s) => t("terse.sortable", &[&s.target_item, &(s.target_pos + 1)]),
+            UINode::FileDragTask(_, s) => match s.files.get(s.target) {
+                Some(f) => t("terse.file_drag", &[&f.label, &s.zone.label]),
+                None => return None,
+            },
+            // Containers only speak for their own submit click
+            UINode::Card(..) => return None,
+            UINode::Form(_, s, _) if !steps.is_empty() => t("terse.click", &[&s.submit_label]),
+            UINode::Form(..) => return None,
+            UINode::Modal(_, s, _) if !s.open && !steps.is_empty() => {
+                t("terse.modal_closed", &[&s.title, &clicked_labels(steps)])
+            }
+            UINode::Modal(..) => return None,
+            UINode::Loader(_, s, _) if !s.loaded => t("terse.loader", &[label, &s.remaining_ms]),
+            UINode::Loader(..) => return None,
+            UINode::Pane(..) => return None,
+            UINode::ScrollArea(..) if steps.is_empty() => return None,
+            UINode::ScrollArea(..) => steps.iter()
+                .filter_map(|a| match a {
+                    Action::Scroll { target, within: None } => Some(t("terse.scroll_page", &[target])),
+                    Action::Scroll { target, within: Some(c) } => Some(t("terse.scroll_within", &[target, c])),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+            UINode::Article(..) if steps.is_empty() => return None,
+            UINode::Article(..) => steps.iter()
+                .filter_map(|a| match a {
+                    Action::Scroll { target, .. } => Some(t("terse.scroll_page", &[target])),
+                    Action::Click { target } => Some(t("terse.click", &[target])),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+        Some(thought)
+    }
+}
@@ -0,0 +1,47 @@
+//! WebSocket bridge — drive the playground from an external agent loop.
+//!
+//! With `?ws=ws://host:port` (or `window.__wsUrl` set before load) the page
+//! connects to that socket and serves the same commands as the postMessage
+//! API (`navigate`, `act`, `observe`, `reset`, ...). Each incoming text frame
+//! is `{ id, command, args }`; each reply is `{ id, ok, result }` or
+//! `{ id, ok: false, error }`. On connect the page sends
+//! `{ type: 'hello', path, commands }`. Dropped connections are retried with
+//! backoff up to 30 s.
+
+pub const WS_JS: &str = r#"
+    if (!window.__wsBridge) {
+        const url = new URLSearchParams(location.search).get('ws') || window.__wsUrl;
+        if (url && window.__control) {
+            const bridge = window.__wsBridge = { url, socket: null, connected: false, retryMs: 1000 };
+            const connect = () => {
+                const ws = new WebSocket(url);
+                bridge.socket = ws;
+                ws.onopen = () => {
+                    bridge.connected = true;
+                    bridge.retryMs = 1000;
+                    ws.send(JSON.stringify({
+                        type: 'hello',
+                        path: location.pathname + location.search,
+                        commands: Object.keys(window.__control.commands),
+                    }));
+                };
+                ws.onmessage = async (e) => {
+                    let d;
+                    try { d = JSON.parse(e.data); } catch {
+                        ws.send(JSON.stringify({ id: null, ok: false, error: 'message is not JSON' }));
+                        return;
+                    }
+                    const msg = await window.__control.run(d.command, d.args);
+                    if (ws.readyState === WebSocket.OPEN) ws.send(JSON.stringify({ id: d.id ?? null, ...msg }));
+                };
+                ws.onclose = () => {
+                    bridge.connected = false;
+                    setTimeout(connect, bridge.retryMs);
+                    bridge.retryMs = Math.min(bridge.retryMs * 2, 30000);
+                };
+            };
+            connect();
+            console.log('ws: bridging to', url);
+        }
+    }
+"#;